@@ -0,0 +1,132 @@
+use colorgrad::{Gradient, GradientBuilder, LinearGradient};
+use dioxus::prelude::*;
+use lib_rpg::common::log_data::const_colors::{DARK_RED, LIGHT_BLUE, LIGHT_GREEN};
+use once_cell::sync::Lazy;
+
+/// `user_settings` keys of the accessibility options (Settings sheet).
+pub const SETTING_COLOR_PALETTE: &str = "color_palette";
+pub const SETTING_REDUCED_MOTION: &str = "reduced_motion";
+
+/// Colour sets for bars, combat logs and the damage/heal texts. The colour-blind
+/// palettes avoid the red/green pairs the default one relies on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorPalette {
+    #[default]
+    Default,
+    Deuteranopia,
+    Protanopia,
+    HighContrast,
+}
+
+impl ColorPalette {
+    pub const ALL: [ColorPalette; 4] = [
+        ColorPalette::Default,
+        ColorPalette::Deuteranopia,
+        ColorPalette::Protanopia,
+        ColorPalette::HighContrast,
+    ];
+
+    /// Value saved in `user_settings` and set as `<html data-palette>` for main.css.
+    pub fn key(self) -> &'static str {
+        match self {
+            ColorPalette::Default => "default",
+            ColorPalette::Deuteranopia => "deuteranopia",
+            ColorPalette::Protanopia => "protanopia",
+            ColorPalette::HighContrast => "high-contrast",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|p| p.key() == key)
+            .unwrap_or_default()
+    }
+
+    /// Bar colours from empty to full.
+    fn bar_stops(self) -> [&'static str; 3] {
+        match self {
+            ColorPalette::Default => ["#ff2600ff", "#f2ff00ff", "#11c426ff"],
+            // vermillion → yellow → blue (Okabe-Ito)
+            ColorPalette::Deuteranopia => ["#d55e00", "#f0e442", "#0072b2"],
+            // orange → gold → light blue (IBM colour-blind safe)
+            ColorPalette::Protanopia => ["#fe6100", "#ffb000", "#648fff"],
+            ColorPalette::HighContrast => ["#ff4040", "#ffff00", "#00ffff"],
+        }
+    }
+
+    /// Damage, heal and event log colours replacing lib-rpg's `DARK_RED`,
+    /// `LIGHT_GREEN` and `LIGHT_BLUE`.
+    fn log_colors(self) -> Option<[&'static str; 3]> {
+        match self {
+            ColorPalette::Default => None,
+            ColorPalette::Deuteranopia => Some(["#e69f00", "#56b4e9", "#cc79a7"]),
+            ColorPalette::Protanopia => Some(["#fe6100", "#648fff", "#dc267f"]),
+            ColorPalette::HighContrast => Some(["#ff6060", "#00ffff", "#ffff00"]),
+        }
+    }
+}
+
+static BAR_GRADIENTS: Lazy<Vec<LinearGradient>> = Lazy::new(|| {
+    ColorPalette::ALL
+        .iter()
+        .map(|p| {
+            GradientBuilder::new()
+                .html_colors(&p.bar_stops())
+                .build::<LinearGradient>()
+                .expect("Failed to build gradient")
+        })
+        .collect()
+});
+
+/// Colour of a bar filled at `percent` (0-100).
+pub fn bar_color(palette: ColorPalette, percent: i32) -> String {
+    BAR_GRADIENTS[palette as usize]
+        .at(percent as f32 / 100.0)
+        .to_css_hex()
+}
+
+/// Colour to display a `LogData.color` with. Colours outside the damage/heal/event
+/// trio are kept as is.
+pub fn log_color(palette: ColorPalette, color: &str) -> String {
+    let Some([damage, heal, event]) = palette.log_colors() else {
+        return color.to_owned();
+    };
+    match color {
+        c if c == DARK_RED => damage.to_owned(),
+        c if c == LIGHT_GREEN => heal.to_owned(),
+        c if c == LIGHT_BLUE => event.to_owned(),
+        c => c.to_owned(),
+    }
+}
+
+/// Mirrors the palette and reduced-motion settings on `<html>` so main.css can
+/// swap its colour variables and switch animations off.
+pub fn apply_to_document(palette: ColorPalette, reduced_motion: bool) {
+    let key = palette.key();
+    document::eval(&format!(
+        "document.documentElement.setAttribute('data-palette', '{key}'); \
+         document.documentElement.toggleAttribute('data-reduced-motion', {reduced_motion});"
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palette_keys_round_trip() {
+        for palette in ColorPalette::ALL {
+            assert_eq!(ColorPalette::from_key(palette.key()), palette);
+        }
+        assert_eq!(ColorPalette::from_key("sepia"), ColorPalette::Default);
+    }
+
+    #[test]
+    fn log_colors_are_remapped_except_in_default_palette() {
+        assert_eq!(log_color(ColorPalette::Default, DARK_RED), DARK_RED);
+        assert_eq!(log_color(ColorPalette::Deuteranopia, DARK_RED), "#e69f00");
+        assert_eq!(log_color(ColorPalette::Protanopia, LIGHT_GREEN), "#648fff");
+        assert_eq!(log_color(ColorPalette::HighContrast, "#123456"), "#123456");
+    }
+}
@@ -0,0 +1,158 @@
+//! Data-driven achievements. Each JSON file of `offlines/achievements/` lists
+//! achievements, each one unlocked when a per-player counter of game events
+//! (scenarios won, critical hits, gold held…) reaches its target. The counters
+//! and unlocks live in SQLite (see `server_fn/achievements.rs`); the game events
+//! are picked up in `websocket_handler/event_achievements.rs`.
+
+use std::collections::BTreeMap;
+
+use crate::locales::LocalizedText;
+
+/// Directory under `OFFLINE_PATH` holding the achievement JSON files.
+pub const ACHIEVEMENTS_DIR: &str = "achievements";
+
+/// Game event counted towards achievements.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub enum AchievementKind {
+    /// Scenarios won
+    ScenarioCompleted,
+    /// Universes won to the last scenario (`GameStatus::EndOfGame`)
+    GameWon,
+    /// Critical hits landed by the player's heroes
+    CriticalHit,
+    /// Scenarios won without any hero falling
+    FlawlessFight,
+    /// Talent paths with every talent unlocked
+    TalentPathCompleted,
+    /// Most gold held at once by one of the player's heroes
+    GoldHeld,
+    /// Overworld boss NPCs defeated
+    BossDefeated,
+}
+
+impl AchievementKind {
+    pub const ALL: [AchievementKind; 7] = [
+        AchievementKind::ScenarioCompleted,
+        AchievementKind::GameWon,
+        AchievementKind::CriticalHit,
+        AchievementKind::FlawlessFight,
+        AchievementKind::TalentPathCompleted,
+        AchievementKind::GoldHeld,
+        AchievementKind::BossDefeated,
+    ];
+
+    /// Name of the kind in the JSON files and the `achievement_counters` table.
+    pub fn key(self) -> String {
+        format!("{self:?}")
+    }
+
+    pub fn from_key(key: &str) -> Option<AchievementKind> {
+        AchievementKind::ALL.into_iter().find(|k| k.key() == key)
+    }
+
+    /// Whether the counter adds up the events, or keeps the highest value seen.
+    pub fn accumulates(self) -> bool {
+        self != AchievementKind::GoldHeld
+    }
+
+    /// `counter` once `amount` is recorded.
+    pub fn apply(self, counter: i64, amount: i64) -> i64 {
+        if self.accumulates() {
+            counter + amount
+        } else {
+            counter.max(amount)
+        }
+    }
+}
+
+/// One achievement of `offlines/achievements/*.json`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct AchievementDef {
+    pub id: String,
+    #[serde(default)]
+    pub icon: String,
+    pub name: LocalizedText,
+    #[serde(default)]
+    pub description: LocalizedText,
+    pub kind: AchievementKind,
+    /// Counter value unlocking the achievement
+    pub target: i64,
+}
+
+/// An achievement as seen by one player.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct AchievementProgress {
+    pub def: AchievementDef,
+    /// Counter of the achievement's kind, capped at its target
+    pub progress: i64,
+    /// `None` while locked
+    pub unlocked_at: Option<String>,
+}
+
+/// Achievements of `defs` the `counters` reach that are not in `unlocked` yet.
+pub fn newly_unlocked<'a>(
+    defs: &'a [AchievementDef],
+    counters: &BTreeMap<AchievementKind, i64>,
+    unlocked: &[String],
+) -> Vec<&'a AchievementDef> {
+    defs.iter()
+        .filter(|d| !unlocked.contains(&d.id))
+        .filter(|d| counters.get(&d.kind).is_some_and(|c| *c >= d.target))
+        .collect()
+}
+
+/// Unlocked achievements first (newest first), then the locked ones closest to
+/// their target.
+pub fn sort_for_display(achievements: &mut [AchievementProgress]) {
+    achievements.sort_by(|a, b| {
+        b.unlocked_at.cmp(&a.unlocked_at).then_with(|| {
+            let ratio = |p: &AchievementProgress| p.progress * 1000 / p.def.target.max(1);
+            ratio(b).cmp(&ratio(a))
+        })
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn def(id: &str, kind: AchievementKind, target: i64) -> AchievementDef {
+        AchievementDef {
+            id: id.to_owned(),
+            icon: String::new(),
+            name: LocalizedText::from_en_fr("", id, ""),
+            description: LocalizedText::default(),
+            kind,
+            target,
+        }
+    }
+
+    #[test]
+    fn unlocks_once_the_target_is_reached() {
+        let defs = [
+            def("first-win", AchievementKind::ScenarioCompleted, 1),
+            def("veteran", AchievementKind::ScenarioCompleted, 10),
+            def("rich", AchievementKind::GoldHeld, 500),
+        ];
+        let mut counters = BTreeMap::new();
+        counters.insert(
+            AchievementKind::ScenarioCompleted,
+            AchievementKind::ScenarioCompleted.apply(0, 1),
+        );
+        counters.insert(
+            AchievementKind::GoldHeld,
+            AchievementKind::GoldHeld.apply(600, 200),
+        );
+        let ids = |u: Vec<&AchievementDef>| u.iter().map(|d| d.id.clone()).collect::<Vec<_>>();
+        assert_eq!(
+            ids(newly_unlocked(&defs, &counters, &[])),
+            ["first-win", "rich"]
+        );
+        assert_eq!(
+            ids(newly_unlocked(&defs, &counters, &["first-win".to_owned()])),
+            ["rich"]
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! Arena mode. A player registers the party of one of their saves; another
+//! player's party is then picked by rating to fight it. The attacker plays their
+//! party live while the defender's heroes fight as bosses, driven by the boss AI.
+//! Ratings follow the Elo system. The server side is in
+//! `websocket_handler/event_arena.rs`, the tables in `server_fn/arena.rs`.
+
+use rand::{SeedableRng, rngs::StdRng, seq::IndexedRandom};
+
+/// Rating of a newly registered party.
+pub const ARENA_START_RATING: i64 = 1000;
+/// Most rating points a match can move.
+pub const ARENA_ELO_K: f64 = 32.0;
+/// Opponents with the closest ratings, one of them being drawn.
+pub const ARENA_MATCHMAKING_WINDOW: usize = 5;
+/// Name of the scenario of a match.
+pub const ARENA_SCENARIO: &str = "🏟️ Arena";
+
+/// Match played on a server, from matchmaking to its result.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ArenaMatch {
+    pub attacker: String,
+    pub attacker_rating: i64,
+    pub defender: String,
+    pub defender_rating: i64,
+    /// Names the defender's heroes fight under, see `defender_name`
+    pub defender_heroes: Vec<String>,
+}
+
+/// Name of the defender's `hero` as a boss, apart from the attacker's heroes
+/// even when both parties have the same ones.
+pub fn defender_name(defender: &str, hero: &str) -> String {
+    format!("{hero} ({defender})")
+}
+
+/// Chances of a party rated `rating` to beat one rated `other`, from 0 to 1.
+pub fn expected_score(rating: i64, other: i64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((other - rating) as f64 / 400.0))
+}
+
+/// Rating changes of the attacker and the defender after a match.
+pub fn elo_changes(attacker: i64, defender: i64, attacker_won: bool) -> (i64, i64) {
+    let score = if attacker_won { 1.0 } else { 0.0 };
+    let change = (ARENA_ELO_K * (score - expected_score(attacker, defender))).round() as i64;
+    (change, -change)
+}
+
+/// Opponent for a party rated `rating`, drawn with `seed` among the
+/// `ARENA_MATCHMAKING_WINDOW` `candidates` (name, rating) closest to it.
+pub fn pick_opponent(rating: i64, candidates: &[(String, i64)], seed: u64) -> Option<String> {
+    let mut closest: Vec<&(String, i64)> = candidates.iter().collect();
+    closest.sort_by(|a, b| {
+        (a.1 - rating)
+            .abs()
+            .cmp(&(b.1 - rating).abs())
+            .then_with(|| a.0.cmp(&b.0))
+    });
+    closest.truncate(ARENA_MATCHMAKING_WINDOW);
+    closest
+        .choose(&mut StdRng::seed_from_u64(seed))
+        .map(|(name, _)| name.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elo_rewards_upsets_more() {
+        assert_eq!(expected_score(1000, 1000), 0.5);
+        assert_eq!(elo_changes(1000, 1000, true), (16, -16));
+        assert_eq!(elo_changes(1000, 1000, false), (-16, 16));
+
+        // Beating a stronger party is worth more than beating a weaker one
+        let (upset, _) = elo_changes(1000, 1400, true);
+        let (expected, _) = elo_changes(1400, 1000, true);
+        assert!(upset > expected);
+        assert!(expected > 0);
+        let (loss, gain) = elo_changes(1400, 1000, false);
+        assert_eq!(loss, -gain);
+        assert!(loss < -16);
+    }
+
+    #[test]
+    fn opponents_come_from_close_ratings() {
+        let candidates: Vec<(String, i64)> = [
+            ("far", 2000),
+            ("a", 1010),
+            ("b", 990),
+            ("c", 1050),
+            ("d", 950),
+            ("e", 1100),
+            ("g", 1500),
+        ]
+        .iter()
+        .map(|(name, rating)| (name.to_string(), *rating))
+        .collect();
+        for seed in 0..20 {
+            let opponent = pick_opponent(1000, &candidates, seed).unwrap();
+            assert!(!["far", "g"].contains(&opponent.as_str()));
+            assert_eq!(pick_opponent(1000, &candidates, seed), Some(opponent));
+        }
+        assert_eq!(pick_opponent(1000, &[], 3), None);
+        assert_eq!(defender_name("alice", "Elara"), "Elara (alice)");
+    }
+}
@@ -1,7 +1,12 @@
+use std::collections::BTreeMap;
+
 use dioxus::prelude::*;
 use lib_rpg::common::sound_cue::SoundCue;
 
-use crate::common::CtxAudioSettings;
+use crate::{
+    common::{CtxAudioSettings, music_src, sfx_src},
+    music::MusicSource,
+};
 
 const MUSIC_HOME: Asset = asset!("/assets/audio/music/home.ogg");
 const MUSIC_OVERWORLD: Asset = asset!("/assets/audio/music/overworld.ogg");
@@ -14,9 +19,28 @@ const SFX_HEAL: Asset = asset!("/assets/audio/sfx/heal.ogg");
 const SFX_POTION: Asset = asset!("/assets/audio/sfx/potion.ogg");
 const SFX_VICTORY: Asset = asset!("/assets/audio/sfx/victory.ogg");
 const SFX_GAMEOVER: Asset = asset!("/assets/audio/sfx/gameover.ogg");
+const SFX_BOSS_ENTRANCE: Asset = asset!("/assets/audio/sfx/boss_entrance.wav");
+const SFX_LEVEL_UP: Asset = asset!("/assets/audio/sfx/level_up.wav");
+
+/// Extensions accepted for uploaded music tracks and sound effects.
+pub const AUDIO_EXTENSIONS: [&str; 4] = ["ogg", "mp3", "opus", "wav"];
 
-/// Looping background tracks. Which one (if any) should be playing is decided in
-/// `Navbar` from the current `GamePhase` (see its music-transition effect).
+/// Whether `name` is a plain audio file name (no path, supported extension).
+pub fn is_audio_file_name(name: &str) -> bool {
+    let ext = std::path::Path::new(name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    !name.contains("..")
+        && !name.contains('/')
+        && !name.contains('\\')
+        && AUDIO_EXTENSIONS.contains(&ext.as_str())
+}
+
+/// Looping background tracks bundled with the app: the fallbacks of the
+/// data-driven music (see `music.rs`). Which track (if any) should be playing is
+/// decided in `Navbar` from the current `GamePhase` (see its music-transition effect).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MusicTrack {
     Home,
@@ -32,6 +56,9 @@ impl MusicTrack {
     }
 }
 
+/// Duration of the fade between two background tracks, or to silence.
+const CROSSFADE_MS: u32 = 1500;
+
 fn sfx_asset(cue: SoundCue) -> Asset {
     match cue {
         SoundCue::Hit => SFX_HIT,
@@ -45,12 +72,70 @@ fn sfx_asset(cue: SoundCue) -> Asset {
     }
 }
 
-/// Injects the JS audio bridge once: a persistent looping `<audio>` element for
-/// music, plus a `playSfx` helper that fires a fresh `Audio()` per call so
-/// overlapping one-shots don't cut each other off. Call once from `App()`, the
-/// same way the theme/viewport `document::eval` calls in `main.rs` are — this
-/// works uniformly across web, desktop (tao/wry webview), and mobile (Android
-/// webview) since all three render through a browser engine.
+/// A one-shot sound effect: a combat cue, one of the app's own cues, or an
+/// attack's uploaded effect served from `/sfx-srv/`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Sfx {
+    Cue(SoundCue),
+    BossEntrance,
+    LevelUp,
+    Served(String),
+}
+
+/// Side of the board a sound comes from, panned left for the heroes and right
+/// for the bosses (the board layout).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SfxSide {
+    Heroes,
+    Center,
+    Bosses,
+}
+
+impl SfxSide {
+    fn pan(self) -> f64 {
+        match self {
+            SfxSide::Heroes => -0.6,
+            SfxSide::Center => 0.0,
+            SfxSide::Bosses => 0.6,
+        }
+    }
+}
+
+/// Uploaded sound effects named by attack JSONs (`"Sfx"`), fetched once by the
+/// client.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SfxCatalog {
+    /// Attack name (`"Nom"`) → effect file
+    pub attacks: BTreeMap<String, String>,
+}
+
+/// Effects of one attack result: the attack's own effect, when it has one,
+/// replaces the generic hit/critical/heal cues (played once however many
+/// targets were hit); dodges and blocks keep theirs.
+pub fn attack_sfx(cues: impl IntoIterator<Item = SoundCue>, own: Option<&str>) -> Vec<Sfx> {
+    let own = own.filter(|f| !f.is_empty());
+    let mut sfx: Vec<Sfx> = Vec::new();
+    for cue in cues {
+        let next = match (cue, own) {
+            (SoundCue::Hit | SoundCue::CriticalHit | SoundCue::Heal, Some(file)) => {
+                Sfx::Served(file.to_owned())
+            }
+            (cue, _) => Sfx::Cue(cue),
+        };
+        if !(matches!(next, Sfx::Served(_)) && sfx.contains(&next)) {
+            sfx.push(next);
+        }
+    }
+    sfx
+}
+
+/// Injects the JS audio bridge once: two persistent looping `<audio>` elements for
+/// music, crossfaded over `CROSSFADE_MS` on every track change, plus a `playSfx`
+/// helper that fires a fresh `Audio()` per call so overlapping one-shots don't cut
+/// each other off, stereo-panned by the side of the board they come from. Call
+/// once from `App()`, the same way the theme/viewport `document::eval` calls in
+/// `main.rs` are — this works uniformly across web, desktop (tao/wry webview), and
+/// mobile (Android webview) since all three render through a browser engine.
 ///
 /// Browsers block audio.play() with sound until the page has had a genuine user
 /// gesture (click/key/touch) — Home's music auto-starts on mount, before any
@@ -58,16 +143,39 @@ fn sfx_asset(cue: SoundCue) -> Asset {
 /// e.g. VS Code's, are more permissive and don't hit this). A one-time listener
 /// below retries as soon as the very first gesture happens anywhere on the page.
 pub fn init_audio_bridge() {
-    document::eval(
+    document::eval(&format!(
+        "const CROSSFADE_MS = {CROSSFADE_MS};{}",
         r#"
         if (!window.__dxAudio) {
-            const bgm = document.createElement('audio');
-            bgm.loop = true;
-            document.body.appendChild(bgm);
+            const makeTrack = () => {
+                const el = document.createElement('audio');
+                el.loop = true;
+                document.body.appendChild(el);
+                return el;
+            };
+            // Two elements so the outgoing track fades out while the next fades in.
+            let current = makeTrack();
+            let previous = makeTrack();
+            // Web Audio context of the panned effects, created on the first one.
+            let panCtx = null;
             const describe = (e) => (e && (e.name || e.message)) ? `${e.name}: ${e.message}` : String(e);
+            // setInterval rather than requestAnimationFrame: rAF stalls in background tabs.
+            const fade = (el, to, done) => {
+                clearInterval(el.__fade);
+                const from = el.volume;
+                const start = performance.now();
+                el.__fade = setInterval(() => {
+                    const k = Math.min(1, (performance.now() - start) / CROSSFADE_MS);
+                    el.volume = from + (to - from) * k;
+                    if (k >= 1) {
+                        clearInterval(el.__fade);
+                        if (done) done();
+                    }
+                }, 50);
+            };
             const resumeOnFirstGesture = () => {
-                if (bgm.paused && bgm.src) {
-                    bgm.play()
+                if (current.paused && current.src) {
+                    current.play()
                         .then(() => console.debug('[dxAudio] resumed on first gesture'))
                         .catch((e) => console.warn(`[dxAudio] resume on gesture failed: ${describe(e)}`));
                 }
@@ -79,47 +187,77 @@ pub fn init_audio_bridge() {
                 (evt) => document.addEventListener(evt, resumeOnFirstGesture)
             );
             window.__dxAudio = {
-                bgm,
                 playMusic(src, volume, muted) {
-                    if (bgm.src.indexOf(src) === -1) {
-                        bgm.src = src;
+                    const level = muted ? 0 : volume;
+                    if (current.src && current.src.indexOf(src) !== -1) {
+                        fade(current, level);
+                        current.play().catch((e) => console.warn(`[dxAudio] playMusic failed: ${src}: ${describe(e)}`));
+                        return;
                     }
-                    bgm.volume = muted ? 0 : volume;
-                    bgm.play().catch((e) => console.warn(`[dxAudio] playMusic failed: ${src}: ${describe(e)}`));
+                    [current, previous] = [previous, current];
+                    const outgoing = previous;
+                    fade(outgoing, 0, () => outgoing.pause());
+                    current.src = src;
+                    current.volume = 0;
+                    current.play().catch((e) => console.warn(`[dxAudio] playMusic failed: ${src}: ${describe(e)}`));
+                    fade(current, level);
                 },
                 stopMusic() {
-                    bgm.pause();
+                    const outgoing = current;
+                    fade(outgoing, 0, () => outgoing.pause());
                 },
                 setMusicVolume(volume, muted) {
-                    bgm.volume = muted ? 0 : volume;
+                    clearInterval(current.__fade);
+                    current.volume = muted ? 0 : volume;
                 },
-                playSfx(src, volume, muted) {
+                playSfx(src, volume, muted, pan) {
                     if (muted || volume <= 0) {
                         return;
                     }
                     const sfx = new Audio(src);
                     sfx.volume = volume;
+                    // Panning routes the element through Web Audio, which outputs silence for
+                    // cross-origin sources served without CORS (native clients fetch from
+                    // the server URL), so only same-origin effects are panned.
+                    if (pan && new URL(sfx.src, location.href).origin === location.origin) {
+                        try {
+                            const Ctx = window.AudioContext || window.webkitAudioContext;
+                            panCtx = panCtx || new Ctx();
+                            if (panCtx.state === 'suspended') {
+                                panCtx.resume();
+                            }
+                            const panner = panCtx.createStereoPanner();
+                            panner.pan.value = pan;
+                            panCtx.createMediaElementSource(sfx).connect(panner).connect(panCtx.destination);
+                        } catch (e) {
+                            console.debug(`[dxAudio] stereo panning unavailable: ${describe(e)}`);
+                        }
+                    }
                     sfx.play().catch((e) => console.warn(`[dxAudio] playSfx failed: ${src}: ${describe(e)}`));
                 },
             };
         }
-        "#,
-    );
+        "#
+    ));
 }
 
-/// Starts (or switches to) a looping background track, respecting the current
-/// volume/mute settings. `src` values are always compile-time asset paths
-/// (`Asset`'s `Display` impl), never user input, so this string-built `eval` call
-/// stays injection-safe.
-pub fn play_music(track: MusicTrack, settings: CtxAudioSettings) {
-    let src = track.asset();
+/// Crossfades to a looping background track (or just applies the volume if it is
+/// already playing), respecting the current volume/mute settings. Served track
+/// names come from admin uploads, so the URL is passed as a JSON string literal.
+pub fn play_music(source: &MusicSource, settings: CtxAudioSettings) {
+    let src = match source {
+        MusicSource::Bundled(track) => track.asset().to_string(),
+        MusicSource::Served(track) => music_src(track),
+    };
+    let src = serde_json::to_string(&src).unwrap_or_default();
     let volume = settings.music_volume.read().max(0) as f64 / 100.0;
     let muted = *settings.muted.read();
     document::eval(&format!(
-        "window.__dxAudio && window.__dxAudio.playMusic('{src}', {volume}, {muted});"
+        "window.__dxAudio && window.__dxAudio.playMusic({src}, {volume}, {muted});"
     ));
 }
 
+/// Fades the background track out.
 pub fn stop_music() {
     document::eval("window.__dxAudio && window.__dxAudio.stopMusic();");
 }
@@ -134,12 +272,45 @@ pub fn set_music_volume(settings: CtxAudioSettings) {
     ));
 }
 
-/// Plays a one-shot sound effect for the given combat cue.
-pub fn play_sfx(cue: SoundCue, settings: CtxAudioSettings) {
-    let src = sfx_asset(cue);
+/// Plays a one-shot sound effect, panned towards `side`. Served effect names come
+/// from admin uploads, so the URL is passed as a JSON string literal.
+pub fn play_sfx(sfx: &Sfx, side: SfxSide, settings: CtxAudioSettings) {
+    let src = match sfx {
+        Sfx::Cue(cue) => sfx_asset(*cue).to_string(),
+        Sfx::BossEntrance => SFX_BOSS_ENTRANCE.to_string(),
+        Sfx::LevelUp => SFX_LEVEL_UP.to_string(),
+        Sfx::Served(file) => sfx_src(file),
+    };
+    let src = serde_json::to_string(&src).unwrap_or_default();
     let volume = settings.sfx_volume.read().max(0) as f64 / 100.0;
     let muted = *settings.muted.read();
+    let pan = side.pan();
     document::eval(&format!(
-        "window.__dxAudio && window.__dxAudio.playSfx('{src}', {volume}, {muted});"
+        "window.__dxAudio && window.__dxAudio.playSfx({src}, {volume}, {muted}, {pan});"
     ));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn audio_file_names_are_checked() {
+        assert!(is_audio_file_name("Moria Theme.OGG"));
+        assert!(!is_audio_file_name("../moria.ogg"));
+        assert!(!is_audio_file_name("cover.png"));
+    }
+
+    #[test]
+    fn attack_sfx_replaces_impact_cues_only() {
+        let cues = || [SoundCue::Hit, SoundCue::CriticalHit, SoundCue::Dodge];
+        assert_eq!(
+            attack_sfx(cues(), Some("fireball.ogg")),
+            vec![
+                Sfx::Served("fireball.ogg".to_owned()),
+                Sfx::Cue(SoundCue::Dodge)
+            ]
+        );
+        assert_eq!(attack_sfx(cues(), Some("")), cues().map(Sfx::Cue).to_vec());
+    }
+}
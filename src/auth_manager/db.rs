@@ -57,6 +57,35 @@ async fn db() -> Pool<Sqlite> {
             .await.unwrap();
     pool.execute(r#"CREATE TABLE IF NOT EXISTS user_settings ( "username" VARCHAR(256) NOT NULL, "key" VARCHAR(64) NOT NULL, "value" VARCHAR(256) NOT NULL, PRIMARY KEY("username","key"))"#,)
             .await.unwrap();
+    pool.execute(r#"CREATE TABLE IF NOT EXISTS content_revisions ( "id" INTEGER PRIMARY KEY AUTOINCREMENT, "path" TEXT NOT NULL, "username" VARCHAR(256) NOT NULL, "action" VARCHAR(16) NOT NULL, "created_at" VARCHAR(32) NOT NULL, "before" TEXT, "after" TEXT)"#,)
+            .await.unwrap();
+    // Player profiles: one row per finished scenario, lifetime totals per hero and attack
+    pool.execute(r#"CREATE TABLE IF NOT EXISTS profile_fights ( "id" INTEGER PRIMARY KEY AUTOINCREMENT, "username" VARCHAR(256) NOT NULL, "universe" VARCHAR(64) NOT NULL, "scenario" VARCHAR(256) NOT NULL, "won" BOOLEAN NOT NULL, "damage" INTEGER NOT NULL, "healing" INTEGER NOT NULL, "turns" INTEGER NOT NULL, "duration_secs" INTEGER NOT NULL, "finished_at" VARCHAR(32) NOT NULL)"#,)
+            .await.unwrap();
+    pool.execute(r#"CREATE TABLE IF NOT EXISTS profile_heroes ( "username" VARCHAR(256) NOT NULL, "hero" VARCHAR(256) NOT NULL, "fights" INTEGER NOT NULL, "damage" INTEGER NOT NULL, "healing" INTEGER NOT NULL, PRIMARY KEY("username","hero"))"#,)
+            .await.unwrap();
+    pool.execute(r#"CREATE TABLE IF NOT EXISTS profile_attacks ( "username" VARCHAR(256) NOT NULL, "attack" VARCHAR(256) NOT NULL, "uses" INTEGER NOT NULL, "damage" INTEGER NOT NULL, "healing" INTEGER NOT NULL, PRIMARY KEY("username","attack"))"#,)
+            .await.unwrap();
+    pool.execute(r#"CREATE TABLE IF NOT EXISTS profile_universes ( "username" VARCHAR(256) NOT NULL, "universe" VARCHAR(64) NOT NULL, "completed_at" VARCHAR(32) NOT NULL, PRIMARY KEY("username","universe"))"#,)
+            .await.unwrap();
+    pool.execute(r#"CREATE TABLE IF NOT EXISTS profile_endless_runs ( "id" INTEGER PRIMARY KEY AUTOINCREMENT, "username" VARCHAR(256) NOT NULL, "universe" VARCHAR(64) NOT NULL, "floors" INTEGER NOT NULL, "heroes" TEXT NOT NULL, "finished_at" VARCHAR(32) NOT NULL)"#,)
+            .await.unwrap();
+    // Leaderboards: one row per ranked clear; an empty scenario is a whole-universe run
+    pool.execute(r#"CREATE TABLE IF NOT EXISTS leaderboard_entries ( "id" INTEGER PRIMARY KEY AUTOINCREMENT, "universe" VARCHAR(64) NOT NULL, "scenario" VARCHAR(256) NOT NULL, "single_player" BOOLEAN NOT NULL, "players" TEXT NOT NULL, "heroes" TEXT NOT NULL, "turns" INTEGER NOT NULL, "damage" INTEGER NOT NULL, "potions" INTEGER NOT NULL, "finished_at" VARCHAR(32) NOT NULL)"#,)
+            .await.unwrap();
+    // Daily challenges: one row per player and day, written when the challenge starts
+    pool.execute(r#"CREATE TABLE IF NOT EXISTS daily_challenge_results ( "username" VARCHAR(256) NOT NULL, "day" VARCHAR(16) NOT NULL, "scenario" VARCHAR(256) NOT NULL, "heroes" TEXT NOT NULL DEFAULT '', "won" BOOLEAN NOT NULL DEFAULT 0, "turns" INTEGER NOT NULL DEFAULT 0, "damage" INTEGER NOT NULL DEFAULT 0, "potions" INTEGER NOT NULL DEFAULT 0, "started_at" VARCHAR(32) NOT NULL, "finished_at" VARCHAR(32), PRIMARY KEY("username","day"))"#,)
+            .await.unwrap();
+    // Achievements: one counter per kind of game event, one row per unlock
+    pool.execute(r#"CREATE TABLE IF NOT EXISTS achievement_counters ( "username" VARCHAR(256) NOT NULL, "kind" VARCHAR(64) NOT NULL, "value" INTEGER NOT NULL, PRIMARY KEY("username","kind"))"#,)
+            .await.unwrap();
+    pool.execute(r#"CREATE TABLE IF NOT EXISTS achievements_unlocked ( "username" VARCHAR(256) NOT NULL, "achievement_id" VARCHAR(64) NOT NULL, "unlocked_at" VARCHAR(32) NOT NULL, PRIMARY KEY("username","achievement_id"))"#,)
+            .await.unwrap();
+    // Arena: one registered party per player with its rating, one row per match with its log
+    pool.execute(r#"CREATE TABLE IF NOT EXISTS arena_parties ( "username" VARCHAR(256) PRIMARY KEY, "universe" VARCHAR(64) NOT NULL, "heroes" TEXT NOT NULL, "party" TEXT NOT NULL, "rating" INTEGER NOT NULL, "wins" INTEGER NOT NULL DEFAULT 0, "losses" INTEGER NOT NULL DEFAULT 0, "updated_at" VARCHAR(32) NOT NULL)"#,)
+            .await.unwrap();
+    pool.execute(r#"CREATE TABLE IF NOT EXISTS arena_matches ( "id" INTEGER PRIMARY KEY AUTOINCREMENT, "attacker" VARCHAR(256) NOT NULL, "defender" VARCHAR(256) NOT NULL, "attacker_won" BOOLEAN NOT NULL, "attacker_rating" INTEGER NOT NULL, "defender_rating" INTEGER NOT NULL, "rating_change" INTEGER NOT NULL, "turns" INTEGER NOT NULL, "log" TEXT NOT NULL, "played_at" VARCHAR(32) NOT NULL)"#,)
+            .await.unwrap();
 
     // Insert in some test data for two users (one anonymous, one normal)
     pool.execute(r#"INSERT INTO users (id, anonymous, username, password, is_connected) SELECT 1, true, 'Admin', '', false ON CONFLICT(id) DO UPDATE SET anonymous = EXCLUDED.anonymous, username = EXCLUDED.username, password = EXCLUDED.password, is_connected = EXCLUDED.is_connected"#,)
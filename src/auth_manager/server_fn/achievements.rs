@@ -0,0 +1,168 @@
+#[cfg(feature = "server")]
+use crate::achievements::AchievementKind;
+use crate::achievements::{AchievementDef, AchievementProgress};
+#[cfg(feature = "server")]
+use crate::auth_manager::db::get_db;
+use dioxus::prelude::*;
+
+/// Achievements of every JSON file of `offline_root/achievements/`, in file then
+/// list order. Unreadable files are skipped with a warning; an id defined twice
+/// keeps its first definition.
+#[cfg(feature = "server")]
+pub fn load_achievement_defs(offline_root: &std::path::Path) -> Vec<AchievementDef> {
+    use crate::achievements::ACHIEVEMENTS_DIR;
+    use dioxus::logger::tracing;
+
+    let mut paths: Vec<_> = std::fs::read_dir(offline_root.join(ACHIEVEMENTS_DIR))
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.extension().is_some_and(|e| e == "json"))
+                .collect()
+        })
+        .unwrap_or_default();
+    paths.sort();
+    let mut defs: Vec<AchievementDef> = Vec::new();
+    for path in paths {
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|s| {
+                serde_json::from_str::<Vec<AchievementDef>>(&s).map_err(|e| e.to_string())
+            });
+        match parsed {
+            Ok(list) => {
+                for def in list {
+                    if defs.iter().any(|d| d.id == def.id) {
+                        tracing::warn!("{path:?}: achievement '{}' is already defined", def.id);
+                    } else {
+                        defs.push(def);
+                    }
+                }
+            }
+            Err(e) => tracing::warn!("Cannot read the achievements of {path:?}: {e}"),
+        }
+    }
+    defs
+}
+
+#[cfg(feature = "server")]
+async fn load_counters(
+    username: &str,
+) -> sqlx::Result<std::collections::BTreeMap<AchievementKind, i64>> {
+    let rows: Vec<(String, i64)> =
+        sqlx::query_as("SELECT kind, value FROM achievement_counters WHERE username = ?1")
+            .bind(username)
+            .fetch_all(get_db().await)
+            .await?;
+    Ok(rows
+        .into_iter()
+        .filter_map(|(kind, value)| AchievementKind::from_key(&kind).map(|k| (k, value)))
+        .collect())
+}
+
+/// Adds the `events` (kind, amount) to the counters of `username` and unlocks
+/// the achievements they reach. Returns the newly unlocked ones.
+#[cfg(feature = "server")]
+pub async fn record_achievement_events(
+    username: &str,
+    events: &[(AchievementKind, i64)],
+) -> sqlx::Result<Vec<AchievementDef>> {
+    use crate::{achievements::newly_unlocked, common::OFFLINE_PATH};
+
+    let mut counters = load_counters(username).await?;
+    let mut tx = get_db().await.begin().await?;
+    for &(kind, amount) in events {
+        let counter = counters.entry(kind).or_default();
+        let value = kind.apply(*counter, amount);
+        if value == *counter {
+            continue;
+        }
+        *counter = value;
+        sqlx::query(
+            "INSERT INTO achievement_counters (username, kind, value) VALUES (?1, ?2, ?3)
+             ON CONFLICT(username, kind) DO UPDATE SET value = EXCLUDED.value",
+        )
+        .bind(username)
+        .bind(kind.key())
+        .bind(value)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    let defs = load_achievement_defs(std::path::Path::new(OFFLINE_PATH));
+    let unlocked: Vec<(String,)> =
+        sqlx::query_as("SELECT achievement_id FROM achievements_unlocked WHERE username = ?1")
+            .bind(username)
+            .fetch_all(&mut *tx)
+            .await?;
+    let unlocked: Vec<String> = unlocked.into_iter().map(|(id,)| id).collect();
+    let new = newly_unlocked(&defs, &counters, &unlocked);
+    let unlocked_at = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    for def in &new {
+        sqlx::query(
+            "INSERT INTO achievements_unlocked (username, achievement_id, unlocked_at)
+             VALUES (?1, ?2, ?3) ON CONFLICT(username, achievement_id) DO NOTHING",
+        )
+        .bind(username)
+        .bind(&def.id)
+        .bind(&unlocked_at)
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+    Ok(new.into_iter().cloned().collect())
+}
+
+/// Every achievement with the progress of `username`, unlocked ones first. Any
+/// signed-in player can view any profile.
+#[post("/api/get_achievements")]
+pub async fn get_achievements(username: String) -> Result<Vec<AchievementProgress>, ServerFnError> {
+    use crate::{achievements::sort_for_display, common::OFFLINE_PATH};
+
+    let err = |e: sqlx::Error| ServerFnError::new(format!("{e}"));
+    let counters = load_counters(&username).await.map_err(err)?;
+    let unlocked: std::collections::HashMap<String, String> = sqlx::query_as(
+        "SELECT achievement_id, unlocked_at FROM achievements_unlocked WHERE username = ?1",
+    )
+    .bind(&username)
+    .fetch_all(get_db().await)
+    .await
+    .map_err(err)?
+    .into_iter()
+    .collect();
+    let mut achievements: Vec<AchievementProgress> =
+        load_achievement_defs(std::path::Path::new(OFFLINE_PATH))
+            .into_iter()
+            .map(|def| AchievementProgress {
+                progress: counters
+                    .get(&def.kind)
+                    .copied()
+                    .unwrap_or_default()
+                    .min(def.target),
+                unlocked_at: unlocked.get(&def.id).cloned(),
+                def,
+            })
+            .collect();
+    sort_for_display(&mut achievements);
+    Ok(achievements)
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_achievements_are_valid() {
+        let defs = load_achievement_defs(std::path::Path::new(crate::common::OFFLINE_PATH));
+        assert!(!defs.is_empty());
+        for def in &defs {
+            assert!(def.target > 0, "{}", def.id);
+            assert!(
+                !def.name.get("fr").is_empty() && !def.description.get("en").is_empty(),
+                "{}",
+                def.id
+            );
+        }
+    }
+}
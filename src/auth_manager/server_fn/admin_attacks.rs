@@ -1,3 +1,5 @@
+#[cfg(feature = "server")]
+use super::admin_revisions::{remove_content_file, write_content_file};
 use dioxus::prelude::*;
 
 /// Key fields of an attack for structured form editing.
@@ -20,6 +22,8 @@ pub struct AttackFormData {
     pub regen_rage: i64,
     pub regen_vigueur: i64,
     pub photo: String,
+    /// Uploaded sound effect played on impact (`/sfx-srv/`); empty = the generic cues.
+    pub sfx: String,
     /// Raw JSON array string for the complex Effet field.
     pub effet_json: String,
 }
@@ -78,8 +82,7 @@ pub async fn admin_save_attack_json(
     std::fs::create_dir_all(&dir)
         .map_err(|e| ServerFnError::new(format!("Cannot create dir {dir:?}: {e}")))?;
     let path = dir.join(format!("{attack_name}.json"));
-    std::fs::write(&path, json_content.as_bytes())
-        .map_err(|e| ServerFnError::new(format!("Cannot write {path:?}: {e}")))
+    write_content_file(&path, json_content.as_bytes()).await
 }
 
 /// Deletes an attack file for a character.
@@ -94,8 +97,7 @@ pub async fn admin_delete_attack(
         .join("attack")
         .join(&character_name)
         .join(format!("{attack_name}.json"));
-    std::fs::remove_file(&path)
-        .map_err(|e| ServerFnError::new(format!("Cannot delete {path:?}: {e}")))
+    remove_content_file(&path).await
 }
 
 /// Returns the key fields of an attack for form-based editing.
@@ -137,6 +139,7 @@ pub async fn admin_get_attack_form(
         regen_rage: v["Regen rage"].as_i64().unwrap_or(0),
         regen_vigueur: v["Regen vigueur"].as_i64().unwrap_or(0),
         photo: v["Photo"].as_str().unwrap_or("").to_owned(),
+        sfx: v["Sfx"].as_str().unwrap_or("").to_owned(),
         effet_json,
     })
 }
@@ -152,7 +155,7 @@ pub async fn admin_save_attack_form(
     use std::path::Path;
     let effet: serde_json::Value = serde_json::from_str(&form.effet_json)
         .unwrap_or_else(|_| serde_json::Value::Array(Vec::new()));
-    let attack = serde_json::json!({
+    let mut attack = serde_json::json!({
         "Nom": form.nom,
         "Niveau": form.niveau,
         "Description": form.description,
@@ -172,12 +175,15 @@ pub async fn admin_save_attack_form(
         "Photo": form.photo,
         "Effet": effet,
     });
+    // App-side field, unknown to lib-rpg: only written when set.
+    if !form.sfx.is_empty() {
+        attack["Sfx"] = serde_json::Value::String(form.sfx);
+    }
     let json_content = serde_json::to_string_pretty(&attack)
         .map_err(|e| ServerFnError::new(format!("Cannot serialize: {e}")))?;
     let dir = Path::new(OFFLINE_PATH).join("attack").join(&character_name);
     std::fs::create_dir_all(&dir)
         .map_err(|e| ServerFnError::new(format!("Cannot create dir {dir:?}: {e}")))?;
     let path = dir.join(format!("{attack_name}.json"));
-    std::fs::write(&path, json_content.as_bytes())
-        .map_err(|e| ServerFnError::new(format!("Cannot write {path:?}: {e}")))
+    write_content_file(&path, json_content.as_bytes()).await
 }
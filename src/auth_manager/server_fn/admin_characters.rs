@@ -1,5 +1,9 @@
+#[cfg(feature = "server")]
+use super::admin_revisions::write_content_file;
 use dioxus::prelude::*;
 
+use crate::locales::LocalizedText;
+
 /// Summary of one character (hero or boss) for the admin panel.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct AdminCharacterInfo {
@@ -7,27 +11,17 @@ pub struct AdminCharacterInfo {
     pub photo_name: String,
     pub class: String,
     pub level: u64,
-    pub description: String,
-    pub description_en: String,
-    pub description_fr: String,
+    /// Per-language descriptions, with the legacy `Description` as last fallback
+    pub description: LocalizedText,
     pub universe: String,
     pub stats: std::collections::HashMap<String, (u64, u64)>,
 }
 
 impl AdminCharacterInfo {
-    /// Locale-specific description; falls back to the legacy `description`
-    /// field when the locale-specific one is empty (unmigrated characters).
-    /// Mirrors `lib_rpg::character_mod::character::Character::description_for`.
-    pub fn description_for(&self, lang: lib_rpg::common::lang::Lang) -> &str {
-        let localized = match lang {
-            lib_rpg::common::lang::Lang::En => &self.description_en,
-            lib_rpg::common::lang::Lang::Fr => &self.description_fr,
-        };
-        if localized.is_empty() {
-            &self.description
-        } else {
-            localized
-        }
+    /// Description in the app language `code` ("en", "fr", "de-DE"…), see
+    /// `LocalizedText::get` for the fallbacks.
+    pub fn description_for(&self, code: &str) -> &str {
+        self.description.get(code)
     }
 }
 
@@ -74,9 +68,11 @@ pub async fn admin_list_characters() -> Result<Vec<AdminCharacterInfo>, ServerFn
             photo_name: c.photo_name.clone(),
             class: format!("{} {}", c.class.to_emoji(), c.class.to_str()),
             level: c.level,
-            description: c.description.clone(),
-            description_en: c.description_en.clone(),
-            description_fr: c.description_fr.clone(),
+            description: LocalizedText::from_en_fr(
+                &c.description,
+                &c.description_en,
+                &c.description_fr,
+            ),
             universe: c.universe.clone(),
             stats: c
                 .stats
@@ -104,9 +100,11 @@ pub async fn admin_list_bosses() -> Result<Vec<AdminCharacterInfo>, ServerFnErro
             photo_name: c.photo_name.clone(),
             class: format!("{} {}", c.class.to_emoji(), c.class.to_str()),
             level: c.level,
-            description: c.description.clone(),
-            description_en: c.description_en.clone(),
-            description_fr: c.description_fr.clone(),
+            description: LocalizedText::from_en_fr(
+                &c.description,
+                &c.description_en,
+                &c.description_fr,
+            ),
             universe: c.universe.clone(),
             stats: c
                 .stats
@@ -185,8 +183,7 @@ pub async fn admin_save_character_json(
     std::fs::create_dir_all(&dir)
         .map_err(|e| ServerFnError::new(format!("Cannot create dir {dir:?}: {e}")))?;
     let path = dir.join(format!("{character_name}.json"));
-    std::fs::write(&path, json_content.as_bytes())
-        .map_err(|e| ServerFnError::new(format!("Cannot write {path:?}: {e}")))?;
+    write_content_file(&path, json_content.as_bytes()).await?;
     let mut dm = DATA_MANAGER
         .lock()
         .map_err(|e| ServerFnError::new(format!("{e}")))?;
@@ -307,8 +304,7 @@ pub async fn admin_save_character_form(
     }
     let json_content = serde_json::to_string_pretty(&v)
         .map_err(|e| ServerFnError::new(format!("Cannot serialize: {e}")))?;
-    std::fs::write(&path, json_content.as_bytes())
-        .map_err(|e| ServerFnError::new(format!("Cannot write {path:?}: {e}")))?;
+    write_content_file(&path, json_content.as_bytes()).await?;
     let mut dm = DATA_MANAGER
         .lock()
         .map_err(|e| ServerFnError::new(format!("{e}")))?;
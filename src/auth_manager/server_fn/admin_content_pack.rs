@@ -0,0 +1,962 @@
+use dioxus::prelude::*;
+
+/// Version written into `manifest.json`; archives with a newer version are refused.
+pub const CONTENT_PACK_FORMAT_VERSION: u32 = 1;
+
+/// Manifest stored at the root of every content-pack archive.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ContentPackManifest {
+    pub format_version: u32,
+    pub universe: String,
+    /// Export time (RFC 3339)
+    pub exported_at: String,
+    /// `dx-rpg` version of the exporting server
+    pub app_version: String,
+    /// Archive paths of every content file (`offlines/...` and `photos/...`)
+    pub files: Vec<String>,
+    /// Names of the universe's characters, the only `offlines/attack/<name>/`
+    /// folders the pack may write. Packs exported before it use the names of
+    /// their character files.
+    #[serde(default)]
+    pub characters: Vec<String>,
+    /// Images referenced by the content but not found on the exporting server
+    pub missing_images: Vec<String>,
+}
+
+/// How one archive entry compares with what is already on the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ContentPackEntryStatus {
+    New,
+    Unchanged,
+    Conflict,
+}
+
+/// One file of a content pack along with its dry-run status.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ContentPackEntry {
+    pub path: String,
+    pub status: ContentPackEntryStatus,
+}
+
+/// Dry-run result of a content-pack import. Nothing is written to disk.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ContentPackPreview {
+    pub manifest: ContentPackManifest,
+    pub entries: Vec<ContentPackEntry>,
+    /// Blocking problems: the import is refused while this is non-empty
+    pub errors: Vec<String>,
+    /// Non-blocking problems (unknown bosses, missing images…)
+    pub warnings: Vec<String>,
+}
+
+/// What to do with archive files that differ from the existing server files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ContentPackConflictPolicy {
+    Skip,
+    Overwrite,
+}
+
+/// Outcome of an applied content-pack import.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ContentPackImportReport {
+    pub written: usize,
+    pub skipped: usize,
+}
+
+#[cfg(feature = "server")]
+const MANIFEST_FILE: &str = "manifest.json";
+#[cfg(feature = "server")]
+const IMAGE_EXTENSIONS: [&str; 5] = ["png", "jpg", "jpeg", "webp", "gif"];
+
+#[cfg(feature = "server")]
+pub(super) fn photos_dir() -> std::path::PathBuf {
+    std::env::var("PHOTOS_PATH")
+        .unwrap_or_else(|_| "photos".to_owned())
+        .into()
+}
+
+/// A single path component: non-empty, no traversal, no separators.
+#[cfg(feature = "server")]
+pub(super) fn is_safe_component(s: &str) -> bool {
+    !s.is_empty() && s != "." && !s.contains("..") && !s.contains('/') && !s.contains('\\')
+}
+
+/// Checks that an archive path is one a content pack may contain for `universe`:
+/// - `offlines/{characters,talents,scenarios}/<universe>/<file>.json`
+/// - `offlines/attack/<character>/<file>.json`, for one of its `characters`
+/// - `offlines/maps/<file>.json`, whose content `preview_archive` ties to `universe`
+/// - `photos/<image>`
+#[cfg(feature = "server")]
+fn validate_archive_path(path: &str, universe: &str, characters: &[String]) -> Result<(), String> {
+    let parts: Vec<&str> = path.split('/').collect();
+    if parts.iter().any(|p| !is_safe_component(p)) {
+        return Err(format!("Unsafe path '{path}'"));
+    }
+    let is_json = path.ends_with(".json");
+    match parts.as_slice() {
+        ["offlines", "characters" | "talents" | "scenarios", u, _] if is_json => {
+            if *u == universe {
+                Ok(())
+            } else {
+                Err(format!("'{path}' is outside universe '{universe}'"))
+            }
+        }
+        ["offlines", "attack", owner, _] if is_json => {
+            if characters.iter().any(|c| c == owner) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "'{path}' belongs to '{owner}', not a character of the pack"
+                ))
+            }
+        }
+        ["offlines", "maps", _] if is_json => Ok(()),
+        ["photos", file] => {
+            let ext = std::path::Path::new(file)
+                .extension()
+                .and_then(|e| e.to_str())
+                .unwrap_or("")
+                .to_lowercase();
+            if IMAGE_EXTENSIONS.contains(&ext.as_str()) {
+                Ok(())
+            } else {
+                Err(format!("Unsupported image '{path}'"))
+            }
+        }
+        _ => Err(format!("Unexpected file '{path}'")),
+    }
+}
+
+/// Maps an archive path to its destination on this server.
+#[cfg(feature = "server")]
+fn destination_path(
+    offline_root: &std::path::Path,
+    photos_dir: &std::path::Path,
+    archive_path: &str,
+) -> std::path::PathBuf {
+    if let Some(rel) = archive_path.strip_prefix("offlines/") {
+        offline_root.join(rel)
+    } else {
+        photos_dir.join(archive_path.trim_start_matches("photos/"))
+    }
+}
+
+/// Image filename for a `Photo` field (bare names default to `.png`, like `photo_src`).
+#[cfg(feature = "server")]
+fn photo_file_name(photo: &str) -> String {
+    if photo.contains('.') {
+        photo.to_owned()
+    } else {
+        format!("{photo}.png")
+    }
+}
+
+/// Archive content keyed by archive path.
+#[cfg(feature = "server")]
+type ArchiveFiles = std::collections::BTreeMap<String, Vec<u8>>;
+
+/// Reads every `*.json` file of `dir` as `(file name, parsed value, raw bytes)`.
+#[cfg(feature = "server")]
+fn read_json_files(dir: &std::path::Path) -> Vec<(String, serde_json::Value, Vec<u8>)> {
+    let mut files: Vec<(String, serde_json::Value, Vec<u8>)> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().extension().map(|x| x == "json").unwrap_or(false))
+                .filter_map(|e| {
+                    let name = e.file_name().to_string_lossy().to_string();
+                    let bytes = std::fs::read(e.path()).ok()?;
+                    let v = serde_json::from_slice(&bytes).ok()?;
+                    Some((name, v, bytes))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    files
+}
+
+/// Whether the map `v` belongs to `universe`: by `<universe>_` id prefix, as its
+/// starting map, or by an NPC fighting or an encounter of one of its `scenarios`.
+#[cfg(feature = "server")]
+fn is_map_of(v: &serde_json::Value, universe: &str, scenarios: &[String]) -> bool {
+    let by_prefix = v["id"]
+        .as_str()
+        .is_some_and(|id| id.starts_with(&format!("{universe}_")));
+    let by_start = v["start_of"] == universe;
+    let is_own_scenario =
+        |s: &serde_json::Value| s.as_str().is_some_and(|s| scenarios.iter().any(|n| n == s));
+    let by_fight = v["npcs"].as_array().is_some_and(|npcs| {
+        npcs.iter()
+            .any(|npc| is_own_scenario(&npc["fight_scenario_id"]))
+    });
+    let by_encounter = v["encounters"]
+        .as_array()
+        .is_some_and(|encounters| encounters.iter().any(is_own_scenario));
+    by_prefix || by_start || by_fight || by_encounter
+}
+
+/// `field` of the JSON files of `files` right under the archive directory `dir`,
+/// e.g. the `Name`s of `offlines/characters/<universe>/`.
+#[cfg(feature = "server")]
+fn archive_names<'a>(
+    files: impl IntoIterator<Item = (&'a String, &'a Vec<u8>)>,
+    dir: &str,
+    field: &str,
+) -> Vec<String> {
+    files
+        .into_iter()
+        .filter(|(path, _)| {
+            path.strip_prefix(dir)
+                .and_then(|rest| rest.strip_prefix('/'))
+                .is_some_and(|file| !file.contains('/') && file.ends_with(".json"))
+        })
+        .filter_map(|(_, bytes)| {
+            let v: serde_json::Value = serde_json::from_slice(bytes).ok()?;
+            v[field].as_str().map(str::to_owned)
+        })
+        .collect()
+}
+
+/// Universe of every character of this server, by `Name`.
+#[cfg(feature = "server")]
+fn character_universes(
+    offline_root: &std::path::Path,
+) -> std::collections::BTreeMap<String, String> {
+    let mut universes = std::collections::BTreeMap::new();
+    let Ok(entries) = std::fs::read_dir(offline_root.join("characters")) else {
+        return universes;
+    };
+    for dir in entries.flatten().filter(|e| e.path().is_dir()) {
+        let universe = dir.file_name().to_string_lossy().to_string();
+        for (_, v, _) in read_json_files(&dir.path()) {
+            if let Some(name) = v["Name"].as_str() {
+                universes.insert(name.to_owned(), universe.clone());
+            }
+        }
+    }
+    universes
+}
+
+/// Gathers every file belonging to `universe`: its characters with their attacks
+/// and talents, its scenarios, the maps tied to it (by `<universe>_` id prefix, as
+/// its starting map, by an NPC fighting one of its scenarios or by an encounter of
+/// one of its scenarios)
+/// and every referenced image.
+/// Returns the `(archive path, bytes)` pairs and the referenced images not found.
+#[cfg(feature = "server")]
+pub(super) fn collect_universe_files(
+    offline_root: &std::path::Path,
+    photos_dir: &std::path::Path,
+    universe: &str,
+) -> (Vec<(String, Vec<u8>)>, Vec<String>) {
+    let mut files: Vec<(String, Vec<u8>)> = Vec::new();
+    let mut images: std::collections::BTreeSet<String> = std::collections::BTreeSet::new();
+
+    for (name, v, bytes) in read_json_files(&offline_root.join("characters").join(universe)) {
+        if let Some(photo) = v["Photo"].as_str().filter(|p| !p.is_empty()) {
+            images.insert(photo_file_name(photo));
+        }
+        if let Some(char_name) = v["Name"].as_str().filter(|n| is_safe_component(n)) {
+            for (atk_name, atk, atk_bytes) in
+                read_json_files(&offline_root.join("attack").join(char_name))
+            {
+                if let Some(photo) = atk["Photo"].as_str().filter(|p| !p.is_empty()) {
+                    images.insert(photo_file_name(photo));
+                }
+                files.push((format!("offlines/attack/{char_name}/{atk_name}"), atk_bytes));
+            }
+        }
+        files.push((format!("offlines/characters/{universe}/{name}"), bytes));
+    }
+
+    for (name, _, bytes) in read_json_files(&offline_root.join("talents").join(universe)) {
+        files.push((format!("offlines/talents/{universe}/{name}"), bytes));
+    }
+
+    let mut scenario_names: Vec<String> = Vec::new();
+    for (name, v, bytes) in read_json_files(&offline_root.join("scenarios").join(universe)) {
+        if let Some(n) = v["name"].as_str() {
+            scenario_names.push(n.to_owned());
+        }
+        files.push((format!("offlines/scenarios/{universe}/{name}"), bytes));
+    }
+
+    for (name, v, bytes) in read_json_files(&offline_root.join("maps")) {
+        if is_map_of(&v, universe, &scenario_names) {
+            files.push((format!("offlines/maps/{name}"), bytes));
+        }
+    }
+
+    let mut missing: Vec<String> = Vec::new();
+    for image in images {
+        if !is_safe_component(&image) {
+            continue;
+        }
+        let candidates = [
+            photos_dir.join(&image),
+            std::path::Path::new("assets/img").join(&image),
+        ];
+        match candidates.iter().find_map(|p| std::fs::read(p).ok()) {
+            Some(bytes) => files.push((format!("photos/{image}"), bytes)),
+            None => missing.push(image),
+        }
+    }
+    (files, missing)
+}
+
+#[cfg(feature = "server")]
+fn build_archive(
+    manifest: &ContentPackManifest,
+    files: &[(String, Vec<u8>)],
+) -> Result<Vec<u8>, String> {
+    let manifest_json = serde_json::to_vec_pretty(manifest).map_err(|e| e.to_string())?;
+    let mut builder = tar::Builder::new(Vec::new());
+    for (path, data) in std::iter::once((MANIFEST_FILE, manifest_json.as_slice()))
+        .chain(files.iter().map(|(p, d)| (p.as_str(), d.as_slice())))
+    {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, path, data)
+            .map_err(|e| format!("Cannot add '{path}': {e}"))?;
+    }
+    builder.into_inner().map_err(|e| e.to_string())
+}
+
+/// Splits an archive into its manifest and its content files.
+#[cfg(feature = "server")]
+fn read_archive(bytes: &[u8]) -> Result<(ContentPackManifest, ArchiveFiles), String> {
+    use std::io::Read;
+    let mut archive = tar::Archive::new(bytes);
+    let mut manifest: Option<ContentPackManifest> = None;
+    let mut files = ArchiveFiles::new();
+    for entry in archive
+        .entries()
+        .map_err(|e| format!("Invalid archive: {e}"))?
+    {
+        let mut entry = entry.map_err(|e| format!("Invalid archive: {e}"))?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let path = entry
+            .path()
+            .map_err(|e| format!("Invalid path in archive: {e}"))?
+            .to_string_lossy()
+            .replace('\\', "/");
+        let mut data = Vec::new();
+        entry
+            .read_to_end(&mut data)
+            .map_err(|e| format!("Cannot read '{path}': {e}"))?;
+        if path == MANIFEST_FILE {
+            manifest =
+                Some(serde_json::from_slice(&data).map_err(|e| format!("Invalid manifest: {e}"))?);
+        } else {
+            files.insert(path, data);
+        }
+    }
+    let manifest = manifest.ok_or_else(|| format!("Missing {MANIFEST_FILE}"))?;
+    Ok((manifest, files))
+}
+
+/// Validates the archive content and diffs it against the server files.
+/// `known_bosses` are the boss names already loaded on this server.
+#[cfg(feature = "server")]
+fn preview_archive(
+    offline_root: &std::path::Path,
+    photos_dir: &std::path::Path,
+    manifest: ContentPackManifest,
+    files: &ArchiveFiles,
+    known_bosses: &[String],
+) -> ContentPackPreview {
+    let mut errors: Vec<String> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
+    let mut entries: Vec<ContentPackEntry> = Vec::new();
+
+    if manifest.format_version == 0 || manifest.format_version > CONTENT_PACK_FORMAT_VERSION {
+        errors.push(format!(
+            "Unsupported format version {} (this server reads up to {})",
+            manifest.format_version, CONTENT_PACK_FORMAT_VERSION
+        ));
+    }
+    if !is_safe_component(&manifest.universe) {
+        errors.push(format!("Invalid universe name '{}'", manifest.universe));
+    }
+    for listed in &manifest.files {
+        if !files.contains_key(listed) {
+            errors.push(format!("'{listed}' is listed in the manifest but missing"));
+        }
+    }
+    for image in &manifest.missing_images {
+        warnings.push(format!("Image '{image}' was not exported"));
+    }
+
+    let universe = manifest.universe.as_str();
+    let pack_characters = archive_names(files, &format!("offlines/characters/{universe}"), "Name");
+    let characters = if manifest.characters.is_empty() {
+        &pack_characters
+    } else {
+        &manifest.characters
+    };
+    let server_characters = character_universes(offline_root);
+    for name in characters {
+        if !pack_characters.contains(name) {
+            errors.push(format!(
+                "Character '{name}' is declared but not in the pack"
+            ));
+        }
+        if let Some(other) = server_characters.get(name).filter(|u| *u != universe) {
+            errors.push(format!("Character '{name}' belongs to universe '{other}'"));
+        }
+    }
+    // Maps are tied to the universe by the scenarios of the pack and of the server
+    let mut scenarios = archive_names(files, &format!("offlines/scenarios/{universe}"), "name");
+    scenarios.extend(
+        read_json_files(&offline_root.join("scenarios").join(universe))
+            .into_iter()
+            .filter_map(|(_, v, _)| v["name"].as_str().map(str::to_owned)),
+    );
+
+    let mut scenario_bosses: Vec<(String, String)> = Vec::new();
+    for (path, data) in files {
+        if let Err(e) = validate_archive_path(path, universe, characters) {
+            errors.push(e);
+            continue;
+        }
+        if !manifest.files.contains(path) {
+            warnings.push(format!("'{path}' is not listed in the manifest"));
+        }
+        if path.ends_with(".json") {
+            let v: serde_json::Value = match serde_json::from_slice(data) {
+                Ok(v) => v,
+                Err(e) => {
+                    errors.push(format!("'{path}': invalid JSON: {e}"));
+                    continue;
+                }
+            };
+            let (required, value) = match path.split('/').nth(1).unwrap_or("") {
+                "characters" => ("Name", v["Name"].as_str()),
+                "attack" => ("Nom", v["Nom"].as_str()),
+                "talents" => ("hero_key", v["hero_key"].as_str()),
+                "scenarios" => ("name", v["name"].as_str()),
+                _ => ("id", v["id"].as_str()),
+            };
+            if value.is_none_or(str::is_empty) {
+                errors.push(format!("'{path}': missing '{required}'"));
+            }
+            // A map of another universe is never overwritten, even by a map
+            // claiming this one
+            if path.starts_with("offlines/maps/") {
+                let on_server = existing_content(offline_root, photos_dir, path)
+                    .ok()
+                    .and_then(|bytes| serde_json::from_slice::<serde_json::Value>(&bytes).ok());
+                if !is_map_of(&v, universe, &scenarios)
+                    || on_server.is_some_and(|old| !is_map_of(&old, universe, &scenarios))
+                {
+                    errors.push(format!("'{path}' is not a map of universe '{universe}'"));
+                }
+            }
+            if required == "name"
+                && let Some(obj) = v["boss_patterns"].as_object()
+            {
+                for boss in obj.keys() {
+                    scenario_bosses.push((path.clone(), boss.clone()));
+                }
+            }
+        }
+
+        let status = match existing_content(offline_root, photos_dir, path) {
+            Err(_) => ContentPackEntryStatus::New,
+            Ok(existing) if same_content(&existing, data, path) => {
+                ContentPackEntryStatus::Unchanged
+            }
+            Ok(_) => ContentPackEntryStatus::Conflict,
+        };
+        entries.push(ContentPackEntry {
+            path: path.clone(),
+            status,
+        });
+    }
+
+    for (path, boss) in scenario_bosses {
+        if !pack_characters.contains(&boss) && !known_bosses.contains(&boss) {
+            warnings.push(format!("'{path}': unknown boss '{boss}'"));
+        }
+    }
+
+    ContentPackPreview {
+        manifest,
+        entries,
+        errors,
+        warnings,
+    }
+}
+
+/// Current server content for an archive path. Images fall back to the bundled
+/// `assets/img` directory, mirroring the `/img-srv` handler.
+#[cfg(feature = "server")]
+fn existing_content(
+    offline_root: &std::path::Path,
+    photos_dir: &std::path::Path,
+    archive_path: &str,
+) -> std::io::Result<Vec<u8>> {
+    let dest = destination_path(offline_root, photos_dir, archive_path);
+    std::fs::read(&dest).or_else(|e| match archive_path.strip_prefix("photos/") {
+        Some(file) => std::fs::read(std::path::Path::new("assets/img").join(file)),
+        None => Err(e),
+    })
+}
+
+/// JSON files compare by value so that re-formatting alone is not a conflict.
+#[cfg(feature = "server")]
+fn same_content(existing: &[u8], incoming: &[u8], path: &str) -> bool {
+    if path.ends_with(".json") {
+        match (
+            serde_json::from_slice::<serde_json::Value>(existing),
+            serde_json::from_slice::<serde_json::Value>(incoming),
+        ) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => existing == incoming,
+        }
+    } else {
+        existing == incoming
+    }
+}
+
+#[cfg(feature = "server")]
+fn decode_archive(
+    archive_base64: &str,
+) -> Result<(ContentPackManifest, ArchiveFiles), ServerFnError> {
+    let bytes = data_encoding::BASE64
+        .decode(archive_base64.as_bytes())
+        .map_err(|e| ServerFnError::new(format!("Invalid base64: {e}")))?;
+    read_archive(&bytes).map_err(ServerFnError::new)
+}
+
+#[cfg(feature = "server")]
+fn known_boss_names() -> Result<Vec<String>, ServerFnError> {
+    use crate::common::DATA_MANAGER;
+    let dm = DATA_MANAGER
+        .lock()
+        .map_err(|e| ServerFnError::new(format!("{e}")))?;
+    Ok(dm
+        .all_bosses
+        .iter()
+        .map(|c| c.db_full_name.clone())
+        .collect())
+}
+
+/// Exports a whole universe as a base64-encoded `.tar` content pack.
+#[post("/api/admin_export_content_pack")]
+pub async fn admin_export_content_pack(universe: String) -> Result<String, ServerFnError> {
+    use crate::common::OFFLINE_PATH;
+    use std::path::Path;
+    if !is_safe_component(&universe) {
+        return Err(ServerFnError::new("Invalid universe name".to_owned()));
+    }
+    let (files, missing_images) =
+        collect_universe_files(Path::new(OFFLINE_PATH), &photos_dir(), &universe);
+    if files.is_empty() {
+        return Err(ServerFnError::new(format!(
+            "Universe '{universe}' has no content"
+        )));
+    }
+    let characters = archive_names(
+        files.iter().map(|(p, d)| (p, d)),
+        &format!("offlines/characters/{universe}"),
+        "Name",
+    );
+    let manifest = ContentPackManifest {
+        format_version: CONTENT_PACK_FORMAT_VERSION,
+        universe,
+        exported_at: chrono::Local::now().to_rfc3339(),
+        app_version: env!("CARGO_PKG_VERSION").to_owned(),
+        files: files.iter().map(|(p, _)| p.clone()).collect(),
+        characters,
+        missing_images,
+    };
+    let bytes = build_archive(&manifest, &files).map_err(ServerFnError::new)?;
+    Ok(data_encoding::BASE64.encode(&bytes))
+}
+
+/// Validates a content pack and diffs it against the server without writing anything.
+#[post("/api/admin_preview_content_pack")]
+pub async fn admin_preview_content_pack(
+    archive_base64: String,
+) -> Result<ContentPackPreview, ServerFnError> {
+    use crate::common::OFFLINE_PATH;
+    use std::path::Path;
+    let (manifest, files) = decode_archive(&archive_base64)?;
+    Ok(preview_archive(
+        Path::new(OFFLINE_PATH),
+        &photos_dir(),
+        manifest,
+        &files,
+        &known_boss_names()?,
+    ))
+}
+
+/// Writes the `paths` of `files` under a fresh temporary directory, laid out as
+/// in the archive, so that disk errors show up before any server file is touched.
+#[cfg(feature = "server")]
+fn stage_files(paths: &[&str], files: &ArchiveFiles) -> Result<std::path::PathBuf, String> {
+    let staging = std::env::temp_dir().join(format!(
+        "dx_rpg_content_pack_{}_{}",
+        std::process::id(),
+        chrono::Local::now().timestamp_micros()
+    ));
+    let staged = paths.iter().try_for_each(|path| {
+        let dest = staging.join(path);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| format!("Cannot stage '{path}': {e}"))?;
+        }
+        std::fs::write(&dest, &files[*path]).map_err(|e| format!("Cannot stage '{path}': {e}"))
+    });
+    match staged {
+        Ok(()) => Ok(staging),
+        Err(e) => {
+            let _ = std::fs::remove_dir_all(&staging);
+            Err(e)
+        }
+    }
+}
+
+/// Moves the staged `paths` to their destinations through `write_content_file`.
+/// On a failure the files already moved are put back as they were, so the server
+/// never keeps half a pack.
+#[cfg(feature = "server")]
+async fn swap_in_staged(
+    staging: &std::path::Path,
+    offline_root: &std::path::Path,
+    photos_dir: &std::path::Path,
+    paths: &[&str],
+) -> Result<(), ServerFnError> {
+    let mut moved: Vec<(std::path::PathBuf, Option<Vec<u8>>)> = Vec::new();
+    for path in paths {
+        let dest = destination_path(offline_root, photos_dir, path);
+        moved.push((dest.clone(), std::fs::read(&dest).ok()));
+        if let Err(e) = move_staged(&staging.join(path), &dest).await {
+            roll_back(moved).await;
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "server")]
+async fn move_staged(
+    staged: &std::path::Path,
+    dest: &std::path::Path,
+) -> Result<(), ServerFnError> {
+    let data = std::fs::read(staged)
+        .map_err(|e| ServerFnError::new(format!("Cannot read {staged:?}: {e}")))?;
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| ServerFnError::new(format!("Cannot create {parent:?}: {e}")))?;
+    }
+    super::admin_revisions::write_content_file(dest, &data).await
+}
+
+/// Puts back the files of an aborted import, last moved first.
+#[cfg(feature = "server")]
+async fn roll_back(moved: Vec<(std::path::PathBuf, Option<Vec<u8>>)>) {
+    use super::admin_revisions::{remove_content_file, write_content_file};
+    use dioxus::logger::tracing;
+    for (dest, before) in moved.into_iter().rev() {
+        let restored = match before {
+            Some(content) => write_content_file(&dest, &content).await,
+            None if dest.exists() => remove_content_file(&dest).await,
+            None => Ok(()),
+        };
+        if let Err(e) = restored {
+            tracing::error!("Cannot roll back {dest:?} after a failed import: {e}");
+        }
+    }
+}
+
+/// Imports a content pack. Refused when validation fails; conflicting files are
+/// overwritten or skipped according to `policy`. The files are staged first and
+/// swapped in all together, then every content kind they touch is reloaded.
+#[post("/api/admin_import_content_pack")]
+pub async fn admin_import_content_pack(
+    archive_base64: String,
+    policy: ContentPackConflictPolicy,
+) -> Result<ContentPackImportReport, ServerFnError> {
+    use super::admin_revisions::reload_data_manager_for;
+    use crate::common::OFFLINE_PATH;
+    use std::path::Path;
+    let (manifest, files) = decode_archive(&archive_base64)?;
+    let offline_root = Path::new(OFFLINE_PATH);
+    let photos = photos_dir();
+    let preview = preview_archive(
+        offline_root,
+        &photos,
+        manifest,
+        &files,
+        &known_boss_names()?,
+    );
+    if !preview.errors.is_empty() {
+        return Err(ServerFnError::new(format!(
+            "Invalid content pack: {}",
+            preview.errors.join("; ")
+        )));
+    }
+
+    let to_write: Vec<&str> = preview
+        .entries
+        .iter()
+        .filter(|entry| match entry.status {
+            ContentPackEntryStatus::New => true,
+            ContentPackEntryStatus::Unchanged => false,
+            ContentPackEntryStatus::Conflict => policy == ContentPackConflictPolicy::Overwrite,
+        })
+        .map(|entry| entry.path.as_str())
+        .collect();
+    let staging = stage_files(&to_write, &files).map_err(ServerFnError::new)?;
+    let swapped = swap_in_staged(&staging, offline_root, &photos, &to_write).await;
+    let _ = std::fs::remove_dir_all(&staging);
+    swapped?;
+
+    let sections: std::collections::BTreeSet<&str> = to_write
+        .iter()
+        .filter_map(|path| path.strip_prefix("offlines/")?.split('/').next())
+        .collect();
+    for section in sections {
+        reload_data_manager_for(section)?;
+    }
+    Ok(ContentPackImportReport {
+        written: to_write.len(),
+        skipped: preview.entries.len() - to_write.len(),
+    })
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// Small `offlines/` and `photos/` trees for universes lotr and pokemon,
+    /// under a temporary directory named after `test`.
+    fn fixture(test: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("dx_rpg_content_pack_{test}_{}", std::process::id()));
+        for (path, content) in [
+            (
+                "offlines/characters/lotr/Thalia.json",
+                r#"{"Name": "Thalia", "Photo": "Thalia"}"#,
+            ),
+            ("offlines/attack/Thalia/Charge.json", r#"{"Nom": "Charge"}"#),
+            (
+                "offlines/talents/lotr/thalia.json",
+                r#"{"hero_key": "Thalia"}"#,
+            ),
+            (
+                "offlines/scenarios/lotr/stage_1.json",
+                r#"{"name": "La Moria", "boss_patterns": {"Balrog": {}}}"#,
+            ),
+            ("offlines/maps/lotr_moria.json", r#"{"id": "lotr_moria"}"#),
+            (
+                "offlines/maps/bree.json",
+                r#"{"id": "bree", "encounters": ["La Moria"]}"#,
+            ),
+            (
+                "offlines/characters/pokemon/Gengar.json",
+                r#"{"Name": "Gengar", "Photo": "Gengar"}"#,
+            ),
+            ("offlines/attack/Gengar/Lick.json", r#"{"Nom": "Lick"}"#),
+            (
+                "offlines/maps/pallet_town.json",
+                r#"{"id": "pallet_town", "start_of": "pokemon"}"#,
+            ),
+            ("photos/Thalia.png", "PNG"),
+        ] {
+            let dest = root.join(path);
+            std::fs::create_dir_all(dest.parent().unwrap()).unwrap();
+            std::fs::write(dest, content).unwrap();
+        }
+        root
+    }
+
+    fn manifest(universe: &str, files: Vec<String>, characters: &[&str]) -> ContentPackManifest {
+        ContentPackManifest {
+            format_version: CONTENT_PACK_FORMAT_VERSION,
+            universe: universe.to_owned(),
+            exported_at: String::new(),
+            app_version: String::new(),
+            files,
+            characters: characters.iter().map(|c| c.to_string()).collect(),
+            missing_images: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn validate_archive_path_accepts_pack_layout() {
+        let thalia = ["Thalia".to_owned()];
+        for path in [
+            "offlines/characters/lotr/Thalia.json",
+            "offlines/attack/Thalia/Charge.json",
+            "offlines/maps/lotr_moria.json",
+            "photos/Thalia.png",
+        ] {
+            assert!(
+                validate_archive_path(path, "lotr", &thalia).is_ok(),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn validate_archive_path_rejects_traversal_and_foreign_files() {
+        let thalia = ["Thalia".to_owned()];
+        for path in [
+            "offlines/characters/../../etc.json",
+            "offlines/characters/pokemon/Gengar.json",
+            "offlines/characters/lotr/notes.txt",
+            "offlines/attack/Gengar/Lick.json",
+            "photos/script.sh",
+            "src/main.rs",
+        ] {
+            assert!(
+                validate_archive_path(path, "lotr", &thalia).is_err(),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn exported_universe_round_trips_without_conflicts() {
+        let root = fixture("round_trip");
+        let (offline_root, photos) = (root.join("offlines"), root.join("photos"));
+        let (files, missing_images) = collect_universe_files(&offline_root, &photos, "lotr");
+        let mut paths: Vec<&str> = files.iter().map(|(p, _)| p.as_str()).collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [
+                "offlines/attack/Thalia/Charge.json",
+                "offlines/characters/lotr/Thalia.json",
+                "offlines/maps/bree.json",
+                "offlines/maps/lotr_moria.json",
+                "offlines/scenarios/lotr/stage_1.json",
+                "offlines/talents/lotr/thalia.json",
+                "photos/Thalia.png",
+            ]
+        );
+
+        let mut manifest = manifest(
+            "lotr",
+            files.iter().map(|(p, _)| p.clone()).collect(),
+            &["Thalia"],
+        );
+        manifest.missing_images = missing_images;
+        let bytes = build_archive(&manifest, &files).unwrap();
+        let (read_manifest, read_files) = read_archive(&bytes).unwrap();
+        assert_eq!(read_manifest, manifest);
+        assert_eq!(read_files.len(), files.len());
+
+        let preview = preview_archive(&offline_root, &photos, read_manifest, &read_files, &[]);
+        std::fs::remove_dir_all(&root).unwrap();
+        assert!(preview.errors.is_empty(), "{:?}", preview.errors);
+        assert!(
+            preview
+                .entries
+                .iter()
+                .all(|e| e.status == ContentPackEntryStatus::Unchanged),
+            "{:?}",
+            preview.entries
+        );
+        assert_eq!(
+            preview.warnings,
+            ["'offlines/scenarios/lotr/stage_1.json': unknown boss 'Balrog'"]
+        );
+    }
+
+    #[test]
+    fn preview_refuses_content_of_other_universes() {
+        let root = fixture("other_universes");
+        let mut files = ArchiveFiles::new();
+        for (path, content) in [
+            (
+                "offlines/characters/lotr/Thalia.json",
+                r#"{"Name": "Thalia"}"#,
+            ),
+            // Gengar is pokemon's, a lotr pack can't take his attacks over
+            (
+                "offlines/characters/lotr/Gengar.json",
+                r#"{"Name": "Gengar"}"#,
+            ),
+            ("offlines/attack/Gengar/Lick.json", r#"{"Nom": "Lick"}"#),
+            // pokemon's starting map, claimed by the pack
+            (
+                "offlines/maps/pallet_town.json",
+                r#"{"id": "pallet_town", "start_of": "lotr"}"#,
+            ),
+            ("offlines/maps/route_9.json", r#"{"id": "route_9"}"#),
+            ("offlines/maps/lotr_mordor.json", r#"{"id": "lotr_mordor"}"#),
+        ] {
+            files.insert(path.to_owned(), content.as_bytes().to_vec());
+        }
+        let manifest = manifest(
+            "lotr",
+            files.keys().cloned().collect(),
+            &["Thalia", "Gengar"],
+        );
+        let preview = preview_archive(
+            &root.join("offlines"),
+            &root.join("photos"),
+            manifest.clone(),
+            &files,
+            &[],
+        );
+        assert_eq!(
+            preview.errors,
+            [
+                "Character 'Gengar' belongs to universe 'pokemon'",
+                "'offlines/maps/pallet_town.json' is not a map of universe 'lotr'",
+                "'offlines/maps/route_9.json' is not a map of universe 'lotr'",
+            ]
+        );
+
+        // Attacks of characters the manifest doesn't declare are refused
+        let manifest = ContentPackManifest {
+            characters: vec!["Thalia".to_owned()],
+            ..manifest
+        };
+        let preview = preview_archive(
+            &root.join("offlines"),
+            &root.join("photos"),
+            manifest,
+            &files,
+            &[],
+        );
+        std::fs::remove_dir_all(&root).unwrap();
+        assert!(
+            preview
+                .errors
+                .contains(&"'offlines/attack/Gengar/Lick.json' belongs to 'Gengar', not a character of the pack".to_owned()),
+            "{:?}",
+            preview.errors
+        );
+    }
+
+    #[test]
+    fn preview_rejects_newer_format_and_unlisted_traversal() {
+        let root = fixture("newer_format");
+        let mut manifest = manifest("lotr", vec!["offlines/maps/missing.json".to_owned()], &[]);
+        manifest.format_version = CONTENT_PACK_FORMAT_VERSION + 1;
+        let mut files = ArchiveFiles::new();
+        files.insert("../evil.json".to_owned(), b"{}".to_vec());
+        let preview = preview_archive(
+            &root.join("offlines"),
+            &root.join("photos"),
+            manifest,
+            &files,
+            &[],
+        );
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(preview.errors.len(), 3, "{:?}", preview.errors);
+        assert!(preview.entries.is_empty());
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "server")]
+use super::admin_revisions::{remove_content_file, write_content_file};
 use dioxus::prelude::*;
 
 /// Returns a list of top-level equipment type directories (e.g. "body", "characters").
@@ -127,8 +129,7 @@ pub async fn admin_save_equipment_json(
     std::fs::create_dir_all(&dir)
         .map_err(|e| ServerFnError::new(format!("Cannot create dir: {e}")))?;
     let path = dir.join(format!("{item_name}.json"));
-    std::fs::write(&path, json_content.as_bytes())
-        .map_err(|e| ServerFnError::new(format!("Cannot write {path:?}: {e}")))
+    write_content_file(&path, json_content.as_bytes()).await
 }
 
 /// Deletes an equipment item file.
@@ -148,8 +149,7 @@ pub async fn admin_delete_equipment(
         .join(&eq_type)
         .join(&category)
         .join(format!("{item_name}.json"));
-    std::fs::remove_file(&path)
-        .map_err(|e| ServerFnError::new(format!("Cannot delete {path:?}: {e}")))
+    remove_content_file(&path).await
 }
 
 /// Creates a new equipment item, copying the stats template from an existing
@@ -220,8 +220,7 @@ pub async fn admin_create_equipment(
         .map_err(|e| ServerFnError::new(format!("Cannot create dir: {e}")))?;
     let content = serde_json::to_string_pretty(&new_item)
         .map_err(|e| ServerFnError::new(format!("Serialize error: {e}")))?;
-    std::fs::write(&dest, content.as_bytes())
-        .map_err(|e| ServerFnError::new(format!("Cannot write {dest:?}: {e}")))
+    write_content_file(&dest, content.as_bytes()).await
 }
 
 /// Returns a list of available image filenames.
@@ -353,8 +352,7 @@ pub async fn admin_save_equipment_form(
         .map_err(|e| ServerFnError::new(format!("Cannot serialize: {e}")))?;
     std::fs::create_dir_all(&dir)
         .map_err(|e| ServerFnError::new(format!("Cannot create dir: {e}")))?;
-    std::fs::write(&path, json_content.as_bytes())
-        .map_err(|e| ServerFnError::new(format!("Cannot write {path:?}: {e}")))
+    write_content_file(&path, json_content.as_bytes()).await
 }
 
 // ── Universe creation ─────────────────────────────────────────────────────────
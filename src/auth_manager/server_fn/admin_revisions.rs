@@ -0,0 +1,407 @@
+#[cfg(feature = "server")]
+use super::admin_content_pack::photos_dir;
+#[cfg(feature = "server")]
+use crate::auth_manager::{db::get_db, server_fn::get_user_name};
+#[cfg(feature = "server")]
+use dioxus::logger::tracing;
+use dioxus::prelude::*;
+
+/// Revision action stored when an admin save creates or overwrites a file.
+pub const REVISION_ACTION_SAVE: &str = "save";
+/// Revision action stored when an admin delete removes a file.
+pub const REVISION_ACTION_DELETE: &str = "delete";
+/// Revision action stored when a file is put back by `admin_restore_revision`.
+pub const REVISION_ACTION_RESTORE: &str = "restore";
+
+/// One recorded change of a content file under `OFFLINE_PATH` or of an image of
+/// the photos directory.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct ContentRevision {
+    pub id: i64,
+    /// Path relative to `OFFLINE_PATH` (e.g. `scenarios/lotr/stage_1.json`), or
+    /// `photos/<file>` for an image
+    pub path: String,
+    pub username: String,
+    /// `save` | `delete` | `restore`
+    pub action: String,
+    pub created_at: String,
+    /// File content before the change, `None` when the file did not exist.
+    /// Binary files are base64-encoded after a `base64:` prefix
+    pub before: Option<String>,
+    /// File content after the change, `None` when the file was deleted
+    pub after: Option<String>,
+}
+
+/// Summary of a content file that has at least one revision.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct RevisedFileInfo {
+    pub path: String,
+    pub nb_revisions: i64,
+    pub last_at: String,
+    pub last_username: String,
+}
+
+/// Kind of a line in a `diff_lines` result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum DiffKind {
+    Same,
+    Added,
+    Removed,
+}
+
+/// One line of a line-based diff.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DiffLine {
+    pub kind: DiffKind,
+    pub text: String,
+}
+
+/// Line-based diff (longest common subsequence) between two file contents.
+/// The common prefix and suffix are trimmed first, so typical admin edits stay cheap.
+pub fn diff_lines(before: &str, after: &str) -> Vec<DiffLine> {
+    let a: Vec<&str> = before.lines().collect();
+    let b: Vec<&str> = after.lines().collect();
+    let prefix = a.iter().zip(&b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a_mid, b_mid) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+
+    // lcs[i][j] = LCS length of a_mid[i..] and b_mid[j..]
+    let mut lcs = vec![vec![0u32; b_mid.len() + 1]; a_mid.len() + 1];
+    for i in (0..a_mid.len()).rev() {
+        for j in (0..b_mid.len()).rev() {
+            lcs[i][j] = if a_mid[i] == b_mid[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let line = |kind: DiffKind, text: &str| DiffLine {
+        kind,
+        text: text.to_owned(),
+    };
+    let mut out: Vec<DiffLine> = a[..prefix]
+        .iter()
+        .map(|l| line(DiffKind::Same, l))
+        .collect();
+    let (mut i, mut j) = (0, 0);
+    while i < a_mid.len() && j < b_mid.len() {
+        if a_mid[i] == b_mid[j] {
+            out.push(line(DiffKind::Same, a_mid[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            out.push(line(DiffKind::Removed, a_mid[i]));
+            i += 1;
+        } else {
+            out.push(line(DiffKind::Added, b_mid[j]));
+            j += 1;
+        }
+    }
+    out.extend(a_mid[i..].iter().map(|l| line(DiffKind::Removed, l)));
+    out.extend(b_mid[j..].iter().map(|l| line(DiffKind::Added, l)));
+    out.extend(
+        a[a.len() - suffix..]
+            .iter()
+            .map(|l| line(DiffKind::Same, l)),
+    );
+    out
+}
+
+/// Revision path of an image of the photos directory.
+#[cfg(feature = "server")]
+const PHOTOS_REVISION_DIR: &str = "photos";
+
+/// Prefix of a revision content holding a binary file (an image), base64-encoded.
+#[cfg(feature = "server")]
+const BINARY_REVISION_PREFIX: &str = "base64:";
+
+/// Path of a content file relative to `OFFLINE_PATH`, or `photos/<file>` for an
+/// image of the photos directory, with `/` separators.
+#[cfg(feature = "server")]
+fn relative_content_path(path: &std::path::Path) -> String {
+    use crate::common::OFFLINE_PATH;
+    let relative = match path.strip_prefix(photos_dir()) {
+        Ok(file) => std::path::Path::new(PHOTOS_REVISION_DIR).join(file),
+        Err(_) => path
+            .strip_prefix(OFFLINE_PATH)
+            .unwrap_or(path)
+            .to_path_buf(),
+    };
+    relative.to_string_lossy().replace('\\', "/")
+}
+
+/// Where the file of a revision path is on this server.
+#[cfg(feature = "server")]
+fn content_path(rel_path: &str) -> std::path::PathBuf {
+    use crate::common::OFFLINE_PATH;
+    match rel_path.strip_prefix(&format!("{PHOTOS_REVISION_DIR}/")) {
+        Some(file) => photos_dir().join(file),
+        None => std::path::Path::new(OFFLINE_PATH).join(rel_path),
+    }
+}
+
+/// Content of a file as stored in a revision: binary files are base64-encoded.
+#[cfg(feature = "server")]
+fn revision_text(content: &[u8]) -> String {
+    match std::str::from_utf8(content) {
+        Ok(text) => text.to_owned(),
+        Err(_) => format!(
+            "{BINARY_REVISION_PREFIX}{}",
+            data_encoding::BASE64.encode(content)
+        ),
+    }
+}
+
+/// File content of a revision text, the reverse of `revision_text`.
+#[cfg(feature = "server")]
+fn revision_bytes(text: &str) -> Vec<u8> {
+    text.strip_prefix(BINARY_REVISION_PREFIX)
+        .and_then(|encoded| data_encoding::BASE64.decode(encoded.as_bytes()).ok())
+        .unwrap_or_else(|| text.as_bytes().to_vec())
+}
+
+/// Stores one revision row. The file change itself already happened, so a
+/// failure says so: the admin must know the change has no revision to undo it.
+#[cfg(feature = "server")]
+async fn record_revision(
+    path: &std::path::Path,
+    action: &str,
+    before: Option<String>,
+    after: Option<String>,
+) -> Result<(), ServerFnError> {
+    let username = get_user_name()
+        .await
+        .unwrap_or_else(|_| "unknown".to_owned());
+    let created_at = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let pool = get_db().await;
+    sqlx::query(
+        "INSERT INTO content_revisions (path, username, action, created_at, before, after)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+    )
+    .bind(relative_content_path(path))
+    .bind(&username)
+    .bind(action)
+    .bind(&created_at)
+    .bind(&before)
+    .bind(&after)
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Cannot record revision of {path:?}: {e}");
+        ServerFnError::new(format!(
+            "{path:?} was changed, but its revision could not be recorded: {e}"
+        ))
+    })?;
+    Ok(())
+}
+
+/// Writes a content file and records the change as a revision.
+/// Every admin save under `OFFLINE_PATH` or in the photos directory goes through here.
+#[cfg(feature = "server")]
+pub(crate) async fn write_content_file(
+    path: &std::path::Path,
+    content: &[u8],
+) -> Result<(), ServerFnError> {
+    write_content_file_as(path, content, REVISION_ACTION_SAVE).await
+}
+
+#[cfg(feature = "server")]
+async fn write_content_file_as(
+    path: &std::path::Path,
+    content: &[u8],
+    action: &str,
+) -> Result<(), ServerFnError> {
+    let before = std::fs::read(path).ok().map(|b| revision_text(&b));
+    std::fs::write(path, content)
+        .map_err(|e| ServerFnError::new(format!("Cannot write {path:?}: {e}")))?;
+    let after = revision_text(content);
+    if before.as_deref() != Some(after.as_str()) {
+        record_revision(path, action, before, Some(after)).await?;
+    }
+    Ok(())
+}
+
+/// Deletes a content file and records its last content as a revision.
+#[cfg(feature = "server")]
+pub(crate) async fn remove_content_file(path: &std::path::Path) -> Result<(), ServerFnError> {
+    remove_content_file_as(path, REVISION_ACTION_DELETE).await
+}
+
+#[cfg(feature = "server")]
+async fn remove_content_file_as(path: &std::path::Path, action: &str) -> Result<(), ServerFnError> {
+    let before = std::fs::read(path).ok().map(|b| revision_text(&b));
+    std::fs::remove_file(path)
+        .map_err(|e| ServerFnError::new(format!("Cannot delete {path:?}: {e}")))?;
+    record_revision(path, action, before, None).await
+}
+
+/// Reloads the part of DATA_MANAGER backed by the given relative content path.
+#[cfg(feature = "server")]
+pub(super) fn reload_data_manager_for(rel_path: &str) -> Result<(), ServerFnError> {
+    use crate::common::{DATA_MANAGER, OFFLINE_PATH};
+    use lib_rpg::server::data_manager::DataManager;
+    let mut dm = DATA_MANAGER
+        .lock()
+        .map_err(|e| ServerFnError::new(format!("{e}")))?;
+    let offline_root = dm.offline_root.clone();
+    match rel_path.split('/').next().unwrap_or("") {
+        "characters" => {
+            dm.all_heroes.clear();
+            dm.all_bosses.clear();
+            dm.load_all_characters(&offline_root)
+                .map_err(|e| ServerFnError::new(format!("Reload failed: {e}")))?;
+        }
+        "scenarios" => {
+            dm.all_scenarios.clear();
+            dm.load_all_scenarios(&offline_root)
+                .map_err(|e| ServerFnError::new(format!("Reload failed: {e}")))?;
+        }
+        // lib-rpg only reads these when the manager is built, so it is rebuilt
+        // whole; the old one stays in place if the new data doesn't load
+        "attack" | "talents" | "equipment" | "maps" => {
+            *dm = DataManager::try_new(OFFLINE_PATH)
+                .map_err(|e| ServerFnError::new(format!("Reload failed: {e}")))?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Returns every content file with at least one revision, most recently changed first.
+#[post("/api/admin_list_revised_files")]
+pub async fn admin_list_revised_files() -> Result<Vec<RevisedFileInfo>, ServerFnError> {
+    let pool = get_db().await;
+    sqlx::query_as(
+        "SELECT r.path AS path, COUNT(*) AS nb_revisions, MAX(r.created_at) AS last_at,
+                (SELECT l.username FROM content_revisions l WHERE l.path = r.path
+                 ORDER BY l.id DESC LIMIT 1) AS last_username
+         FROM content_revisions r GROUP BY r.path ORDER BY MAX(r.id) DESC",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("{e}")))
+}
+
+/// Returns the revisions of one content file, newest first.
+#[post("/api/admin_list_revisions")]
+pub async fn admin_list_revisions(path: String) -> Result<Vec<ContentRevision>, ServerFnError> {
+    let pool = get_db().await;
+    sqlx::query_as(
+        "SELECT id, path, username, action, created_at, before, after
+         FROM content_revisions WHERE path = ?1 ORDER BY id DESC",
+    )
+    .bind(&path)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("{e}")))
+}
+
+/// Undoes one revision: puts its file back to the content it had before that change
+/// (deleting it if the revision created it). The restore is itself recorded.
+#[post("/api/admin_restore_revision")]
+pub async fn admin_restore_revision(revision_id: i64) -> Result<(), ServerFnError> {
+    let pool = get_db().await;
+    let row: Option<(String, Option<String>)> =
+        sqlx::query_as("SELECT path, before FROM content_revisions WHERE id = ?1")
+            .bind(revision_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| ServerFnError::new(format!("{e}")))?;
+    let Some((rel_path, before)) = row else {
+        return Err(ServerFnError::new(format!(
+            "Revision {revision_id} not found"
+        )));
+    };
+    if rel_path.contains("..") || rel_path.starts_with('/') || rel_path.contains('\\') {
+        return Err(ServerFnError::new("Invalid path".to_owned()));
+    }
+    let path = content_path(&rel_path);
+    match before {
+        Some(content) => {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| ServerFnError::new(format!("Cannot create dir: {e}")))?;
+            }
+            write_content_file_as(&path, &revision_bytes(&content), REVISION_ACTION_RESTORE)
+                .await?;
+        }
+        None => {
+            if path.exists() {
+                remove_content_file_as(&path, REVISION_ACTION_RESTORE).await?;
+            }
+        }
+    }
+    reload_data_manager_for(&rel_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(diff: &[DiffLine]) -> Vec<(DiffKind, &str)> {
+        diff.iter().map(|l| (l.kind, l.text.as_str())).collect()
+    }
+
+    #[test]
+    fn diff_lines_marks_changed_lines_only() {
+        let diff = diff_lines("a\nb\nc\nd", "a\nB\nc\nd\ne");
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                (DiffKind::Same, "a"),
+                (DiffKind::Removed, "b"),
+                (DiffKind::Added, "B"),
+                (DiffKind::Same, "c"),
+                (DiffKind::Same, "d"),
+                (DiffKind::Added, "e"),
+            ]
+        );
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn binary_contents_round_trip_through_revisions() {
+        let png = [0x89, b'P', b'N', b'G', 0xff, 0x00];
+        let text = revision_text(&png);
+        assert!(text.starts_with(BINARY_REVISION_PREFIX));
+        assert_eq!(revision_bytes(&text), png);
+        assert_eq!(revision_text(b"{\"id\": 1}"), "{\"id\": 1}");
+        assert_eq!(revision_bytes("{\"id\": 1}"), b"{\"id\": 1}");
+        assert_eq!(
+            content_path("photos/Thalia.png"),
+            photos_dir().join("Thalia.png")
+        );
+        assert_eq!(
+            relative_content_path(&photos_dir().join("Thalia.png")),
+            "photos/Thalia.png"
+        );
+    }
+
+    #[test]
+    fn diff_lines_handles_created_and_deleted_files() {
+        assert!(
+            diff_lines("", "x\ny")
+                .iter()
+                .all(|l| l.kind == DiffKind::Added)
+        );
+        assert!(
+            diff_lines("x\ny", "")
+                .iter()
+                .all(|l| l.kind == DiffKind::Removed)
+        );
+        assert!(
+            diff_lines("same", "same")
+                .iter()
+                .all(|l| l.kind == DiffKind::Same)
+        );
+    }
+}
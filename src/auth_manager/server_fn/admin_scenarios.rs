@@ -1,3 +1,5 @@
+#[cfg(feature = "server")]
+use super::admin_revisions::{remove_content_file, write_content_file};
 use dioxus::prelude::*;
 
 /// Summary of one scenario shown in the admin scenario list.
@@ -119,6 +121,30 @@ pub async fn list_scenarios_for_universe(universe: String) -> Result<Vec<String>
     Ok(names)
 }
 
+/// Returns the id of the map whose `start_of` names `universe`: the overworld map
+/// a new game of that universe starts on. `None` for universes without overworld.
+#[post("/api/get_universe_start_map")]
+pub async fn get_universe_start_map(universe: String) -> Result<Option<String>, ServerFnError> {
+    use crate::common::OFFLINE_PATH;
+    let dir = std::path::Path::new(OFFLINE_PATH).join("maps");
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Ok(None);
+    };
+    let mut paths: Vec<std::path::PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().map(|x| x == "json").unwrap_or(false))
+        .collect();
+    paths.sort();
+    Ok(paths.iter().find_map(|path| {
+        let v: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()?;
+        (v["start_of"] == universe.as_str())
+            .then(|| v["id"].as_str().map(str::to_owned))
+            .flatten()
+    }))
+}
+
 /// A single loot item for scenario editing.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct ScenarioLootItem {
@@ -142,6 +168,10 @@ pub struct ScenarioDetail {
     pub boss_patterns_text: String,
     /// Structured loot items
     pub loots: Vec<ScenarioLootItem>,
+    /// Music track of the fight; empty = the universe's combat or boss track
+    pub music: String,
+    /// Plays the boss track instead of the combat one
+    pub boss_fight: bool,
 }
 
 /// Returns a structured ScenarioDetail for the admin edit form.
@@ -213,6 +243,8 @@ pub async fn get_scenario_detail(
         level,
         boss_patterns_text,
         loots,
+        music: v["music"].as_str().unwrap_or("").to_owned(),
+        boss_fight: v["boss_fight"].as_bool().unwrap_or(false),
     })
 }
 
@@ -269,7 +301,7 @@ pub async fn save_scenario_detail(
         })
         .collect();
 
-    let scenario = serde_json::json!({
+    let mut scenario = serde_json::json!({
         "name": detail.name,
         "description": detail.description,
         "level": detail.level,
@@ -277,6 +309,13 @@ pub async fn save_scenario_detail(
         "loots": serde_json::Value::Array(loots_json_arr),
         "universe": universe,
     });
+    // Optional music fields (see `music.rs`), left out when unset
+    if !detail.music.is_empty() {
+        scenario["music"] = serde_json::Value::String(detail.music);
+    }
+    if detail.boss_fight {
+        scenario["boss_fight"] = serde_json::Value::Bool(true);
+    }
 
     let json_content = serde_json::to_string_pretty(&scenario)
         .map_err(|e| ServerFnError::new(format!("Cannot serialize: {e}")))?;
@@ -285,8 +324,7 @@ pub async fn save_scenario_detail(
     std::fs::create_dir_all(&dir)
         .map_err(|e| ServerFnError::new(format!("Cannot create dir: {e}")))?;
     let path = dir.join(format!("{file_stem}.json"));
-    std::fs::write(&path, json_content.as_bytes())
-        .map_err(|e| ServerFnError::new(format!("Cannot write: {e}")))?;
+    write_content_file(&path, json_content.as_bytes()).await?;
 
     let mut dm = DATA_MANAGER
         .lock()
@@ -329,8 +367,7 @@ pub async fn save_scenario_json(
     std::fs::create_dir_all(&dir)
         .map_err(|e| ServerFnError::new(format!("Cannot create dir {dir:?}: {e}")))?;
     let path = dir.join(format!("{file_stem}.json"));
-    std::fs::write(&path, json_content.as_bytes())
-        .map_err(|e| ServerFnError::new(format!("Cannot write {path:?}: {e}")))?;
+    write_content_file(&path, json_content.as_bytes()).await?;
     let mut dm = DATA_MANAGER
         .lock()
         .map_err(|e| ServerFnError::new(format!("{e}")))?;
@@ -353,8 +390,7 @@ pub async fn delete_scenario_json(
         .join("scenarios")
         .join(&universe)
         .join(format!("{file_stem}.json"));
-    std::fs::remove_file(&path)
-        .map_err(|e| ServerFnError::new(format!("Cannot delete {path:?}: {e}")))?;
+    remove_content_file(&path).await?;
     let mut dm = DATA_MANAGER
         .lock()
         .map_err(|e| ServerFnError::new(format!("{e}")))?;
@@ -0,0 +1,23 @@
+use crate::utils::balance_simulator::{SimulationReport, SimulationRequest};
+use dioxus::prelude::*;
+
+/// Plays `request.nb_fights` headless fights and returns the aggregated balance report.
+/// The simulation runs on a blocking thread since it can take a few seconds, on a copy of
+/// the data manager so that games are not kept waiting for its lock meanwhile.
+#[post("/api/admin_simulate_scenario")]
+pub async fn admin_simulate_scenario(
+    request: SimulationRequest,
+) -> Result<SimulationReport, ServerFnError> {
+    use crate::common::DATA_MANAGER;
+    use crate::utils::balance_simulator::simulate_fights;
+    tokio::task::spawn_blocking(move || {
+        let dm = DATA_MANAGER
+            .lock()
+            .map_err(|e| ServerFnError::new(format!("{e}")))?
+            .clone();
+        simulate_fights(&dm, &request)
+            .map_err(|e| ServerFnError::new(format!("Simulation failed: {e}")))
+    })
+    .await
+    .map_err(|e| ServerFnError::new(format!("{e}")))?
+}
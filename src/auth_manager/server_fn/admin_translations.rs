@@ -0,0 +1,817 @@
+#[cfg(feature = "server")]
+use super::admin_content_pack::is_safe_component;
+#[cfg(feature = "server")]
+use super::admin_revisions::{reload_data_manager_for, write_content_file};
+use dioxus::prelude::*;
+
+/// How far a bilingual field is from being translated.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub enum TranslationStatus {
+    /// One of the two languages is empty
+    Missing,
+    /// Both languages hold the same text, often an untranslated copy
+    Identical,
+    Translated,
+}
+
+/// Identifies one English/French field pair inside a content file.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct TranslationKey {
+    /// Path relative to `OFFLINE_PATH`, e.g. `attack/Bulbasaur/Charge.json`
+    pub path: String,
+    /// JSON pointer of the object holding the pair, empty for the file root
+    pub pointer: String,
+    pub en_key: String,
+    pub fr_key: String,
+}
+
+impl TranslationKey {
+    /// Single-string id used by the CSV and XLIFF files: `path#pointer#en_key#fr_key`.
+    pub fn id(&self) -> String {
+        format!(
+            "{}#{}#{}#{}",
+            self.path, self.pointer, self.en_key, self.fr_key
+        )
+    }
+
+    /// Parses an id built by `id()`. Split from the right since file names may contain `#`.
+    pub fn from_id(id: &str) -> Option<Self> {
+        let mut parts = id.rsplitn(4, '#');
+        let fr_key = parts.next()?;
+        let en_key = parts.next()?;
+        let pointer = parts.next()?;
+        let path = parts.next()?;
+        if path.is_empty() || en_key.is_empty() || fr_key.is_empty() {
+            return None;
+        }
+        Some(Self {
+            path: path.to_owned(),
+            pointer: pointer.to_owned(),
+            en_key: en_key.to_owned(),
+            fr_key: fr_key.to_owned(),
+        })
+    }
+}
+
+/// One bilingual field found in `offlines/`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TranslationEntry {
+    pub key: TranslationKey,
+    /// Text of the single-language field the pair replaces (`Description`, `dialog`…)
+    pub legacy: Option<String>,
+    /// Multi-line dialogs are joined with `\n`
+    pub en: String,
+    pub fr: String,
+    pub status: TranslationStatus,
+}
+
+/// New texts for one field pair. Empty texts are ignored rather than clearing the field.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TranslationUpdate {
+    pub key: TranslationKey,
+    pub en: String,
+    pub fr: String,
+}
+
+/// File formats for offline translation work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum TranslationFormat {
+    Csv,
+    /// XLIFF 1.2, English source and French target
+    Xliff,
+}
+
+impl TranslationFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            TranslationFormat::Csv => "csv",
+            TranslationFormat::Xliff => "xlf",
+        }
+    }
+}
+
+/// Outcome of saving or importing translations.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TranslationImportReport {
+    pub updated: usize,
+    pub unchanged: usize,
+    /// Rows that were skipped: unknown ids, unreadable files…
+    pub errors: Vec<String>,
+}
+
+pub fn translation_status(en: &str, fr: &str) -> TranslationStatus {
+    let (en, fr) = (en.trim(), fr.trim());
+    if en.is_empty() || fr.is_empty() {
+        TranslationStatus::Missing
+    } else if en == fr {
+        TranslationStatus::Identical
+    } else {
+        TranslationStatus::Translated
+    }
+}
+
+/// One English/French field pair of a content type.
+#[cfg(feature = "server")]
+struct FieldPair {
+    en: &'static str,
+    fr: &'static str,
+    /// Single-language field read by older content, shown as a hint
+    legacy: Option<&'static str>,
+    /// Array of strings (NPC dialog lines) instead of a string
+    lines: bool,
+}
+
+#[cfg(feature = "server")]
+const fn pair(en: &'static str, fr: &'static str, legacy: Option<&'static str>) -> FieldPair {
+    FieldPair {
+        en,
+        fr,
+        legacy,
+        lines: false,
+    }
+}
+
+#[cfg(feature = "server")]
+const ATTACK_FIELDS: [FieldPair; 3] = [
+    pair("NomEn", "NomFr", Some("Nom")),
+    pair("DescriptionEn", "DescriptionFr", Some("Description")),
+    pair(
+        "DescriptionEffectsEn",
+        "DescriptionEffectsFr",
+        Some("DescriptionEffects"),
+    ),
+];
+#[cfg(feature = "server")]
+const CHARACTER_FIELDS: [FieldPair; 1] =
+    [pair("DescriptionEn", "DescriptionFr", Some("Description"))];
+/// Equipment names are English in `Nom`, with the French name next to it.
+#[cfg(feature = "server")]
+const EQUIPMENT_FIELDS: [FieldPair; 1] = [pair("Nom", "NomFr", None)];
+#[cfg(feature = "server")]
+const TALENT_PATH_FIELDS: [FieldPair; 1] = [pair("name_en", "name_fr", None)];
+#[cfg(feature = "server")]
+const TALENT_FIELDS: [FieldPair; 2] = [
+    pair("name_en", "name_fr", None),
+    pair("description_en", "description_fr", None),
+];
+#[cfg(feature = "server")]
+const NPC_FIELDS: [FieldPair; 1] = [FieldPair {
+    en: "dialog_en",
+    fr: "dialog_fr",
+    legacy: Some("dialog"),
+    lines: true,
+}];
+
+/// Content directories scanned for bilingual fields. Scenarios only have a single
+/// `description` and are not part of the workbench.
+#[cfg(feature = "server")]
+const TRANSLATED_DIRS: [&str; 5] = ["attack", "characters", "equipment", "maps", "talents"];
+
+/// Objects of a content file holding bilingual fields, as (JSON pointer, field pairs).
+#[cfg(feature = "server")]
+fn field_slots(rel_path: &str, v: &serde_json::Value) -> Vec<(String, &'static [FieldPair])> {
+    match rel_path.split('/').next().unwrap_or("") {
+        "attack" => vec![(String::new(), &ATTACK_FIELDS[..])],
+        "characters" => vec![(String::new(), &CHARACTER_FIELDS[..])],
+        "equipment" => vec![(String::new(), &EQUIPMENT_FIELDS[..])],
+        "talents" => {
+            let mut slots = Vec::new();
+            for (i, path) in v["paths"].as_array().into_iter().flatten().enumerate() {
+                slots.push((format!("/paths/{i}"), &TALENT_PATH_FIELDS[..]));
+                for j in 0..path["talents"].as_array().map_or(0, |t| t.len()) {
+                    slots.push((format!("/paths/{i}/talents/{j}"), &TALENT_FIELDS[..]));
+                }
+            }
+            slots
+        }
+        "maps" => (0..v["npcs"].as_array().map_or(0, |n| n.len()))
+            .map(|i| (format!("/npcs/{i}"), &NPC_FIELDS[..]))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(feature = "server")]
+fn read_text(v: &serde_json::Value, lines: bool) -> String {
+    if lines {
+        v.as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|l| l.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    } else {
+        v.as_str().unwrap_or_default().to_owned()
+    }
+}
+
+#[cfg(feature = "server")]
+fn write_text(text: &str, lines: bool) -> serde_json::Value {
+    if lines {
+        serde_json::Value::Array(
+            text.lines()
+                .map(|l| serde_json::Value::String(l.to_owned()))
+                .collect(),
+        )
+    } else {
+        serde_json::Value::String(text.to_owned())
+    }
+}
+
+/// Lists every bilingual field pair of one parsed content file.
+#[cfg(feature = "server")]
+fn scan_translations(rel_path: &str, v: &serde_json::Value) -> Vec<TranslationEntry> {
+    let mut entries = Vec::new();
+    for (pointer, fields) in field_slots(rel_path, v) {
+        let Some(obj) = v.pointer(&pointer) else {
+            continue;
+        };
+        for field in fields {
+            let en = read_text(&obj[field.en], field.lines);
+            let fr = read_text(&obj[field.fr], field.lines);
+            let legacy = field
+                .legacy
+                .map(|k| read_text(&obj[k], field.lines))
+                .filter(|l| !l.is_empty());
+            entries.push(TranslationEntry {
+                key: TranslationKey {
+                    path: rel_path.to_owned(),
+                    pointer: pointer.clone(),
+                    en_key: field.en.to_owned(),
+                    fr_key: field.fr.to_owned(),
+                },
+                legacy,
+                status: translation_status(&en, &fr),
+                en,
+                fr,
+            });
+        }
+    }
+    entries
+}
+
+/// Applies updates to one parsed content file. Returns how many of them changed
+/// a text, or an error when a key does not name a bilingual field of the file.
+#[cfg(feature = "server")]
+fn apply_translations(
+    rel_path: &str,
+    v: &mut serde_json::Value,
+    updates: &[&TranslationUpdate],
+) -> Result<usize, String> {
+    let slots = field_slots(rel_path, v);
+    let mut changed = 0;
+    for update in updates {
+        let key = &update.key;
+        let Some(field) = slots
+            .iter()
+            .filter(|(pointer, _)| *pointer == key.pointer)
+            .flat_map(|(_, fields)| fields.iter())
+            .find(|f| f.en == key.en_key && f.fr == key.fr_key)
+        else {
+            return Err(format!("'{}' is not a translatable field", key.id()));
+        };
+        let Some(obj) = v.pointer_mut(&key.pointer).and_then(|o| o.as_object_mut()) else {
+            return Err(format!("'{}' does not exist", key.id()));
+        };
+        let mut update_changed = false;
+        for (json_key, text) in [(field.en, &update.en), (field.fr, &update.fr)] {
+            let current = obj
+                .get(json_key)
+                .map(|t| read_text(t, field.lines))
+                .unwrap_or_default();
+            if !text.trim().is_empty() && current != *text {
+                obj.insert(json_key.to_owned(), write_text(text, field.lines));
+                update_changed = true;
+            }
+        }
+        changed += usize::from(update_changed);
+    }
+    Ok(changed)
+}
+
+/// Relative paths of every JSON file in the translated content directories.
+#[cfg(feature = "server")]
+fn collect_translated_files(offline_root: &std::path::Path) -> Vec<String> {
+    fn walk(dir: &std::path::Path, root: &std::path::Path, out: &mut Vec<String>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                walk(&path, root, out);
+            } else if path.extension().is_some_and(|e| e == "json")
+                && let Ok(rel) = path.strip_prefix(root)
+            {
+                out.push(rel.to_string_lossy().replace('\\', "/"));
+            }
+        }
+    }
+    let mut files = Vec::new();
+    for dir in TRANSLATED_DIRS {
+        walk(&offline_root.join(dir), offline_root, &mut files);
+    }
+    files.sort();
+    files
+}
+
+/// A relative content path the workbench may rewrite.
+#[cfg(feature = "server")]
+fn is_translated_path(rel_path: &str) -> bool {
+    let parts: Vec<&str> = rel_path.split('/').collect();
+    parts.len() >= 2
+        && TRANSLATED_DIRS.contains(&parts[0])
+        && parts.iter().all(|p| is_safe_component(p))
+        && rel_path.ends_with(".json")
+}
+
+#[cfg(feature = "server")]
+fn load_all_translations(offline_root: &std::path::Path) -> Vec<TranslationEntry> {
+    collect_translated_files(offline_root)
+        .into_iter()
+        .filter_map(|rel| {
+            let content = std::fs::read_to_string(offline_root.join(&rel)).ok()?;
+            let v: serde_json::Value = serde_json::from_str(&content).ok()?;
+            Some(scan_translations(&rel, &v))
+        })
+        .flatten()
+        .collect()
+}
+
+// ── CSV / XLIFF ───────────────────────────────────────────────────────────────
+
+#[cfg(feature = "server")]
+const CSV_HEADER: [&str; 5] = ["id", "status", "legacy", "en", "fr"];
+
+#[cfg(feature = "server")]
+fn status_label(status: TranslationStatus) -> &'static str {
+    match status {
+        TranslationStatus::Missing => "missing",
+        TranslationStatus::Identical => "identical",
+        TranslationStatus::Translated => "translated",
+    }
+}
+
+#[cfg(feature = "server")]
+pub(super) fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+#[cfg(feature = "server")]
+fn entries_to_csv(entries: &[TranslationEntry]) -> String {
+    let mut out = CSV_HEADER.join(",");
+    out.push('\n');
+    for e in entries {
+        let id = e.key.id();
+        let row = [
+            id.as_str(),
+            status_label(e.status),
+            e.legacy.as_deref().unwrap_or(""),
+            &e.en,
+            &e.fr,
+        ];
+        out.push_str(&row.map(csv_field).join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// RFC 4180 reader: quoted fields may hold commas, quotes and line breaks.
+#[cfg(feature = "server")]
+fn parse_csv(content: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+#[cfg(feature = "server")]
+fn csv_to_updates(content: &str) -> Result<Vec<TranslationUpdate>, String> {
+    let rows = parse_csv(content);
+    let Some((header, rows)) = rows.split_first() else {
+        return Err("Empty CSV file".to_owned());
+    };
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|h| h.trim().eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("Missing CSV column '{name}'"))
+    };
+    let (id_col, en_col, fr_col) = (column("id")?, column("en")?, column("fr")?);
+    let mut updates = Vec::new();
+    for (i, row) in rows.iter().enumerate() {
+        if row.iter().all(|f| f.is_empty()) {
+            continue;
+        }
+        let cell = |col: usize| row.get(col).cloned().unwrap_or_default();
+        let key = TranslationKey::from_id(&cell(id_col))
+            .ok_or_else(|| format!("Line {}: invalid id '{}'", i + 2, cell(id_col)))?;
+        updates.push(TranslationUpdate {
+            key,
+            en: cell(en_col),
+            fr: cell(fr_col),
+        });
+    }
+    Ok(updates)
+}
+
+#[cfg(feature = "server")]
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(feature = "server")]
+fn xml_unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|d| d.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(feature = "server")]
+fn entries_to_xliff(entries: &[TranslationEntry]) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <xliff version=\"1.2\" xmlns=\"urn:oasis:names:tc:xliff:document:1.2\">\n  \
+         <file original=\"offlines\" source-language=\"en\" target-language=\"fr\" datatype=\"plaintext\">\n    \
+         <body>\n",
+    );
+    for e in entries {
+        let state = match e.status {
+            TranslationStatus::Translated => "translated",
+            TranslationStatus::Identical => "needs-review-translation",
+            TranslationStatus::Missing => "needs-translation",
+        };
+        out.push_str(&format!(
+            "      <trans-unit id=\"{}\" xml:space=\"preserve\">\n        \
+             <source>{}</source>\n        \
+             <target state=\"{state}\">{}</target>\n",
+            xml_escape(&e.key.id()),
+            xml_escape(&e.en),
+            xml_escape(&e.fr),
+        ));
+        if let Some(legacy) = &e.legacy {
+            out.push_str(&format!("        <note>{}</note>\n", xml_escape(legacy)));
+        }
+        out.push_str("      </trans-unit>\n");
+    }
+    out.push_str("    </body>\n  </file>\n</xliff>\n");
+    out
+}
+
+/// Text of the first `<tag …>…</tag>` element of `unit`; `<tag/>` gives an empty text.
+#[cfg(feature = "server")]
+fn xml_element_text(unit: &str, tag: &str) -> Option<String> {
+    let open = format!("<{tag}");
+    let mut search = unit;
+    let start = loop {
+        let pos = search.find(&open)?;
+        let after = &search[pos + open.len()..];
+        // skip longer tag names sharing the prefix
+        if after.starts_with(['>', '/', ' ', '\t', '\n', '\r']) {
+            break after;
+        }
+        search = after;
+    };
+    let tag_end = start.find('>')?;
+    if start[..tag_end].ends_with('/') {
+        return Some(String::new());
+    }
+    let body = &start[tag_end + 1..];
+    let close = body.find(&format!("</{tag}>"))?;
+    Some(xml_unescape(&body[..close]))
+}
+
+#[cfg(feature = "server")]
+fn xml_attribute(tag: &str, name: &str) -> Option<String> {
+    let pattern = format!(" {name}=\"");
+    let start = tag.find(&pattern)? + pattern.len();
+    let end = tag[start..].find('"')?;
+    Some(xml_unescape(&tag[start..start + end]))
+}
+
+#[cfg(feature = "server")]
+fn xliff_to_updates(content: &str) -> Result<Vec<TranslationUpdate>, String> {
+    if !content.contains("<xliff") {
+        return Err("Not an XLIFF file".to_owned());
+    }
+    let mut updates = Vec::new();
+    for unit in content.split("<trans-unit").skip(1) {
+        let Some(unit) = unit.split("</trans-unit>").next() else {
+            continue;
+        };
+        let tag = &unit[..unit.find('>').unwrap_or(unit.len())];
+        let id = xml_attribute(tag, "id").ok_or("trans-unit without id")?;
+        let key =
+            TranslationKey::from_id(&id).ok_or_else(|| format!("Invalid trans-unit id '{id}'"))?;
+        updates.push(TranslationUpdate {
+            key,
+            en: xml_element_text(unit, "source").unwrap_or_default(),
+            fr: xml_element_text(unit, "target").unwrap_or_default(),
+        });
+    }
+    Ok(updates)
+}
+
+/// Writes `updates` to the content files, one revision per changed file.
+#[cfg(feature = "server")]
+async fn save_translations(
+    offline_root: &std::path::Path,
+    updates: &[TranslationUpdate],
+) -> Result<TranslationImportReport, ServerFnError> {
+    use std::collections::{BTreeMap, BTreeSet};
+    let mut by_file: BTreeMap<&str, Vec<&TranslationUpdate>> = BTreeMap::new();
+    for update in updates {
+        by_file
+            .entry(update.key.path.as_str())
+            .or_default()
+            .push(update);
+    }
+    let mut report = TranslationImportReport {
+        updated: 0,
+        unchanged: 0,
+        errors: Vec::new(),
+    };
+    let mut touched_sections = BTreeSet::new();
+    for (rel_path, file_updates) in by_file {
+        if !is_translated_path(rel_path) {
+            report
+                .errors
+                .push(format!("'{rel_path}' is not a translatable file"));
+            continue;
+        }
+        let path = offline_root.join(rel_path);
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).map_err(|e| e.to_string()));
+        let mut v = match parsed {
+            Ok(v) => v,
+            Err(e) => {
+                report.errors.push(format!("Cannot read '{rel_path}': {e}"));
+                continue;
+            }
+        };
+        let changed = match apply_translations(rel_path, &mut v, &file_updates) {
+            Ok(changed) => changed,
+            Err(e) => {
+                report.errors.push(e);
+                continue;
+            }
+        };
+        report.unchanged += file_updates.len() - changed;
+        if changed == 0 {
+            continue;
+        }
+        let json = serde_json::to_string_pretty(&v)
+            .map_err(|e| ServerFnError::new(format!("Cannot serialize: {e}")))?;
+        write_content_file(&path, json.as_bytes()).await?;
+        report.updated += changed;
+        touched_sections.insert(rel_path.split('/').next().unwrap_or(""));
+    }
+    for section in touched_sections {
+        reload_data_manager_for(section)?;
+    }
+    Ok(report)
+}
+
+/// Lists every bilingual field of the game data with its translation status.
+#[post("/api/admin_list_translations")]
+pub async fn admin_list_translations() -> Result<Vec<TranslationEntry>, ServerFnError> {
+    use crate::common::OFFLINE_PATH;
+    Ok(load_all_translations(std::path::Path::new(OFFLINE_PATH)))
+}
+
+/// Saves translations edited in the admin workbench.
+#[post("/api/admin_save_translations")]
+pub async fn admin_save_translations(
+    updates: Vec<TranslationUpdate>,
+) -> Result<TranslationImportReport, ServerFnError> {
+    use crate::common::OFFLINE_PATH;
+    save_translations(std::path::Path::new(OFFLINE_PATH), &updates).await
+}
+
+/// Exports the bilingual fields as a CSV or XLIFF document, optionally only the
+/// missing and identical ones.
+#[post("/api/admin_export_translations")]
+pub async fn admin_export_translations(
+    format: TranslationFormat,
+    only_incomplete: bool,
+) -> Result<String, ServerFnError> {
+    use crate::common::OFFLINE_PATH;
+    let entries: Vec<TranslationEntry> = load_all_translations(std::path::Path::new(OFFLINE_PATH))
+        .into_iter()
+        .filter(|e| !only_incomplete || e.status != TranslationStatus::Translated)
+        .collect();
+    Ok(match format {
+        TranslationFormat::Csv => entries_to_csv(&entries),
+        TranslationFormat::Xliff => entries_to_xliff(&entries),
+    })
+}
+
+/// Imports a CSV or XLIFF document produced by `admin_export_translations`.
+/// Unknown ids are reported and skipped; empty cells leave the field untouched.
+#[post("/api/admin_import_translations")]
+pub async fn admin_import_translations(
+    format: TranslationFormat,
+    content: String,
+) -> Result<TranslationImportReport, ServerFnError> {
+    use crate::common::OFFLINE_PATH;
+    let updates = match format {
+        TranslationFormat::Csv => csv_to_updates(&content),
+        TranslationFormat::Xliff => xliff_to_updates(&content),
+    }
+    .map_err(ServerFnError::new)?;
+    save_translations(std::path::Path::new(OFFLINE_PATH), &updates).await
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+
+    fn talent_file() -> serde_json::Value {
+        serde_json::json!({
+            "hero_key": "Thalia",
+            "paths": [{
+                "name_en": "Archery",
+                "name_fr": "",
+                "talents": [
+                    {"name_en": "Aim", "name_fr": "Visée", "description_en": "Crit up", "description_fr": "Crit up"}
+                ]
+            }]
+        })
+    }
+
+    #[test]
+    fn scan_finds_missing_and_identical_fields() {
+        let entries = scan_translations("talents/lotr/Thalia.json", &talent_file());
+        let statuses: Vec<(&str, &str, TranslationStatus)> = entries
+            .iter()
+            .map(|e| (e.key.pointer.as_str(), e.key.en_key.as_str(), e.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("/paths/0", "name_en", TranslationStatus::Missing),
+                (
+                    "/paths/0/talents/0",
+                    "name_en",
+                    TranslationStatus::Translated
+                ),
+                (
+                    "/paths/0/talents/0",
+                    "description_en",
+                    TranslationStatus::Identical
+                ),
+            ]
+        );
+
+        let map = serde_json::json!({"npcs": [{"dialog": ["Bonjour", "Adieu"]}]});
+        let entries = scan_translations("maps/lotr_moria.json", &map);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].legacy.as_deref(), Some("Bonjour\nAdieu"));
+        assert_eq!(entries[0].status, TranslationStatus::Missing);
+    }
+
+    #[test]
+    fn apply_updates_only_whitelisted_fields() {
+        let mut v = talent_file();
+        let key = |pointer: &str, en_key: &str, fr_key: &str| TranslationKey {
+            path: "talents/lotr/Thalia.json".to_owned(),
+            pointer: pointer.to_owned(),
+            en_key: en_key.to_owned(),
+            fr_key: fr_key.to_owned(),
+        };
+        let update = TranslationUpdate {
+            key: key("/paths/0", "name_en", "name_fr"),
+            en: "Archery".to_owned(),
+            fr: "Tir à l'arc".to_owned(),
+        };
+        assert_eq!(
+            apply_translations("talents/lotr/Thalia.json", &mut v, &[&update]),
+            Ok(1)
+        );
+        assert_eq!(v["paths"][0]["name_fr"], "Tir à l'arc");
+        // Saved again, the same texts change nothing
+        assert_eq!(
+            apply_translations("talents/lotr/Thalia.json", &mut v, &[&update]),
+            Ok(0)
+        );
+
+        let forbidden = TranslationUpdate {
+            key: key("", "hero_key", "hero_key"),
+            en: "x".to_owned(),
+            fr: "y".to_owned(),
+        };
+        assert!(apply_translations("talents/lotr/Thalia.json", &mut v, &[&forbidden]).is_err());
+        assert_eq!(v["hero_key"], "Thalia");
+
+        let mut map = serde_json::json!({"npcs": [{"dialog": ["Salut"]}]});
+        let dialog = TranslationUpdate {
+            key: key("/npcs/0", "dialog_en", "dialog_fr"),
+            en: "Hi\nBye".to_owned(),
+            fr: String::new(),
+        };
+        assert_eq!(
+            apply_translations("maps/m.json", &mut map, &[&dialog]),
+            Ok(1)
+        );
+        assert_eq!(
+            map["npcs"][0]["dialog_en"],
+            serde_json::json!(["Hi", "Bye"])
+        );
+        assert!(map["npcs"][0].get("dialog_fr").is_none());
+    }
+
+    #[test]
+    fn csv_and_xliff_round_trip() {
+        let entries: Vec<TranslationEntry> = scan_translations(
+            "attack/Thal#ia/Tir.json",
+            &serde_json::json!({
+                "Nom": "Tir",
+                "NomEn": "Shot, \"aimed\"",
+                "NomFr": "Tir <visé> & précis",
+                "DescriptionEn": "Line one\nline two",
+            }),
+        );
+        let expected: Vec<TranslationUpdate> = entries
+            .iter()
+            .map(|e| TranslationUpdate {
+                key: e.key.clone(),
+                en: e.en.clone(),
+                fr: e.fr.clone(),
+            })
+            .collect();
+        assert_eq!(
+            csv_to_updates(&entries_to_csv(&entries)),
+            Ok(expected.clone())
+        );
+        assert_eq!(xliff_to_updates(&entries_to_xliff(&entries)), Ok(expected));
+        assert_eq!(
+            TranslationKey::from_id("attack/Thal#ia/Tir.json##NomEn#NomFr"),
+            Some(entries[0].key.clone())
+        );
+    }
+}
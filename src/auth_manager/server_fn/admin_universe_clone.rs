@@ -0,0 +1,327 @@
+#[cfg(feature = "server")]
+use super::admin_content_pack::{collect_universe_files, is_safe_component, photos_dir};
+#[cfg(feature = "server")]
+use super::admin_revisions::write_content_file;
+use dioxus::prelude::*;
+
+/// Result of `admin_clone_universe`.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UniverseCloneReport {
+    pub characters: usize,
+    pub scenarios: usize,
+    pub maps: usize,
+    /// Every file written, attacks and talents included
+    pub files: usize,
+}
+
+/// Characters and scenarios are looked up by name across all universes,
+/// so the copies get the target universe appended: `Gengar` → `Gengar (pokemon2)`.
+#[cfg(feature = "server")]
+fn cloned_name(name: &str, target: &str) -> String {
+    format!("{name} ({target})")
+}
+
+/// Map ids carry the universe as prefix: `lotr_moria` → `<target>_moria`.
+/// Ids without the source prefix (e.g. `pallet_town`) get the target prefix added.
+#[cfg(feature = "server")]
+fn cloned_map_id(id: &str, source: &str, target: &str) -> String {
+    let base = id.strip_prefix(&format!("{source}_")).unwrap_or(id);
+    format!("{target}_{base}")
+}
+
+/// `(path, bytes)` pairs, paths as in a content pack (`offlines/...`).
+#[cfg(feature = "server")]
+type ContentFiles = Vec<(String, Vec<u8>)>;
+
+/// Ids renamed by a clone, used to rewrite the cross-references between files.
+#[cfg(feature = "server")]
+#[derive(Default)]
+struct CloneRenames {
+    characters: std::collections::HashMap<String, String>,
+    scenarios: std::collections::HashMap<String, String>,
+    maps: std::collections::HashMap<String, String>,
+}
+
+#[cfg(feature = "server")]
+fn rename_str(v: &mut serde_json::Value, renames: &std::collections::HashMap<String, String>) {
+    if let Some(new) = v.as_str().and_then(|s| renames.get(s)) {
+        *v = serde_json::Value::String(new.clone());
+    }
+}
+
+/// Rewrites every `{"door": {"target_map": ...}}` found in a map's tiles.
+#[cfg(feature = "server")]
+fn rename_door_targets(
+    v: &mut serde_json::Value,
+    maps: &std::collections::HashMap<String, String>,
+) {
+    match v {
+        serde_json::Value::Object(obj) => {
+            if let Some(target_map) = obj.get_mut("door").and_then(|d| d.get_mut("target_map")) {
+                rename_str(target_map, maps);
+            }
+            obj.values_mut()
+                .for_each(|child| rename_door_targets(child, maps));
+        }
+        serde_json::Value::Array(items) => items
+            .iter_mut()
+            .for_each(|child| rename_door_targets(child, maps)),
+        _ => {}
+    }
+}
+
+/// Turns the files of `source` (as returned by `collect_universe_files`) into the
+/// files of a `target` copy: characters, scenarios and maps are renamed, and boss
+/// patterns, talent owners, NPC fights, encounters, door targets and the starting
+/// map follow.
+/// References to content outside `source` are left untouched; images are shared.
+#[cfg(feature = "server")]
+fn clone_universe_files(
+    files: &[(String, Vec<u8>)],
+    source: &str,
+    target: &str,
+) -> Result<(ContentFiles, UniverseCloneReport), String> {
+    let parse = |path: &str, bytes: &[u8]| {
+        serde_json::from_slice::<serde_json::Value>(bytes)
+            .map_err(|e| format!("Invalid JSON in '{path}': {e}"))
+    };
+    let id_of = |path: &str, bytes: &[u8], field: &str| {
+        parse(path, bytes)?[field]
+            .as_str()
+            .map(str::to_owned)
+            .ok_or_else(|| format!("'{path}' has no \"{field}\""))
+    };
+
+    let mut renames = CloneRenames::default();
+    for (path, bytes) in files {
+        match path.split('/').nth(1) {
+            Some("characters") => {
+                let old = id_of(path, bytes, "Name")?;
+                let new = cloned_name(&old, target);
+                renames.characters.insert(old, new);
+            }
+            Some("scenarios") => {
+                let old = id_of(path, bytes, "name")?;
+                let new = cloned_name(&old, target);
+                renames.scenarios.insert(old, new);
+            }
+            Some("maps") => {
+                let old = id_of(path, bytes, "id")?;
+                let new = cloned_map_id(&old, source, target);
+                renames.maps.insert(old, new);
+            }
+            _ => {}
+        }
+    }
+
+    let mut report = UniverseCloneReport::default();
+    let mut cloned = ContentFiles::new();
+    for (path, bytes) in files {
+        let (dest, v) = match path.split('/').collect::<Vec<_>>().as_slice() {
+            ["offlines", "characters", _, _] => {
+                let mut v = parse(path, bytes)?;
+                let old = v["Name"].as_str().unwrap_or_default().to_owned();
+                let new = renames.characters[&old].clone();
+                if let Some(id_name) = v["IdName"].as_str()
+                    && let Some(suffix) = id_name.strip_prefix(&old)
+                {
+                    v["IdName"] = serde_json::Value::String(format!("{new}{suffix}"));
+                }
+                v["Name"] = serde_json::Value::String(new.clone());
+                report.characters += 1;
+                (format!("offlines/characters/{target}/{new}.json"), v)
+            }
+            ["offlines", "attack", owner, file] => {
+                let owner = renames
+                    .characters
+                    .get(*owner)
+                    .map_or(*owner, String::as_str);
+                cloned.push((format!("offlines/attack/{owner}/{file}"), bytes.clone()));
+                continue;
+            }
+            ["offlines", "talents", _, file] => {
+                let mut v = parse(path, bytes)?;
+                rename_str(&mut v["hero_key"], &renames.characters);
+                let dest = match v["hero_key"].as_str() {
+                    Some(hero) if is_safe_component(hero) => {
+                        format!("offlines/talents/{target}/{hero}.json")
+                    }
+                    _ => format!("offlines/talents/{target}/{file}"),
+                };
+                (dest, v)
+            }
+            ["offlines", "scenarios", _, file] => {
+                let mut v = parse(path, bytes)?;
+                rename_str(&mut v["name"], &renames.scenarios);
+                if let Some(patterns) = v["boss_patterns"].as_object_mut() {
+                    *patterns = std::mem::take(patterns)
+                        .into_iter()
+                        .map(|(boss, pattern)| {
+                            (
+                                renames.characters.get(&boss).cloned().unwrap_or(boss),
+                                pattern,
+                            )
+                        })
+                        .collect();
+                }
+                report.scenarios += 1;
+                (format!("offlines/scenarios/{target}/{file}"), v)
+            }
+            ["offlines", "maps", _] => {
+                let mut v = parse(path, bytes)?;
+                rename_str(&mut v["id"], &renames.maps);
+                if v["start_of"] == source {
+                    v["start_of"] = serde_json::Value::String(target.to_owned());
+                }
+                for npc in v["npcs"].as_array_mut().into_iter().flatten() {
+                    if let Some(fight) = npc.get_mut("fight_scenario_id") {
+                        rename_str(fight, &renames.scenarios);
+                    }
+                }
+                for encounter in v["encounters"].as_array_mut().into_iter().flatten() {
+                    rename_str(encounter, &renames.scenarios);
+                }
+                rename_door_targets(&mut v["tiles"], &renames.maps);
+                let id = v["id"].as_str().unwrap_or_default().to_owned();
+                report.maps += 1;
+                (format!("offlines/maps/{id}.json"), v)
+            }
+            // Images are referenced by file name and shared by both universes.
+            _ => continue,
+        };
+        let json = serde_json::to_string_pretty(&v)
+            .map_err(|e| format!("Cannot serialize '{dest}': {e}"))?;
+        cloned.push((dest, json.into_bytes()));
+    }
+    report.files = cloned.len();
+    Ok((cloned, report))
+}
+
+/// Deep-copies universe `source` as a new universe `target`: characters with their
+/// attacks and talents, scenarios and maps, with ids and cross-references renamed
+/// so the copy is playable on its own. Refuses to overwrite any existing file.
+#[post("/api/admin_clone_universe")]
+pub async fn admin_clone_universe(
+    source: String,
+    target: String,
+) -> Result<UniverseCloneReport, ServerFnError> {
+    use crate::common::{DATA_MANAGER, OFFLINE_PATH};
+    use std::path::Path;
+    if !is_safe_component(&source) || !is_safe_component(&target) {
+        return Err(ServerFnError::new("Invalid universe name".to_owned()));
+    }
+    let offline_root = Path::new(OFFLINE_PATH);
+    if ["characters", "scenarios", "talents"]
+        .iter()
+        .any(|subdir| offline_root.join(subdir).join(&target).exists())
+    {
+        return Err(ServerFnError::new(format!(
+            "Universe '{target}' already exists"
+        )));
+    }
+    let (files, _) = collect_universe_files(offline_root, &photos_dir(), &source);
+    if files.is_empty() {
+        return Err(ServerFnError::new(format!(
+            "Universe '{source}' has no content"
+        )));
+    }
+    let (cloned, report) =
+        clone_universe_files(&files, &source, &target).map_err(ServerFnError::new)?;
+
+    let destinations: Vec<std::path::PathBuf> = cloned
+        .iter()
+        .map(|(path, _)| Path::new(OFFLINE_PATH).join(path.trim_start_matches("offlines/")))
+        .collect();
+    if let Some(existing) = destinations.iter().find(|dest| dest.exists()) {
+        return Err(ServerFnError::new(format!(
+            "{existing:?} already exists, clone aborted"
+        )));
+    }
+    for subdir in &["characters", "scenarios"] {
+        let dir = offline_root.join(subdir).join(&target);
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| ServerFnError::new(format!("Cannot create {dir:?}: {e}")))?;
+    }
+    for (dest, (_, data)) in destinations.iter().zip(&cloned) {
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| ServerFnError::new(format!("Cannot create {parent:?}: {e}")))?;
+        }
+        write_content_file(dest, data).await?;
+    }
+
+    let mut dm = DATA_MANAGER
+        .lock()
+        .map_err(|e| ServerFnError::new(format!("{e}")))?;
+    let root = dm.offline_root.clone();
+    dm.all_heroes.clear();
+    dm.all_bosses.clear();
+    dm.load_all_characters(&root)
+        .map_err(|e| ServerFnError::new(format!("Reload failed: {e}")))?;
+    dm.all_scenarios.clear();
+    dm.load_all_scenarios(&root)
+        .map_err(|e| ServerFnError::new(format!("Reload failed: {e}")))?;
+    Ok(report)
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn cloned_json(cloned: &[(String, Vec<u8>)], path: &str) -> serde_json::Value {
+        let (_, bytes) = cloned
+            .iter()
+            .find(|(p, _)| p == path)
+            .unwrap_or_else(|| panic!("{path} not cloned"));
+        serde_json::from_slice(bytes).unwrap()
+    }
+
+    #[test]
+    fn clone_renames_ids_and_cross_references() {
+        let (files, _) =
+            collect_universe_files(Path::new("offlines"), Path::new("photos"), "pokemon");
+        let (cloned, report) = clone_universe_files(&files, "pokemon", "kanto").unwrap();
+        assert!(report.characters > 0 && report.scenarios > 0 && report.maps > 0);
+        assert_eq!(report.files, cloned.len());
+        assert!(cloned.iter().all(|(p, _)| !p.contains("/pokemon/")));
+
+        let rattata = cloned_json(&cloned, "offlines/characters/kanto/Rattata (kanto).json");
+        assert_eq!(rattata["Name"], "Rattata (kanto)");
+        assert_eq!(rattata["IdName"], "Rattata (kanto)_#1");
+        assert!(
+            cloned
+                .iter()
+                .any(|(p, _)| p.starts_with("offlines/attack/Rattata (kanto)/"))
+        );
+
+        let stage_1 = cloned_json(&cloned, "offlines/scenarios/kanto/stage_1.json");
+        assert_eq!(stage_1["name"], "Patrouille Rattata (kanto)");
+        assert!(stage_1["boss_patterns"].get("Rattata (kanto)").is_some());
+
+        let town = cloned_json(&cloned, "offlines/maps/kanto_pallet_town.json");
+        assert_eq!(town["id"], "kanto_pallet_town");
+        assert_eq!(town["start_of"], "kanto");
+        assert_eq!(town["encounters"][0], "Patrouille Rattata (kanto)");
+        assert!(
+            town.to_string()
+                .contains("\"target_map\":\"kanto_route_1\"")
+        );
+    }
+
+    #[test]
+    fn clone_rewrites_talent_owners_and_npc_fights() {
+        let (files, _) = collect_universe_files(Path::new("offlines"), Path::new("photos"), "lotr");
+        let (cloned, _) = clone_universe_files(&files, "lotr", "arda").unwrap();
+
+        let talents = cloned_json(&cloned, "offlines/talents/arda/Thalia (arda).json");
+        assert_eq!(talents["hero_key"], "Thalia (arda)");
+
+        let shire = cloned_json(&cloned, "offlines/maps/arda_shire.json");
+        let npcs = shire["npcs"].as_array().unwrap();
+        assert!(
+            npcs.iter()
+                .any(|npc| npc["fight_scenario_id"] == "Patrouille Gobeline (arda)")
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use crate::arena::ArenaMatch;
+#[cfg(feature = "server")]
+use crate::auth_manager::db::get_db;
+use crate::server_messages::LogMessages;
+use dioxus::prelude::*;
+use lib_rpg::common::log_data::LogData;
+
+/// Number of parties on the arena standings.
+pub const ARENA_STANDINGS_SIZE: i64 = 20;
+/// Number of matches in a player's arena history.
+pub const ARENA_HISTORY_SIZE: i64 = 20;
+
+/// Log of an arena match, kept for its replay: the lines of its scenario and the
+/// messages of the lines the server wrote.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ArenaLog {
+    pub lines: Vec<LogData>,
+    pub messages: LogMessages,
+}
+
+/// Party a player registered for the arena, and how it fares.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct ArenaParty {
+    pub username: String,
+    pub universe: String,
+    /// Comma-separated hero names
+    pub heroes: String,
+    pub rating: i64,
+    pub wins: i64,
+    pub losses: i64,
+    pub updated_at: String,
+}
+
+/// One arena match, as the history lists it; its log is fetched on replay.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct ArenaMatchRecord {
+    pub id: i64,
+    pub attacker: String,
+    pub defender: String,
+    pub attacker_won: bool,
+    /// Ratings before the match
+    pub attacker_rating: i64,
+    pub defender_rating: i64,
+    /// Won by the attacker, lost by the defender
+    pub rating_change: i64,
+    pub turns: i64,
+    pub played_at: String,
+}
+
+/// Everything the Arena page shows.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ArenaOverview {
+    /// Party of the player, once registered
+    pub party: Option<ArenaParty>,
+    /// Best rated parties
+    pub standings: Vec<ArenaParty>,
+    /// Latest matches of the player, as attacker or defender
+    pub history: Vec<ArenaMatchRecord>,
+}
+
+/// Heroes of the party `username` registered, and its rating.
+#[cfg(feature = "server")]
+pub async fn arena_party_of(
+    username: &str,
+) -> anyhow::Result<Option<(i64, Vec<lib_rpg::character_mod::character::Character>)>> {
+    let row: Option<(i64, String)> =
+        sqlx::query_as("SELECT rating, party FROM arena_parties WHERE username = ?1")
+            .bind(username)
+            .fetch_optional(get_db().await)
+            .await?;
+    row.map(|(rating, party)| Ok((rating, serde_json::from_str(&party)?)))
+        .transpose()
+}
+
+/// Registered parties other than the one of `username`: (player, rating).
+#[cfg(feature = "server")]
+pub async fn arena_opponents(username: &str) -> sqlx::Result<Vec<(String, i64)>> {
+    sqlx::query_as("SELECT username, rating FROM arena_parties WHERE username != ?1")
+        .bind(username)
+        .fetch_all(get_db().await)
+        .await
+}
+
+/// Records the result of `arena_match`: both ratings move by the Elo change
+/// of their current values, and the match joins the history with `log`. A
+/// failure is logged, not returned.
+#[cfg(feature = "server")]
+pub async fn save_arena_result(
+    arena_match: &ArenaMatch,
+    attacker_won: bool,
+    turns: i64,
+    log: &ArenaLog,
+) {
+    use crate::arena::elo_changes;
+    use dioxus::logger::tracing;
+
+    let result: anyhow::Result<()> = async {
+        let pool = get_db().await;
+        let mut tx = pool.begin().await?;
+        // A party may have been registered again since matchmaking, never removed
+        let mut ratings = Vec::new();
+        for (username, rating) in [
+            (&arena_match.attacker, arena_match.attacker_rating),
+            (&arena_match.defender, arena_match.defender_rating),
+        ] {
+            let row: Option<(i64,)> =
+                sqlx::query_as("SELECT rating FROM arena_parties WHERE username = ?1")
+                    .bind(username)
+                    .fetch_optional(&mut *tx)
+                    .await?;
+            ratings.push(row.map_or(rating, |(r,)| r));
+        }
+        let (attacker_rating, defender_rating) = (ratings[0], ratings[1]);
+        let (attacker_change, defender_change) =
+            elo_changes(attacker_rating, defender_rating, attacker_won);
+        for (username, change, won) in [
+            (&arena_match.attacker, attacker_change, attacker_won),
+            (&arena_match.defender, defender_change, !attacker_won),
+        ] {
+            sqlx::query(
+                "UPDATE arena_parties SET rating = rating + ?2, wins = wins + ?3,
+                 losses = losses + ?4 WHERE username = ?1",
+            )
+            .bind(username)
+            .bind(change)
+            .bind(i64::from(won))
+            .bind(i64::from(!won))
+            .execute(&mut *tx)
+            .await?;
+        }
+        sqlx::query(
+            "INSERT INTO arena_matches (attacker, defender, attacker_won, attacker_rating,
+             defender_rating, rating_change, turns, log, played_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        )
+        .bind(&arena_match.attacker)
+        .bind(&arena_match.defender)
+        .bind(attacker_won)
+        .bind(attacker_rating)
+        .bind(defender_rating)
+        .bind(attacker_change)
+        .bind(turns)
+        .bind(serde_json::to_string(log)?)
+        .bind(chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string())
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+    .await;
+    if let Err(e) = result {
+        tracing::error!(
+            "Cannot record the arena match of {} against {}: {e}",
+            arena_match.attacker,
+            arena_match.defender
+        );
+    }
+}
+
+/// Registers the party of the save `slot` of the signed-in player for the
+/// arena, in place of the previous one. The rating is kept.
+#[post("/api/register_arena_party")]
+pub async fn register_arena_party(slot: String) -> Result<ArenaParty, ServerFnError> {
+    use super::get_user_name;
+    use crate::{arena::ARENA_START_RATING, common::SAVED_DATA};
+    use lib_rpg::{
+        character_mod::character::Character,
+        common::constants::{core_game_data_const::SAVED_CORE_GAME_DATA, paths_const::GAMES_DIR},
+    };
+
+    let username = get_user_name()
+        .await
+        .map_err(|e| ServerFnError::new(format!("{e}")))?;
+    if slot.trim().is_empty() || slot.contains("..") || slot.contains('/') || slot.contains('\\') {
+        return Err(ServerFnError::new("Invalid save name".to_owned()));
+    }
+    let save_file = SAVED_DATA
+        .join(&username)
+        .join(GAMES_DIR.to_path_buf())
+        .join(&slot)
+        .join(SAVED_CORE_GAME_DATA);
+    let save: serde_json::Value = std::fs::read_to_string(&save_file)
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .ok_or_else(|| ServerFnError::new(format!("Cannot read the save '{slot}'")))?;
+    let party: Vec<Character> = save
+        .pointer("/game_manager/pm/active_heroes")
+        .cloned()
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default();
+    if party.is_empty() {
+        return Err(ServerFnError::new(format!(
+            "The save '{slot}' has no heroes"
+        )));
+    }
+    let universe = save
+        .pointer("/universe")
+        .and_then(|u| u.as_str())
+        .unwrap_or_default()
+        .to_owned();
+    let heroes = party
+        .iter()
+        .map(|h| h.db_full_name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let party_json =
+        serde_json::to_string(&party).map_err(|e| ServerFnError::new(format!("{e}")))?;
+    let updated_at = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    sqlx::query_as(
+        "INSERT INTO arena_parties (username, universe, heroes, party, rating, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+         ON CONFLICT(username) DO UPDATE SET universe = EXCLUDED.universe,
+             heroes = EXCLUDED.heroes, party = EXCLUDED.party, updated_at = EXCLUDED.updated_at
+         RETURNING username, universe, heroes, rating, wins, losses, updated_at",
+    )
+    .bind(&username)
+    .bind(&universe)
+    .bind(&heroes)
+    .bind(&party_json)
+    .bind(ARENA_START_RATING)
+    .bind(&updated_at)
+    .fetch_one(get_db().await)
+    .await
+    .map_err(|e| ServerFnError::new(format!("{e}")))
+}
+
+/// Party, standings and history of the signed-in player.
+#[post("/api/get_arena_overview")]
+pub async fn get_arena_overview() -> Result<ArenaOverview, ServerFnError> {
+    use super::get_user_name;
+
+    let username = get_user_name()
+        .await
+        .map_err(|e| ServerFnError::new(format!("{e}")))?;
+    let pool = get_db().await;
+    let err = |e: sqlx::Error| ServerFnError::new(format!("{e}"));
+    let columns = "username, universe, heroes, rating, wins, losses, updated_at";
+    let party = sqlx::query_as(&format!(
+        "SELECT {columns} FROM arena_parties WHERE username = ?1"
+    ))
+    .bind(&username)
+    .fetch_optional(pool)
+    .await
+    .map_err(err)?;
+    let standings = sqlx::query_as(&format!(
+        "SELECT {columns} FROM arena_parties ORDER BY rating DESC, username LIMIT ?1"
+    ))
+    .bind(ARENA_STANDINGS_SIZE)
+    .fetch_all(pool)
+    .await
+    .map_err(err)?;
+    let history = sqlx::query_as(
+        "SELECT id, attacker, defender, attacker_won, attacker_rating, defender_rating,
+                rating_change, turns, played_at
+         FROM arena_matches WHERE attacker = ?1 OR defender = ?1 ORDER BY id DESC LIMIT ?2",
+    )
+    .bind(&username)
+    .bind(ARENA_HISTORY_SIZE)
+    .fetch_all(pool)
+    .await
+    .map_err(err)?;
+    Ok(ArenaOverview {
+        party,
+        standings,
+        history,
+    })
+}
+
+/// Log of the arena match `match_id`, to replay it. Matches recorded before the
+/// messages were kept have their lines only.
+#[post("/api/get_arena_replay")]
+pub async fn get_arena_replay(match_id: i64) -> Result<ArenaLog, ServerFnError> {
+    let (log,): (String,) = sqlx::query_as("SELECT log FROM arena_matches WHERE id = ?1")
+        .bind(match_id)
+        .fetch_one(get_db().await)
+        .await
+        .map_err(|e| ServerFnError::new(format!("{e}")))?;
+    serde_json::from_str::<ArenaLog>(&log)
+        .or_else(|_| {
+            serde_json::from_str::<Vec<LogData>>(&log).map(|lines| ArenaLog {
+                lines,
+                ..Default::default()
+            })
+        })
+        .map_err(|e| ServerFnError::new(format!("{e}")))
+}
+
+/// The arena match played on `server_name`, if its game is one.
+#[post("/api/get_server_arena_match")]
+pub async fn get_server_arena_match(
+    server_name: String,
+) -> Result<Option<ArenaMatch>, ServerFnError> {
+    Ok(crate::websocket_handler::event_arena::match_of(
+        &server_name,
+    ))
+}
@@ -1,6 +1,8 @@
 #[cfg(feature = "server")]
 use crate::auth_manager::{auth::Session, auth::User, db::get_db, model::SqlUser};
 #[cfg(feature = "server")]
+use crate::server_messages::ServerMessage;
+#[cfg(feature = "server")]
 use dioxus::logger::tracing;
 use dioxus::prelude::*;
 #[cfg(feature = "server")]
@@ -100,9 +102,7 @@ pub async fn login(
     use_password: bool,
 ) -> Result<String, ServerFnError> {
     if username.trim() == "" || (password.is_empty() && use_password) {
-        Err(ServerFnError::new(
-            "Username or Password can't be empty!".to_owned(),
-        ))
+        Err(ServerMessage::CredentialsEmpty.into())
     } else {
         let pool = get_db().await;
         let rows: Vec<SqlUser> = sqlx::query_as("SELECT * FROM users WHERE username = ?1")
@@ -112,10 +112,7 @@ pub async fn login(
             .unwrap();
 
         if rows.is_empty() {
-            Err(ServerFnError::new(format!(
-                "Username {} is not registered!",
-                username
-            )))
+            Err(ServerMessage::UsernameNotRegistered { username }.into())
         } else {
             // A `None`/empty password means this account was created (or migrated from)
             // before USE_PASSWORD was enabled and has no real password to check against
@@ -139,9 +136,7 @@ pub async fn login(
                 live_says_connected
             );
             if db_says_connected || live_says_connected {
-                return Err(ServerFnError::new(
-                    "That user is already connected.".to_owned(),
-                ));
+                return Err(ServerMessage::UserAlreadyConnected.into());
             }
             if !use_password || is_valid {
                 tracing::info!("{}", format!("{:?}", rows[0].id));
@@ -154,13 +149,11 @@ pub async fn login(
                     }
                     Err(e) => {
                         tracing::info!("{}", e);
-                        Err(ServerFnError::new(
-                            "Fail to update connection status on db, abort login".to_owned(),
-                        ))
+                        Err(ServerMessage::ConnectionStatusFailed.into())
                     }
                 }
             } else {
-                Err(ServerFnError::new("Password is not correct!".to_owned()))
+                Err(ServerMessage::WrongPassword.into())
             }
         }
     }
@@ -173,9 +166,7 @@ pub async fn register(
     use_password: bool,
 ) -> Result<(), ServerFnError> {
     if username.trim() == "" || (password.is_empty() && use_password) {
-        Err(ServerFnError::new(
-            "Username or Password can't be empty!".to_owned(),
-        ))
+        Err(ServerMessage::CredentialsEmpty.into())
     } else {
         let pool = get_db().await;
         let rows: Vec<SqlUser> = sqlx::query_as("SELECT * FROM users WHERE username = ?1")
@@ -184,10 +175,7 @@ pub async fn register(
             .await
             .unwrap();
         if !rows.is_empty() {
-            Err(ServerFnError::new(format!(
-                "Username {} is already taken!",
-                username
-            )))
+            Err(ServerMessage::UsernameTaken { username }.into())
         } else if use_password {
             let hash_password = bcrypt::hash(password, 10).unwrap();
             match sqlx::query(
@@ -233,9 +221,7 @@ pub async fn change_password(
     use_password: bool,
 ) -> Result<(), ServerFnError> {
     if new_password.trim().is_empty() {
-        return Err(ServerFnError::new(
-            "New password can't be empty!".to_owned(),
-        ));
+        return Err(ServerMessage::NewPasswordEmpty.into());
     }
     let pool = get_db().await;
     let rows: Vec<SqlUser> = sqlx::query_as("SELECT * FROM users WHERE username = ?1")
@@ -245,10 +231,7 @@ pub async fn change_password(
         .map_err(|e| ServerFnError::new(format!("{}", e)))?;
 
     let Some(row) = rows.into_iter().next() else {
-        return Err(ServerFnError::new(format!(
-            "Username {} is not registered!",
-            username
-        )));
+        return Err(ServerMessage::UsernameNotRegistered { username }.into());
     };
 
     let old_password_ok = match row.password.as_deref() {
@@ -258,9 +241,7 @@ pub async fn change_password(
         _ => true,
     };
     if !old_password_ok {
-        return Err(ServerFnError::new(
-            "Current password is not correct!".to_owned(),
-        ));
+        return Err(ServerMessage::WrongCurrentPassword.into());
     }
 
     let hash_password =
@@ -281,11 +262,9 @@ pub async fn delete_user(
     use_password: bool,
 ) -> Result<(), ServerFnError> {
     if username.trim() == "Admin" {
-        let msg = "Admin cannot be deleted".to_owned();
-        Err(ServerFnError::new(msg))
+        Err(ServerMessage::AdminNotDeletable.into())
     } else if username.trim() == "" || (password.is_empty() && use_password) {
-        let msg = "Username or Password can't be empty!".to_owned();
-        Err(ServerFnError::new(msg))
+        Err(ServerMessage::CredentialsEmpty.into())
     } else {
         let pool = get_db().await;
         let rows: Vec<SqlUser> = sqlx::query_as("SELECT * FROM users WHERE username = ?1")
@@ -295,8 +274,7 @@ pub async fn delete_user(
             .unwrap();
 
         if rows.is_empty() {
-            let msg = format!("Username {} is not registered!", username);
-            Err(ServerFnError::new(msg))
+            Err(ServerMessage::UsernameNotRegistered { username }.into())
         } else {
             let is_valid = match rows[0].password.as_deref() {
                 Some(hash) if !hash.is_empty() => bcrypt::verify(&password, hash).is_ok(),
@@ -318,7 +296,7 @@ pub async fn delete_user(
                         Err(e) => Err(ServerFnError::new(format!("{}", e))),
                     }
                 } else {
-                    Err(ServerFnError::new("Password is not correct!".to_owned()))
+                    Err(ServerMessage::WrongPassword.into())
                 }
             } else {
                 match sqlx::query("DELETE FROM users WHERE username = ?1")
@@ -0,0 +1,145 @@
+#[cfg(feature = "server")]
+use super::admin_translations::csv_field;
+use dioxus::prelude::*;
+
+use crate::combat_log::LogExportFormat;
+#[cfg(feature = "server")]
+use crate::combat_log::{LogEntry, LogKind};
+#[cfg(feature = "server")]
+use crate::server_messages;
+
+/// Writes `entries` in `format`, their messages in English. Text keeps the markers
+/// as headers; JSON and CSV drop them since each row carries its attack.
+#[cfg(feature = "server")]
+fn entries_to_export(entries: &[LogEntry], format: LogExportFormat) -> String {
+    let text_of = |e: &LogEntry| e.text_with(server_messages::english);
+    let rows = || entries.iter().filter(|e| !e.kind.is_marker());
+    match format {
+        LogExportFormat::Text => {
+            let mut out = String::new();
+            for e in entries {
+                let text = text_of(e);
+                match e.kind {
+                    LogKind::ScenarioMarker => out.push_str(&format!("\n=== {text} ===\n")),
+                    LogKind::AttackMarker => out.push_str(&format!("\n--- {text} ---\n")),
+                    _ => {
+                        out.push_str(&text);
+                        out.push('\n');
+                    }
+                }
+            }
+            out.trim_start().to_owned()
+        }
+        LogExportFormat::Json => {
+            let rows: Vec<serde_json::Value> = rows()
+                .map(|e| {
+                    serde_json::json!({
+                        "kind": e.kind,
+                        "turn": e.context.as_ref().map(|c| c.turn),
+                        "round": e.context.as_ref().map(|c| c.round),
+                        "launcher": e.context.as_ref().map(|c| &c.launcher),
+                        "attack": e.context.as_ref().map(|c| &c.attack),
+                        "targets": e.context.as_ref().map(|c| &c.targets),
+                        "message": text_of(e),
+                    })
+                })
+                .collect();
+            serde_json::to_string_pretty(&rows).unwrap_or_default()
+        }
+        LogExportFormat::Csv => {
+            let mut out = "turn,round,launcher,attack,targets,kind,message\n".to_owned();
+            for e in rows() {
+                let (turn, round, launcher, attack, targets) = match &e.context {
+                    Some(c) => (
+                        c.turn.to_string(),
+                        c.round.to_string(),
+                        c.launcher.clone(),
+                        c.attack.clone(),
+                        c.targets.join(";"),
+                    ),
+                    None => Default::default(),
+                };
+                let kind = serde_json::to_value(e.kind)
+                    .ok()
+                    .and_then(|v| v.as_str().map(str::to_owned))
+                    .unwrap_or_default();
+                let row = [turn, round, launcher, attack, targets, kind, text_of(e)];
+                out.push_str(&row.map(|f| csv_field(&f)).join(","));
+                out.push('\n');
+            }
+            out
+        }
+    }
+}
+
+/// Log of the scenario being played on `server_name`, in `format`. Only for the
+/// players of that game.
+#[post("/api/export_combat_log")]
+pub async fn export_combat_log(
+    server_name: String,
+    format: LogExportFormat,
+) -> Result<String, ServerFnError> {
+    use crate::{
+        auth_manager::server_fn::get_user_name,
+        combat_log::{annotate, current_scenario},
+        websocket_handler::{event::get_server_data_by_server_name, event_logs},
+    };
+    let username = get_user_name()
+        .await
+        .map_err(|e| ServerFnError::new(format!("{e}")))?;
+    let server_data = get_server_data_by_server_name(&server_name)
+        .filter(|d| d.players_data.players_info.contains_key(&username))
+        .ok_or_else(|| ServerFnError::new(format!("{username} is not playing on {server_name}")))?;
+    let messages = event_logs::log_messages_of(&server_name);
+    let entries = annotate(
+        current_scenario(&server_data.core_game_data.game_manager.logs, &messages),
+        &messages,
+    );
+    Ok(entries_to_export(&entries, format))
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+    use crate::combat_log::{AttackContext, annotate, attack_marker, scenario_marker};
+    use crate::server_messages::LogMessages;
+    use lib_rpg::common::log_data::{LogData, const_colors::DARK_RED};
+
+    #[test]
+    fn exports_carry_the_attack_of_each_line() {
+        let mut messages = LogMessages::default();
+        let logs = vec![
+            scenario_marker(&mut messages, "La Moria"),
+            attack_marker(
+                &mut messages,
+                AttackContext {
+                    turn: 2,
+                    round: 1,
+                    launcher: "Elara".to_owned(),
+                    attack: "Boule de feu".to_owned(),
+                    targets: vec!["Orc".to_owned(), "Troll".to_owned()],
+                },
+            ),
+            LogData {
+                message: "Elara hits Orc, \"hard\"".to_owned(),
+                color: DARK_RED.to_owned(),
+            },
+        ];
+        let entries = annotate(&logs, &messages);
+
+        let csv = entries_to_export(&entries, LogExportFormat::Csv);
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            rows[1..],
+            ["2,1,Elara,Boule de feu,Orc;Troll,combat,\"Elara hits Orc, \"\"hard\"\"\""]
+        );
+
+        let text = entries_to_export(&entries, LogExportFormat::Text);
+        assert!(text.contains("📜 Scenario La Moria ==="));
+        assert!(text.contains("Turn 2.1 · Elara ➜ Orc, Troll ---"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&entries_to_export(&entries, LogExportFormat::Json)).unwrap();
+        assert_eq!(json[0]["targets"][1], "Troll");
+    }
+}
@@ -0,0 +1,237 @@
+#[cfg(feature = "server")]
+use super::ScenarioRecord;
+#[cfg(feature = "server")]
+use crate::auth_manager::db::get_db;
+use crate::daily_challenge::DailyChallenge;
+use dioxus::prelude::*;
+
+/// Number of results on the daily leaderboard.
+pub const DAILY_LEADERBOARD_SIZE: i64 = 10;
+
+/// One player's attempt at a daily challenge.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct DailyChallengeResult {
+    pub username: String,
+    pub day: String,
+    /// Comma-separated hero names
+    pub heroes: String,
+    /// Won within the rules of the challenge
+    pub won: bool,
+    pub turns: i64,
+    pub damage: i64,
+    pub potions: i64,
+    /// `None` while the attempt is played, or once abandoned
+    pub finished_at: Option<String>,
+}
+
+/// Everything the Home page card shows.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DailyChallengeOverview {
+    /// `None` when the server has no scenario to draw a challenge from
+    pub challenge: Option<DailyChallenge>,
+    /// Today's attempt of the player, once started
+    pub attempt: Option<DailyChallengeResult>,
+    /// Rank of `attempt` on today's leaderboard, once finished
+    pub rank: Option<i64>,
+    /// Best rank of the player on a challenge won, and its day
+    pub best_rank: Option<(i64, String)>,
+    /// Best finished attempts of the day
+    pub leaderboard: Vec<DailyChallengeResult>,
+}
+
+/// Challenge of the current day (server time), drawn among every loaded
+/// scenario having heroes in its universe.
+#[cfg(feature = "server")]
+pub fn todays_challenge() -> Option<DailyChallenge> {
+    use crate::{
+        common::DATA_MANAGER,
+        daily_challenge::{ChallengeCandidate, generate_daily_challenge},
+    };
+    use lib_rpg::character_mod::character::CharacterKind;
+
+    let dm = DATA_MANAGER.lock().ok()?;
+    let candidates: Vec<ChallengeCandidate> = dm
+        .all_scenarios
+        .iter()
+        .map(|s| ChallengeCandidate {
+            universe: s.universe.clone(),
+            scenario: s.name.clone(),
+            heroes: dm
+                .all_heroes
+                .iter()
+                .filter(|c| c.kind == CharacterKind::Hero && c.universe == s.universe)
+                .map(|c| c.db_full_name.clone())
+                .collect(),
+        })
+        .collect();
+    drop(dm);
+    generate_daily_challenge(chrono::Local::now().date_naive(), &candidates)
+}
+
+/// Whether `username` already started the challenge of `day`.
+#[cfg(feature = "server")]
+pub async fn has_started_daily_challenge(username: &str, day: &str) -> sqlx::Result<bool> {
+    let row: Option<(String,)> = sqlx::query_as(
+        "SELECT started_at FROM daily_challenge_results WHERE username = ?1 AND day = ?2",
+    )
+    .bind(username)
+    .bind(day)
+    .fetch_optional(get_db().await)
+    .await?;
+    Ok(row.is_some())
+}
+
+/// Records that each of `usernames` starts `challenge` with `heroes`, all of
+/// them or none. Returns the players who already started it, in which case
+/// nothing is recorded: a challenge is played once.
+#[cfg(feature = "server")]
+pub async fn claim_daily_challenge(
+    usernames: &[String],
+    challenge: &DailyChallenge,
+    heroes: &str,
+) -> sqlx::Result<Vec<String>> {
+    let started_at = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let mut tx = get_db().await.begin().await?;
+    let mut already_played = Vec::new();
+    for username in usernames {
+        let row: Option<(String,)> = sqlx::query_as(
+            "SELECT started_at FROM daily_challenge_results WHERE username = ?1 AND day = ?2",
+        )
+        .bind(username)
+        .bind(&challenge.date)
+        .fetch_optional(&mut *tx)
+        .await?;
+        if row.is_some() {
+            already_played.push(username.clone());
+        }
+    }
+    if !already_played.is_empty() {
+        // Dropped, the transaction is rolled back
+        return Ok(already_played);
+    }
+    for username in usernames {
+        let inserted = sqlx::query(
+            "INSERT INTO daily_challenge_results (username, day, scenario, heroes, started_at)
+             VALUES (?1, ?2, ?3, ?4, ?5) ON CONFLICT(username, day) DO NOTHING",
+        )
+        .bind(username)
+        .bind(&challenge.date)
+        .bind(&challenge.scenario)
+        .bind(heroes)
+        .bind(&started_at)
+        .execute(&mut *tx)
+        .await?;
+        // Started meanwhile from another game
+        if inserted.rows_affected() == 0 {
+            return Ok(vec![username.clone()]);
+        }
+    }
+    tx.commit().await?;
+    Ok(Vec::new())
+}
+
+/// Writes the end of `challenge`, described by `record`, into the attempt of
+/// each of `players`. Only the first end counts: replaying the fight changes
+/// nothing. A failure is logged, not returned.
+#[cfg(feature = "server")]
+pub async fn save_daily_challenge_result(
+    players: &[String],
+    challenge: &DailyChallenge,
+    record: &ScenarioRecord,
+) {
+    use dioxus::logger::tracing;
+
+    let won = challenge.is_beaten(record.won, record.turns);
+    let damage: i64 = record.heroes.iter().map(|(_, d, _)| d).sum();
+    let finished_at = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    for player in players {
+        let updated = sqlx::query(
+            "UPDATE daily_challenge_results
+             SET won = ?1, turns = ?2, damage = ?3, potions = ?4, finished_at = ?5
+             WHERE username = ?6 AND day = ?7 AND finished_at IS NULL",
+        )
+        .bind(won)
+        .bind(record.turns)
+        .bind(damage)
+        .bind(record.potions_used)
+        .bind(&finished_at)
+        .bind(player)
+        .bind(&challenge.date)
+        .execute(get_db().await)
+        .await;
+        if let Err(e) = updated {
+            tracing::error!("Cannot record the daily challenge of {player}: {e}");
+        }
+    }
+}
+
+/// Today's challenge with the attempt of `username` and the day's leaderboard.
+#[post("/api/get_daily_challenge")]
+pub async fn get_daily_challenge(
+    username: String,
+) -> Result<DailyChallengeOverview, ServerFnError> {
+    let err = |e: sqlx::Error| ServerFnError::new(format!("{e}"));
+    let Some(challenge) = todays_challenge() else {
+        return Ok(DailyChallengeOverview::default());
+    };
+    let db = get_db().await;
+
+    let attempt: Option<DailyChallengeResult> = sqlx::query_as(
+        "SELECT username, day, heroes, won, turns, damage, potions, finished_at
+         FROM daily_challenge_results WHERE username = ?1 AND day = ?2",
+    )
+    .bind(&username)
+    .bind(&challenge.date)
+    .fetch_optional(db)
+    .await
+    .map_err(err)?;
+    let leaderboard: Vec<DailyChallengeResult> = sqlx::query_as(
+        "SELECT username, day, heroes, won, turns, damage, potions, finished_at
+         FROM daily_challenge_results WHERE day = ?1 AND finished_at IS NOT NULL
+         ORDER BY won DESC, turns ASC, damage DESC, finished_at ASC LIMIT ?2",
+    )
+    .bind(&challenge.date)
+    .bind(DAILY_LEADERBOARD_SIZE)
+    .fetch_all(db)
+    .await
+    .map_err(err)?;
+    let rank: Option<(i64,)> = sqlx::query_as(
+        "SELECT rank FROM (
+             SELECT username, RANK() OVER (ORDER BY won DESC, turns ASC, damage DESC) AS rank
+             FROM daily_challenge_results WHERE day = ?1 AND finished_at IS NOT NULL
+         ) WHERE username = ?2",
+    )
+    .bind(&challenge.date)
+    .bind(&username)
+    .fetch_optional(db)
+    .await
+    .map_err(err)?;
+    let best_rank: Option<(i64, String)> = sqlx::query_as(
+        "SELECT rank, day FROM (
+             SELECT username, day, won,
+                    RANK() OVER (PARTITION BY day ORDER BY won DESC, turns ASC, damage DESC) AS rank
+             FROM daily_challenge_results WHERE finished_at IS NOT NULL
+         ) WHERE username = ?1 AND won ORDER BY rank ASC, day DESC LIMIT 1",
+    )
+    .bind(&username)
+    .fetch_optional(db)
+    .await
+    .map_err(err)?;
+
+    Ok(DailyChallengeOverview {
+        challenge: Some(challenge),
+        attempt,
+        rank: rank.map(|(r,)| r),
+        best_rank,
+        leaderboard,
+    })
+}
+
+/// The daily challenge played on `server_name`, if its game is one.
+#[post("/api/get_server_daily_challenge")]
+pub async fn get_server_daily_challenge(
+    server_name: String,
+) -> Result<Option<DailyChallenge>, ServerFnError> {
+    Ok(crate::websocket_handler::event_daily_challenge::challenge_of(&server_name))
+}
@@ -0,0 +1,12 @@
+use crate::endless::EndlessRun;
+use dioxus::prelude::*;
+
+/// The endless run played on `server_name`, if its game is one.
+#[post("/api/get_server_endless_run")]
+pub async fn get_server_endless_run(
+    server_name: String,
+) -> Result<Option<EndlessRun>, ServerFnError> {
+    Ok(crate::websocket_handler::event_endless::run_of(
+        &server_name,
+    ))
+}
@@ -0,0 +1,209 @@
+#[cfg(feature = "server")]
+use super::ScenarioRecord;
+#[cfg(feature = "server")]
+use crate::auth_manager::db::get_db;
+use dioxus::prelude::*;
+
+/// Number of entries of a leaderboard.
+pub const LEADERBOARD_SIZE: i64 = 20;
+
+/// What a leaderboard ranks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum LeaderboardCategory {
+    /// Fewest turns
+    #[default]
+    FastestClear,
+    /// Most damage dealt by the party
+    MostDamage,
+    /// Fewest potions drunk, then fewest turns
+    FewestPotions,
+}
+
+impl LeaderboardCategory {
+    pub const ALL: [LeaderboardCategory; 3] = [
+        LeaderboardCategory::FastestClear,
+        LeaderboardCategory::MostDamage,
+        LeaderboardCategory::FewestPotions,
+    ];
+
+    /// Query of the category's leaderboard, earliest result first on ties.
+    #[cfg(feature = "server")]
+    fn query(self) -> &'static str {
+        macro_rules! leaderboard_query {
+            ($order:literal) => {
+                concat!(
+                    "SELECT players, heroes, turns, damage, potions, finished_at FROM leaderboard_entries
+                     WHERE universe = ?1 AND scenario = ?2 AND single_player = ?3 AND finished_at >= ?4
+                     ORDER BY ",
+                    $order,
+                    " LIMIT ?5"
+                )
+            };
+        }
+        match self {
+            LeaderboardCategory::FastestClear => leaderboard_query!("turns ASC, finished_at ASC"),
+            LeaderboardCategory::MostDamage => leaderboard_query!("damage DESC, finished_at ASC"),
+            LeaderboardCategory::FewestPotions => {
+                leaderboard_query!("potions ASC, turns ASC, finished_at ASC")
+            }
+        }
+    }
+}
+
+/// Time span of a leaderboard.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum LeaderboardPeriod {
+    /// Since Monday 00:00, server time
+    #[default]
+    Weekly,
+    AllTime,
+}
+
+/// Which leaderboard to show.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LeaderboardQuery {
+    pub universe: String,
+    /// Empty = whole-universe runs
+    pub scenario: String,
+    pub category: LeaderboardCategory,
+    pub single_player: bool,
+    pub period: LeaderboardPeriod,
+}
+
+/// One ranked clear, of a scenario or of a whole universe.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct LeaderboardEntry {
+    /// Comma-separated usernames
+    pub players: String,
+    /// Comma-separated hero names
+    pub heroes: String,
+    pub turns: i64,
+    pub damage: i64,
+    pub potions: i64,
+    pub finished_at: String,
+}
+
+/// Sums of a universe played from its first scenario to its last one.
+#[cfg(feature = "server")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunTotals {
+    pub turns: i64,
+    pub damage: i64,
+    pub potions: i64,
+}
+
+/// Start of the current week (Monday 00:00) in the `finished_at` format.
+#[cfg(feature = "server")]
+fn week_start(now: chrono::NaiveDateTime) -> String {
+    use chrono::Datelike;
+    let monday =
+        now.date() - chrono::Duration::days(i64::from(now.weekday().num_days_from_monday()));
+    format!("{} 00:00:00", monday.format("%Y-%m-%d"))
+}
+
+/// Adds the clear of `record` (and of its universe, when it ends a ranked run)
+/// to the leaderboards. Only called by the server from its own game state on a
+/// scenario end: there is no way for a client to submit a result. A failure is
+/// logged, not returned.
+#[cfg(feature = "server")]
+pub async fn save_leaderboard_entries(players: &[String], record: &ScenarioRecord) {
+    use dioxus::logger::tracing;
+
+    if !record.ranked {
+        return;
+    }
+    let players = players.join(", ");
+    let heroes = record
+        .heroes
+        .iter()
+        .map(|(name, _, _)| name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let damage: i64 = record.heroes.iter().map(|(_, d, _)| d).sum();
+    let mut entries = vec![(
+        record.scenario.as_str(),
+        record.turns,
+        damage,
+        record.potions_used,
+    )];
+    if let Some(run) = &record.universe_run {
+        entries.push(("", run.turns, run.damage, run.potions));
+    }
+    let finished_at = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    for (scenario, turns, damage, potions) in entries {
+        let inserted = sqlx::query(
+            "INSERT INTO leaderboard_entries
+             (universe, scenario, single_player, players, heroes, turns, damage, potions, finished_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        )
+        .bind(&record.universe)
+        .bind(scenario)
+        .bind(record.single_player)
+        .bind(&players)
+        .bind(&heroes)
+        .bind(turns)
+        .bind(damage)
+        .bind(potions)
+        .bind(&finished_at)
+        .execute(get_db().await)
+        .await;
+        if let Err(e) = inserted {
+            tracing::error!("Cannot record the leaderboard entry of {players}: {e}");
+        }
+    }
+}
+
+/// Best entries of the leaderboard described by `query`.
+#[post("/api/get_leaderboard")]
+pub async fn get_leaderboard(
+    query: LeaderboardQuery,
+) -> Result<Vec<LeaderboardEntry>, ServerFnError> {
+    let since = match query.period {
+        LeaderboardPeriod::Weekly => week_start(chrono::Local::now().naive_local()),
+        LeaderboardPeriod::AllTime => String::new(),
+    };
+    sqlx::query_as(query.category.query())
+        .bind(&query.universe)
+        .bind(&query.scenario)
+        .bind(query.single_player)
+        .bind(&since)
+        .bind(LEADERBOARD_SIZE)
+        .fetch_all(get_db().await)
+        .await
+        .map_err(|e| ServerFnError::new(format!("{e}")))
+}
+
+/// Universes having ranked entries, each with its scenarios (whole-universe
+/// runs excluded), for the leaderboard filters.
+#[post("/api/list_leaderboard_scopes")]
+pub async fn list_leaderboard_scopes()
+-> Result<std::collections::BTreeMap<String, Vec<String>>, ServerFnError> {
+    let rows: Vec<(String, String)> = sqlx::query_as(
+        "SELECT DISTINCT universe, scenario FROM leaderboard_entries ORDER BY universe, scenario",
+    )
+    .fetch_all(get_db().await)
+    .await
+    .map_err(|e| ServerFnError::new(format!("{e}")))?;
+    let mut scopes: std::collections::BTreeMap<String, Vec<String>> = Default::default();
+    for (universe, scenario) in rows {
+        let scenarios = scopes.entry(universe).or_default();
+        if !scenario.is_empty() {
+            scenarios.push(scenario);
+        }
+    }
+    Ok(scopes)
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weeks_start_on_monday() {
+        let at = |s: &str| chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+        // 2026-10-18 is a Sunday
+        assert_eq!(week_start(at("2026-10-18 23:59:00")), "2026-10-12 00:00:00");
+        assert_eq!(week_start(at("2026-10-19 08:00:00")), "2026-10-19 00:00:00");
+    }
+}
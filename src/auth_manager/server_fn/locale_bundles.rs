@@ -0,0 +1,69 @@
+use dioxus::prelude::*;
+
+use crate::locales::LocaleBundle;
+
+/// Reads the `<lang-id>.ftl` files of `dir`. Files whose name is not a language
+/// identifier, and the bundled locales (compiled into every client), are skipped.
+#[cfg(feature = "server")]
+fn scan_locale_dir(dir: &std::path::Path) -> Vec<LocaleBundle> {
+    use crate::locales::BUNDLED_LOCALES;
+    use unic_langid::LanguageIdentifier;
+
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut bundles: Vec<LocaleBundle> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "ftl"))
+        .filter_map(|path| {
+            let stem = path.file_stem()?.to_str()?;
+            let lang_id = stem.parse::<LanguageIdentifier>().ok()?.to_string();
+            if BUNDLED_LOCALES.iter().any(|b| b.lang_id == lang_id) {
+                return None;
+            }
+            let ftl = std::fs::read_to_string(&path).ok()?;
+            Some(LocaleBundle { lang_id, ftl })
+        })
+        .collect();
+    bundles.sort_by(|a, b| a.lang_id.cmp(&b.lang_id));
+    bundles
+}
+
+/// Lists the locales served from `offlines/locales/` on top of the bundled ones.
+/// Public: the language selector is available before login.
+#[post("/api/list_locale_bundles")]
+pub async fn list_locale_bundles() -> Result<Vec<LocaleBundle>, ServerFnError> {
+    use crate::{common::OFFLINE_PATH, locales::LOCALES_DIR};
+    Ok(scan_locale_dir(
+        &std::path::Path::new(OFFLINE_PATH).join(LOCALES_DIR),
+    ))
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scan_skips_bundled_and_invalid_files() {
+        let dir = std::env::temp_dir().join(format!("dx_rpg_locales_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for (name, content) in [
+            ("de-DE.ftl", "locale-name = Deutsch\n"),
+            ("fr-FR.ftl", "locale-name = Français\n"),
+            ("not a lang.ftl", "x = y\n"),
+            ("es.txt", "x = y\n"),
+        ] {
+            std::fs::write(dir.join(name), content).unwrap();
+        }
+        let bundles = scan_locale_dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            bundles,
+            vec![LocaleBundle {
+                lang_id: "de-DE".to_owned(),
+                ftl: "locale-name = Deutsch\n".to_owned(),
+            }]
+        );
+    }
+}
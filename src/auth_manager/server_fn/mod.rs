@@ -1,13 +1,45 @@
+pub mod achievements;
 pub mod admin_attacks;
 pub mod admin_characters;
+pub mod admin_content_pack;
 pub mod admin_equipment;
+pub mod admin_revisions;
 pub mod admin_scenarios;
+pub mod admin_simulation;
+pub mod admin_translations;
+pub mod admin_universe_clone;
 pub mod admin_users;
+pub mod arena;
 pub mod auth;
+pub mod combat_log;
+pub mod daily_challenges;
+pub mod endless_runs;
+pub mod leaderboards;
+pub mod locale_bundles;
+pub mod music;
+pub mod profiles;
+pub mod recipes;
+pub mod sfx;
 
+pub use achievements::*;
 pub use admin_attacks::*;
 pub use admin_characters::*;
+pub use admin_content_pack::*;
 pub use admin_equipment::*;
+pub use admin_revisions::*;
 pub use admin_scenarios::*;
+pub use admin_simulation::*;
+pub use admin_translations::*;
+pub use admin_universe_clone::*;
 pub use admin_users::*;
+pub use arena::*;
 pub use auth::*;
+pub use combat_log::*;
+pub use daily_challenges::*;
+pub use endless_runs::*;
+pub use leaderboards::*;
+pub use locale_bundles::*;
+pub use music::*;
+pub use profiles::*;
+pub use recipes::*;
+pub use sfx::*;
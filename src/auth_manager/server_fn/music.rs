@@ -0,0 +1,315 @@
+#[cfg(feature = "server")]
+use super::admin_revisions::write_content_file;
+use dioxus::prelude::*;
+
+use crate::music::{MusicCatalog, SlotTracks};
+
+/// Directory of the uploaded track files, from the MUSIC_PATH env var (default: "music").
+#[cfg(feature = "server")]
+pub fn music_dir() -> std::path::PathBuf {
+    std::env::var("MUSIC_PATH")
+        .unwrap_or_else(|_| "music".to_owned())
+        .into()
+}
+
+/// Audio files of `dir`, sorted.
+#[cfg(feature = "server")]
+pub(super) fn scan_audio_dir(dir: &std::path::Path) -> Vec<String> {
+    use crate::audio::is_audio_file_name;
+    let mut names: Vec<String> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.file_name().to_string_lossy().to_string())
+                .filter(|n| is_audio_file_name(n))
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+/// Builds the catalog from the music settings, the maps and the scenarios of
+/// `offline_root`. Tracks that aren't in `tracks` are dropped (with a warning), so
+/// a missing file falls back to the next candidate instead of playing nothing.
+#[cfg(feature = "server")]
+fn build_music_catalog(offline_root: &std::path::Path, tracks: &[String]) -> MusicCatalog {
+    use crate::music::{
+        DEFAULT_MUSIC_CONFIG, MUSIC_CONFIG_DIR, MusicSlot, ScenarioMusic, scenario_key,
+    };
+    use dioxus::logger::tracing;
+
+    let read_json = |path: &std::path::Path| -> Option<serde_json::Value> {
+        serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()
+    };
+    let json_files = |dir: std::path::PathBuf| -> Vec<std::path::PathBuf> {
+        let mut paths: Vec<_> = std::fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|e| e.path())
+                    .filter(|p| p.extension().is_some_and(|e| e == "json"))
+                    .collect()
+            })
+            .unwrap_or_default();
+        paths.sort();
+        paths
+    };
+    let known = |track: &str, owner: &std::path::Path| -> String {
+        if track.is_empty() || tracks.iter().any(|t| t == track) {
+            track.to_owned()
+        } else {
+            tracing::warn!("{owner:?} names the missing music track '{track}'");
+            String::new()
+        }
+    };
+
+    let mut catalog = MusicCatalog::default();
+    for path in json_files(offline_root.join(MUSIC_CONFIG_DIR)) {
+        let Some(stem) = path.file_stem().map(|s| s.to_string_lossy().to_string()) else {
+            continue;
+        };
+        let Some(mut slots) =
+            read_json(&path).and_then(|v| serde_json::from_value::<SlotTracks>(v).ok())
+        else {
+            continue;
+        };
+        for slot in MusicSlot::ALL {
+            let track = known(slots.get(slot), &path);
+            slots.set(slot, track);
+        }
+        if stem == DEFAULT_MUSIC_CONFIG {
+            catalog.defaults = slots;
+        } else {
+            catalog.universes.insert(stem, slots);
+        }
+    }
+
+    for path in json_files(offline_root.join("maps")) {
+        let Some(v) = read_json(&path) else {
+            continue;
+        };
+        let track = known(v["music"].as_str().unwrap_or(""), &path);
+        if let Some(id) = v["id"].as_str()
+            && !track.is_empty()
+        {
+            catalog.maps.insert(id.to_owned(), track);
+        }
+    }
+
+    let scenarios_dir = offline_root.join("scenarios");
+    let universe_dirs = std::fs::read_dir(&scenarios_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().is_dir())
+                .map(|e| e.file_name().to_string_lossy().to_string())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    for universe in universe_dirs {
+        for path in json_files(scenarios_dir.join(&universe)) {
+            let Some(v) = read_json(&path) else {
+                continue;
+            };
+            let music = ScenarioMusic {
+                track: known(v["music"].as_str().unwrap_or(""), &path),
+                boss_fight: v["boss_fight"].as_bool().unwrap_or(false),
+            };
+            if let Some(name) = v["name"].as_str()
+                && music != ScenarioMusic::default()
+            {
+                catalog
+                    .scenarios
+                    .insert(scenario_key(&universe, name), music);
+            }
+        }
+    }
+    catalog
+}
+
+/// Every music setting of the server. Public: music plays before login.
+#[post("/api/get_music_catalog")]
+pub async fn get_music_catalog() -> Result<MusicCatalog, ServerFnError> {
+    use crate::common::OFFLINE_PATH;
+    let tracks = scan_audio_dir(&music_dir());
+    Ok(build_music_catalog(
+        std::path::Path::new(OFFLINE_PATH),
+        &tracks,
+    ))
+}
+
+/// Returns the uploaded track file names.
+#[post("/api/admin_list_music_tracks")]
+pub async fn admin_list_music_tracks() -> Result<Vec<String>, ServerFnError> {
+    Ok(scan_audio_dir(&music_dir()))
+}
+
+/// Uploads a track to the music directory.
+/// `file_data_base64` must be a standard base64-encoded string of the audio bytes.
+#[post("/api/upload_music_track")]
+pub async fn upload_music_track(
+    file_name: String,
+    file_data_base64: String,
+) -> Result<String, ServerFnError> {
+    use crate::audio::is_audio_file_name;
+    if !is_audio_file_name(&file_name) {
+        return Err(ServerFnError::new(
+            "Invalid filename or unsupported audio format".to_owned(),
+        ));
+    }
+    let bytes = data_encoding::BASE64
+        .decode(file_data_base64.as_bytes())
+        .map_err(|e| ServerFnError::new(format!("Invalid base64: {e}")))?;
+    let dir = music_dir();
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| ServerFnError::new(format!("Cannot create music dir: {e}")))?;
+    std::fs::write(dir.join(&file_name), &bytes)
+        .map_err(|e| ServerFnError::new(format!("Cannot write track: {e}")))?;
+    Ok(file_name)
+}
+
+/// Returns the slot tracks of a universe, or of the defaults for `DEFAULT_MUSIC_CONFIG`.
+#[post("/api/admin_get_universe_music")]
+pub async fn admin_get_universe_music(universe: String) -> Result<SlotTracks, ServerFnError> {
+    use super::admin_content_pack::is_safe_component;
+    use crate::{common::OFFLINE_PATH, music::MUSIC_CONFIG_DIR};
+    if !is_safe_component(&universe) {
+        return Err(ServerFnError::new(format!("Invalid universe '{universe}'")));
+    }
+    let path = std::path::Path::new(OFFLINE_PATH)
+        .join(MUSIC_CONFIG_DIR)
+        .join(format!("{universe}.json"));
+    match std::fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|e| ServerFnError::new(format!("Invalid JSON in {path:?}: {e}"))),
+        Err(_) => Ok(SlotTracks::default()),
+    }
+}
+
+/// Saves the slot tracks of a universe, or of the defaults for `DEFAULT_MUSIC_CONFIG`.
+#[post("/api/admin_save_universe_music")]
+pub async fn admin_save_universe_music(
+    universe: String,
+    tracks: SlotTracks,
+) -> Result<(), ServerFnError> {
+    use super::admin_content_pack::is_safe_component;
+    use crate::{common::OFFLINE_PATH, music::MUSIC_CONFIG_DIR};
+    if !is_safe_component(&universe) {
+        return Err(ServerFnError::new(format!("Invalid universe '{universe}'")));
+    }
+    let dir = std::path::Path::new(OFFLINE_PATH).join(MUSIC_CONFIG_DIR);
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| ServerFnError::new(format!("Cannot create dir: {e}")))?;
+    let content = serde_json::to_string_pretty(&tracks)
+        .map_err(|e| ServerFnError::new(format!("Cannot serialize: {e}")))?;
+    write_content_file(&dir.join(format!("{universe}.json")), content.as_bytes()).await
+}
+
+/// Music of one map for the admin music tab.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MapMusicInfo {
+    pub map_id: String,
+    pub file_name: String,
+    /// Empty = the universe's overworld track
+    pub track: String,
+}
+
+/// Returns every map with the track its JSON names.
+#[post("/api/admin_list_map_music")]
+pub async fn admin_list_map_music() -> Result<Vec<MapMusicInfo>, ServerFnError> {
+    use crate::common::OFFLINE_PATH;
+    let dir = std::path::Path::new(OFFLINE_PATH).join("maps");
+    let mut maps: Vec<MapMusicInfo> = std::fs::read_dir(&dir)
+        .map_err(|e| ServerFnError::new(format!("Cannot read {dir:?}: {e}")))?
+        .flatten()
+        .filter(|e| e.path().extension().is_some_and(|x| x == "json"))
+        .filter_map(|e| {
+            let content = std::fs::read_to_string(e.path()).ok()?;
+            let v: serde_json::Value = serde_json::from_str(&content).ok()?;
+            Some(MapMusicInfo {
+                map_id: v["id"].as_str()?.to_owned(),
+                file_name: e.file_name().to_string_lossy().to_string(),
+                track: v["music"].as_str().unwrap_or("").to_owned(),
+            })
+        })
+        .collect();
+    maps.sort_by(|a, b| a.map_id.cmp(&b.map_id));
+    Ok(maps)
+}
+
+/// Sets (or clears, with an empty `track`) the `music` field of a map JSON; the
+/// other fields are kept as they are.
+#[post("/api/admin_save_map_music")]
+pub async fn admin_save_map_music(file_name: String, track: String) -> Result<(), ServerFnError> {
+    use super::admin_content_pack::is_safe_component;
+    use crate::common::OFFLINE_PATH;
+    if !is_safe_component(&file_name) || !file_name.ends_with(".json") {
+        return Err(ServerFnError::new(format!(
+            "Invalid map file '{file_name}'"
+        )));
+    }
+    let path = std::path::Path::new(OFFLINE_PATH)
+        .join("maps")
+        .join(&file_name);
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| ServerFnError::new(format!("Cannot read {path:?}: {e}")))?;
+    let mut v: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| ServerFnError::new(format!("Invalid JSON: {e}")))?;
+    let Some(map) = v.as_object_mut() else {
+        return Err(ServerFnError::new(format!("{path:?} is not a JSON object")));
+    };
+    if track.is_empty() {
+        map.remove("music");
+    } else {
+        map.insert("music".to_owned(), serde_json::Value::String(track));
+    }
+    let content = serde_json::to_string_pretty(&v)
+        .map_err(|e| ServerFnError::new(format!("Cannot serialize: {e}")))?;
+    write_content_file(&path, content.as_bytes()).await
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catalog_reads_settings_maps_and_scenarios() {
+        let root = std::env::temp_dir().join(format!("dx_rpg_music_{}", std::process::id()));
+        for dir in ["music", "maps", "scenarios/lotr"] {
+            std::fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        for (path, content) in [
+            ("music/default.json", r#"{"combat": "battle.ogg"}"#),
+            (
+                "music/lotr.json",
+                r#"{"boss": "sauron.ogg", "home": "gone.ogg"}"#,
+            ),
+            (
+                "maps/moria.json",
+                r#"{"id": "lotr_moria", "music": "moria.ogg"}"#,
+            ),
+            ("maps/shire.json", r#"{"id": "lotr_shire"}"#),
+            (
+                "scenarios/lotr/stage_10.json",
+                r#"{"name": "Sauron", "boss_fight": true}"#,
+            ),
+            ("scenarios/lotr/stage_1.json", r#"{"name": "Goblins"}"#),
+        ] {
+            std::fs::write(root.join(path), content).unwrap();
+        }
+        let tracks: Vec<String> = ["battle.ogg", "moria.ogg", "sauron.ogg"]
+            .map(String::from)
+            .to_vec();
+        let catalog = build_music_catalog(&root, &tracks);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(catalog.defaults.combat, "battle.ogg");
+        let lotr = &catalog.universes["lotr"];
+        assert_eq!((lotr.boss.as_str(), lotr.home.as_str()), ("sauron.ogg", ""));
+        assert_eq!(catalog.maps.len(), 1);
+        assert_eq!(catalog.maps["lotr_moria"], "moria.ogg");
+        assert_eq!(catalog.scenarios.len(), 1);
+        assert!(catalog.scenarios["lotr/Sauron"].boss_fight);
+    }
+}
@@ -0,0 +1,295 @@
+#[cfg(feature = "server")]
+use crate::auth_manager::db::get_db;
+use dioxus::prelude::*;
+
+/// Lifetime damage, healing and uses of one hero or attack.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct ProfileUsage {
+    pub name: String,
+    /// Fights for a hero, casts for an attack
+    pub count: i64,
+    pub damage: i64,
+    pub healing: i64,
+}
+
+/// One finished scenario of a player.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct ProfileFight {
+    pub universe: String,
+    pub scenario: String,
+    pub won: bool,
+    pub damage: i64,
+    pub healing: i64,
+    pub turns: i64,
+    pub duration_secs: i64,
+    pub finished_at: String,
+}
+
+/// Deepest endless run of a player in one universe.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct ProfileEndlessRun {
+    pub universe: String,
+    /// Floors cleared before the party fell
+    pub floors: i64,
+    /// Comma-separated hero names
+    pub heroes: String,
+    pub finished_at: String,
+}
+
+/// Lifetime statistics of a player, aggregated from the `profile_*` tables.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PlayerProfile {
+    pub username: String,
+    pub fights_won: i64,
+    pub fights_lost: i64,
+    pub damage: i64,
+    pub healing: i64,
+    pub time_played_secs: i64,
+    /// Most played first
+    pub heroes: Vec<ProfileUsage>,
+    /// Most cast first
+    pub attacks: Vec<ProfileUsage>,
+    pub universes_completed: Vec<String>,
+    /// Newest first, at most `PROFILE_RECENT_FIGHTS`
+    pub recent_fights: Vec<ProfileFight>,
+    /// Deepest first, one per universe
+    pub endless_runs: Vec<ProfileEndlessRun>,
+}
+
+/// Number of fights listed on the profile page.
+pub const PROFILE_RECENT_FIGHTS: i64 = 10;
+/// Number of heroes and attacks listed on the profile page.
+pub const PROFILE_TOP_ENTRIES: i64 = 8;
+
+/// What one finished scenario adds to a player's profile, built by
+/// `event_profile::take_scenario_record`.
+#[cfg(feature = "server")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScenarioRecord {
+    pub universe: String,
+    pub scenario: String,
+    pub single_player: bool,
+    pub won: bool,
+    /// The last scenario of the universe was won
+    pub universe_completed: bool,
+    pub turns: i64,
+    pub duration_secs: i64,
+    pub potions_used: i64,
+    /// Won and tracked from its first turn: enters the leaderboards
+    pub ranked: bool,
+    /// Totals of the whole universe, when `universe_completed` ends a ranked run
+    pub universe_run: Option<super::RunTotals>,
+    /// (hero, damage, healing) for each hero of the party
+    pub heroes: Vec<(String, i64, i64)>,
+    /// (attack, uses, damage, healing)
+    pub attacks: Vec<(String, i64, i64, i64)>,
+}
+
+/// Part of a `ScenarioRecord` played by one player: their own heroes and the
+/// attacks of those heroes.
+#[cfg(feature = "server")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlayerShare {
+    pub player: String,
+    /// (hero, damage, healing)
+    pub heroes: Vec<(String, i64, i64)>,
+    /// (attack, uses, damage, healing)
+    pub attacks: Vec<(String, i64, i64, i64)>,
+}
+
+/// Adds `record` to the profile of the player of each of `shares`, with only
+/// the heroes and attacks of that player. A failure is logged, not returned:
+/// the game goes on either way.
+#[cfg(feature = "server")]
+pub async fn save_scenario_record(shares: &[PlayerShare], record: &ScenarioRecord) {
+    use dioxus::logger::tracing;
+    for share in shares {
+        let username = &share.player;
+        let record = ScenarioRecord {
+            heroes: share.heroes.clone(),
+            attacks: share.attacks.clone(),
+            ..record.clone()
+        };
+        if let Err(e) = insert_scenario_record(username, &record).await {
+            tracing::error!("Cannot record the scenario of {username} in its profile: {e}");
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+async fn insert_scenario_record(username: &str, record: &ScenarioRecord) -> sqlx::Result<()> {
+    let finished_at = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let damage: i64 = record.heroes.iter().map(|(_, d, _)| d).sum();
+    let healing: i64 = record.heroes.iter().map(|(_, _, h)| h).sum();
+    let mut tx = get_db().await.begin().await?;
+    sqlx::query(
+        "INSERT INTO profile_fights
+         (username, universe, scenario, won, damage, healing, turns, duration_secs, finished_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+    )
+    .bind(username)
+    .bind(&record.universe)
+    .bind(&record.scenario)
+    .bind(record.won)
+    .bind(damage)
+    .bind(healing)
+    .bind(record.turns)
+    .bind(record.duration_secs)
+    .bind(&finished_at)
+    .execute(&mut *tx)
+    .await?;
+    for (hero, damage, healing) in &record.heroes {
+        sqlx::query(
+            "INSERT INTO profile_heroes (username, hero, fights, damage, healing)
+             VALUES (?1, ?2, 1, ?3, ?4)
+             ON CONFLICT(username, hero) DO UPDATE SET fights = fights + 1,
+             damage = damage + EXCLUDED.damage, healing = healing + EXCLUDED.healing",
+        )
+        .bind(username)
+        .bind(hero)
+        .bind(damage)
+        .bind(healing)
+        .execute(&mut *tx)
+        .await?;
+    }
+    for (attack, uses, damage, healing) in &record.attacks {
+        sqlx::query(
+            "INSERT INTO profile_attacks (username, attack, uses, damage, healing)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(username, attack) DO UPDATE SET uses = uses + EXCLUDED.uses,
+             damage = damage + EXCLUDED.damage, healing = healing + EXCLUDED.healing",
+        )
+        .bind(username)
+        .bind(attack)
+        .bind(uses)
+        .bind(damage)
+        .bind(healing)
+        .execute(&mut *tx)
+        .await?;
+    }
+    if record.universe_completed {
+        sqlx::query(
+            "INSERT INTO profile_universes (username, universe, completed_at)
+             VALUES (?1, ?2, ?3) ON CONFLICT(username, universe) DO NOTHING",
+        )
+        .bind(username)
+        .bind(&record.universe)
+        .bind(&finished_at)
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await
+}
+
+/// Adds an endless run of the universe `universe`, ended after `floors` floors
+/// cleared by `heroes`, to the profile of each of `usernames`. A failure is
+/// logged, not returned.
+#[cfg(feature = "server")]
+pub async fn save_endless_run(usernames: &[String], universe: &str, floors: u32, heroes: &str) {
+    use dioxus::logger::tracing;
+    let finished_at = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    for username in usernames {
+        let inserted = sqlx::query(
+            "INSERT INTO profile_endless_runs (username, universe, floors, heroes, finished_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+        )
+        .bind(username)
+        .bind(universe)
+        .bind(floors)
+        .bind(heroes)
+        .bind(&finished_at)
+        .execute(get_db().await)
+        .await;
+        if let Err(e) = inserted {
+            tracing::error!("Cannot record the endless run of {username} in its profile: {e}");
+        }
+    }
+}
+
+/// Profile of `username`. Any signed-in player can view any profile.
+#[post("/api/get_player_profile")]
+pub async fn get_player_profile(username: String) -> Result<PlayerProfile, ServerFnError> {
+    let pool = get_db().await;
+    let err = |e: sqlx::Error| ServerFnError::new(format!("{e}"));
+    let (fights_won, fights_lost, damage, healing, time_played_secs): (i64, i64, i64, i64, i64) =
+        sqlx::query_as(
+            "SELECT COALESCE(SUM(won), 0), COALESCE(SUM(NOT won), 0), COALESCE(SUM(damage), 0),
+                    COALESCE(SUM(healing), 0), COALESCE(SUM(duration_secs), 0)
+             FROM profile_fights WHERE username = ?1",
+        )
+        .bind(&username)
+        .fetch_one(pool)
+        .await
+        .map_err(err)?;
+    let heroes = sqlx::query_as(
+        "SELECT hero AS name, fights AS count, damage, healing FROM profile_heroes
+         WHERE username = ?1 ORDER BY fights DESC, hero LIMIT ?2",
+    )
+    .bind(&username)
+    .bind(PROFILE_TOP_ENTRIES)
+    .fetch_all(pool)
+    .await
+    .map_err(err)?;
+    let attacks = sqlx::query_as(
+        "SELECT attack AS name, uses AS count, damage, healing FROM profile_attacks
+         WHERE username = ?1 ORDER BY uses DESC, attack LIMIT ?2",
+    )
+    .bind(&username)
+    .bind(PROFILE_TOP_ENTRIES)
+    .fetch_all(pool)
+    .await
+    .map_err(err)?;
+    let universes_completed: Vec<(String,)> = sqlx::query_as(
+        "SELECT universe FROM profile_universes WHERE username = ?1 ORDER BY completed_at",
+    )
+    .bind(&username)
+    .fetch_all(pool)
+    .await
+    .map_err(err)?;
+    let recent_fights = sqlx::query_as(
+        "SELECT universe, scenario, won, damage, healing, turns, duration_secs, finished_at
+         FROM profile_fights WHERE username = ?1 ORDER BY id DESC LIMIT ?2",
+    )
+    .bind(&username)
+    .bind(PROFILE_RECENT_FIGHTS)
+    .fetch_all(pool)
+    .await
+    .map_err(err)?;
+    // SQLite takes the other columns from the row holding the MAX
+    let endless_runs = sqlx::query_as(
+        "SELECT universe, MAX(floors) AS floors, heroes, finished_at
+         FROM profile_endless_runs WHERE username = ?1 GROUP BY universe ORDER BY floors DESC",
+    )
+    .bind(&username)
+    .fetch_all(pool)
+    .await
+    .map_err(err)?;
+    Ok(PlayerProfile {
+        username,
+        fights_won,
+        fights_lost,
+        damage,
+        healing,
+        time_played_secs,
+        heroes,
+        attacks,
+        universes_completed: universes_completed.into_iter().map(|(u,)| u).collect(),
+        recent_fights,
+        endless_runs,
+    })
+}
+
+/// Players having at least one recorded fight, for the profile picker.
+#[post("/api/list_profiles")]
+pub async fn list_profiles() -> Result<Vec<String>, ServerFnError> {
+    let pool = get_db().await;
+    let rows: Vec<(String,)> =
+        sqlx::query_as("SELECT DISTINCT username FROM profile_fights ORDER BY username")
+            .fetch_all(pool)
+            .await
+            .map_err(|e| ServerFnError::new(format!("{e}")))?;
+    Ok(rows.into_iter().map(|(u,)| u).collect())
+}
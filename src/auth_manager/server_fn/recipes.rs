@@ -0,0 +1,80 @@
+#[cfg(feature = "server")]
+use super::admin_revisions::{remove_content_file, write_content_file};
+use crate::crafting::Recipe;
+use dioxus::prelude::*;
+
+/// A recipe with the stem of its file in `offlines/recipes/`, which identifies it.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RecipeEntry {
+    pub file_stem: String,
+    pub recipe: Recipe,
+}
+
+#[cfg(feature = "server")]
+fn recipe_path(file_stem: &str) -> Result<std::path::PathBuf, ServerFnError> {
+    use crate::{common::OFFLINE_PATH, crafting::RECIPES_DIR};
+    use std::path::Path;
+    if file_stem.trim().is_empty()
+        || file_stem.contains("..")
+        || file_stem.contains('/')
+        || file_stem.contains('\\')
+    {
+        return Err(ServerFnError::new("Invalid recipe file name".to_owned()));
+    }
+    Ok(Path::new(OFFLINE_PATH)
+        .join(RECIPES_DIR)
+        .join(format!("{file_stem}.json")))
+}
+
+/// Returns every recipe, sorted by name.
+#[post("/api/get_recipes")]
+pub async fn get_recipes() -> Result<Vec<RecipeEntry>, ServerFnError> {
+    use crate::{
+        common::OFFLINE_PATH,
+        crafting::{RECIPES_DIR, read_recipes},
+    };
+    use std::path::Path;
+    Ok(read_recipes(&Path::new(OFFLINE_PATH).join(RECIPES_DIR))
+        .into_iter()
+        .map(|(file_stem, recipe)| RecipeEntry { file_stem, recipe })
+        .collect())
+}
+
+/// Saves a recipe, once checked and its output found in the game data.
+#[post("/api/admin_save_recipe")]
+pub async fn admin_save_recipe(file_stem: String, recipe: Recipe) -> Result<(), ServerFnError> {
+    use crate::{common::DATA_MANAGER, crafting::CraftedKind};
+    use lib_rpg::shop::build_consumable_by_name;
+
+    let path = recipe_path(&file_stem)?;
+    recipe.validate().map_err(ServerFnError::new)?;
+    let output_exists = match recipe.kind {
+        CraftedKind::Equipment => DATA_MANAGER
+            .lock()
+            .unwrap()
+            .equipment_table
+            .values()
+            .flatten()
+            .any(|e| e.unique_name == recipe.output),
+        CraftedKind::Consumable => build_consumable_by_name(&recipe.output).is_some(),
+    };
+    if !output_exists {
+        return Err(ServerFnError::new(format!(
+            "Unknown {:?} '{}'",
+            recipe.kind, recipe.output
+        )));
+    }
+    let json_content = serde_json::to_string_pretty(&recipe)
+        .map_err(|e| ServerFnError::new(format!("Cannot serialize: {e}")))?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| ServerFnError::new(format!("Cannot create dir: {e}")))?;
+    }
+    write_content_file(&path, json_content.as_bytes()).await
+}
+
+/// Deletes a recipe file.
+#[post("/api/admin_delete_recipe")]
+pub async fn admin_delete_recipe(file_stem: String) -> Result<(), ServerFnError> {
+    remove_content_file(&recipe_path(&file_stem)?).await
+}
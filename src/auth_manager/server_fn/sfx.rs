@@ -0,0 +1,122 @@
+use dioxus::prelude::*;
+
+use crate::audio::SfxCatalog;
+
+/// Directory of the uploaded sound effects, from the SFX_PATH env var (default: "sfx").
+#[cfg(feature = "server")]
+pub fn sfx_dir() -> std::path::PathBuf {
+    std::env::var("SFX_PATH")
+        .unwrap_or_else(|_| "sfx".to_owned())
+        .into()
+}
+
+/// Builds the catalog from the `"Sfx"` field of the attacks of `offline_root`.
+/// Effects that aren't in `files` are dropped (with a warning), so the attack
+/// falls back to the generic cues.
+#[cfg(feature = "server")]
+fn build_sfx_catalog(offline_root: &std::path::Path, files: &[String]) -> SfxCatalog {
+    use dioxus::logger::tracing;
+
+    let mut catalog = SfxCatalog::default();
+    let characters = std::fs::read_dir(offline_root.join("attack"))
+        .map(|entries| entries.flatten().map(|e| e.path()).collect::<Vec<_>>())
+        .unwrap_or_default();
+    for dir in characters {
+        let attacks = std::fs::read_dir(&dir)
+            .map(|entries| entries.flatten().map(|e| e.path()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        for path in attacks {
+            if path.extension().is_none_or(|e| e != "json") {
+                continue;
+            }
+            let Some(v) = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).ok())
+            else {
+                continue;
+            };
+            let (Some(name), Some(sfx)) = (v["Nom"].as_str(), v["Sfx"].as_str()) else {
+                continue;
+            };
+            if sfx.is_empty() {
+                continue;
+            }
+            if files.iter().any(|f| f == sfx) {
+                catalog.attacks.insert(name.to_owned(), sfx.to_owned());
+            } else {
+                tracing::warn!("{path:?} names the missing sound effect '{sfx}'");
+            }
+        }
+    }
+    catalog
+}
+
+/// Sound effects of the attacks. Public, like the music catalog.
+#[post("/api/get_sfx_catalog")]
+pub async fn get_sfx_catalog() -> Result<SfxCatalog, ServerFnError> {
+    use crate::common::OFFLINE_PATH;
+    let files = super::music::scan_audio_dir(&sfx_dir());
+    Ok(build_sfx_catalog(
+        std::path::Path::new(OFFLINE_PATH),
+        &files,
+    ))
+}
+
+/// Returns the uploaded sound effect file names.
+#[post("/api/admin_list_sfx")]
+pub async fn admin_list_sfx() -> Result<Vec<String>, ServerFnError> {
+    Ok(super::music::scan_audio_dir(&sfx_dir()))
+}
+
+/// Uploads a sound effect to the sfx directory.
+/// `file_data_base64` must be a standard base64-encoded string of the audio bytes.
+#[post("/api/upload_sfx")]
+pub async fn upload_sfx(
+    file_name: String,
+    file_data_base64: String,
+) -> Result<String, ServerFnError> {
+    use crate::audio::is_audio_file_name;
+    if !is_audio_file_name(&file_name) {
+        return Err(ServerFnError::new(
+            "Invalid filename or unsupported audio format".to_owned(),
+        ));
+    }
+    let bytes = data_encoding::BASE64
+        .decode(file_data_base64.as_bytes())
+        .map_err(|e| ServerFnError::new(format!("Invalid base64: {e}")))?;
+    let dir = sfx_dir();
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| ServerFnError::new(format!("Cannot create sfx dir: {e}")))?;
+    std::fs::write(dir.join(&file_name), &bytes)
+        .map_err(|e| ServerFnError::new(format!("Cannot write sound effect: {e}")))?;
+    Ok(file_name)
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catalog_keeps_existing_attack_effects() {
+        let root = std::env::temp_dir().join(format!("dx_rpg_sfx_{}", std::process::id()));
+        let dir = root.join("attack").join("Thalia");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("fireball.json"),
+            r#"{"Nom": "Boule de feu", "Sfx": "fireball.ogg"}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("frost.json"),
+            r#"{"Nom": "Givre", "Sfx": "frost.ogg"}"#,
+        )
+        .unwrap();
+        std::fs::write(dir.join("slash.json"), r#"{"Nom": "Entaille"}"#).unwrap();
+
+        let catalog = build_sfx_catalog(&root, &["fireball.ogg".to_owned()]);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(catalog.attacks.len(), 1);
+        assert_eq!(catalog.attacks["Boule de feu"], "fireball.ogg");
+    }
+}
@@ -4,8 +4,11 @@ use dioxus_i18n::t;
 use crate::{
     auth_manager::server_fn::is_admin_enabled,
     board_game_components::{
-        admin_tab_characters::AdminCharactersTab, admin_tab_equipment::AdminEquipmentTab,
-        admin_tab_scenarios::AdminScenariosTab, admin_tab_users::AdminUsersTab,
+        admin_tab_characters::AdminCharactersTab, admin_tab_content_packs::AdminContentPacksTab,
+        admin_tab_equipment::AdminEquipmentTab, admin_tab_history::AdminHistoryTab,
+        admin_tab_music::AdminMusicTab, admin_tab_recipes::AdminRecipesTab,
+        admin_tab_scenarios::AdminScenariosTab, admin_tab_simulator::AdminSimulatorTab,
+        admin_tab_translations::AdminTranslationsTab, admin_tab_users::AdminUsersTab,
     },
 };
 
@@ -15,6 +18,12 @@ enum AdminTab {
     Scenarios,
     Characters,
     Equipment,
+    Recipes,
+    Music,
+    ContentPacks,
+    History,
+    Simulator,
+    Translations,
 }
 
 #[component]
@@ -64,6 +73,36 @@ pub fn AdminPage() -> Element {
                     onclick: move |_| tab.set(AdminTab::Equipment),
                     {t!("admin-tab-equipment")}
                 }
+                button {
+                    class: if tab() == AdminTab::Recipes { "admin-tab active" } else { "admin-tab" },
+                    onclick: move |_| tab.set(AdminTab::Recipes),
+                    {t!("admin-tab-recipes")}
+                }
+                button {
+                    class: if tab() == AdminTab::Music { "admin-tab active" } else { "admin-tab" },
+                    onclick: move |_| tab.set(AdminTab::Music),
+                    {t!("admin-tab-music")}
+                }
+                button {
+                    class: if tab() == AdminTab::ContentPacks { "admin-tab active" } else { "admin-tab" },
+                    onclick: move |_| tab.set(AdminTab::ContentPacks),
+                    {t!("admin-tab-content-packs")}
+                }
+                button {
+                    class: if tab() == AdminTab::History { "admin-tab active" } else { "admin-tab" },
+                    onclick: move |_| tab.set(AdminTab::History),
+                    {t!("admin-tab-history")}
+                }
+                button {
+                    class: if tab() == AdminTab::Simulator { "admin-tab active" } else { "admin-tab" },
+                    onclick: move |_| tab.set(AdminTab::Simulator),
+                    {t!("admin-tab-simulator")}
+                }
+                button {
+                    class: if tab() == AdminTab::Translations { "admin-tab active" } else { "admin-tab" },
+                    onclick: move |_| tab.set(AdminTab::Translations),
+                    {t!("admin-tab-translations")}
+                }
             }
 
             match tab() {
@@ -79,6 +118,24 @@ pub fn AdminPage() -> Element {
                 AdminTab::Equipment => rsx! {
                     AdminEquipmentTab {}
                 },
+                AdminTab::Recipes => rsx! {
+                    AdminRecipesTab {}
+                },
+                AdminTab::Music => rsx! {
+                    AdminMusicTab {}
+                },
+                AdminTab::ContentPacks => rsx! {
+                    AdminContentPacksTab {}
+                },
+                AdminTab::History => rsx! {
+                    AdminHistoryTab {}
+                },
+                AdminTab::Simulator => rsx! {
+                    AdminSimulatorTab {}
+                },
+                AdminTab::Translations => rsx! {
+                    AdminTranslationsTab {}
+                },
             }
         }
     }
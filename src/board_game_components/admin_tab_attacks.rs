@@ -4,7 +4,8 @@ use dioxus_i18n::t;
 use crate::{
     auth_manager::server_fn::{
         AttackFormData, admin_delete_attack, admin_get_attack_form, admin_get_attack_json,
-        admin_list_attacks, admin_save_attack_form, admin_save_attack_json, upload_photo,
+        admin_list_attacks, admin_list_sfx, admin_save_attack_form, admin_save_attack_json,
+        upload_photo, upload_sfx,
     },
     components::{
         button::{Button, ButtonVariant},
@@ -29,6 +30,17 @@ const JS_READ_ATK_PHOTO: &str = "const input = document.getElementById('atk-phot
      }; \
      reader.readAsDataURL(file);";
 
+// Same as `JS_READ_ATK_PHOTO`, for the sound effect input 'atk-sfx-file'.
+const JS_READ_ATK_SFX: &str = "const input = document.getElementById('atk-sfx-file'); \
+     const file = input && input.files && input.files[0]; \
+     if (!file) { dioxus.send(null); return; } \
+     const reader = new FileReader(); \
+     reader.onload = function(ev) { \
+         const b64 = ev.target.result.split(',')[1]; \
+         dioxus.send({name: file.name, data: b64}); \
+     }; \
+     reader.readAsDataURL(file);";
+
 /// Attack management panel for a single character.
 /// `char_name`     — the character whose attacks are being edited.
 /// `attacks_list`  — shared signal (owned by the parent character tab) so
@@ -59,6 +71,8 @@ pub fn AdminAttacksPanel(char_name: String, attacks_list: Signal<Vec<String>>) -
     let mut atk_regen_rage = use_signal(|| "0".to_owned());
     let mut atk_regen_vigueur = use_signal(|| "0".to_owned());
     let mut atk_photo = use_signal(String::new);
+    let mut atk_sfx = use_signal(String::new);
+    let mut sfx_resource = use_resource(admin_list_sfx);
     let mut atk_effet = use_signal(|| "[]".to_owned());
 
     // Photo upload handler
@@ -87,6 +101,40 @@ pub fn AdminAttacksPanel(char_name: String, attacks_list: Signal<Vec<String>>) -
         });
     };
 
+    // Sound effect upload handler
+    let on_atk_sfx_change = move |_: FormEvent| {
+        let mut js = document::eval(JS_READ_ATK_SFX);
+        spawn(async move {
+            if let Ok(val) = js.recv::<serde_json::Value>().await
+                && !val.is_null()
+                && let (Some(name), Some(data)) = (
+                    val.get("name")
+                        .and_then(|v: &serde_json::Value| v.as_str())
+                        .map(String::from),
+                    val.get("data")
+                        .and_then(|v: &serde_json::Value| v.as_str())
+                        .map(String::from),
+                )
+            {
+                match upload_sfx(name, data).await {
+                    Ok(name) => {
+                        atk_sfx.set(name);
+                        sfx_resource.restart();
+                    }
+                    Err(e) => {
+                        attack_feedback.set(t!("admin-atk-upload-error", error: e.to_string()))
+                    }
+                }
+            }
+        });
+    };
+    let sfx_files = sfx_resource
+        .read()
+        .as_ref()
+        .and_then(|r| r.as_ref().ok())
+        .cloned()
+        .unwrap_or_default();
+
     rsx! {
         div { class: "admin-full-card",
             p { class: "admin-section-title",
@@ -266,6 +314,37 @@ pub fn AdminAttacksPanel(char_name: String, attacks_list: Signal<Vec<String>>) -
                                         onchange: on_atk_photo_change,
                                     }
                                 }
+                                div { class: "admin-form-field",
+                                    Label {
+                                        html_for: "atk-sfx",
+                                        color: "var(--rpg-text-muted)",
+                                        font_size: "0.82rem",
+                                        {t!("admin-atk-sfx-label")}
+                                    }
+                                    select {
+                                        class: "admin-select",
+                                        value: "{atk_sfx}",
+                                        onchange: move |e| atk_sfx.set(e.value()),
+                                        option { value: "", selected: atk_sfx().is_empty(), {t!("admin-atk-sfx-none")} }
+                                        for file in sfx_files.iter() {
+                                            option { value: "{file}", selected: atk_sfx() == *file, "{file}" }
+                                        }
+                                    }
+                                }
+                                div { class: "admin-form-field",
+                                    Label {
+                                        html_for: "atk-sfx-file",
+                                        color: "var(--rpg-text-muted)",
+                                        font_size: "0.82rem",
+                                        {t!("admin-atk-upload-sfx-label")}
+                                    }
+                                    input {
+                                        r#type: "file",
+                                        id: "atk-sfx-file",
+                                        accept: ".ogg,.opus,.mp3,.wav",
+                                        onchange: on_atk_sfx_change,
+                                    }
+                                }
                                 div { class: "admin-form-field",
                                     Label {
                                         html_for: "atk-mana",
@@ -445,6 +524,7 @@ pub fn AdminAttacksPanel(char_name: String, attacks_list: Signal<Vec<String>>) -
                                             regen_rage: atk_regen_rage().trim().parse::<i64>().unwrap_or(0),
                                             regen_vigueur: atk_regen_vigueur().trim().parse::<i64>().unwrap_or(0),
                                             photo: atk_photo(),
+                                            sfx: atk_sfx(),
                                             effet_json: atk_effet(),
                                         };
                                         spawn(async move {
@@ -513,6 +593,7 @@ pub fn AdminAttacksPanel(char_name: String, attacks_list: Signal<Vec<String>>) -
                                                     atk_regen_rage.set(form.regen_rage.to_string());
                                                     atk_regen_vigueur.set(form.regen_vigueur.to_string());
                                                     atk_photo.set(form.photo);
+                                                    atk_sfx.set(form.sfx);
                                                     atk_effet.set(form.effet_json);
                                                     attack_edit_form_mode.set(true);
                                                 }
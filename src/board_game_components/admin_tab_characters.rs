@@ -4,13 +4,13 @@ use dioxus_i18n::t;
 
 use crate::{
     auth_manager::server_fn::{
-        AdminCharacterInfo, CharacterFormData, StatEntry, admin_create_universe,
-        admin_get_character_form, admin_get_character_json, admin_list_attacks, admin_list_bosses,
-        admin_list_characters, admin_save_character_form, admin_save_character_json,
-        list_universes_server, upload_photo,
+        AdminCharacterInfo, CharacterFormData, StatEntry, admin_clone_universe,
+        admin_create_universe, admin_get_character_form, admin_get_character_json,
+        admin_list_attacks, admin_list_bosses, admin_list_characters, admin_save_character_form,
+        admin_save_character_json, list_universes_server, upload_photo,
     },
     board_game_components::admin_tab_attacks::AdminAttacksPanel,
-    common::{CtxAppLang, lang_from_app_lang, photo_src},
+    common::{CtxAppLang, photo_src},
     components::{
         button::{Button, ButtonVariant},
         input::Input,
@@ -42,7 +42,7 @@ pub fn AdminCharactersTab() -> Element {
     let mut loading = use_signal(|| true);
     let mut selected_universe = use_signal(String::new);
     let mut show_bosses = use_signal(|| false);
-    let universes_resource = use_resource(list_universes_server);
+    let mut universes_resource = use_resource(list_universes_server);
 
     // Character edit state
     let mut edit_char_name: Signal<Option<String>> = use_signal(|| None);
@@ -72,6 +72,9 @@ pub fn AdminCharactersTab() -> Element {
     // Universe creation
     let mut new_universe_name = use_signal(String::new);
     let mut universe_feedback = use_signal(String::new);
+    let mut clone_source = use_signal(String::new);
+    let mut clone_target = use_signal(String::new);
+    let mut clone_feedback = use_signal(String::new);
 
     use_effect(move || {
         spawn(async move {
@@ -204,6 +207,66 @@ pub fn AdminCharactersTab() -> Element {
             }
         }
 
+        // Clone an existing universe
+        div { class: "admin-card",
+            p { class: "admin-section-title", {t!("admin-chars-clone-universe-title")} }
+            p { style: "color:var(--rpg-text-muted);font-size:0.82rem;", {t!("admin-chars-clone-universe-hint")} }
+            div { style: "display:flex;gap:8px;align-items:center;",
+                select {
+                    class: "admin-select",
+                    value: "{clone_source}",
+                    onchange: move |e| clone_source.set(e.value()),
+                    option { value: "", {t!("admin-scenarios-choose-universe")} }
+                    for u in &universes {
+                        option { value: "{u}", "{u}" }
+                    }
+                }
+                Input {
+                    placeholder: t!("admin-chars-universe-name-placeholder"),
+                    r#type: "text",
+                    value: "{clone_target}",
+                    oninput: move |e: FormEvent| clone_target.set(e.value()),
+                }
+                Button {
+                    variant: ButtonVariant::Primary,
+                    disabled: clone_source().is_empty() || clone_target().trim().is_empty(),
+                    onclick: move |_| {
+                        let source = clone_source();
+                        let target = clone_target().trim().to_owned();
+                        spawn(async move {
+                            match admin_clone_universe(source.clone(), target.clone()).await {
+                                Ok(report) => {
+                                    clone_feedback
+                                        .set(
+                                            t!(
+                                                "admin-chars-universe-cloned", source : source, target : target,
+                                                characters : report.characters as i64, scenarios : report.scenarios as
+                                                i64, maps : report.maps as i64
+                                            ),
+                                        );
+                                    clone_target.set(String::new());
+                                    universes_resource.restart();
+                                    if let Ok(c) = admin_list_characters().await {
+                                        characters.set(c);
+                                    }
+                                    if let Ok(b) = admin_list_bosses().await {
+                                        boss_characters.set(b);
+                                    }
+                                }
+                                Err(e) => clone_feedback.set(t!("admin-error", error : e.to_string())),
+                            }
+                        });
+                    },
+                    {t!("admin-chars-clone-universe-button")}
+                }
+            }
+            if !clone_feedback().is_empty() {
+                p { class: if clone_feedback().starts_with('✅') { "admin-answer" } else { "admin-answer-error" },
+                    "{clone_feedback}"
+                }
+            }
+        }
+
         div { class: "admin-full-card",
             div { style: "display:flex;align-items:center;justify-content:space-between;margin-bottom:12px;",
                 p { class: "admin-section-title", style: "margin:0;", "{kind_label}" }
@@ -266,7 +329,7 @@ pub fn AdminCharactersTab() -> Element {
                                         }
                                     }
                                     {
-                                        let desc = c.description_for(lang_from_app_lang(&app_lang())).to_owned();
+                                        let desc = c.description_for(&app_lang()).to_owned();
                                         rsx! {
                                             if !desc.is_empty() {
                                                 p { class: "admin-char-desc", "{desc}" }
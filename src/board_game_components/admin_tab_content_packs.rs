@@ -0,0 +1,263 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::{
+    auth_manager::server_fn::{
+        ContentPackConflictPolicy, ContentPackEntryStatus, ContentPackPreview,
+        admin_export_content_pack, admin_import_content_pack, admin_preview_content_pack,
+        list_universes_server,
+    },
+    components::button::{Button, ButtonVariant},
+};
+
+// JavaScript code to read the content-pack archive from the input with id 'content-pack-file'.
+// Same shape as the photo readers: sends {name, data} with base64 data, or null.
+const JS_READ_CONTENT_PACK: &str = "const input = document.getElementById('content-pack-file'); \
+     const file = input && input.files && input.files[0]; \
+     if (!file) { dioxus.send(null); return; } \
+     const reader = new FileReader(); \
+     reader.onload = function(ev) { \
+         const b64 = ev.target.result.split(',')[1]; \
+         dioxus.send({name: file.name, data: b64}); \
+     }; \
+     reader.readAsDataURL(file);";
+
+// JavaScript code to save a base64 archive received from Dioxus as a file download.
+const JS_DOWNLOAD_CONTENT_PACK: &str = "const msg = await dioxus.recv(); \
+     const bytes = Uint8Array.from(atob(msg.data), c => c.charCodeAt(0)); \
+     const url = URL.createObjectURL(new Blob([bytes], {type: 'application/x-tar'})); \
+     const a = document.createElement('a'); \
+     a.href = url; \
+     a.download = msg.name; \
+     a.click(); \
+     URL.revokeObjectURL(url);";
+
+#[component]
+pub fn AdminContentPacksTab() -> Element {
+    let universes_resource = use_resource(list_universes_server);
+    let mut export_universe = use_signal(String::new);
+    let mut export_feedback = use_signal(String::new);
+    let mut archive_data = use_signal(String::new);
+    let mut preview: Signal<Option<ContentPackPreview>> = use_signal(|| None);
+    let mut overwrite_conflicts = use_signal(|| false);
+    let mut import_feedback = use_signal(String::new);
+
+    let universes = universes_resource
+        .read()
+        .as_ref()
+        .and_then(|r| r.as_ref().ok())
+        .cloned()
+        .unwrap_or_default();
+
+    let on_pack_change = move |_: FormEvent| {
+        let mut js = document::eval(JS_READ_CONTENT_PACK);
+        preview.set(None);
+        import_feedback.set(String::new());
+        spawn(async move {
+            if let Ok(val) = js.recv::<serde_json::Value>().await
+                && !val.is_null()
+                && let Some(data) = val
+                    .get("data")
+                    .and_then(|v: &serde_json::Value| v.as_str())
+                    .map(String::from)
+            {
+                match admin_preview_content_pack(data.clone()).await {
+                    Ok(p) => {
+                        archive_data.set(data);
+                        preview.set(Some(p));
+                    }
+                    Err(e) => import_feedback.set(t!("admin-error", error : e.to_string())),
+                }
+            }
+        });
+    };
+
+    let preview_snap = preview();
+
+    rsx! {
+        // Export
+        div { class: "admin-card",
+            p { class: "admin-section-title", {t!("admin-packs-export-title")} }
+            div { style: "display:flex;gap:8px;align-items:center;",
+                select {
+                    class: "admin-select",
+                    value: "{export_universe}",
+                    onchange: move |e| {
+                        export_universe.set(e.value());
+                        export_feedback.set(String::new());
+                    },
+                    option { value: "", {t!("admin-scenarios-choose-universe")} }
+                    for u in &universes {
+                        option { value: "{u}", "{u}" }
+                    }
+                }
+                Button {
+                    variant: ButtonVariant::Primary,
+                    disabled: export_universe().is_empty(),
+                    onclick: move |_| {
+                        let universe = export_universe();
+                        spawn(async move {
+                            match admin_export_content_pack(universe.clone()).await {
+                                Ok(data) => {
+                                    let js = document::eval(JS_DOWNLOAD_CONTENT_PACK);
+                                    let _ = js
+                                        .send(
+                                            serde_json::json!({
+                                                "name": format!("{universe}.dxpack.tar"), "data": data
+                                            }),
+                                        );
+                                    export_feedback.set(t!("admin-packs-exported", universe : universe));
+                                }
+                                Err(e) => export_feedback.set(t!("admin-error", error : e.to_string())),
+                            }
+                        });
+                    },
+                    {t!("admin-packs-export-button")}
+                }
+            }
+            if !export_feedback().is_empty() {
+                p { class: if export_feedback().starts_with('✅') { "admin-answer" } else { "admin-answer-error" },
+                    "{export_feedback}"
+                }
+            }
+        }
+
+        // Import
+        div { class: "admin-full-card",
+            p { class: "admin-section-title", {t!("admin-packs-import-title")} }
+            p { style: "color:var(--rpg-text-muted);font-size:0.82rem;", {t!("admin-packs-import-hint")} }
+            input {
+                id: "content-pack-file",
+                r#type: "file",
+                accept: ".tar",
+                onchange: on_pack_change,
+            }
+
+            if let Some(p) = preview_snap {
+                {
+                    let nb_new = p
+                        .entries
+                        .iter()
+                        .filter(|e| e.status == ContentPackEntryStatus::New)
+                        .count();
+                    let nb_conflicts = p
+                        .entries
+                        .iter()
+                        .filter(|e| e.status == ContentPackEntryStatus::Conflict)
+                        .count();
+                    let nb_unchanged = p.entries.len() - nb_new - nb_conflicts;
+                    let has_errors = !p.errors.is_empty();
+                    rsx! {
+                        p { style: "margin-top:12px;",
+                            {
+                                t!(
+                                    "admin-packs-manifest", universe : p.manifest.universe.clone(), version : p
+                                    .manifest.format_version as i64, exported_at : p.manifest.exported_at.clone(),
+                                    app_version : p.manifest.app_version.clone()
+                                )
+                            }
+                        }
+                        p { style: "color:var(--rpg-text-muted);font-size:0.82rem;",
+                            {
+                                t!(
+                                    "admin-packs-summary", new : nb_new as i64, conflicts : nb_conflicts as i64,
+                                    unchanged : nb_unchanged as i64
+                                )
+                            }
+                        }
+                        for err in p.errors.iter() {
+                            p { class: "admin-answer-error", "❌ {err}" }
+                        }
+                        for warn in p.warnings.iter() {
+                            p { style: "font-size:0.82rem;color:var(--rpg-gold);", "⚠️ {warn}" }
+                        }
+                        table { class: "admin-table",
+                            thead {
+                                tr {
+                                    th { class: "col-name", {t!("admin-packs-col-status")} }
+                                    th { {t!("admin-scenarios-col-file")} }
+                                }
+                            }
+                            tbody {
+                                for entry in p.entries.iter() {
+                                    tr {
+                                        td { class: "col-name",
+                                            match entry.status {
+                                                ContentPackEntryStatus::New => rsx! {
+                                                    span { class: "scenario-chip completed", {t!("admin-packs-status-new")} }
+                                                },
+                                                ContentPackEntryStatus::Conflict => rsx! {
+                                                    span { class: "scenario-chip conflict", {t!("admin-packs-status-conflict")} }
+                                                },
+                                                ContentPackEntryStatus::Unchanged => rsx! {
+                                                    span { class: "scenario-chip", {t!("admin-packs-status-unchanged")} }
+                                                },
+                                            }
+                                        }
+                                        td { style: "word-break:break-word;font-size:0.78rem;", "{entry.path}" }
+                                    }
+                                }
+                            }
+                        }
+                        if nb_conflicts > 0 {
+                            div { style: "display:flex;gap:8px;align-items:center;margin-top:12px;",
+                                span { {t!("admin-packs-conflict-label")} }
+                                select {
+                                    class: "admin-select",
+                                    value: if overwrite_conflicts() { "overwrite" } else { "skip" },
+                                    onchange: move |e| overwrite_conflicts.set(e.value() == "overwrite"),
+                                    option { value: "skip", {t!("admin-packs-conflict-skip")} }
+                                    option { value: "overwrite", {t!("admin-packs-conflict-overwrite")} }
+                                }
+                            }
+                        }
+                        div { style: "display:flex;gap:8px;margin-top:12px;",
+                            Button {
+                                variant: ButtonVariant::Primary,
+                                disabled: has_errors,
+                                onclick: move |_| {
+                                    let policy = if overwrite_conflicts() {
+                                        ContentPackConflictPolicy::Overwrite
+                                    } else {
+                                        ContentPackConflictPolicy::Skip
+                                    };
+                                    spawn(async move {
+                                        match admin_import_content_pack(archive_data(), policy).await {
+                                            Ok(report) => {
+                                                import_feedback
+                                                    .set(
+                                                        t!(
+                                                            "admin-packs-imported", written : report.written as i64, skipped :
+                                                            report.skipped as i64
+                                                        ),
+                                                    );
+                                                preview.set(None);
+                                                archive_data.set(String::new());
+                                            }
+                                            Err(e) => import_feedback.set(t!("admin-error", error : e.to_string())),
+                                        }
+                                    });
+                                },
+                                {t!("admin-packs-import-button")}
+                            }
+                            Button {
+                                variant: ButtonVariant::Secondary,
+                                onclick: move |_| {
+                                    preview.set(None);
+                                    archive_data.set(String::new());
+                                },
+                                {t!("common-cancel")}
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !import_feedback().is_empty() {
+                p { class: if import_feedback().starts_with('✅') { "admin-answer" } else { "admin-answer-error" },
+                    "{import_feedback}"
+                }
+            }
+        }
+    }
+}
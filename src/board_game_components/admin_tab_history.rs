@@ -0,0 +1,259 @@
+use dioxus::logger::tracing;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::{
+    auth_manager::server_fn::{
+        ContentRevision, DiffKind, REVISION_ACTION_DELETE, REVISION_ACTION_RESTORE,
+        RevisedFileInfo, admin_list_revised_files, admin_list_revisions, admin_restore_revision,
+        diff_lines,
+    },
+    components::{
+        button::{Button, ButtonVariant},
+        input::Input,
+    },
+};
+
+/// Unchanged lines kept around each change in the diff view.
+const DIFF_CONTEXT_LINES: usize = 3;
+
+fn action_label(action: &str) -> String {
+    match action {
+        REVISION_ACTION_DELETE => t!("admin-history-action-delete"),
+        REVISION_ACTION_RESTORE => t!("admin-history-action-restore"),
+        _ => t!("admin-history-action-save"),
+    }
+}
+
+#[component]
+pub fn AdminHistoryTab() -> Element {
+    let mut files: Signal<Vec<RevisedFileInfo>> = use_signal(Vec::new);
+    let mut loading = use_signal(|| true);
+    let mut filter = use_signal(String::new);
+    let mut selected_path: Signal<Option<String>> = use_signal(|| None);
+    let mut revisions: Signal<Vec<ContentRevision>> = use_signal(Vec::new);
+    let mut selected_revision: Signal<Option<i64>> = use_signal(|| None);
+    let mut confirm_restore: Signal<Option<i64>> = use_signal(|| None);
+    let mut feedback = use_signal(String::new);
+
+    use_effect(move || {
+        spawn(async move {
+            match admin_list_revised_files().await {
+                Ok(f) => {
+                    files.set(f);
+                    loading.set(false);
+                }
+                Err(e) => tracing::error!("admin_list_revised_files: {e}"),
+            }
+        });
+    });
+
+    use_effect(move || {
+        let Some(path) = selected_path() else {
+            revisions.set(Vec::new());
+            return;
+        };
+        spawn(async move {
+            match admin_list_revisions(path).await {
+                Ok(r) => {
+                    selected_revision.set(r.first().map(|rev| rev.id));
+                    revisions.set(r);
+                }
+                Err(e) => feedback.set(t!("admin-error", error : e.to_string())),
+            }
+        });
+    });
+
+    let needle = filter().to_lowercase();
+    let displayed: Vec<RevisedFileInfo> = files()
+        .into_iter()
+        .filter(|f| needle.is_empty() || f.path.to_lowercase().contains(&needle))
+        .collect();
+
+    let diff = revisions()
+        .into_iter()
+        .find(|r| Some(r.id) == selected_revision())
+        .map(|r| {
+            let lines = diff_lines(
+                r.before.as_deref().unwrap_or(""),
+                r.after.as_deref().unwrap_or(""),
+            );
+            // Keep only changed lines and their surrounding context.
+            let keep: Vec<bool> = (0..lines.len())
+                .map(|i| {
+                    let lo = i.saturating_sub(DIFF_CONTEXT_LINES);
+                    let hi = (i + DIFF_CONTEXT_LINES + 1).min(lines.len());
+                    lines[lo..hi].iter().any(|l| l.kind != DiffKind::Same)
+                })
+                .collect();
+            lines
+                .into_iter()
+                .zip(keep)
+                .filter_map(|(l, k)| k.then_some(l))
+                .collect::<Vec<_>>()
+        });
+
+    rsx! {
+        div { class: "admin-full-card",
+            p { class: "admin-section-title", {t!("admin-history-title")} }
+            Input {
+                placeholder: t!("admin-history-filter-placeholder"),
+                r#type: "text",
+                value: "{filter}",
+                oninput: move |e: FormEvent| filter.set(e.value()),
+            }
+            if loading() {
+                p { style: "color:var(--rpg-text-muted);", {t!("common-loading")} }
+            } else if displayed.is_empty() {
+                p { style: "color:var(--rpg-text-muted);", {t!("admin-history-empty")} }
+            } else {
+                table { class: "admin-table",
+                    thead {
+                        tr {
+                            th { class: "col-description", {t!("admin-scenarios-col-file")} }
+                            th { {t!("admin-history-col-revisions")} }
+                            th { {t!("admin-history-col-last-edit")} }
+                            th { {t!("admin-history-col-user")} }
+                            th { {t!("admin-scenarios-col-actions")} }
+                        }
+                    }
+                    tbody {
+                        for f in displayed {
+                            {
+                                let path = f.path.clone();
+                                rsx! {
+                                    tr {
+                                        td { class: "col-description", "{f.path}" }
+                                        td { "{f.nb_revisions}" }
+                                        td { "{f.last_at}" }
+                                        td { "{f.last_username}" }
+                                        td {
+                                            Button {
+                                                variant: ButtonVariant::Secondary,
+                                                onclick: move |_| {
+                                                    feedback.set(String::new());
+                                                    confirm_restore.set(None);
+                                                    selected_path.set(Some(path.clone()));
+                                                },
+                                                {t!("admin-history-view")}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(path) = selected_path() {
+            div { class: "admin-full-card",
+                p { class: "admin-section-title", {t!("admin-history-file-title", path : path.clone())} }
+                table { class: "admin-table",
+                    thead {
+                        tr {
+                            th { {t!("admin-history-col-date")} }
+                            th { {t!("admin-history-col-user")} }
+                            th { {t!("admin-history-col-action")} }
+                            th { {t!("admin-scenarios-col-actions")} }
+                        }
+                    }
+                    tbody {
+                        for rev in revisions() {
+                            {
+                                let id = rev.id;
+                                let path = path.clone();
+                                let is_selected = selected_revision() == Some(id);
+                                rsx! {
+                                    tr { class: if is_selected { "admin-history-selected" } else { "" },
+                                        td { "{rev.created_at}" }
+                                        td { "{rev.username}" }
+                                        td { {action_label(&rev.action)} }
+                                        td {
+                                            div { style: "display:flex;gap:6px;",
+                                                Button {
+                                                    variant: ButtonVariant::Secondary,
+                                                    onclick: move |_| selected_revision.set(Some(id)),
+                                                    {t!("admin-history-diff")}
+                                                }
+                                                if confirm_restore() == Some(id) {
+                                                    Button {
+                                                        variant: ButtonVariant::Destructive,
+                                                        onclick: move |_| {
+                                                            let path = path.clone();
+                                                            spawn(async move {
+                                                                match admin_restore_revision(id).await {
+                                                                    Ok(()) => {
+                                                                        feedback.set(t!("admin-history-restored"));
+                                                                        confirm_restore.set(None);
+                                                                        if let Ok(r) = admin_list_revisions(path).await {
+                                                                            selected_revision.set(r.first().map(|rev| rev.id));
+                                                                            revisions.set(r);
+                                                                        }
+                                                                        if let Ok(f) = admin_list_revised_files().await {
+                                                                            files.set(f);
+                                                                        }
+                                                                    }
+                                                                    Err(e) => {
+                                                                        feedback.set(t!("admin-error", error : e.to_string()));
+                                                                        confirm_restore.set(None);
+                                                                    }
+                                                                }
+                                                            });
+                                                        },
+                                                        {t!("admin-history-confirm-undo")}
+                                                    }
+                                                    Button {
+                                                        variant: ButtonVariant::Secondary,
+                                                        onclick: move |_| confirm_restore.set(None),
+                                                        {t!("common-cancel")}
+                                                    }
+                                                } else {
+                                                    Button {
+                                                        variant: ButtonVariant::Destructive,
+                                                        onclick: move |_| confirm_restore.set(Some(id)),
+                                                        {t!("admin-history-undo")}
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if !feedback().is_empty() {
+                    p { class: if feedback().starts_with('✅') { "admin-answer" } else { "admin-answer-error" },
+                        "{feedback}"
+                    }
+                }
+
+                if let Some(lines) = diff {
+                    p { class: "admin-section-title", style: "margin-top:12px;", {t!("admin-history-diff-title")} }
+                    if lines.is_empty() {
+                        p { style: "color:var(--rpg-text-muted);", {t!("admin-history-no-change")} }
+                    } else {
+                        pre { class: "admin-diff",
+                            for line in lines {
+                                match line.kind {
+                                    DiffKind::Added => rsx! {
+                                        div { class: "admin-diff-line added", "+ {line.text}" }
+                                    },
+                                    DiffKind::Removed => rsx! {
+                                        div { class: "admin-diff-line removed", "- {line.text}" }
+                                    },
+                                    DiffKind::Same => rsx! {
+                                        div { class: "admin-diff-line", "  {line.text}" }
+                                    },
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::{
+    auth_manager::server_fn::{
+        admin_get_universe_music, admin_list_map_music, admin_list_music_tracks,
+        admin_save_map_music, admin_save_universe_music, list_universes_server, upload_music_track,
+    },
+    common::music_src,
+    components::button::{Button, ButtonVariant},
+    music::{DEFAULT_MUSIC_CONFIG, MusicSlot, SlotTracks},
+};
+
+// JavaScript code to read the track from the input with id 'music-track-file'.
+// Same shape as the photo readers: sends {name, data} with base64 data, or null.
+const JS_READ_MUSIC_TRACK: &str = "const input = document.getElementById('music-track-file'); \
+     const file = input && input.files && input.files[0]; \
+     if (!file) { dioxus.send(null); return; } \
+     const reader = new FileReader(); \
+     reader.onload = function(ev) { \
+         const b64 = ev.target.result.split(',')[1]; \
+         dioxus.send({name: file.name, data: b64}); \
+     }; \
+     reader.readAsDataURL(file);";
+
+fn slot_label(slot: MusicSlot) -> String {
+    match slot {
+        MusicSlot::Home => t!("admin-music-slot-home"),
+        MusicSlot::Overworld => t!("admin-music-slot-overworld"),
+        MusicSlot::Combat => t!("admin-music-slot-combat"),
+        MusicSlot::Boss => t!("admin-music-slot-boss"),
+        MusicSlot::Victory => t!("admin-music-slot-victory"),
+    }
+}
+
+#[component]
+pub fn AdminMusicTab() -> Element {
+    let universes_resource = use_resource(list_universes_server);
+    let mut tracks_resource = use_resource(admin_list_music_tracks);
+    let mut maps_resource = use_resource(admin_list_map_music);
+    let mut upload_feedback = use_signal(String::new);
+    let mut scope = use_signal(|| DEFAULT_MUSIC_CONFIG.to_owned());
+    let mut slot_tracks: Signal<SlotTracks> = use_signal(SlotTracks::default);
+    let mut slots_feedback = use_signal(String::new);
+    let mut maps_feedback = use_signal(String::new);
+
+    use_effect(move || {
+        let universe = scope();
+        spawn(async move {
+            match admin_get_universe_music(universe).await {
+                Ok(tracks) => slot_tracks.set(tracks),
+                Err(e) => slots_feedback.set(t!("admin-error", error : e.to_string())),
+            }
+        });
+    });
+
+    let universes = universes_resource
+        .read()
+        .as_ref()
+        .and_then(|r| r.as_ref().ok())
+        .cloned()
+        .unwrap_or_default();
+    let tracks = tracks_resource
+        .read()
+        .as_ref()
+        .and_then(|r| r.as_ref().ok())
+        .cloned()
+        .unwrap_or_default();
+    let maps = maps_resource
+        .read()
+        .as_ref()
+        .and_then(|r| r.as_ref().ok())
+        .cloned()
+        .unwrap_or_default();
+
+    let on_track_change = move |_: FormEvent| {
+        let mut js = document::eval(JS_READ_MUSIC_TRACK);
+        upload_feedback.set(String::new());
+        spawn(async move {
+            if let Ok(val) = js.recv::<serde_json::Value>().await
+                && !val.is_null()
+            {
+                let name = val
+                    .get("name")
+                    .and_then(|v: &serde_json::Value| v.as_str())
+                    .map(String::from);
+                let data = val
+                    .get("data")
+                    .and_then(|v: &serde_json::Value| v.as_str())
+                    .map(String::from);
+                if let (Some(name), Some(data)) = (name, data) {
+                    match upload_music_track(name, data).await {
+                        Ok(name) => {
+                            upload_feedback.set(t!("admin-music-uploaded", track : name));
+                            tracks_resource.restart();
+                        }
+                        Err(e) => {
+                            upload_feedback.set(t!("admin-atk-upload-error", error : e.to_string()))
+                        }
+                    }
+                }
+            }
+        });
+    };
+
+    rsx! {
+        // Tracks
+        div { class: "admin-card",
+            p { class: "admin-section-title", {t!("admin-music-tracks-title")} }
+            p { style: "color:var(--rpg-text-muted);font-size:0.82rem;", {t!("admin-music-upload-hint")} }
+            input {
+                id: "music-track-file",
+                r#type: "file",
+                accept: ".ogg,.opus,.mp3,.wav",
+                onchange: on_track_change,
+            }
+            if !upload_feedback().is_empty() {
+                p { class: if upload_feedback().starts_with('✅') { "admin-answer" } else { "admin-answer-error" },
+                    "{upload_feedback}"
+                }
+            }
+            if tracks.is_empty() {
+                p { style: "color:var(--rpg-text-muted);", {t!("admin-music-no-tracks")} }
+            }
+            for track in tracks.iter() {
+                div { style: "display:flex;gap:8px;align-items:center;margin-top:6px;",
+                    span { style: "flex:1;word-break:break-word;font-size:0.85rem;", "{track}" }
+                    audio {
+                        controls: true,
+                        preload: "none",
+                        src: music_src(track),
+                    }
+                }
+            }
+        }
+
+        // Slot tracks of the defaults or of a universe
+        div { class: "admin-card",
+            p { class: "admin-section-title", {t!("admin-music-slots-title")} }
+            select {
+                class: "admin-select",
+                value: "{scope}",
+                onchange: move |e| {
+                    scope.set(e.value());
+                    slots_feedback.set(String::new());
+                },
+                option { value: DEFAULT_MUSIC_CONFIG, {t!("admin-music-scope-default")} }
+                for u in &universes {
+                    option { value: "{u}", "{u}" }
+                }
+            }
+            for slot in MusicSlot::ALL {
+                div { style: "display:flex;gap:8px;align-items:center;margin-top:6px;",
+                    span { style: "min-width:110px;font-size:0.85rem;", {slot_label(slot)} }
+                    select {
+                        class: "admin-select",
+                        value: "{slot_tracks().get(slot)}",
+                        onchange: move |e| slot_tracks.write().set(slot, e.value()),
+                        option { value: "", selected: slot_tracks().get(slot).is_empty(),
+                            {t!("admin-music-slot-default")}
+                        }
+                        for track in tracks.iter() {
+                            option { value: "{track}", selected: slot_tracks().get(slot) == track.as_str(), "{track}" }
+                        }
+                    }
+                }
+            }
+            Button {
+                variant: ButtonVariant::Primary,
+                onclick: move |_| {
+                    let universe = scope();
+                    let tracks = slot_tracks();
+                    spawn(async move {
+                        match admin_save_universe_music(universe, tracks).await {
+                            Ok(()) => slots_feedback.set(t!("admin-equip-saved")),
+                            Err(e) => slots_feedback.set(t!("admin-error", error : e.to_string())),
+                        }
+                    });
+                },
+                {t!("admin-equip-save")}
+            }
+            if !slots_feedback().is_empty() {
+                p { class: if slots_feedback().starts_with('✅') { "admin-answer" } else { "admin-answer-error" },
+                    "{slots_feedback}"
+                }
+            }
+        }
+
+        // Map tracks
+        div { class: "admin-full-card",
+            p { class: "admin-section-title", {t!("admin-music-maps-title")} }
+            table { class: "admin-table",
+                thead {
+                    tr {
+                        th { class: "col-name", {t!("admin-music-col-map")} }
+                        th { {t!("admin-music-col-track")} }
+                    }
+                }
+                tbody {
+                    for map in maps.iter() {
+                        {
+                            let file_name = map.file_name.clone();
+                            rsx! {
+                                tr {
+                                    td { class: "col-name", "{map.map_id}" }
+                                    td {
+                                        select {
+                                            class: "admin-select",
+                                            value: "{map.track}",
+                                            onchange: move |e| {
+                                                let file_name = file_name.clone();
+                                                let track = e.value();
+                                                spawn(async move {
+                                                    match admin_save_map_music(file_name, track).await {
+                                                        Ok(()) => {
+                                                            maps_feedback.set(t!("admin-equip-saved"));
+                                                            maps_resource.restart();
+                                                        }
+                                                        Err(e) => maps_feedback.set(t!("admin-error", error : e.to_string())),
+                                                    }
+                                                });
+                                            },
+                                            option { value: "", selected: map.track.is_empty(), {t!("admin-music-slot-default")} }
+                                            for track in tracks.iter() {
+                                                option { value: "{track}", selected: map.track == *track, "{track}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if !maps_feedback().is_empty() {
+                p { class: if maps_feedback().starts_with('✅') { "admin-answer" } else { "admin-answer-error" },
+                    "{maps_feedback}"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,313 @@
+use dioxus::logger::tracing;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::{
+    auth_manager::server_fn::{RecipeEntry, admin_delete_recipe, admin_save_recipe, get_recipes},
+    components::{
+        button::{Button, ButtonVariant},
+        input::Input,
+        label::Label,
+    },
+    crafting::{CraftedKind, Recipe, format_materials, parse_materials},
+};
+
+#[derive(Clone, PartialEq)]
+enum RecipeEditMode {
+    None,
+    Edit(String),
+    New,
+}
+
+fn kind_label(kind: CraftedKind) -> String {
+    match kind {
+        CraftedKind::Equipment => t!("loot-kind-equipment"),
+        CraftedKind::Consumable => t!("loot-kind-consumable"),
+    }
+}
+
+#[component]
+pub fn AdminRecipesTab() -> Element {
+    let mut recipes: Signal<Vec<RecipeEntry>> = use_signal(Vec::new);
+    let mut loading = use_signal(|| true);
+    let mut edit_mode = use_signal(|| RecipeEditMode::None);
+    let mut edit_file_stem = use_signal(String::new);
+    let mut edit_name = use_signal(String::new);
+    let mut edit_kind = use_signal(CraftedKind::default);
+    let mut edit_output = use_signal(String::new);
+    let mut edit_gold = use_signal(|| "0".to_owned());
+    let mut edit_materials = use_signal(String::new);
+    let mut feedback = use_signal(String::new);
+    let mut confirm_delete = use_signal(String::new);
+
+    let reload = move || {
+        spawn(async move {
+            match get_recipes().await {
+                Ok(r) => recipes.set(r),
+                Err(e) => tracing::error!("get_recipes: {e}"),
+            }
+            loading.set(false);
+        });
+    };
+    use_effect(reload);
+
+    rsx! {
+        div { class: "admin-full-card",
+            p { class: "admin-section-title", {t!("admin-recipes-title")} }
+            p { class: "settings-hint", {t!("admin-recipes-hint")} }
+
+            if loading() {
+                p { style: "color:var(--rpg-text-muted);", {t!("common-loading")} }
+            } else if recipes().is_empty() {
+                p { style: "color:var(--rpg-text-muted);", {t!("admin-recipes-empty")} }
+            } else {
+                table { class: "admin-table",
+                    thead {
+                        tr {
+                            th { class: "col-name", {t!("admin-equip-name-label")} }
+                            th { {t!("admin-recipes-col-output")} }
+                            th { {t!("admin-recipes-col-materials")} }
+                            th { {t!("admin-recipes-col-gold")} }
+                            th { class: "col-file", {t!("admin-scenarios-col-file")} }
+                            th { {t!("admin-scenarios-col-actions")} }
+                        }
+                    }
+                    tbody {
+                        for entry in recipes() {
+                            {
+                                let file_stem = entry.file_stem.clone();
+                                let fs_del = file_stem.clone();
+                                let recipe = entry.recipe.clone();
+                                let is_confirm = confirm_delete() == file_stem;
+                                rsx! {
+                                    tr {
+                                        td { class: "col-name", style: "font-weight:600;", "{entry.recipe.name}" }
+                                        td { "{kind_label(entry.recipe.kind)} · {entry.recipe.output}" }
+                                        td { style: "white-space:pre-line;", "{format_materials(&entry.recipe.materials)}" }
+                                        td { "{entry.recipe.gold}" }
+                                        td { class: "col-file", "{entry.file_stem}.json" }
+                                        td {
+                                            div { style: "display:flex;gap:6px;",
+                                                Button {
+                                                    variant: ButtonVariant::Secondary,
+                                                    onclick: move |_| {
+                                                        edit_file_stem.set(file_stem.clone());
+                                                        edit_name.set(recipe.name.clone());
+                                                        edit_kind.set(recipe.kind);
+                                                        edit_output.set(recipe.output.clone());
+                                                        edit_gold.set(recipe.gold.to_string());
+                                                        edit_materials.set(format_materials(&recipe.materials));
+                                                        edit_mode.set(RecipeEditMode::Edit(file_stem.clone()));
+                                                        feedback.set(String::new());
+                                                    },
+                                                    {t!("admin-scenarios-edit")}
+                                                }
+                                                if is_confirm {
+                                                    Button {
+                                                        variant: ButtonVariant::Destructive,
+                                                        onclick: move |_| {
+                                                            let fs = fs_del.clone();
+                                                            spawn(async move {
+                                                                match admin_delete_recipe(fs).await {
+                                                                    Ok(()) => {
+                                                                        feedback.set(t!("admin-deleted"));
+                                                                        edit_mode.set(RecipeEditMode::None);
+                                                                        reload();
+                                                                    }
+                                                                    Err(e) => feedback.set(t!("admin-error", error : e.to_string())),
+                                                                }
+                                                                confirm_delete.set(String::new());
+                                                            });
+                                                        },
+                                                        {t!("admin-scenarios-confirm-delete")}
+                                                    }
+                                                    Button {
+                                                        variant: ButtonVariant::Secondary,
+                                                        onclick: move |_| confirm_delete.set(String::new()),
+                                                        {t!("common-cancel")}
+                                                    }
+                                                } else {
+                                                    Button {
+                                                        variant: ButtonVariant::Destructive,
+                                                        onclick: {
+                                                            let fs = entry.file_stem.clone();
+                                                            move |_| confirm_delete.set(fs.clone())
+                                                        },
+                                                        {t!("admin-scenarios-delete")}
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if edit_mode() == RecipeEditMode::None {
+                div { style: "margin-top:12px;",
+                    Button {
+                        variant: ButtonVariant::Primary,
+                        onclick: move |_| {
+                            edit_file_stem.set(String::new());
+                            edit_name.set(String::new());
+                            edit_kind.set(CraftedKind::default());
+                            edit_output.set(String::new());
+                            edit_gold.set("0".to_owned());
+                            edit_materials.set(String::new());
+                            edit_mode.set(RecipeEditMode::New);
+                            feedback.set(String::new());
+                        },
+                        {t!("admin-recipes-add")}
+                    }
+                }
+            }
+        }
+
+        if edit_mode() != RecipeEditMode::None {
+            div { class: "admin-full-card",
+                p { class: "admin-section-title",
+                    if edit_mode() == RecipeEditMode::New {
+                        {t!("admin-recipes-new-title")}
+                    } else {
+                        {t!("admin-recipes-edit-title")}
+                    }
+                }
+
+                if edit_mode() == RecipeEditMode::New {
+                    Label {
+                        html_for: "recipe-stem",
+                        color: "var(--rpg-text-muted)",
+                        font_size: "0.82rem",
+                        {t!("admin-scenarios-file-stem-label")}
+                    }
+                    Input {
+                        placeholder: "super_potion",
+                        r#type: "text",
+                        value: "{edit_file_stem}",
+                        oninput: move |e: FormEvent| edit_file_stem.set(e.value()),
+                    }
+                }
+
+                Label {
+                    html_for: "recipe-name",
+                    color: "var(--rpg-text-muted)",
+                    font_size: "0.82rem",
+                    {t!("admin-equip-name-label")}
+                }
+                Input {
+                    placeholder: t!("admin-recipes-name-placeholder"),
+                    r#type: "text",
+                    value: "{edit_name}",
+                    oninput: move |e: FormEvent| edit_name.set(e.value()),
+                }
+                Label {
+                    html_for: "recipe-output",
+                    color: "var(--rpg-text-muted)",
+                    font_size: "0.82rem",
+                    {t!("admin-recipes-col-output")}
+                }
+                div { style: "display:flex;gap:12px;align-items:center;",
+                    select {
+                        class: "admin-select",
+                        onchange: move |e| {
+                            edit_kind
+                                .set(
+                                    if e.value() == "Consumable" {
+                                        CraftedKind::Consumable
+                                    } else {
+                                        CraftedKind::Equipment
+                                    },
+                                )
+                        },
+                        for kind in [CraftedKind::Equipment, CraftedKind::Consumable] {
+                            option {
+                                value: "{kind:?}",
+                                selected: edit_kind() == kind,
+                                {kind_label(kind)}
+                            }
+                        }
+                    }
+                    Input {
+                        placeholder: t!("admin-recipes-output-placeholder"),
+                        r#type: "text",
+                        value: "{edit_output}",
+                        oninput: move |e: FormEvent| edit_output.set(e.value()),
+                    }
+                }
+                Label {
+                    html_for: "recipe-gold",
+                    color: "var(--rpg-text-muted)",
+                    font_size: "0.82rem",
+                    {t!("admin-recipes-col-gold")}
+                }
+                Input {
+                    placeholder: "0",
+                    r#type: "number",
+                    value: "{edit_gold}",
+                    oninput: move |e: FormEvent| edit_gold.set(e.value()),
+                }
+                Label {
+                    html_for: "recipe-materials",
+                    color: "var(--rpg-text-muted)",
+                    font_size: "0.82rem",
+                    {t!("admin-recipes-materials-label")}
+                }
+                textarea {
+                    class: "admin-json-textarea",
+                    rows: "4",
+                    placeholder: "Iron ore: 4\nLeather",
+                    value: "{edit_materials}",
+                    oninput: move |e: FormEvent| edit_materials.set(e.value()),
+                }
+
+                div { style: "display:flex;gap:8px;margin-top:8px;",
+                    Button {
+                        variant: ButtonVariant::Primary,
+                        onclick: move |_| {
+                            let fs = edit_file_stem();
+                            let recipe = Recipe {
+                                name: edit_name().trim().to_owned(),
+                                kind: edit_kind(),
+                                output: edit_output().trim().to_owned(),
+                                gold: edit_gold().trim().parse::<u64>().unwrap_or(0),
+                                materials: parse_materials(&edit_materials()),
+                            };
+                            spawn(async move {
+                                if fs.trim().is_empty() {
+                                    feedback.set(t!("admin-scenarios-file-stem-empty"));
+                                    return;
+                                }
+                                match admin_save_recipe(fs, recipe).await {
+                                    Ok(()) => {
+                                        feedback.set(t!("admin-equip-saved"));
+                                        edit_mode.set(RecipeEditMode::None);
+                                        reload();
+                                    }
+                                    Err(e) => feedback.set(t!("admin-error", error : e.to_string())),
+                                }
+                            });
+                        },
+                        {t!("admin-equip-save")}
+                    }
+                    Button {
+                        variant: ButtonVariant::Secondary,
+                        onclick: move |_| {
+                            edit_mode.set(RecipeEditMode::None);
+                            feedback.set(String::new());
+                        },
+                        {t!("common-cancel")}
+                    }
+                }
+            }
+        }
+
+        if !feedback().is_empty() {
+            p { class: if feedback().starts_with('✅') { "admin-answer" } else { "admin-answer-error" },
+                "{feedback}"
+            }
+        }
+    }
+}
@@ -4,8 +4,9 @@ use dioxus_i18n::t;
 
 use crate::{
     auth_manager::server_fn::{
-        AdminScenarioInfo, ScenarioDetail, ScenarioLootItem, admin_list_scenarios,
-        delete_scenario_json, get_scenario_detail, list_universes_server, save_scenario_detail,
+        AdminScenarioInfo, ScenarioDetail, ScenarioLootItem, admin_list_music_tracks,
+        admin_list_scenarios, delete_scenario_json, get_scenario_detail, list_universes_server,
+        save_scenario_detail,
     },
     components::{
         button::{Button, ButtonVariant},
@@ -34,6 +35,9 @@ pub fn AdminScenariosTab() -> Element {
     let mut edit_level = use_signal(|| "1".to_owned());
     let mut edit_bosses = use_signal(String::new);
     let mut edit_loots: Signal<Vec<ScenarioLootItem>> = use_signal(Vec::new);
+    let mut edit_music = use_signal(String::new);
+    let mut edit_boss_fight = use_signal(|| false);
+    let music_tracks_resource = use_resource(admin_list_music_tracks);
     let mut feedback = use_signal(String::new);
     let mut confirm_delete = use_signal(String::new);
 
@@ -64,6 +68,12 @@ pub fn AdminScenariosTab() -> Element {
         .and_then(|r| r.as_ref().ok())
         .cloned()
         .unwrap_or_default();
+    let music_tracks = music_tracks_resource
+        .read()
+        .as_ref()
+        .and_then(|r| r.as_ref().ok())
+        .cloned()
+        .unwrap_or_default();
 
     rsx! {
         div { class: "admin-card",
@@ -149,6 +159,8 @@ pub fn AdminScenariosTab() -> Element {
                                                                         edit_level.set(detail.level.to_string());
                                                                         edit_bosses.set(detail.boss_patterns_text);
                                                                         edit_loots.set(detail.loots);
+                                                                        edit_music.set(detail.music);
+                                                                        edit_boss_fight.set(detail.boss_fight);
                                                                         edit_file_stem.set(fs);
                                                                         edit_mode.set(ScenarioEditMode::Edit(fs_for_mode));
                                                                     }
@@ -221,6 +233,8 @@ pub fn AdminScenariosTab() -> Element {
                                 edit_level.set("1".to_owned());
                                 edit_bosses.set(String::new());
                                 edit_loots.set(Vec::new());
+                                edit_music.set(String::new());
+                                edit_boss_fight.set(false);
                                 edit_mode.set(ScenarioEditMode::New);
                                 feedback.set(String::new());
                             },
@@ -304,6 +318,31 @@ pub fn AdminScenariosTab() -> Element {
                         value: "{edit_bosses}",
                         oninput: move |e: FormEvent| edit_bosses.set(e.value()),
                     }
+                    Label {
+                        html_for: "scenario-music",
+                        color: "var(--rpg-text-muted)",
+                        font_size: "0.82rem",
+                        {t!("admin-scenarios-music-label")}
+                    }
+                    div { style: "display:flex;gap:12px;align-items:center;",
+                        select {
+                            class: "admin-select",
+                            value: "{edit_music}",
+                            onchange: move |e| edit_music.set(e.value()),
+                            option { value: "", selected: edit_music().is_empty(), {t!("admin-music-slot-default")} }
+                            for track in music_tracks.iter() {
+                                option { value: "{track}", selected: edit_music() == *track, "{track}" }
+                            }
+                        }
+                        label { style: "display:flex;gap:4px;align-items:center;font-size:0.82rem;",
+                            input {
+                                r#type: "checkbox",
+                                checked: edit_boss_fight(),
+                                onchange: move |e| edit_boss_fight.set(e.checked()),
+                            }
+                            {t!("admin-scenarios-boss-fight-label")}
+                        }
+                    }
 
                     Label {
                         html_for: "scenario-loots",
@@ -431,6 +470,8 @@ pub fn AdminScenariosTab() -> Element {
                                     level,
                                     boss_patterns_text: edit_bosses(),
                                     loots: edit_loots(),
+                                    music: edit_music(),
+                                    boss_fight: edit_boss_fight(),
                                 };
                                 spawn(async move {
                                     if fs.trim().is_empty() {
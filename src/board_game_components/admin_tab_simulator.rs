@@ -0,0 +1,229 @@
+use dioxus::logger::tracing;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use std::collections::BTreeMap;
+
+use crate::{
+    auth_manager::server_fn::{
+        AdminCharacterInfo, AdminScenarioInfo, admin_list_characters, admin_list_scenarios,
+        admin_simulate_scenario,
+    },
+    components::{
+        button::{Button, ButtonVariant},
+        input::Input,
+    },
+    utils::balance_simulator::{
+        MAX_SIMULATED_FIGHTS, SimulationPolicy, SimulationReport, SimulationRequest,
+    },
+    widgets::charts::{HeroStatsPanel, StatCard},
+};
+
+#[component]
+pub fn AdminSimulatorTab() -> Element {
+    let mut scenarios: Signal<Vec<AdminScenarioInfo>> = use_signal(Vec::new);
+    let mut heroes: Signal<Vec<AdminCharacterInfo>> = use_signal(Vec::new);
+    let mut scenario_name = use_signal(String::new);
+    let mut party: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut hero_to_add = use_signal(String::new);
+    let mut nb_fights = use_signal(|| "20".to_owned());
+    let mut scripted = use_signal(|| false);
+    // hero name → comma-separated attack names by priority
+    let mut scripts: Signal<BTreeMap<String, String>> = use_signal(BTreeMap::new);
+    let mut running = use_signal(|| false);
+    let mut report: Signal<Option<SimulationReport>> = use_signal(|| None);
+    let mut feedback = use_signal(String::new);
+
+    use_effect(move || {
+        spawn(async move {
+            match admin_list_scenarios().await {
+                Ok(s) => scenarios.set(s),
+                Err(e) => tracing::error!("admin_list_scenarios: {e}"),
+            }
+            match admin_list_characters().await {
+                Ok(h) => heroes.set(h),
+                Err(e) => tracing::error!("admin_list_characters: {e}"),
+            }
+        });
+    });
+
+    let on_run = move |_| {
+        let policy = if scripted() {
+            SimulationPolicy::Scripted(
+                scripts()
+                    .into_iter()
+                    .map(|(hero, atks)| {
+                        let atks = atks
+                            .split(',')
+                            .map(|a| a.trim().to_owned())
+                            .filter(|a| !a.is_empty())
+                            .collect();
+                        (hero, atks)
+                    })
+                    .collect(),
+            )
+        } else {
+            SimulationPolicy::Greedy
+        };
+        let request = SimulationRequest {
+            scenario_name: scenario_name(),
+            heroes: party(),
+            nb_fights: nb_fights().trim().parse().unwrap_or(1).max(1),
+            policy,
+        };
+        running.set(true);
+        feedback.set(String::new());
+        spawn(async move {
+            match admin_simulate_scenario(request).await {
+                Ok(r) => report.set(Some(r)),
+                Err(e) => feedback.set(t!("admin-error", error : e.to_string())),
+            }
+            running.set(false);
+        });
+    };
+
+    let mut distinct_party = party();
+    distinct_party.sort();
+    distinct_party.dedup();
+
+    rsx! {
+        div { class: "admin-full-card",
+            p { class: "admin-section-title", {t!("admin-sim-title")} }
+            p { style: "color:var(--rpg-text-muted);font-size:0.82rem;", {t!("admin-sim-hint")} }
+
+            div { style: "display:flex;gap:8px;align-items:center;flex-wrap:wrap;margin-bottom:8px;",
+                select {
+                    class: "admin-select",
+                    value: "{scenario_name}",
+                    onchange: move |e| scenario_name.set(e.value()),
+                    option { value: "", {t!("admin-sim-choose-scenario")} }
+                    for s in scenarios() {
+                        option { value: "{s.name}", "{s.universe} — {s.name} (lvl {s.level})" }
+                    }
+                }
+                Input {
+                    r#type: "number",
+                    min: "1",
+                    max: "{MAX_SIMULATED_FIGHTS}",
+                    value: "{nb_fights}",
+                    oninput: move |e: FormEvent| nb_fights.set(e.value()),
+                }
+                span { style: "font-size:0.82rem;", {t!("admin-sim-fights")} }
+            }
+
+            div { style: "display:flex;gap:8px;align-items:center;flex-wrap:wrap;margin-bottom:8px;",
+                select {
+                    class: "admin-select",
+                    value: "{hero_to_add}",
+                    onchange: move |e| hero_to_add.set(e.value()),
+                    option { value: "", {t!("admin-sim-choose-hero")} }
+                    for h in heroes() {
+                        option { value: "{h.db_full_name}", "{h.universe} — {h.db_full_name}" }
+                    }
+                }
+                Button {
+                    variant: ButtonVariant::Secondary,
+                    disabled: hero_to_add().is_empty(),
+                    onclick: move |_| party.write().push(hero_to_add()),
+                    {t!("admin-sim-add-hero")}
+                }
+            }
+            div { style: "display:flex;gap:6px;flex-wrap:wrap;margin-bottom:8px;",
+                for (i, name) in party().into_iter().enumerate() {
+                    Button {
+                        variant: ButtonVariant::Outline,
+                        onclick: move |_| {
+                            party.write().remove(i);
+                        },
+                        "{name} ✕"
+                    }
+                }
+            }
+
+            div { style: "display:flex;gap:8px;align-items:center;margin-bottom:8px;",
+                span { style: "font-size:0.82rem;", {t!("admin-sim-policy")} }
+                select {
+                    class: "admin-select",
+                    value: if scripted() { "scripted" } else { "greedy" },
+                    onchange: move |e| scripted.set(e.value() == "scripted"),
+                    option { value: "greedy", {t!("admin-sim-policy-greedy")} }
+                    option { value: "scripted", {t!("admin-sim-policy-scripted")} }
+                }
+            }
+            if scripted() {
+                for name in distinct_party {
+                    {
+                        let script = scripts().get(&name).cloned().unwrap_or_default();
+                        let hero = name.clone();
+                        rsx! {
+                            div { style: "display:flex;gap:8px;align-items:center;margin-bottom:6px;",
+                                span { style: "min-width:10em;font-size:0.82rem;", "{name}" }
+                                Input {
+                                    placeholder: t!("admin-sim-script-placeholder"),
+                                    r#type: "text",
+                                    value: "{script}",
+                                    oninput: move |e: FormEvent| {
+                                        scripts.write().insert(hero.clone(), e.value());
+                                    },
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            Button {
+                variant: ButtonVariant::Primary,
+                disabled: running() || scenario_name().is_empty() || party().is_empty(),
+                onclick: on_run,
+                if running() {
+                    {t!("admin-sim-running")}
+                } else {
+                    {t!("admin-sim-run")}
+                }
+            }
+            if !feedback().is_empty() {
+                p { class: "admin-answer-error", "{feedback}" }
+            }
+        }
+
+        if let Some(r) = report() {
+            div { class: "admin-full-card",
+                p { class: "admin-section-title",
+                    {t!("admin-sim-report-title", scenario : r.scenario_name.clone(), fights : r.nb_fights as i64)}
+                }
+                div { class: "rpg-stat-cards",
+                    StatCard {
+                        label: t!("admin-sim-win-rate"),
+                        value: format!("{:.0}%", r.win_rate() * 100.0),
+                        color: "var(--secondary-success-color)".to_owned(),
+                        icon: "🏆".to_owned(),
+                    }
+                    StatCard {
+                        label: t!("admin-sim-avg-turns"),
+                        value: format!("{:.1}", r.avg_turns()),
+                        color: "var(--rpg-gold)".to_owned(),
+                        icon: "⏱️".to_owned(),
+                    }
+                    StatCard {
+                        label: t!("admin-sim-deaths"),
+                        value: format!("{}", r.heroes.iter().map(|h| h.deaths).sum::<usize>()),
+                        color: "var(--secondary-color-2)".to_owned(),
+                        icon: "💀".to_owned(),
+                    }
+                    StatCard {
+                        label: t!("admin-sim-timeouts"),
+                        value: format!("{}", r.timeouts),
+                        color: "var(--rpg-teal)".to_owned(),
+                        icon: "⌛".to_owned(),
+                    }
+                }
+                for hero in r.heroes.iter() {
+                    h4 { class: "rpg-section-title", style: "margin-top:16px;",
+                        {t!("admin-sim-hero-title", name : hero.id_name.clone(), deaths : hero.deaths as i64)}
+                    }
+                    HeroStatsPanel { atks_info: hero.atks_info.clone(), rounds: r.total_turns }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,408 @@
+use dioxus::logger::tracing;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use std::collections::BTreeMap;
+
+use crate::{
+    auth_manager::server_fn::{
+        TranslationEntry, TranslationFormat, TranslationImportReport, TranslationStatus,
+        TranslationUpdate, admin_export_translations, admin_import_translations,
+        admin_list_translations, admin_save_translations,
+    },
+    components::{
+        button::{Button, ButtonVariant},
+        input::Input,
+    },
+};
+
+/// Rows rendered at once; the filter narrows the list down.
+const MAX_DISPLAYED_ROWS: usize = 100;
+
+// JavaScript code to read the translation file from the input with id 'translations-file'.
+// Sends {name, text} with the file content as UTF-8 text, or null.
+const JS_READ_TRANSLATIONS: &str = "const input = document.getElementById('translations-file'); \
+     const file = input && input.files && input.files[0]; \
+     if (!file) { dioxus.send(null); return; } \
+     const reader = new FileReader(); \
+     reader.onload = function(ev) { dioxus.send({name: file.name, text: ev.target.result}); }; \
+     reader.readAsText(file, 'UTF-8');";
+
+// JavaScript code to save a text document received from Dioxus as a file download.
+const JS_DOWNLOAD_TRANSLATIONS: &str = "const msg = await dioxus.recv(); \
+     const url = URL.createObjectURL(new Blob([msg.text], {type: msg.mime})); \
+     const a = document.createElement('a'); \
+     a.href = url; \
+     a.download = msg.name; \
+     a.click(); \
+     URL.revokeObjectURL(url);";
+
+/// Which entries the table shows.
+#[derive(Clone, Copy, PartialEq)]
+enum StatusFilter {
+    Incomplete,
+    Missing,
+    Identical,
+    All,
+}
+
+impl StatusFilter {
+    fn from_value(value: &str) -> Self {
+        match value {
+            "missing" => StatusFilter::Missing,
+            "identical" => StatusFilter::Identical,
+            "all" => StatusFilter::All,
+            _ => StatusFilter::Incomplete,
+        }
+    }
+
+    fn accepts(&self, status: TranslationStatus) -> bool {
+        match self {
+            StatusFilter::Incomplete => status != TranslationStatus::Translated,
+            StatusFilter::Missing => status == TranslationStatus::Missing,
+            StatusFilter::Identical => status == TranslationStatus::Identical,
+            StatusFilter::All => true,
+        }
+    }
+}
+
+fn report_feedback(report: &TranslationImportReport) -> String {
+    let mut feedback = t!(
+        "admin-translations-saved",
+        updated : report.updated as i64,
+        unchanged : report.unchanged as i64
+    );
+    for error in &report.errors {
+        feedback.push_str(&format!("\n⚠️ {error}"));
+    }
+    feedback
+}
+
+/// `pointer` + field name, e.g. `/paths/0/talents/1 · name_en / name_fr`.
+fn field_label(entry: &TranslationEntry) -> String {
+    let key = &entry.key;
+    if key.pointer.is_empty() {
+        format!("{} / {}", key.en_key, key.fr_key)
+    } else {
+        format!("{} · {} / {}", key.pointer, key.en_key, key.fr_key)
+    }
+}
+
+#[component]
+pub fn AdminTranslationsTab() -> Element {
+    let mut entries: Signal<Vec<TranslationEntry>> = use_signal(Vec::new);
+    let mut loading = use_signal(|| true);
+    let mut filter = use_signal(String::new);
+    let mut status_filter = use_signal(|| StatusFilter::Incomplete);
+    // entry id → edited (en, fr), until saved
+    let mut edits: Signal<BTreeMap<String, (String, String)>> = use_signal(BTreeMap::new);
+    let mut feedback = use_signal(String::new);
+    let mut export_format = use_signal(|| TranslationFormat::Csv);
+    let mut export_incomplete_only = use_signal(|| true);
+    let mut transfer_feedback = use_signal(String::new);
+
+    let reload = move || {
+        spawn(async move {
+            match admin_list_translations().await {
+                Ok(e) => {
+                    entries.set(e);
+                    loading.set(false);
+                }
+                Err(e) => tracing::error!("admin_list_translations: {e}"),
+            }
+        });
+    };
+
+    use_effect(move || {
+        reload();
+    });
+
+    let save = move |updates: Vec<TranslationUpdate>| {
+        let saved_ids: Vec<String> = updates.iter().map(|u| u.key.id()).collect();
+        spawn(async move {
+            match admin_save_translations(updates).await {
+                Ok(report) => {
+                    edits.write().retain(|id, _| !saved_ids.contains(id));
+                    feedback.set(report_feedback(&report));
+                    reload();
+                }
+                Err(e) => feedback.set(t!("admin-error", error : e.to_string())),
+            }
+        });
+    };
+
+    let pending_updates = move || -> Vec<TranslationUpdate> {
+        let edits = edits();
+        entries()
+            .into_iter()
+            .filter_map(|e| {
+                let (en, fr) = edits.get(&e.key.id())?.clone();
+                Some(TranslationUpdate { key: e.key, en, fr })
+            })
+            .collect()
+    };
+
+    let on_file_change = move |_: FormEvent| {
+        let mut js = document::eval(JS_READ_TRANSLATIONS);
+        transfer_feedback.set(String::new());
+        spawn(async move {
+            if let Ok(val) = js.recv::<serde_json::Value>().await
+                && !val.is_null()
+            {
+                let name = val["name"].as_str().unwrap_or_default().to_lowercase();
+                let text = val["text"].as_str().unwrap_or_default().to_owned();
+                let format = if name.ends_with(".csv") {
+                    TranslationFormat::Csv
+                } else {
+                    TranslationFormat::Xliff
+                };
+                match admin_import_translations(format, text).await {
+                    Ok(report) => {
+                        transfer_feedback.set(report_feedback(&report));
+                        reload();
+                    }
+                    Err(e) => transfer_feedback.set(t!("admin-error", error : e.to_string())),
+                }
+            }
+        });
+    };
+
+    let all_entries = entries();
+    let count =
+        |status: TranslationStatus| all_entries.iter().filter(|e| e.status == status).count();
+    let (nb_missing, nb_identical, nb_translated) = (
+        count(TranslationStatus::Missing),
+        count(TranslationStatus::Identical),
+        count(TranslationStatus::Translated),
+    );
+    let needle = filter().to_lowercase();
+    let matching: Vec<TranslationEntry> = all_entries
+        .iter()
+        .filter(|e| status_filter().accepts(e.status))
+        .filter(|e| {
+            needle.is_empty()
+                || e.key.id().to_lowercase().contains(&needle)
+                || e.en.to_lowercase().contains(&needle)
+                || e.fr.to_lowercase().contains(&needle)
+        })
+        .cloned()
+        .collect();
+    let nb_matching = matching.len();
+    let displayed: Vec<TranslationEntry> = matching.into_iter().take(MAX_DISPLAYED_ROWS).collect();
+    let nb_edits = edits().len();
+
+    rsx! {
+        // Export / import
+        div { class: "admin-card",
+            p { class: "admin-section-title", {t!("admin-translations-transfer-title")} }
+            p { style: "color:var(--rpg-text-muted);font-size:0.82rem;", {t!("admin-translations-transfer-hint")} }
+            div { style: "display:flex;gap:8px;align-items:center;flex-wrap:wrap;margin-bottom:8px;",
+                select {
+                    class: "admin-select",
+                    value: if export_format() == TranslationFormat::Csv { "csv" } else { "xliff" },
+                    onchange: move |e| {
+                        export_format
+                            .set(
+                                if e.value() == "csv" { TranslationFormat::Csv } else { TranslationFormat::Xliff },
+                            )
+                    },
+                    option { value: "csv", "CSV" }
+                    option { value: "xliff", "XLIFF 1.2" }
+                }
+                label { style: "display:flex;gap:4px;align-items:center;font-size:0.82rem;",
+                    input {
+                        r#type: "checkbox",
+                        checked: export_incomplete_only(),
+                        onchange: move |e| export_incomplete_only.set(e.checked()),
+                    }
+                    {t!("admin-translations-export-incomplete")}
+                }
+                Button {
+                    variant: ButtonVariant::Primary,
+                    onclick: move |_| {
+                        let format = export_format();
+                        spawn(async move {
+                            match admin_export_translations(format, export_incomplete_only()).await {
+                                Ok(text) => {
+                                    let mime = match format {
+                                        TranslationFormat::Csv => "text/csv",
+                                        TranslationFormat::Xliff => "application/xliff+xml",
+                                    };
+                                    let js = document::eval(JS_DOWNLOAD_TRANSLATIONS);
+                                    let _ = js
+                                        .send(
+                                            serde_json::json!({
+                                                "name": format!("translations.{}", format.extension()), "mime": mime, "text":
+                                                text
+                                            }),
+                                        );
+                                    transfer_feedback.set(t!("admin-translations-exported"));
+                                }
+                                Err(e) => transfer_feedback.set(t!("admin-error", error : e.to_string())),
+                            }
+                        });
+                    },
+                    {t!("admin-translations-export-button")}
+                }
+            }
+            div { style: "display:flex;gap:8px;align-items:center;",
+                span { style: "font-size:0.82rem;", {t!("admin-translations-import-label")} }
+                input {
+                    id: "translations-file",
+                    r#type: "file",
+                    accept: ".csv,.xlf,.xliff",
+                    onchange: on_file_change,
+                }
+            }
+            if !transfer_feedback().is_empty() {
+                p {
+                    class: if transfer_feedback().starts_with('✅') { "admin-answer" } else { "admin-answer-error" },
+                    style: "white-space:pre-line;",
+                    "{transfer_feedback}"
+                }
+            }
+        }
+
+        // Side-by-side editor
+        div { class: "admin-full-card",
+            p { class: "admin-section-title", {t!("admin-translations-title")} }
+            div { style: "display:flex;gap:6px;flex-wrap:wrap;margin-bottom:8px;",
+                span { class: "scenario-chip conflict",
+                    {t!("admin-translations-count-missing", count : nb_missing as i64)}
+                }
+                span { class: "scenario-chip in-progress",
+                    {t!("admin-translations-count-identical", count : nb_identical as i64)}
+                }
+                span { class: "scenario-chip completed",
+                    {t!("admin-translations-count-translated", count : nb_translated as i64)}
+                }
+            }
+            div { style: "display:flex;gap:8px;align-items:center;flex-wrap:wrap;margin-bottom:8px;",
+                Input {
+                    placeholder: t!("admin-translations-filter-placeholder"),
+                    r#type: "text",
+                    value: "{filter}",
+                    oninput: move |e: FormEvent| filter.set(e.value()),
+                }
+                select {
+                    class: "admin-select",
+                    onchange: move |e| status_filter.set(StatusFilter::from_value(&e.value())),
+                    option { value: "incomplete", {t!("admin-translations-show-incomplete")} }
+                    option { value: "missing", {t!("admin-translations-show-missing")} }
+                    option { value: "identical", {t!("admin-translations-show-identical")} }
+                    option { value: "all", {t!("admin-translations-show-all")} }
+                }
+                Button {
+                    variant: ButtonVariant::Primary,
+                    disabled: nb_edits == 0,
+                    onclick: move |_| save(pending_updates()),
+                    {t!("admin-translations-save-all", count : nb_edits as i64)}
+                }
+            }
+            if !feedback().is_empty() {
+                p {
+                    class: if feedback().starts_with('✅') { "admin-answer" } else { "admin-answer-error" },
+                    style: "white-space:pre-line;",
+                    "{feedback}"
+                }
+            }
+            if loading() {
+                p { style: "color:var(--rpg-text-muted);", {t!("common-loading")} }
+            } else if displayed.is_empty() {
+                p { style: "color:var(--rpg-text-muted);", {t!("admin-translations-empty")} }
+            } else {
+                if nb_matching > displayed.len() {
+                    p { style: "color:var(--rpg-text-muted);font-size:0.78rem;",
+                        {t!("admin-translations-truncated", shown : displayed.len() as i64, total : nb_matching as i64)}
+                    }
+                }
+                table { class: "admin-table",
+                    thead {
+                        tr {
+                            th { class: "col-name", {t!("admin-packs-col-status")} }
+                            th { class: "col-description", {t!("admin-translations-col-field")} }
+                            th { {t!("admin-translations-col-en")} }
+                            th { {t!("admin-translations-col-fr")} }
+                            th { {t!("admin-scenarios-col-actions")} }
+                        }
+                    }
+                    tbody {
+                        for entry in displayed {
+                            {
+                                let id = entry.key.id();
+                                let (en, fr) = edits()
+                                    .get(&id)
+                                    .cloned()
+                                    .unwrap_or_else(|| (entry.en.clone(), entry.fr.clone()));
+                                let is_edited = edits().contains_key(&id);
+                                let (id_en, id_fr) = (id.clone(), id.clone());
+                                let (en_for_fr, fr_for_en) = (en.clone(), fr.clone());
+                                let (en_save, fr_save) = (en.clone(), fr.clone());
+                                let key = entry.key.clone();
+                                rsx! {
+                                    tr {
+                                        td { class: "col-name",
+                                            match entry.status {
+                                                TranslationStatus::Missing => rsx! {
+                                                    span { class: "scenario-chip conflict", {t!("admin-translations-status-missing")} }
+                                                },
+                                                TranslationStatus::Identical => rsx! {
+                                                    span { class: "scenario-chip in-progress", {t!("admin-translations-status-identical")} }
+                                                },
+                                                TranslationStatus::Translated => rsx! {
+                                                    span { class: "scenario-chip completed", {t!("admin-translations-status-translated")} }
+                                                },
+                                            }
+                                        }
+                                        td { class: "col-description", style: "font-size:0.78rem;",
+                                            div { "{entry.key.path}" }
+                                            div { style: "color:var(--rpg-text-muted);", {field_label(&entry)} }
+                                            if let Some(legacy) = &entry.legacy {
+                                                div { class: "admin-translation-legacy", "{legacy}" }
+                                            }
+                                        }
+                                        td {
+                                            textarea {
+                                                class: "admin-translation-text",
+                                                value: "{en}",
+                                                oninput: move |e| {
+                                                    edits.write().insert(id_en.clone(), (e.value(), fr_for_en.clone()));
+                                                },
+                                            }
+                                        }
+                                        td {
+                                            textarea {
+                                                class: "admin-translation-text",
+                                                value: "{fr}",
+                                                oninput: move |e| {
+                                                    edits.write().insert(id_fr.clone(), (en_for_fr.clone(), e.value()));
+                                                },
+                                            }
+                                        }
+                                        td {
+                                            Button {
+                                                variant: ButtonVariant::Secondary,
+                                                disabled: !is_edited,
+                                                onclick: move |_| {
+                                                    save(
+                                                        vec![
+                                                            TranslationUpdate {
+                                                                key: key.clone(),
+                                                                en: en_save.clone(),
+                                                                fr: fr_save.clone(),
+                                                            },
+                                                        ],
+                                                    );
+                                                },
+                                                {t!("admin-translations-save")}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,346 @@
+use dioxus::{
+    fullstack::{CborEncoding, UseWebsocket},
+    prelude::*,
+};
+use dioxus_i18n::t;
+use lib_rpg::server::server_manager::{GamePhase, ServerData};
+
+use crate::{
+    auth_manager::server_fn::{
+        ArenaLog, ArenaMatchRecord, ArenaOverview, get_arena_overview, get_arena_replay,
+        register_arena_party,
+    },
+    combat_log::{self, LogEntry, LogKind},
+    common::Route,
+    components::button::{Button, ButtonVariant},
+    server_messages,
+    utils::server_file_utils::{SaveSlotInfo, get_save_slots},
+    websocket_handler::{
+        event::{ClientEvent, GameMode, ServerEvent},
+        msg_from_client::send_initialize_game,
+    },
+};
+
+/// Lines of a match log, one step per attack.
+fn replay_steps(log: &ArenaLog) -> Vec<Vec<LogEntry>> {
+    let mut steps: Vec<Vec<LogEntry>> = Vec::new();
+    for entry in combat_log::annotate(&log.lines, &log.messages) {
+        if entry.kind == LogKind::AttackMarker || steps.is_empty() {
+            steps.push(Vec::new());
+        }
+        if let Some(step) = steps.last_mut() {
+            step.push(entry);
+        }
+    }
+    steps
+}
+
+/// A match of the history as `me` lived it: opponent, whether they won, and
+/// their rating change.
+fn match_outcome(record: &ArenaMatchRecord, me: &str) -> (String, bool, i64) {
+    if record.attacker == me {
+        (
+            record.defender.clone(),
+            record.attacker_won,
+            record.rating_change,
+        )
+    } else {
+        (
+            record.attacker.clone(),
+            !record.attacker_won,
+            -record.rating_change,
+        )
+    }
+}
+
+/// Replay of the match `match_id`, stepped through one attack at a time.
+#[component]
+fn ArenaReplay(match_id: i64, on_close: EventHandler<()>) -> Element {
+    let mut steps: Signal<Vec<Vec<LogEntry>>> = use_signal(Vec::new);
+    let mut step = use_signal(|| 0usize);
+    let mut error_msg = use_signal(String::new);
+
+    use_effect(move || {
+        spawn(async move {
+            match get_arena_replay(match_id).await {
+                Ok(log) => {
+                    steps.set(replay_steps(&log));
+                    step.set(0);
+                }
+                Err(e) => error_msg.set(t!(
+                    "arena-fetch-error",
+                    error : server_messages::localize_error(&e)
+                )),
+            }
+        });
+    });
+
+    let all_steps = steps();
+    let last = all_steps.len().saturating_sub(1);
+    let current = step().min(last);
+
+    rsx! {
+        div { class: "arena-replay",
+            div { class: "arena-replay-controls",
+                Button {
+                    variant: ButtonVariant::Secondary,
+                    disabled: current == 0,
+                    onclick: move |_| step.set(current.saturating_sub(1)),
+                    "◀"
+                }
+                span { class: "settings-hint",
+                    {t!("arena-replay-step", step : current as i64 + 1, total : all_steps.len() as i64)}
+                }
+                Button {
+                    variant: ButtonVariant::Secondary,
+                    disabled: current >= last,
+                    onclick: move |_| step.set(current + 1),
+                    "▶"
+                }
+                Button {
+                    variant: ButtonVariant::Ghost,
+                    onclick: move |_| step.set(last),
+                    {t!("arena-replay-end")}
+                }
+                Button {
+                    variant: ButtonVariant::Ghost,
+                    onclick: move |_| on_close.call(()),
+                    {t!("common-close")}
+                }
+            }
+            if !error_msg().is_empty() {
+                p { class: "admin-answer-error", "{error_msg}" }
+            }
+            div { class: "arena-replay-log", role: "log",
+                // Earlier attacks stay shown, the current one stands out
+                for (i , entries) in all_steps.into_iter().take(current + 1).enumerate() {
+                    div { class: if i == current { "arena-replay-step arena-replay-step--current" } else { "arena-replay-step" },
+                        for entry in entries {
+                            div {
+                                class: if entry.kind.is_marker() { "log-marker" } else { "arena-replay-line" },
+                                dangerous_inner_html: entry.text().replace('\n', "<br/>"),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Arena of the signed-in player: the party they registered from one of their
+/// saves, matchmaking against another player's party, the standings and the
+/// history of their matches with their replays.
+#[component]
+pub fn ArenaPage() -> Element {
+    let socket = use_context::<UseWebsocket<ClientEvent, ServerEvent, CborEncoding>>();
+    let local_login_name_session = use_context::<Signal<String>>();
+    let mut server_data = use_context::<Signal<ServerData>>();
+    let navigator = use_navigator();
+
+    let mut overview: Signal<ArenaOverview> = use_signal(ArenaOverview::default);
+    let mut slots: Signal<Vec<SaveSlotInfo>> = use_signal(Vec::new);
+    let mut selected_slot = use_signal(String::new);
+    let mut replay: Signal<Option<i64>> = use_signal(|| None);
+    let mut loading = use_signal(|| true);
+    let mut message = use_signal(String::new);
+
+    use_effect(move || {
+        let player_name = local_login_name_session();
+        spawn(async move {
+            match get_arena_overview().await {
+                Ok(o) => overview.set(o),
+                Err(e) => message.set(t!(
+                    "arena-fetch-error",
+                    error : server_messages::localize_error(&e)
+                )),
+            }
+            if let Ok(s) = get_save_slots(player_name).await {
+                let s: Vec<SaveSlotInfo> = s.into_iter().filter(|s| !s.name.is_empty()).collect();
+                if let Some(first) = s.first() {
+                    selected_slot.set(first.name.clone());
+                }
+                slots.set(s);
+            }
+            loading.set(false);
+        });
+    });
+
+    let me = local_login_name_session();
+    let current = overview();
+    // The standings hold the party of the player too
+    let can_fight = current.party.is_some() && current.standings.len() > 1;
+
+    rsx! {
+        div { class: "home-container",
+            h2 { class: "rpg-title", {t!("arena-title")} }
+            p { class: "settings-hint", {t!("arena-hint")} }
+
+            if !message().is_empty() {
+                p { class: "settings-hint", "{message}" }
+            }
+
+            div { class: "arena-card",
+                if let Some(party) = &current.party {
+                    div { class: "arena-party",
+                        span { class: "arena-rating", {t!("arena-rating", rating : party.rating)} }
+                        span { class: "settings-hint",
+                            {t!("arena-record", wins : party.wins, losses : party.losses)}
+                        }
+                        span { "{party.heroes}" }
+                        span { class: "settings-hint",
+                            {t!("arena-registered-at", universe : party.universe.clone(), date : party.updated_at.clone())}
+                        }
+                    }
+                } else if !loading() {
+                    p { class: "rpg-no-data", {t!("arena-no-party")} }
+                }
+
+                if slots().is_empty() {
+                    p { class: "settings-hint", {t!("arena-no-save")} }
+                } else {
+                    div { class: "arena-register",
+                        select {
+                            class: "admin-select",
+                            aria_label: t!("arena-save-slot"),
+                            onchange: move |e| selected_slot.set(e.value()),
+                            for slot in slots() {
+                                option {
+                                    value: "{slot.name}",
+                                    selected: slot.name == selected_slot(),
+                                    "{slot.name}"
+                                }
+                            }
+                        }
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            onclick: move |_| async move {
+                                match register_arena_party(selected_slot()).await {
+                                    Ok(party) => {
+                                        overview.write().party = Some(party);
+                                        message.set(t!("arena-registered"));
+                                        if let Ok(o) = get_arena_overview().await {
+                                            overview.set(o);
+                                        }
+                                    }
+                                    Err(e) => message.set(t!(
+                                        "arena-register-error",
+                                        error : server_messages::localize_error(&e)
+                                    )),
+                                }
+                            },
+                            if current.party.is_some() {
+                                {t!("arena-register-again")}
+                            } else {
+                                {t!("arena-register")}
+                            }
+                        }
+                    }
+                }
+
+                Button {
+                    variant: if can_fight { ButtonVariant::Primary } else { ButtonVariant::Secondary },
+                    disabled: !can_fight,
+                    onclick: move |_| async move {
+                        server_data.write().core_game_data.game_phase = GamePhase::Default;
+                        send_initialize_game(&local_login_name_session(), "", true, GameMode::Arena, socket).await;
+                        navigator.push(Route::LobbyPage {});
+                    },
+                    {t!("arena-find-opponent")}
+                }
+                if current.party.is_some() && !can_fight {
+                    span { class: "settings-hint", {t!("arena-no-opponent")} }
+                }
+            }
+
+            h3 { class: "arena-section-title", {t!("arena-standings")} }
+            if current.standings.is_empty() {
+                p { class: "rpg-no-data", {t!("arena-standings-empty")} }
+            } else {
+                table { class: "admin-table arena-table",
+                    thead {
+                        tr {
+                            th { "#" }
+                            th { {t!("leaderboard-col-players")} }
+                            th { class: "col-description", {t!("leaderboard-col-heroes")} }
+                            th { {t!("arena-col-rating")} }
+                            th { {t!("arena-col-record")} }
+                        }
+                    }
+                    tbody {
+                        for (rank , party) in current.standings.iter().enumerate() {
+                            tr { class: if party.username == me { "arena-row-me" } else { "" },
+                                td { class: "leaderboard-rank", "{rank + 1}" }
+                                td { "{party.username}" }
+                                td { class: "col-description", "{party.heroes}" }
+                                td { "{party.rating}" }
+                                td { "{party.wins} - {party.losses}" }
+                            }
+                        }
+                    }
+                }
+            }
+
+            h3 { class: "arena-section-title", {t!("arena-history")} }
+            if current.history.is_empty() {
+                p { class: "rpg-no-data", {t!("arena-history-empty")} }
+            } else {
+                table { class: "admin-table arena-table",
+                    thead {
+                        tr {
+                            th { {t!("leaderboard-col-date")} }
+                            th { {t!("arena-col-opponent")} }
+                            th { {t!("arena-col-result")} }
+                            th { {t!("arena-col-change")} }
+                            th { {t!("leaderboard-col-turns")} }
+                            th {}
+                        }
+                    }
+                    tbody {
+                        for record in current.history.iter() {
+                            {
+                                let (opponent, won, change) = match_outcome(record, &me);
+                                let match_id = record.id;
+                                let role = if record.attacker == me {
+                                    t!("arena-role-attacker")
+                                } else {
+                                    t!("arena-role-defender")
+                                };
+                                rsx! {
+                                    tr { key: "{match_id}",
+                                        td { "{record.played_at}" }
+                                        td { "{opponent} ({role})" }
+                                        td { class: if won { "arena-won" } else { "arena-lost" },
+                                            if won {
+                                                {t!("arena-won")}
+                                            } else {
+                                                {t!("arena-lost")}
+                                            }
+                                        }
+                                        td { "{change:+}" }
+                                        td { "{record.turns}" }
+                                        td {
+                                            Button {
+                                                variant: ButtonVariant::Ghost,
+                                                onclick: move |_| replay.set(Some(match_id)),
+                                                {t!("arena-replay")}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(match_id) = replay() {
+                ArenaReplay {
+                    key: "{match_id}",
+                    match_id,
+                    on_close: move |_| replay.set(None),
+                }
+            }
+        }
+    }
+}
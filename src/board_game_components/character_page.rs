@@ -1,4 +1,3 @@
-use colorgrad::Gradient;
 use dioxus::{
     fullstack::{CborEncoding, UseWebsocket},
     prelude::*,
@@ -19,14 +18,17 @@ use lib_rpg::{
 };
 
 use crate::{
+    accessibility::bar_color,
     auth_manager::server_fn::{get_user_setting, save_user_setting},
     common::photo_src,
     components::button::{Button, ButtonVariant},
 };
 use crate::{
+    combat_animation::CueKind,
     common::{
-        CtxAppLang, CtxAtkPanelOrders, CtxShowAtkTooltips, CtxShowBossEnergy, CtxShowBossHp,
-        CtxShowHeroAggro, CtxToggleAtkAnimation, ENERGY_GRAD, SERVER_NAME, lang_from_app_lang,
+        CtxA11y, CtxAppLang, CtxAtkPanelOrders, CtxCombatAnimation, CtxShowAtkTooltips,
+        CtxShowBossEnergy, CtxShowBossHp, CtxShowHeroAggro, CtxToggleAtkAnimation, SERVER_NAME,
+        lang_from_app_lang,
     },
     components::{
         drag_and_drop_list::{DragAndDropList, use_drag_and_drop_list_order},
@@ -64,6 +66,26 @@ fn process_css_class_on_atk(last_atk: &ResultLaunchAttack, id_name: &str) -> &'s
     ""
 }
 
+/// Text of a combat animation cue on a portrait.
+fn cue_text(kind: &CueKind) -> String {
+    match kind {
+        CueKind::Damage {
+            amount,
+            critical: false,
+        } => format!("-{amount}"),
+        CueKind::Damage {
+            amount,
+            critical: true,
+        } => format!("-{amount}!"),
+        CueKind::Heal { amount } => format!("+{amount}"),
+        CueKind::Dodge => t!("character-page-fx-dodge"),
+        CueKind::Block => t!("character-page-fx-block"),
+        CueKind::Buff => "▲".to_owned(),
+        CueKind::Debuff => "▼".to_owned(),
+        CueKind::Death => "☠".to_owned(),
+    }
+}
+
 #[component]
 pub fn CharacterPanel(
     c: Character,
@@ -79,6 +101,8 @@ pub fn CharacterPanel(
     let server_data = use_context::<Signal<ServerData>>();
     let local_session_player_name = use_context::<Signal<String>>();
     let toggle_atk_animation = use_context::<CtxToggleAtkAnimation>().0;
+    let reduced_motion = use_context::<CtxA11y>().reduced_motion;
+    let board_animation = use_context::<CtxCombatAnimation>().0;
     let show_boss_energy = use_context::<CtxShowBossEnergy>().0;
     let show_hero_aggro = use_context::<CtxShowHeroAggro>().0;
     let show_boss_hp = use_context::<CtxShowBossHp>().0;
@@ -111,8 +135,16 @@ pub fn CharacterPanel(
             }
         }
     };
-    // if boss is dead, panel is hidden
-    if c.stats.is_dead().is_some_and(|value| value) && c.kind == CharacterKind::Boss {
+    // Cues of the attack animation on this portrait, hidden while the server resets
+    // the animations (see `SetAtkAnimation`) and with reduced motion.
+    let animation = if toggle_atk_animation() || reduced_motion() {
+        None
+    } else {
+        board_animation()
+    };
+    let is_dying = animation.as_ref().is_some_and(|a| a.is_dying(&c.id_name));
+    // if boss is dead, panel is hidden (once its death fade is over)
+    if c.stats.is_dead().is_some_and(|value| value) && c.kind == CharacterKind::Boss && !is_dying {
         return rsx! {};
     }
     let bg = if c.kind == CharacterKind::Hero {
@@ -147,7 +179,7 @@ pub fn CharacterPanel(
             .last_result_atk,
         &c.id_name,
     );
-    if toggle_atk_animation() {
+    if toggle_atk_animation() || reduced_motion() {
         class_css = "";
     }
 
@@ -164,7 +196,10 @@ pub fn CharacterPanel(
     };
 
     rsx! {
-        div { class: class_css, position: "relative",
+        div {
+            class: class_css,
+            position: "relative",
+            "data-char-id": "{c.id_name}",
             CharacterTooltip {
                 hots_bufs: CharacterRoundsInfo::get_hot_and_buf_nbs_txts(
                     &c.character_rounds_info.all_effects,
@@ -174,6 +209,9 @@ pub fn CharacterPanel(
             }
             div {
                 class: "character",
+                role: "group",
+                aria_label: "{c.db_full_name}",
+                aria_current: if is_active_player { "true" } else { "false" },
                 background_color: bg,
                 border: panel_border,
                 box_shadow: panel_box_shadow,
@@ -207,6 +245,8 @@ pub fn CharacterPanel(
                         Button {
                             variant: ButtonVariant::AtkMenu,
                             disabled: current_character != c.id_name,
+                            aria_label: t!("a11y-attack-menu"),
+                            aria_expanded: atk_menu_display(),
                             onclick: move |_| async move {
                                 atk_menu_display.set(!atk_menu_display());
                                 potion_menu_display.set(false);
@@ -228,6 +268,8 @@ pub fn CharacterPanel(
                         {
                             Button {
                                 variant: ButtonVariant::AtkMenu,
+                                aria_label: t!("a11y-potion-menu"),
+                                aria_expanded: potion_menu_display(),
                                 onclick: move |_| async move {
                                     potion_menu_display.set(!potion_menu_display());
                                     atk_menu_display.set(false);
@@ -280,6 +322,18 @@ pub fn CharacterPanel(
                     selected_consumable_target,
                 }
             }
+            if let Some(anim) = animation.as_ref() {
+                div { class: "fx-layer", aria_hidden: "true",
+                    for (i , cue) in anim.cues_for(&c.id_name).enumerate() {
+                        div {
+                            key: "{anim.key.0}-{anim.key.1}-{anim.key.2}-{i}",
+                            class: cue.kind.class(),
+                            style: "animation-delay: {cue.delay_ms}ms;",
+                            {cue_text(&cue.kind)}
+                        }
+                    }
+                }
+            }
         }
     }
 }
@@ -354,6 +408,8 @@ pub fn CharacterTargetButton(
             Button {
                 variant: ButtonVariant::Primary,
                 class: format!("{}-target-button-active", kind_str),
+                aria_label: t!("a11y-target", name : c.db_full_name.clone()),
+                aria_pressed: "true",
                 onclick,
                 ""
             }
@@ -361,6 +417,8 @@ pub fn CharacterTargetButton(
             Button {
                 variant: ButtonVariant::Primary,
                 class: format!("{}-target-button", kind_str),
+                aria_label: t!("a11y-target", name : c.db_full_name.clone()),
+                aria_pressed: "false",
                 onclick,
                 ""
             }
@@ -370,24 +428,55 @@ pub fn CharacterTargetButton(
 
 #[component]
 pub fn BarComponent(max: u64, current: u64, name: String) -> Element {
+    let palette = use_context::<CtxA11y>().palette;
     let width_display = (current * 100).checked_div(max).unwrap_or(0);
     rsx! {
-        div { class: "bar-row",
-            div { class: "bar-header",
+        div {
+            class: "bar-row",
+            role: "meter",
+            aria_label: "{name}",
+            aria_valuemin: "0",
+            aria_valuemax: "{max}",
+            aria_valuenow: "{current}",
+            aria_valuetext: t!(
+                "a11y-bar-value", current : current as i64, max : max as i64, percent : width_display as i64
+            ),
+            div { class: "bar-header", aria_hidden: "true",
                 span { class: "bar-name", "{name}" }
                 span { class: "bar-value", "{current}/{max}" }
             }
-            div { class: "container-bar",
+            div { class: "container-bar", aria_hidden: "true",
                 div {
                     class: "life-bar",
                     width: "{width_display}%",
-                    background_color: get_color(width_display as i32),
+                    background_color: bar_color(palette(), width_display as i32),
                 }
             }
         }
     }
 }
 
+/// Selects `atk_name` for the launcher and asks the server for its targets.
+/// Shared by the attack buttons and the number-key hotkeys of `GameBoard`.
+pub(crate) async fn select_attack(
+    socket: UseWebsocket<ClientEvent, ServerEvent, CborEncoding>,
+    launcher_id_name: String,
+    atk_name: String,
+    mut selected_atk_name: Signal<String>,
+    mut display_atklist_sig: Signal<bool>,
+) {
+    selected_atk_name.set(atk_name.clone());
+    display_atklist_sig.set(false);
+    let _ = socket
+        .send(ClientEvent::RequestTargetedCharacter(
+            SERVER_NAME(),
+            launcher_id_name.clone(),
+            atk_name.clone(),
+        ))
+        .await;
+    tracing::info!("set_targeted_characters {launcher_id_name} for atk {atk_name}");
+}
+
 #[component]
 pub fn NewAtkButton(
     attack_type: AttackType,
@@ -420,25 +509,13 @@ pub fn NewAtkButton(
                 Button {
                     variant: if can_be_launched { ButtonVariant::AtkName } else { ButtonVariant::AtkNameBlocked },
                     onclick: move |_| {
-                        let async_atk_name = attack_name.clone();
-                        let async_launcher_name = launcher_id_name.clone();
-                        async move {
-                            selected_atk_name.set(async_atk_name.clone());
-                            *display_atklist_sig.write() = false;
-                            let _ = socket
-                                .send(
-                                    ClientEvent::RequestTargetedCharacter(
-                                        SERVER_NAME(),
-                                        async_launcher_name.clone(),
-                                        async_atk_name.clone(),
-                                    ),
-                                )
-                                .await;
-                            tracing::info!(
-                                "set_targeted_characters {} for atk {}", async_launcher_name.clone(),
-                                async_atk_name.clone()
-                            );
-                        }
+                        select_attack(
+                            socket,
+                            launcher_id_name.clone(),
+                            attack_name.clone(),
+                            selected_atk_name,
+                            display_atklist_sig,
+                        )
                     },
                     disabled: !can_be_launched,
                     "{display_name}"
@@ -503,6 +580,32 @@ fn apply_custom_order<'a>(attacks: &[&'a AttackType], order: &[String]) -> Vec<&
     ordered
 }
 
+/// Attacks of `c` unlocked at its level, in the player's saved panel order —
+/// the order the number-key hotkeys follow.
+pub(crate) fn panel_attacks<'a>(c: &'a Character, order: &[String]) -> Vec<&'a AttackType> {
+    let unlocked: Vec<&AttackType> = c
+        .attacks_list
+        .values()
+        .filter(|value| c.level >= value.level)
+        .collect();
+    apply_custom_order(&unlocked, order)
+}
+
+/// Loads a character's saved attack panel order into `CtxAtkPanelOrders`, once.
+pub(crate) async fn load_atk_panel_order(
+    mut atk_panel_orders: Signal<std::collections::HashMap<String, Vec<String>>>,
+    id_name: String,
+) {
+    if atk_panel_orders.peek().contains_key(&id_name) {
+        return;
+    }
+    let key = atk_panel_order_key(&SERVER_NAME(), &id_name);
+    if let Ok(raw) = get_user_setting(key, String::new()).await {
+        let order = parse_order(&raw);
+        atk_panel_orders.write().insert(id_name, order);
+    }
+}
+
 /// Ephemeral, display-only sort applied on top of the saved custom order —
 /// never persisted (see `AttackList`/`AttackPanelConfig`).
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
@@ -540,7 +643,7 @@ pub fn AttackList(
 ) -> Element {
     // contexts
     let server_data = use_context::<Signal<ServerData>>();
-    let mut atk_panel_orders = use_context::<CtxAtkPanelOrders>().0;
+    let atk_panel_orders = use_context::<CtxAtkPanelOrders>().0;
     let mut sort_mode = use_signal(AtkSortMode::default);
     let mut config_open = use_signal(|| false);
 
@@ -552,14 +655,7 @@ pub fn AttackList(
             if atk_panel_orders.read().contains_key(&id_name) {
                 return;
             }
-            let server_name = SERVER_NAME();
-            spawn(async move {
-                let key = atk_panel_order_key(&server_name, &id_name);
-                if let Ok(raw) = get_user_setting(key, String::new()).await {
-                    let order = parse_order(&raw);
-                    atk_panel_orders.write().insert(id_name, order);
-                }
-            });
+            spawn(load_atk_panel_order(atk_panel_orders, id_name));
         }
     });
 
@@ -569,19 +665,14 @@ pub fn AttackList(
         .pm
         .get_active_character(&id_name)
     {
-        let filtered: Vec<&AttackType> = c
-            .attacks_list
-            .values()
-            .filter(|value| c.level >= value.level)
-            .collect();
         let custom_order = atk_panel_orders
             .read()
             .get(&id_name)
             .cloned()
             .unwrap_or_default();
-        let ordered = apply_custom_order(&filtered, &custom_order);
+        let ordered = panel_attacks(&c, &custom_order);
+        let config_attacks: Vec<AttackType> = panel_attacks(&c, &[]).into_iter().cloned().collect();
         let displayed = sort_attacks(ordered, sort_mode());
-        let config_attacks: Vec<AttackType> = filtered.iter().map(|a| (*a).clone()).collect();
 
         rsx! {
             div { class: "attack-list",
@@ -843,10 +934,6 @@ fn AttackPanelConfig(id_name: String, attacks: Vec<AttackType>, open: Signal<boo
     }
 }
 
-fn get_color(value: i32) -> String {
-    ENERGY_GRAD.at(value as f32 / 100.0).to_css_hex()
-}
-
 /// Groups a (possibly repeated) list of item names into display order + per-name counts,
 /// e.g. `["Potion", "Potion", "Ether"]` -> `(["Potion", "Ether"], {"Potion": 2, "Ether": 1})`.
 pub(crate) fn group_by_name(
@@ -864,24 +951,11 @@ pub(crate) fn group_by_name(
     (seen_order, counts)
 }
 
-#[component]
-pub fn PotionList(
-    id_name: String,
-    display_potionlist_sig: Signal<bool>,
-    selected_consumable: Signal<String>,
-) -> Element {
-    let socket = use_context::<UseWebsocket<ClientEvent, ServerEvent, CborEncoding>>();
-    let server_data = use_context::<Signal<ServerData>>();
-    let local_session_player_name = use_context::<Signal<String>>();
-
-    let snap = server_data();
-
-    // Personal potions
-    let potions: Vec<String> = snap
-        .core_game_data
-        .game_manager
-        .pm
-        .get_active_character(&id_name)
+/// Personal potions of `id_name` and the shared party consumables, as repeated names.
+fn combat_consumables(snap: &ServerData, id_name: &str) -> (Vec<String>, Vec<String>) {
+    let pm = &snap.core_game_data.game_manager.pm;
+    let potions = pm
+        .get_active_character(id_name)
         .map(|c| {
             c.inventory
                 .consumables
@@ -891,17 +965,65 @@ pub fn PotionList(
                 .collect()
         })
         .unwrap_or_default();
-
-    // Party bag potions
-    let party_potions: Vec<String> = snap
-        .core_game_data
-        .game_manager
-        .pm
+    let party_potions = pm
         .party_consumables
         .iter()
         .map(|c| c.name.clone())
         .collect();
+    (potions, party_potions)
+}
+
+/// Consumables as listed by `PotionList` ("personal:{name}" then "party:{name}"),
+/// the order the number-key hotkeys follow.
+pub(crate) fn consumable_slots(snap: &ServerData, id_name: &str) -> Vec<String> {
+    let (potions, party_potions) = combat_consumables(snap, id_name);
+    let personal = group_by_name(&potions)
+        .0
+        .into_iter()
+        .map(|n| format!("personal:{n}"));
+    let party = group_by_name(&party_potions)
+        .0
+        .into_iter()
+        .map(|n| format!("party:{n}"));
+    personal.chain(party).collect()
+}
+
+/// Selects a consumable slot ("personal:{name}" or "party:{name}") and asks the
+/// server for its targets. Shared by `PotionList` and the `GameBoard` hotkeys.
+pub(crate) async fn select_consumable(
+    socket: UseWebsocket<ClientEvent, ServerEvent, CborEncoding>,
+    player_name: String,
+    slot: String,
+    mut selected_consumable: Signal<String>,
+    mut display_potionlist_sig: Signal<bool>,
+) {
+    let (is_party, name) = match slot.strip_prefix("party:") {
+        Some(name) => (true, name.to_owned()),
+        None => (false, slot.trim_start_matches("personal:").to_owned()),
+    };
+    let _ = socket
+        .send(ClientEvent::RequestTargetForConsumable(
+            SERVER_NAME(),
+            player_name,
+            name,
+            is_party,
+        ))
+        .await;
+    selected_consumable.set(slot);
+    display_potionlist_sig.set(false);
+}
+
+#[component]
+pub fn PotionList(
+    id_name: String,
+    display_potionlist_sig: Signal<bool>,
+    selected_consumable: Signal<String>,
+) -> Element {
+    let socket = use_context::<UseWebsocket<ClientEvent, ServerEvent, CborEncoding>>();
+    let server_data = use_context::<Signal<ServerData>>();
+    let local_session_player_name = use_context::<Signal<String>>();
 
+    let (potions, party_potions) = combat_consumables(&server_data(), &id_name);
     let (personal_order, personal_counts) = group_by_name(&potions);
     let (party_order, party_counts) = group_by_name(&party_potions);
 
@@ -933,25 +1055,15 @@ pub fn PotionList(
                             Button {
                                 variant: ButtonVariant::AtkName,
                                 onclick: {
-                                    let pname = potion_name.clone();
-                                    let player = local_session_player_name();
+                                    let slot = format!("personal:{potion_name}");
                                     move |_| {
-                                        let async_potion = pname.clone();
-                                        let async_player = player.clone();
-                                        async move {
-                                            let _ = socket
-                                                .send(
-                                                    ClientEvent::RequestTargetForConsumable(
-                                                        SERVER_NAME(),
-                                                        async_player,
-                                                        async_potion.clone(),
-                                                        false,
-                                                    ),
-                                                )
-                                                .await;
-                                            selected_consumable.set(format!("personal:{}", async_potion));
-                                            display_potionlist_sig.set(false);
-                                        }
+                                        select_consumable(
+                                            socket,
+                                            local_session_player_name(),
+                                            slot.clone(),
+                                            selected_consumable,
+                                            display_potionlist_sig,
+                                        )
                                     }
                                 },
                                 "{label}"
@@ -975,25 +1087,15 @@ pub fn PotionList(
                             Button {
                                 variant: ButtonVariant::AtkName,
                                 onclick: {
-                                    let pname = potion_name.clone();
-                                    let player = local_session_player_name();
+                                    let slot = format!("party:{potion_name}");
                                     move |_| {
-                                        let async_potion = pname.clone();
-                                        let async_player = player.clone();
-                                        async move {
-                                            let _ = socket
-                                                .send(
-                                                    ClientEvent::RequestTargetForConsumable(
-                                                        SERVER_NAME(),
-                                                        async_player,
-                                                        async_potion.clone(),
-                                                        true,
-                                                    ),
-                                                )
-                                                .await;
-                                            selected_consumable.set(format!("party:{}", async_potion));
-                                            display_potionlist_sig.set(false);
-                                        }
+                                        select_consumable(
+                                            socket,
+                                            local_session_player_name(),
+                                            slot.clone(),
+                                            selected_consumable,
+                                            display_potionlist_sig,
+                                        )
                                     }
                                 },
                                 "{label}"
@@ -41,8 +41,10 @@ fn strip_id_suffix(id_name: &str) -> &str {
     id_name.split("_#").next().unwrap_or(id_name)
 }
 
+/// `allowed_heroes` restricts the heroes on offer (a daily challenge); empty
+/// offers every hero of `universe`.
 #[component]
-pub fn CharacterSelect(universe: String) -> Element {
+pub fn CharacterSelect(universe: String, #[props(default)] allowed_heroes: Vec<String>) -> Element {
     let server_data = use_context::<Signal<ServerData>>();
     let local_login_name_session = use_context::<Signal<String>>();
 
@@ -89,6 +91,7 @@ pub fn CharacterSelect(universe: String) -> Element {
                     player_name: local_name.clone(),
                     is_single_player: is_single,
                     universe: universe.clone(),
+                    allowed_heroes,
                 }
             } else {
                 div { class: "char-select-chosen-list",
@@ -125,7 +128,12 @@ pub fn CharacterSelect(universe: String) -> Element {
 }
 
 #[component]
-pub fn CharacterCardGrid(player_name: String, is_single_player: bool, universe: String) -> Element {
+pub fn CharacterCardGrid(
+    player_name: String,
+    is_single_player: bool,
+    universe: String,
+    #[props(default)] allowed_heroes: Vec<String>,
+) -> Element {
     let server_data = use_context::<Signal<ServerData>>();
     let all_characters = use_context::<Signal<Vec<Character>>>();
 
@@ -133,6 +141,7 @@ pub fn CharacterCardGrid(player_name: String, is_single_player: bool, universe:
         .into_iter()
         .filter(|c| c.kind == lib_rpg::character_mod::character::CharacterKind::Hero)
         .filter(|c| universe.is_empty() || c.universe == universe)
+        .filter(|c| allowed_heroes.is_empty() || allowed_heroes.contains(&c.db_full_name))
         .collect();
 
     // Full-detail side panel: opened via each card's "Details" button, independent
@@ -0,0 +1,105 @@
+use dioxus::{
+    fullstack::{CborEncoding, UseWebsocket},
+    prelude::*,
+};
+use dioxus_i18n::t;
+
+use crate::{
+    auth_manager::server_fn::get_recipes,
+    common::{CtxMaterials, SERVER_NAME},
+    components::button::{Button, ButtonVariant},
+    crafting::CraftedKind,
+    websocket_handler::event::{ClientEvent, ServerEvent},
+};
+
+/// Crafting tab of the store: the materials of the party, and the recipes
+/// `character_id_name` can make with them and `gold`.
+#[component]
+pub fn CraftingPanel(character_id_name: String, gold: u64) -> Element {
+    let socket = use_context::<UseWebsocket<ClientEvent, ServerEvent, CborEncoding>>();
+    let materials = use_context::<CtxMaterials>().0;
+    let recipes_resource = use_resource(get_recipes);
+    let recipes = recipes_resource
+        .read()
+        .as_ref()
+        .and_then(|r| r.as_ref().ok())
+        .cloned()
+        .unwrap_or_default();
+    let stash = materials();
+
+    rsx! {
+        div { class: "crafting-panel",
+            div { class: "crafting-materials",
+                span { class: "crafting-section-title", {t!("crafting-materials")} }
+                if stash.is_empty() {
+                    span { class: "settings-hint", {t!("crafting-no-materials")} }
+                }
+                for (material , amount) in stash.iter() {
+                    span { class: "crafting-material-chip", "⛏️ {material} ×{amount}" }
+                }
+            }
+            if recipes.is_empty() {
+                p { class: "settings-hint", {t!("crafting-no-recipes")} }
+            }
+            for entry in recipes {
+                {
+                    let recipe = entry.recipe;
+                    let missing = recipe.missing_materials(&stash);
+                    let can_craft = missing.is_empty() && gold >= recipe.gold;
+                    let icon = match recipe.kind {
+                        CraftedKind::Equipment => "🛡️",
+                        CraftedKind::Consumable => "🧪",
+                    };
+                    let cid = character_id_name.clone();
+                    let stem = entry.file_stem;
+                    rsx! {
+                        div { class: "crafting-recipe",
+                            div { class: "crafting-recipe-header",
+                                span { class: "crafting-recipe-name", "{recipe.name}" }
+                                span { class: "crafting-recipe-output", "{icon} {recipe.output}" }
+                            }
+                            div { class: "crafting-ingredients",
+                                for ingredient in recipe.materials.iter() {
+                                    {
+                                        let owned = stash.get(&ingredient.material).copied().unwrap_or(0);
+                                        rsx! {
+                                            span {
+                                                class: if owned >= ingredient.amount { "crafting-material-chip" } else { "crafting-material-chip crafting-material-chip--missing" },
+                                                "{ingredient.material} {owned}/{ingredient.amount}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            div { class: "crafting-recipe-footer",
+                                span { class: "crafting-recipe-gold",
+                                    {t!("gs-gold-amount", amount : recipe.gold as i64)}
+                                }
+                                Button {
+                                    variant: if can_craft { ButtonVariant::Primary } else { ButtonVariant::Secondary },
+                                    disabled: !can_craft,
+                                    onclick: move |_| {
+                                        let cid = cid.clone();
+                                        let stem = stem.clone();
+                                        async move {
+                                            let _ = socket
+                                                .send(ClientEvent::CraftItem(SERVER_NAME(), cid, stem))
+                                                .await;
+                                        }
+                                    },
+                                    if can_craft {
+                                        {t!("crafting-craft")}
+                                    } else if missing.is_empty() {
+                                        {t!("gs-store-no-gold")}
+                                    } else {
+                                        {t!("crafting-missing-materials")}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -5,11 +5,12 @@ use dioxus::{
 use dioxus_i18n::t;
 
 use crate::{
+    board_game_components::difficulty_select::settings_badge,
     common::Route,
     components::button::{Button, ButtonVariant},
     utils::server_file_utils::{SaveSlotInfo, delete_game, get_save_slots},
     websocket_handler::{
-        event::{ClientEvent, ServerEvent},
+        event::{ClientEvent, GameMode, ServerEvent},
         msg_from_client::{request_update_saved_game_list_display, send_initialize_game},
     },
 };
@@ -50,7 +51,7 @@ pub fn CreateServer() -> Element {
                 let _ = delete_game(s.path).await;
             }
             // Universe will be chosen in the lobby; pass empty string to load all universes
-            send_initialize_game(&user_name, "", single, socket).await;
+            send_initialize_game(&user_name, "", single, GameMode::Story, socket).await;
             navigator.push(Route::LobbyPage {});
         }
     };
@@ -138,6 +139,9 @@ pub fn CreateServer() -> Element {
                                                 {t!("loadgame-universe", universe : slot.universe.clone())}
                                             }
                                         }
+                                        if !settings_badge(&slot.settings).is_empty() {
+                                            span { class: "save-slot-difficulty", "⚔️ {settings_badge(&slot.settings)}" }
+                                        }
                                     }
                                 }
                                 if selected_slot() == Some(idx) {
@@ -155,7 +159,7 @@ pub fn CreateServer() -> Element {
                                                         if !p.as_os_str().is_empty() {
                                                             let _ = delete_game(p).await;
                                                         }
-                                                        send_initialize_game(&u, "", single, socket).await;
+                                                        send_initialize_game(&u, "", single, GameMode::Story, socket).await;
                                                         navigator.push(Route::LobbyPage {});
                                                     }
                                                 }
@@ -186,6 +190,22 @@ pub fn CreateServer() -> Element {
                     }
                     p { class: "action-desc", {t!("create-server-load-game-desc")} }
                 }
+                div {
+                    class: "action-card",
+                    onclick: move |_| {
+                        let user_name = local_login_name_session();
+                        let single = is_single_player();
+                        async move {
+                            // Endless runs have no save: no slot to pick
+                            send_initialize_game(&user_name, "", single, GameMode::Endless, socket)
+                                .await;
+                            navigator.push(Route::LobbyPage {});
+                        }
+                    },
+                    span { class: "action-icon", "♾️" }
+                    span { class: "header-text", {t!("create-server-endless")} }
+                    p { class: "action-desc", {t!("create-server-endless-desc")} }
+                }
             }
         }
     }
@@ -0,0 +1,138 @@
+use dioxus::{
+    fullstack::{CborEncoding, UseWebsocket},
+    prelude::*,
+};
+use dioxus_i18n::t;
+use lib_rpg::server::server_manager::{GamePhase, ServerData};
+
+use crate::{
+    auth_manager::server_fn::{DailyChallengeOverview, get_daily_challenge},
+    common::Route,
+    components::button::{Button, ButtonVariant},
+    daily_challenge::{ChallengeModifier, DailyChallenge},
+    websocket_handler::{
+        event::{ClientEvent, GameMode, ServerEvent},
+        msg_from_client::send_initialize_game,
+    },
+};
+
+fn modifier_label(modifier: ChallengeModifier) -> String {
+    match modifier {
+        ChallengeModifier::NoPotions => t!("daily-modifier-no-potions"),
+        ChallengeModifier::NoTalents => t!("daily-modifier-no-talents"),
+        ChallengeModifier::TurnLimit(turns) => t!("daily-modifier-turn-limit", turns : turns),
+    }
+}
+
+/// Scenario, heroes and modifiers of a daily challenge, on the Home page card
+/// and in the lobby of the challenge.
+#[component]
+pub fn ChallengeRules(challenge: DailyChallenge) -> Element {
+    rsx! {
+        div { class: "daily-rules",
+            span { class: "daily-scenario", "⚔️ {challenge.scenario} · {challenge.universe}" }
+            span { class: "settings-hint",
+                {t!("daily-heroes", max : challenge.max_heroes, heroes : challenge.heroes.join(", "))}
+            }
+            div { class: "daily-modifiers",
+                for modifier in challenge.modifiers.iter() {
+                    span { class: "daily-modifier", {modifier_label(*modifier)} }
+                }
+                if challenge.modifiers.is_empty() {
+                    span { class: "settings-hint", {t!("daily-no-modifier")} }
+                }
+            }
+            span { class: "settings-hint", {t!("daily-luck")} }
+        }
+    }
+}
+
+/// Home page card: today's challenge, the player's attempt and the day's
+/// leaderboard. The challenge can be started once a day.
+#[component]
+pub fn DailyChallengeCard() -> Element {
+    let socket = use_context::<UseWebsocket<ClientEvent, ServerEvent, CborEncoding>>();
+    let local_login_name_session = use_context::<Signal<String>>();
+    let mut server_data = use_context::<Signal<ServerData>>();
+    let navigator = use_navigator();
+    let mut overview: Signal<Option<DailyChallengeOverview>> = use_signal(|| None);
+
+    use_effect(move || {
+        let username = local_login_name_session();
+        spawn(async move {
+            if let Ok(o) = get_daily_challenge(username).await {
+                overview.set(Some(o));
+            }
+        });
+    });
+
+    let Some(overview) = overview() else {
+        return rsx! {};
+    };
+    let Some(challenge) = overview.challenge else {
+        return rsx! {};
+    };
+
+    rsx! {
+        div { class: "daily-card",
+            div { class: "daily-card-header",
+                span { class: "action-icon", "📅" }
+                div {
+                    h3 { class: "daily-title", {t!("daily-title")} }
+                    span { class: "settings-hint", "{challenge.date}" }
+                }
+            }
+            ChallengeRules { challenge: challenge.clone() }
+
+            if let Some(attempt) = &overview.attempt {
+                if attempt.finished_at.is_none() {
+                    p { class: "daily-result", {t!("daily-in-progress")} }
+                } else if attempt.won {
+                    p { class: "daily-result daily-result--won",
+                        {t!("daily-result-won", turns : attempt.turns, damage : attempt.damage, rank : overview.rank.unwrap_or_default())}
+                    }
+                } else {
+                    p { class: "daily-result", {t!("daily-result-lost")} }
+                }
+            } else {
+                Button {
+                    variant: ButtonVariant::Primary,
+                    onclick: move |_| async move {
+                        server_data.write().core_game_data.game_phase = GamePhase::Default;
+                        send_initialize_game(&local_login_name_session(), "", true, GameMode::DailyChallenge, socket).await;
+                        navigator.push(Route::LobbyPage {});
+                    },
+                    {t!("daily-play")}
+                }
+            }
+            if let Some((rank, day)) = &overview.best_rank {
+                span { class: "settings-hint", {t!("daily-best-rank", rank : *rank, date : day.clone())} }
+            }
+
+            if !overview.leaderboard.is_empty() {
+                table { class: "admin-table daily-leaderboard",
+                    thead {
+                        tr {
+                            th { "#" }
+                            th { {t!("leaderboard-col-players")} }
+                            th { {t!("leaderboard-col-heroes")} }
+                            th { {t!("leaderboard-col-turns")} }
+                            th { {t!("leaderboard-col-damage")} }
+                        }
+                    }
+                    tbody {
+                        for (rank , result) in overview.leaderboard.iter().enumerate() {
+                            tr { class: if !result.won { "daily-row-lost" } else { "" },
+                                td { class: "leaderboard-rank", "{rank + 1}" }
+                                td { "{result.username}" }
+                                td { "{result.heroes}" }
+                                td { "{result.turns}" }
+                                td { "{result.damage}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use dioxus::{
+    fullstack::{CborEncoding, UseWebsocket},
+    prelude::*,
+};
+use dioxus_i18n::t;
+
+use crate::{
+    common::{CtxGameSettings, SERVER_NAME},
+    difficulty::{Difficulty, GameSettings},
+    websocket_handler::event::{ClientEvent, ServerEvent},
+};
+
+pub(crate) fn difficulty_label(difficulty: Difficulty) -> String {
+    match difficulty {
+        Difficulty::Story => t!("difficulty-story"),
+        Difficulty::Normal => t!("difficulty-normal"),
+        Difficulty::Hard => t!("difficulty-hard"),
+        Difficulty::Nightmare => t!("difficulty-nightmare"),
+    }
+}
+
+fn difficulty_hint(difficulty: Difficulty) -> String {
+    match difficulty {
+        Difficulty::Story => t!("difficulty-story-hint"),
+        Difficulty::Normal => t!("difficulty-normal-hint"),
+        Difficulty::Hard => t!("difficulty-hard-hint"),
+        Difficulty::Nightmare => t!("difficulty-nightmare-hint"),
+    }
+}
+
+/// Difficulty and New Game Plus cycle of `settings`, for save slots and the
+/// game toolbar; empty for a Normal game out of New Game Plus.
+pub(crate) fn settings_badge(settings: &GameSettings) -> String {
+    if settings.is_standard() {
+        return String::new();
+    }
+    let label = difficulty_label(settings.difficulty);
+    if settings.new_game_plus == 0 {
+        label
+    } else {
+        let cycle = t!("difficulty-new-game-plus", cycle : settings.new_game_plus as i64);
+        format!("{label} · {cycle}")
+    }
+}
+
+/// Difficulty of the game, picked in the lobby by its owner. `locked` shows it
+/// read-only, for a game loaded from a save.
+#[component]
+pub fn DifficultySelect(locked: bool) -> Element {
+    let socket = use_context::<UseWebsocket<ClientEvent, ServerEvent, CborEncoding>>();
+    let local_login_name_session = use_context::<Signal<String>>();
+    let settings = use_context::<CtxGameSettings>().settings;
+    let current = settings().difficulty;
+    let locked_label = match settings_badge(&settings()) {
+        badge if badge.is_empty() => difficulty_label(current),
+        badge => badge,
+    };
+    let can_pick = !locked && SERVER_NAME() == local_login_name_session();
+
+    rsx! {
+        div { class: "lobby-universe-select",
+            label { class: "lobby-info-label", {t!("lobby-difficulty-label")} }
+            if can_pick {
+                div { class: "mode-toggle",
+                    for difficulty in Difficulty::ALL {
+                        button {
+                            class: if difficulty == current { "mode-btn mode-btn-active" } else { "mode-btn" },
+                            onclick: move |_| async move {
+                                let _ = socket
+                                    .send(
+                                        ClientEvent::SetDifficulty(
+                                            SERVER_NAME(),
+                                            local_login_name_session(),
+                                            difficulty,
+                                        ),
+                                    )
+                                    .await;
+                            },
+                            {difficulty_label(difficulty)}
+                        }
+                    }
+                }
+            } else {
+                div { class: "lobby-universe-locked", "{locked_label}" }
+            }
+            p { class: "create-server-mode-hint", {difficulty_hint(current)} }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use dioxus::{
+    fullstack::{CborEncoding, UseWebsocket},
+    prelude::*,
+};
+use dioxus_i18n::t;
+use lib_rpg::server::server_manager::ServerData;
+
+use crate::{
+    common::SERVER_NAME,
+    endless::{EndlessReward, EndlessRun},
+    websocket_handler::event::{ClientEvent, ServerEvent},
+};
+
+fn reward_label(reward: &EndlessReward) -> String {
+    match reward {
+        EndlessReward::Equipment(name) => format!("🛡️ {name}"),
+        EndlessReward::Consumable(name) => format!("🧪 {name}"),
+        EndlessReward::TalentPoints(points) => {
+            t!("endless-reward-talent-points", points : *points as i64)
+        }
+    }
+}
+
+/// Rewards offered after a floor of an endless run, in place of the scenario
+/// loots. The host picks one for the whole party, which starts the next floor.
+#[component]
+pub fn EndlessRewardPicker(run: EndlessRun) -> Element {
+    let socket = use_context::<UseWebsocket<ClientEvent, ServerEvent, CborEncoding>>();
+    let server_data = use_context::<Signal<ServerData>>();
+    let local_login_name_session = use_context::<Signal<String>>();
+    let is_owner = server_data().players_data.owner_player_name == local_login_name_session();
+
+    rsx! {
+        div { class: "scenario-section endless-rewards",
+            h3 { class: "scenario-section-title",
+                {t!("endless-floor-cleared", floor : run.floors_cleared as i64)}
+            }
+            p { class: "settings-hint",
+                if is_owner {
+                    {t!("endless-pick-reward")}
+                } else {
+                    {t!("endless-host-picks-reward")}
+                }
+            }
+            div { class: "endless-reward-grid",
+                for (index , reward) in run.offered_rewards.iter().enumerate() {
+                    button {
+                        class: "endless-reward",
+                        disabled: !is_owner,
+                        onclick: move |_| async move {
+                            let _ = socket
+                                .send(
+                                    ClientEvent::PickEndlessReward(
+                                        SERVER_NAME(),
+                                        local_login_name_session(),
+                                        index,
+                                    ),
+                                )
+                                .await;
+                        },
+                        "{reward_label(reward)}"
+                    }
+                }
+            }
+            if !run.rewards_taken.is_empty() {
+                div { class: "endless-rewards-taken",
+                    span { class: "settings-hint", {t!("endless-rewards-taken")} }
+                    for reward in run.rewards_taken.iter() {
+                        span { class: "endless-reward-chip", "{reward_label(reward)}" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -7,13 +7,7 @@ use dioxus_primitives::scroll_area::ScrollDirection;
 use indexmap::IndexMap;
 use lib_rpg::{
     character_mod::{energy::EnergyKind, loot::LootType},
-    common::{
-        constants::stats_const::{BERSERK, HP, MANA, VIGOR},
-        log_data::{
-            LogData,
-            const_colors::{DARK_RED, LIGHT_BLUE, LIGHT_GREEN},
-        },
-    },
+    common::constants::stats_const::{BERSERK, HP, MANA, VIGOR},
     server::{
         scenario::ScenarioState,
         server_manager::{GamePhase, ServerData},
@@ -22,11 +16,21 @@ use lib_rpg::{
 };
 
 use crate::{
-    auth_manager::server_fn::{get_user_setting, save_user_setting},
-    board_game_components::character_page::{BarComponent, group_by_name},
-    common::{CtxAppLang, SERVER_NAME, lang_from_app_lang},
+    accessibility::{ColorPalette, SETTING_COLOR_PALETTE, SETTING_REDUCED_MOTION, log_color},
+    auth_manager::server_fn::{export_combat_log, get_user_setting, save_user_setting},
+    board_game_components::{
+        character_page::{BarComponent, group_by_name},
+        crafting_panel::CraftingPanel,
+        trade_panel::{TradePanel, TransferPanel},
+    },
+    combat_log::{self, LogEntry, LogExportFormat, LogFilter},
+    common::{
+        CtxA11y, CtxAppLang, CtxGamepad, CtxHotkeys, CtxLogMessages, CtxQuests, CtxTheme,
+        SERVER_NAME, lang_from_app_lang,
+    },
     components::{
         button::{Button, ButtonVariant},
+        input::Input,
         label::Label,
         scroll_area::ScrollArea,
         separator::Separator,
@@ -37,6 +41,14 @@ use crate::{
         sidebar::{Sidebar, SidebarTrigger},
         tabs::{TabContent, TabList, TabTrigger, Tabs},
     },
+    gamepad::{GamepadBindings, PadAction, SETTING_GAMEPAD, use_pad_action},
+    hotkeys::{
+        HotkeyAction, HotkeyBindings, SETTING_HOTKEYS, document_key_listener, key_label,
+        remove_document_key_listener, set_document_consumed_keys,
+    },
+    quests::{Objective, QuestReward, QuestStatus},
+    server_messages,
+    theme::{SETTING_THEME, SETTING_UNIVERSE_SKINS, ThemeMode},
     websocket_handler::{
         event::{ClientEvent, ServerEvent},
         msg_from_client::request_save_game,
@@ -44,6 +56,15 @@ use crate::{
     widgets::{charts::TabStats, tab_equipment::TabEquipment, tab_talents::TabTalents},
 };
 
+// JavaScript code to save the exported log received from Dioxus as a file download.
+const JS_DOWNLOAD_LOG: &str = "const msg = await dioxus.recv(); \
+     const url = URL.createObjectURL(new Blob([msg.text], {type: msg.mime})); \
+     const a = document.createElement('a'); \
+     a.href = url; \
+     a.download = msg.name; \
+     a.click(); \
+     URL.revokeObjectURL(url);";
+
 #[component]
 fn SaveButton(is_saved: Signal<bool>) -> Element {
     // contexts
@@ -72,10 +93,26 @@ enum SheetKind {
     Logs,
     Stats,
     Scenarios,
+    Quests,
     Settings,
     Store,
 }
 
+/// Sheet opened by the hotkey of `action`, if it opens one.
+fn sheet_of(action: HotkeyAction) -> Option<SheetKind> {
+    match action {
+        HotkeyAction::OpenMenu => Some(SheetKind::Menu),
+        HotkeyAction::OpenTalents => Some(SheetKind::Talents),
+        HotkeyAction::OpenInventory => Some(SheetKind::Inventory),
+        HotkeyAction::OpenLogs => Some(SheetKind::Logs),
+        HotkeyAction::OpenStats => Some(SheetKind::Stats),
+        HotkeyAction::OpenScenarios => Some(SheetKind::Scenarios),
+        HotkeyAction::OpenSettings => Some(SheetKind::Settings),
+        HotkeyAction::OpenStore => Some(SheetKind::Store),
+        _ => None,
+    }
+}
+
 /// Whether a hero counts toward a toolbar notification badge: in single-player
 /// every active hero counts, in multiplayer only the logged-in player's own
 /// character does. Shared by the "new equipment" and "unspent talent points"
@@ -103,17 +140,94 @@ pub fn GameSheets() -> Element {
     let server_data = use_context::<Signal<ServerData>>();
     let local_login_name_session = use_context::<Signal<String>>();
     let mut shop_enabled = use_context::<crate::common::CtxShopEnabled>().0;
-
-    // Load shop_enabled from DB on mount so the button reflects the saved
-    // setting without the user having to open Settings first.
+    let mut hotkeys = use_context::<CtxHotkeys>().0;
+    let mut pad_bindings = use_context::<CtxGamepad>().bindings;
+    let a11y = use_context::<CtxA11y>();
+    let (mut palette, mut reduced_motion) = (a11y.palette, a11y.reduced_motion);
+    let theme = use_context::<CtxTheme>();
+    let (mut theme_mode, mut universe_skins) = (theme.mode, theme.universe_skins);
+
+    // Load shop_enabled, the key bindings, the accessibility and the theme options from
+    // DB on mount so the toolbar, the hotkeys and the colours reflect the saved
+    // settings without opening Settings first.
     use_effect(move || {
         spawn(async move {
             if let Ok(val) = get_user_setting("shop_enabled".to_owned(), "false".to_owned()).await {
                 shop_enabled.set(val == "true");
             }
+            if let Ok(val) = get_user_setting(SETTING_HOTKEYS.to_owned(), "{}".to_owned()).await {
+                hotkeys.set(HotkeyBindings::from_setting(&val));
+            }
+            if let Ok(val) = get_user_setting(SETTING_GAMEPAD.to_owned(), "{}".to_owned()).await {
+                pad_bindings.set(GamepadBindings::from_setting(&val));
+            }
+            if let Ok(val) =
+                get_user_setting(SETTING_COLOR_PALETTE.to_owned(), "default".to_owned()).await
+            {
+                palette.set(ColorPalette::from_key(&val));
+            }
+            if let Ok(val) =
+                get_user_setting(SETTING_REDUCED_MOTION.to_owned(), "false".to_owned()).await
+            {
+                reduced_motion.set(val == "true");
+            }
+            if let Ok(val) = get_user_setting(SETTING_THEME.to_owned(), "dark".to_owned()).await {
+                theme_mode.set(ThemeMode::from_key(&val));
+            }
+            if let Ok(val) =
+                get_user_setting(SETTING_UNIVERSE_SKINS.to_owned(), "true".to_owned()).await
+            {
+                universe_skins.set(val == "true");
+            }
         });
     });
 
+    // Sheet hotkeys: pressing the key of the sheet already shown closes it.
+    use_effect(move || {
+        spawn(async move {
+            let mut listener = document_key_listener("game-sheets");
+            while let Ok((key, _shift)) = listener.recv::<(String, bool)>().await {
+                let kind = match hotkeys.peek().action_for(&key).and_then(sheet_of) {
+                    Some(SheetKind::Store) if !*shop_enabled.peek() => continue,
+                    Some(kind) => kind,
+                    None => continue,
+                };
+                if *open.peek() && *sheet_kind.peek() == kind {
+                    open.set(false);
+                } else {
+                    sheet_kind.set(kind);
+                    open.set(true);
+                }
+            }
+        });
+    });
+    // The browser keeps the keys of no sheet, e.g. Space for scrolling
+    use_effect(move || {
+        let bindings = hotkeys();
+        let shop = shop_enabled();
+        let keys: Vec<&str> = HotkeyAction::ALL
+            .into_iter()
+            .filter(|action| match sheet_of(*action) {
+                Some(SheetKind::Store) => shop,
+                kind => kind.is_some(),
+            })
+            .map(|action| bindings.key_of(action))
+            .collect();
+        set_document_consumed_keys("game-sheets", &keys);
+    });
+    use_drop(|| remove_document_key_listener("game-sheets"));
+
+    // Gamepad: Start toggles the Menu sheet, Cancel closes whichever sheet is open.
+    use_pad_action(move |action| match action {
+        PadAction::OpenMenu => {
+            let menu_shown = *open.peek() && *sheet_kind.peek() == SheetKind::Menu;
+            sheet_kind.set(SheetKind::Menu);
+            open.set(!menu_shown);
+        }
+        PadAction::Cancel if *open.peek() => open.set(false),
+        _ => {}
+    });
+
     let open_sheet = move |kind: SheetKind| {
         move |_| {
             sheet_kind.set(kind.clone());
@@ -218,6 +332,11 @@ pub fn GameSheets() -> Element {
                     onclick: open_sheet(SheetKind::Scenarios),
                     {t!("gs-scenarios")}
                 }
+                Button {
+                    variant: ButtonVariant::Outline,
+                    onclick: open_sheet(SheetKind::Quests),
+                    {t!("gs-quests")}
+                }
                 Button {
                     variant: ButtonVariant::Outline,
                     onclick: open_sheet(SheetKind::Settings),
@@ -252,6 +371,9 @@ pub fn GameSheets() -> Element {
                 SheetKind::Scenarios => rsx! {
                     ScenariosSheet { s: SheetSide::Right }
                 },
+                SheetKind::Quests => rsx! {
+                    QuestsSheet { s: SheetSide::Right }
+                },
                 SheetKind::Settings => rsx! {
                     SettingsSheet { s: SheetSide::Left }
                 },
@@ -336,6 +458,15 @@ pub fn GameSheets() -> Element {
                 },
                 {t!("gs-scenarios")}
             }
+            Button {
+                variant: ButtonVariant::Outline,
+                onclick: move |_| {
+                    sheet_kind.set(SheetKind::Quests);
+                    open.set(true);
+                    mobile_toolbar_open.set(false);
+                },
+                {t!("gs-quests")}
+            }
             Button {
                 variant: ButtonVariant::Outline,
                 onclick: move |_| {
@@ -618,6 +749,17 @@ fn InventorySheet(s: SheetSide) -> Element {
                 // the "new equipment" badges never clear for other heroes.
                 TabEquipment { key: "{character.id_name}", c: character.clone() }
 
+                // Items and gold change hands between heroes: traded with the other
+                // players in multiplayer, handed to another hero of the party in
+                // single-player.
+                if !is_single_player {
+                    Separator { horizontal: true, decorative: true }
+                    TradePanel { character: character.clone() }
+                } else if heroes_to_show.len() > 1 {
+                    Separator { horizontal: true, decorative: true }
+                    TransferPanel { key: "{character.id_name}", character: character.clone() }
+                }
+
                 // Using potions/consumables is only offered here while resting in the
                 // overworld — during combat, PotionList (reached via the ⚔️ menu) handles
                 // it instead, since that flow also picks a target and advances the turn.
@@ -996,6 +1138,28 @@ fn MenuSheet(s: SheetSide, open_wnd: Signal<bool>, is_saved: Signal<bool>) -> El
 fn LogsSheet(s: SheetSide) -> Element {
     // context
     let server_data = use_context::<Signal<ServerData>>();
+    let log_messages = use_context::<CtxLogMessages>().0;
+    let app_lang = use_context::<CtxAppLang>().0;
+    // local
+    let mut filter = use_signal(LogFilter::default);
+    let mut scenario_only = use_signal(|| true);
+    let mut export_format = use_signal(|| LogExportFormat::Text);
+    let mut export_msg = use_signal(String::new);
+
+    let entries = use_memo(move || {
+        let snap = server_data.read();
+        let logs = &snap.core_game_data.game_manager.logs;
+        let messages = log_messages.read();
+        if scenario_only() {
+            combat_log::annotate(combat_log::current_scenario(logs, &messages), &messages)
+        } else {
+            combat_log::annotate(logs, &messages)
+        }
+    });
+    let facets = use_memo(move || combat_log::facets(&entries()));
+    let lang = lang_from_app_lang(&app_lang());
+    let atk_names = build_atk_name_translations(&server_data(), lang);
+    let turn_value = |turn: Option<usize>| turn.map(|t| t.to_string()).unwrap_or_default();
 
     rsx! {
         SheetContent { side: s,
@@ -1012,6 +1176,91 @@ fn LogsSheet(s: SheetSide) -> Element {
                 flex: "1",
                 overflow: "hidden",
 
+                div { class: "logs-filters",
+                    Input {
+                        r#type: "search",
+                        placeholder: t!("gs-logs-search-placeholder"),
+                        aria_label: t!("gs-logs-search-placeholder"),
+                        value: "{filter().text}",
+                        oninput: move |e: FormEvent| filter.write().text = e.value(),
+                    }
+                    select {
+                        class: "admin-select",
+                        aria_label: t!("gs-logs-actor-label"),
+                        onchange: move |e| filter.write().actor = e.value(),
+                        option { value: "", selected: filter().actor.is_empty(), {t!("gs-logs-any-actor")} }
+                        for name in facets().actors {
+                            option {
+                                value: "{name}",
+                                selected: filter().actor == name,
+                                "{name}"
+                            }
+                        }
+                    }
+                    select {
+                        class: "admin-select",
+                        aria_label: t!("gs-logs-target-label"),
+                        onchange: move |e| filter.write().target = e.value(),
+                        option { value: "", selected: filter().target.is_empty(), {t!("gs-logs-any-target")} }
+                        for name in facets().targets {
+                            option {
+                                value: "{name}",
+                                selected: filter().target == name,
+                                "{name}"
+                            }
+                        }
+                    }
+                    select {
+                        class: "admin-select",
+                        aria_label: t!("gs-logs-attack-label"),
+                        onchange: move |e| filter.write().attack = e.value(),
+                        option { value: "", selected: filter().attack.is_empty(), {t!("gs-logs-any-attack")} }
+                        for name in facets().attacks {
+                            option {
+                                value: "{name}",
+                                selected: filter().attack == name,
+                                {atk_names.get(&name).cloned().unwrap_or(name.clone())}
+                            }
+                        }
+                    }
+                    div { class: "logs-turns",
+                        span { {t!("gs-logs-turns-label")} }
+                        Input {
+                            r#type: "number",
+                            min: "1",
+                            max: "{facets().last_turn}",
+                            aria_label: t!("gs-logs-turn-from"),
+                            placeholder: t!("gs-logs-turn-from"),
+                            value: turn_value(filter().turn_min),
+                            oninput: move |e: FormEvent| filter.write().turn_min = e.value().parse().ok(),
+                        }
+                        Input {
+                            r#type: "number",
+                            min: "1",
+                            max: "{facets().last_turn}",
+                            aria_label: t!("gs-logs-turn-to"),
+                            placeholder: t!("gs-logs-turn-to"),
+                            value: turn_value(filter().turn_max),
+                            oninput: move |e: FormEvent| filter.write().turn_max = e.value().parse().ok(),
+                        }
+                    }
+                    label { class: "logs-scenario-only",
+                        input {
+                            r#type: "checkbox",
+                            checked: scenario_only(),
+                            onchange: move |_| scenario_only.set(!scenario_only()),
+                        }
+                        {t!("gs-logs-scenario-only")}
+                    }
+                    if !filter().is_empty() {
+                        Button {
+                            variant: ButtonVariant::Ghost,
+                            onclick: move |_| filter.set(LogFilter::default()),
+                            {t!("gs-logs-clear-filters")}
+                        }
+                    }
+                }
+
                 Tabs { default_value: "all".to_owned(), horizontal: true,
 
                     TabList {
@@ -1021,34 +1270,68 @@ fn LogsSheet(s: SheetSide) -> Element {
                         TabTrigger { value: "event".to_owned(), index: 3_usize, {t!("gs-logs-events")} }
                     }
 
-                    TabContent { value: "all".to_owned(), index: 0_usize,
-                        LogsList {
-                            logs: server_data().core_game_data.game_manager.logs.clone(),
-                            filter: "all".to_owned(),
+                    for (index , tab) in ["all", "combat", "heal", "event"].into_iter().enumerate() {
+                        TabContent { value: tab.to_owned(), index,
+                            LogsList {
+                                entries: entries(),
+                                tab: tab.to_owned(),
+                                filter: filter(),
+                            }
                         }
                     }
-                    TabContent { value: "combat".to_owned(), index: 1_usize,
-                        LogsList {
-                            logs: server_data().core_game_data.game_manager.logs.clone(),
-                            filter: "combat".to_owned(),
+                }
+            }
+
+            SheetFooter {
+                div { class: "logs-export",
+                    select {
+                        class: "admin-select",
+                        aria_label: t!("gs-logs-export-format"),
+                        onchange: move |e| {
+                            if let Some(f) = LogExportFormat::ALL
+                                .into_iter()
+                                .find(|f| f.extension() == e.value())
+                            {
+                                export_format.set(f);
+                            }
+                        },
+                        for f in LogExportFormat::ALL {
+                            option {
+                                value: f.extension(),
+                                selected: export_format() == f,
+                                {f.extension().to_uppercase()}
+                            }
                         }
                     }
-                    TabContent { value: "heal".to_owned(), index: 2_usize,
-                        LogsList {
-                            logs: server_data().core_game_data.game_manager.logs.clone(),
-                            filter: "heal".to_owned(),
-                        }
+                    Button {
+                        variant: ButtonVariant::Outline,
+                        onclick: move |_| {
+                            let format = export_format();
+                            spawn(async move {
+                                match export_combat_log(SERVER_NAME(), format).await {
+                                    Ok(text) => {
+                                        let js = document::eval(JS_DOWNLOAD_LOG);
+                                        let _ = js
+                                            .send(
+                                                serde_json::json!({
+                                                    "name": format!("combat-log.{}", format.extension()), "mime": format.mime(), "text":
+                                                    text
+                                                }),
+                                            );
+                                        export_msg.set(t!("gs-logs-exported"));
+                                    }
+                                    Err(e) => {
+                                        export_msg.set(t!("gs-logs-export-error", error : server_messages::localize_error(&e)))
+                                    }
+                                }
+                            });
+                        },
+                        {t!("gs-logs-export")}
                     }
-                    TabContent { value: "event".to_owned(), index: 3_usize,
-                        LogsList {
-                            logs: server_data().core_game_data.game_manager.logs.clone(),
-                            filter: "event".to_owned(),
-                        }
+                    if !export_msg().is_empty() {
+                        span { class: "settings-hint", {export_msg()} }
                     }
                 }
-            }
-
-            SheetFooter {
                 SheetClose {
                     r#as: |attributes| rsx! {
                         Button { variant: ButtonVariant::Outline, attributes, {t!("gs-close")} }
@@ -1103,55 +1386,63 @@ fn translate_atk_name_in_log(
     )
 }
 
-/// Filtered, colored list of log entries — newest first.
+/// Filtered, colored list of log entries — newest attack first, each headed by
+/// its turn marker.
 #[component]
-fn LogsList(logs: Vec<LogData>, filter: String) -> Element {
+fn LogsList(entries: Vec<LogEntry>, tab: String, filter: LogFilter) -> Element {
     let server_data = use_context::<Signal<ServerData>>();
     let app_lang = use_context::<CtxAppLang>().0;
     let lang = lang_from_app_lang(&app_lang());
     // French is the canonical language baked into every log message — nothing to translate.
     let name_map = (lang != lib_rpg::common::lang::Lang::Fr)
         .then(|| build_atk_name_translations(&server_data(), lang));
-
-    let filtered: Vec<&LogData> = logs
-        .iter()
-        .rev()
-        .filter(|log| match filter.as_str() {
-            "combat" => log.color == DARK_RED,
-            "heal" => log.color == LIGHT_GREEN,
-            "event" => log.color == LIGHT_BLUE,
-            _ => true,
-        })
+    let palette = use_context::<CtxA11y>().palette;
+
+    // Server-built lines carry their message, lib-rpg ones a French attack name
+    let display_text = |entry: &LogEntry| {
+        let message = entry.text();
+        name_map
+            .as_ref()
+            .map_or(message.clone(), |m| translate_atk_name_in_log(&message, m))
+    };
+    let shown: Vec<(&LogEntry, String)> =
+        combat_log::newest_first(combat_log::filter_entries(&entries, |entry| {
+            entry.kind.in_tab(&tab) && filter.matches(entry, &display_text(entry))
+        }))
+        .into_iter()
+        .map(|entry| (entry, display_text(entry).replace('\n', "<br/>")))
         .collect();
 
     rsx! {
         ScrollArea {
             width: "100%",
-            height: "calc(100vh - 18rem)",
+            height: "calc(100vh - 24rem)",
             border: "1px solid var(--rpg-border-light)",
             border_radius: "8px",
             padding: "0.5em",
             direction: ScrollDirection::Vertical,
             tabindex: "0",
-            div { class: "scroll-content",
-                if filtered.is_empty() {
+            div { class: "scroll-content", role: "log",
+                if shown.is_empty() {
                     div { style: "color: var(--rpg-text-muted); text-align: center; padding: 2rem; font-size: 0.85rem;",
-                        {t!("gs-logs-empty")}
+                        if entries.is_empty() {
+                            {t!("gs-logs-empty")}
+                        } else {
+                            {t!("gs-logs-no-match")}
+                        }
                     }
                 }
-                for log in filtered {
-                    {
-                        let translated = name_map
-                            .as_ref()
-                            .map(|m| translate_atk_name_in_log(&log.message, m));
-                        let msg = translated
-                            .as_deref()
-                            .unwrap_or(&log.message)
-                            .replace('\n', "<br/>");
-                        rsx! {
-                            div {
-                                style: "padding: 4px 8px; margin: 2px 0; border-left: 3px solid {log.color}; border-radius: 0 4px 4px 0; font-size: 0.82rem; color: {log.color}; word-break: break-word;",
-                                dangerous_inner_html: "{msg}",
+                for (entry , msg) in shown {
+                    if entry.kind.is_marker() {
+                        div { class: "log-marker", dangerous_inner_html: "{msg}" }
+                    } else {
+                        {
+                            let color = log_color(palette(), &entry.color);
+                            rsx! {
+                                div {
+                                    style: "padding: 4px 8px; margin: 2px 0; border-left: 3px solid {color}; border-radius: 0 4px 4px 0; font-size: 0.82rem; color: {color}; word-break: break-word;",
+                                    dangerous_inner_html: "{msg}",
+                                }
                             }
                         }
                     }
@@ -1247,6 +1538,125 @@ fn ScenariosSheet(s: SheetSide) -> Element {
     }
 }
 
+// ─── Quests Sheet ─────────────────────────────────────────────────────────────
+
+/// What the party must do for an objective, in the app language.
+fn objective_label(objective: &Objective) -> String {
+    match objective {
+        Objective::TalkTo { npc, .. } => {
+            t!("gs-quest-objective-talk", npc : npc.replace('_', " "))
+        }
+        Objective::Defeat { scenario } => {
+            t!("gs-quest-objective-defeat", scenario : scenario.clone())
+        }
+        Objective::ReachMap { map } => t!("gs-quest-objective-reach", map : map.replace('_', " ")),
+        Objective::CollectMaterials { material, amount } => t!(
+            "gs-quest-objective-collect",
+            material : material.clone(),
+            amount : *amount as i64
+        ),
+    }
+}
+
+fn rewards_label(rewards: &QuestReward) -> String {
+    let mut parts = Vec::new();
+    if rewards.gold > 0 {
+        parts.push(t!("gs-quest-reward-gold", gold : rewards.gold as i64));
+    }
+    parts.extend(rewards.consumables.iter().cloned());
+    parts.extend(
+        rewards
+            .materials
+            .iter()
+            .map(|ingredient| format!("{} ×{}", ingredient.material, ingredient.amount)),
+    );
+    parts.join(", ")
+}
+
+/// Quest log of the party: the quests started, the active ones first, with
+/// their objectives and rewards.
+#[component]
+fn QuestsSheet(s: SheetSide) -> Element {
+    let quests = use_context::<CtxQuests>().0;
+    let lang = use_context::<CtxAppLang>().0();
+    let mut started = quests().quests;
+    started.sort_by_key(|(_, progress)| progress.status == QuestStatus::Completed);
+
+    rsx! {
+        SheetContent { side: s,
+            SheetHeader {
+                SheetTitle { {t!("gs-quests-title")} }
+                SheetDescription { {t!("gs-quests-desc")} }
+            }
+
+            div {
+                display: "flex",
+                flex_direction: "column",
+                gap: "0.5rem",
+                padding: "0 1rem",
+
+                if started.is_empty() {
+                    div { style: "color:var(--rpg-text-muted); text-align:center; padding:2rem; font-size:0.85rem;",
+                        {t!("gs-quests-empty")}
+                    }
+                } else {
+                    for (quest , progress) in started.iter() {
+                        {
+                            let completed = progress.status == QuestStatus::Completed;
+                            let rewards = rewards_label(&quest.rewards);
+                            rsx! {
+                                div {
+                                    key: "{quest.id}",
+                                    class: if completed { "quest-item completed" } else { "quest-item" },
+                                    div { class: "quest-header",
+                                        span { class: "quest-title", {quest.title.get(&lang)} }
+                                        span { class: if completed { "scenario-chip completed" } else { "scenario-chip in-progress" },
+                                            if completed {
+                                                {t!("gs-quest-completed")}
+                                            } else {
+                                                {t!("gs-quest-active")}
+                                            }
+                                        }
+                                    }
+                                    p { class: "quest-description", {quest.description.get(&lang)} }
+                                    ul { class: "quest-objectives",
+                                        for (i , objective) in quest.objectives.iter().enumerate() {
+                                            {
+                                                let done = progress.done.get(i).copied().unwrap_or(completed);
+                                                rsx! {
+                                                    li { class: if done { "quest-objective done" } else { "quest-objective" },
+                                                        if done {
+                                                            "✓ "
+                                                        } else {
+                                                            "○ "
+                                                        }
+                                                        {objective_label(objective)}
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    if !rewards.is_empty() {
+                                        p { class: "quest-rewards", {t!("gs-quest-rewards", rewards : rewards)} }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            SheetFooter {
+                SheetClose {
+                    r#as: |attributes| rsx! {
+                        Button { variant: ButtonVariant::Outline, attributes, {t!("gs-close")} }
+                    },
+                }
+            }
+        }
+    }
+}
+
 // ─── Store Sheet ─────────────────────────────────────────────────────────────
 
 pub(crate) fn rank_color(rank: &lib_rpg::character_mod::rank::Rank) -> &'static str {
@@ -1307,7 +1717,7 @@ pub fn StoreSheet(s: SheetSide) -> Element {
 
     let char_id = character.id_name.clone();
     let gold = character.inventory.money;
-    // 0 = Shop, 1 = Bag  (signal-driven; never unmounts children)
+    // 0 = Shop, 1 = Bag, 2 = Crafting  (signal-driven; never unmounts children)
     let mut main_tab: Signal<u8> = use_signal(|| 0);
     // 0 = Equipment, 1 = Consumables
     let mut shop_sub_tab: Signal<u8> = use_signal(|| 0);
@@ -1344,7 +1754,7 @@ pub fn StoreSheet(s: SheetSide) -> Element {
                     }
                 }
 
-                // Main tab row: Shop | Bag
+                // Main tab row: Shop | Bag | Crafting
                 div { display: "flex", gap: "0.4rem",
                     button {
                         class: if main_tab() == 0 { "inv-tab inv-tab--active" } else { "inv-tab" },
@@ -1356,6 +1766,11 @@ pub fn StoreSheet(s: SheetSide) -> Element {
                         onclick: move |_| main_tab.set(1),
                         {t!("gs-store-bag")}
                     }
+                    button {
+                        class: if main_tab() == 2 { "inv-tab inv-tab--active" } else { "inv-tab" },
+                        onclick: move |_| main_tab.set(2),
+                        {t!("gs-store-crafting")}
+                    }
                 }
 
                 // ── Shop panel (always mounted) ────────────────────────────
@@ -1800,6 +2215,16 @@ pub fn StoreSheet(s: SheetSide) -> Element {
                         }
                     }
                 }
+
+                // ── Crafting panel (always mounted) ────────────────────────
+                div { display: if main_tab() == 2 { "block" } else { "none" },
+                    ScrollArea {
+                        width: "100%",
+                        height: "calc(100vh - 18rem)",
+                        direction: ScrollDirection::Vertical,
+                        CraftingPanel { character_id_name: char_id.clone(), gold: gold as _ }
+                    }
+                }
             }
 
             SheetFooter {
@@ -1822,6 +2247,58 @@ const SETTING_BOSS_HP: &str = "show_boss_hp";
 const SETTING_AUTO_SAVE: &str = "auto_save_on_scenario";
 const SETTING_SHOP_ENABLED: &str = "shop_enabled";
 
+fn hotkey_action_label(action: HotkeyAction) -> String {
+    match action {
+        HotkeyAction::ToggleAttacks => t!("hotkey-toggle-attacks"),
+        HotkeyAction::TogglePotions => t!("hotkey-toggle-potions"),
+        HotkeyAction::NextTarget => t!("hotkey-next-target"),
+        HotkeyAction::PreviousTarget => t!("hotkey-previous-target"),
+        HotkeyAction::Confirm => t!("hotkey-confirm"),
+        HotkeyAction::Cancel => t!("hotkey-cancel"),
+        HotkeyAction::OpenMenu => t!("hotkey-open-menu"),
+        HotkeyAction::OpenTalents => t!("hotkey-open-talents"),
+        HotkeyAction::OpenInventory => t!("hotkey-open-inventory"),
+        HotkeyAction::OpenLogs => t!("hotkey-open-logs"),
+        HotkeyAction::OpenStats => t!("hotkey-open-stats"),
+        HotkeyAction::OpenScenarios => t!("hotkey-open-scenarios"),
+        HotkeyAction::OpenSettings => t!("hotkey-open-settings"),
+        HotkeyAction::OpenStore => t!("hotkey-open-store"),
+    }
+}
+
+fn theme_label(mode: ThemeMode) -> String {
+    match mode {
+        ThemeMode::Dark => t!("theme-dark"),
+        ThemeMode::Light => t!("theme-light"),
+        ThemeMode::System => t!("theme-system"),
+    }
+}
+
+fn palette_label(palette: ColorPalette) -> String {
+    match palette {
+        ColorPalette::Default => t!("palette-default"),
+        ColorPalette::Deuteranopia => t!("palette-deuteranopia"),
+        ColorPalette::Protanopia => t!("palette-protanopia"),
+        ColorPalette::HighContrast => t!("palette-high-contrast"),
+    }
+}
+
+fn pad_action_label(action: PadAction) -> String {
+    match action {
+        PadAction::Up => t!("pad-action-up"),
+        PadAction::Down => t!("pad-action-down"),
+        PadAction::Left => t!("pad-action-left"),
+        PadAction::Right => t!("pad-action-right"),
+        PadAction::Confirm => t!("pad-action-confirm"),
+        PadAction::Cancel => t!("pad-action-cancel"),
+        PadAction::ToggleAttacks => t!("hotkey-toggle-attacks"),
+        PadAction::TogglePotions => t!("hotkey-toggle-potions"),
+        PadAction::PreviousTarget => t!("hotkey-previous-target"),
+        PadAction::NextTarget => t!("hotkey-next-target"),
+        PadAction::OpenMenu => t!("pad-action-menu"),
+    }
+}
+
 #[component]
 fn SettingsSheet(s: SheetSide) -> Element {
     let mut show_atk_tooltips = use_context::<crate::common::CtxShowAtkTooltips>().0;
@@ -1830,7 +2307,63 @@ fn SettingsSheet(s: SheetSide) -> Element {
     let mut show_boss_hp = use_context::<crate::common::CtxShowBossHp>().0;
     let mut auto_save_scenario = use_context::<crate::common::CtxAutoSaveScenario>().0;
     let mut shop_enabled = use_context::<crate::common::CtxShopEnabled>().0;
+    let mut hotkeys = use_context::<CtxHotkeys>().0;
+    // action waiting for its new key after a click on its key button
+    let mut capturing_action: Signal<Option<HotkeyAction>> = use_signal(|| None);
     let mut save_msg: Signal<String> = use_signal(String::new);
+    let a11y = use_context::<CtxA11y>();
+    let (mut palette, mut reduced_motion) = (a11y.palette, a11y.reduced_motion);
+    let theme = use_context::<CtxTheme>();
+    let (mut theme_mode, mut universe_skins) = (theme.mode, theme.universe_skins);
+
+    let gamepad = use_context::<CtxGamepad>();
+    let mut pad_bindings = gamepad.bindings;
+    let mut capturing_pad = gamepad.capturing;
+    // pad action waiting for its new button, see the capture effect below
+    let mut capturing_pad_action: Signal<Option<PadAction>> = use_signal(|| None);
+    let mut last_pad_seq = use_signal(|| gamepad.pressed.peek().seq);
+
+    let mut save_pad_bindings = move |bindings: GamepadBindings| {
+        let raw = bindings.to_setting();
+        pad_bindings.set(bindings);
+        save_msg.set(t!("gs-settings-saving"));
+        spawn(async move {
+            let _ = save_user_setting(SETTING_GAMEPAD.to_string(), raw).await;
+            save_msg.set(t!("gs-settings-saved"));
+        });
+    };
+
+    // The next pad press after a click on an action's button becomes its binding.
+    // `capturing` flags that press so it does not also act on the board behind the sheet.
+    use_effect(move || {
+        let press = (gamepad.pressed)();
+        if press.seq == *last_pad_seq.peek() {
+            return;
+        }
+        last_pad_seq.set(press.seq);
+        if !press.captured {
+            return;
+        }
+        let (Some(action), Some(button)) = (*capturing_pad_action.peek(), press.button) else {
+            return;
+        };
+        capturing_pad_action.set(None);
+        capturing_pad.set(false);
+        let mut bindings = pad_bindings.peek().clone();
+        bindings.rebind(action, button);
+        save_pad_bindings(bindings);
+    });
+    use_drop(move || capturing_pad.set(false));
+
+    let mut save_hotkeys = move |bindings: HotkeyBindings| {
+        let raw = bindings.to_setting();
+        hotkeys.set(bindings);
+        save_msg.set(t!("gs-settings-saving"));
+        spawn(async move {
+            let _ = save_user_setting(SETTING_HOTKEYS.to_string(), raw).await;
+            save_msg.set(t!("gs-settings-saved"));
+        });
+    };
 
     // Load saved settings on mount
     use_effect(move || {
@@ -2049,6 +2582,207 @@ fn SettingsSheet(s: SheetSide) -> Element {
                     }
                 }
 
+                // ── Theme ──────────────────────────────────────────────────────
+                div { class: "settings-row",
+                    div { class: "settings-label-group",
+                        span { class: "settings-label", {t!("gs-settings-theme-label")} }
+                        span { class: "settings-hint", {t!("gs-settings-theme-hint")} }
+                    }
+                    select {
+                        class: "admin-select",
+                        aria_label: t!("gs-settings-theme-label"),
+                        onchange: move |e| {
+                            let new_val = ThemeMode::from_key(&e.value());
+                            theme_mode.set(new_val);
+                            save_msg.set(t!("gs-settings-saving"));
+                            spawn(async move {
+                                let _ = save_user_setting(
+                                        SETTING_THEME.to_string(),
+                                        new_val.key().to_string(),
+                                    )
+                                    .await;
+                                save_msg.set(t!("gs-settings-saved"));
+                            });
+                        },
+                        for m in ThemeMode::ALL {
+                            option {
+                                value: m.key(),
+                                selected: theme_mode() == m,
+                                {theme_label(m)}
+                            }
+                        }
+                    }
+                }
+                div { class: "settings-row",
+                    div { class: "settings-label-group",
+                        span { class: "settings-label", {t!("gs-settings-universe-skins-label")} }
+                        span { class: "settings-hint", {t!("gs-settings-universe-skins-hint")} }
+                    }
+                    label { class: "toggle-switch",
+                        input {
+                            r#type: "checkbox",
+                            checked: universe_skins(),
+                            onchange: move |_| {
+                                let new_val = !universe_skins();
+                                universe_skins.set(new_val);
+                                save_msg.set(t!("gs-settings-saving"));
+                                spawn(async move {
+                                    let _ = save_user_setting(
+                                            SETTING_UNIVERSE_SKINS.to_string(),
+                                            if new_val { "true" } else { "false" }.to_string(),
+                                        )
+                                        .await;
+                                    save_msg.set(t!("gs-settings-saved"));
+                                });
+                            },
+                        }
+                        span { class: "toggle-slider" }
+                    }
+                }
+
+                // ── Accessibility ──────────────────────────────────────────────
+                div { class: "settings-row",
+                    div { class: "settings-label-group",
+                        span { class: "settings-label", {t!("gs-settings-palette-label")} }
+                        span { class: "settings-hint", {t!("gs-settings-palette-hint")} }
+                    }
+                    select {
+                        class: "admin-select",
+                        aria_label: t!("gs-settings-palette-label"),
+                        onchange: move |e| {
+                            let new_val = ColorPalette::from_key(&e.value());
+                            palette.set(new_val);
+                            save_msg.set(t!("gs-settings-saving"));
+                            spawn(async move {
+                                let _ = save_user_setting(
+                                        SETTING_COLOR_PALETTE.to_string(),
+                                        new_val.key().to_string(),
+                                    )
+                                    .await;
+                                save_msg.set(t!("gs-settings-saved"));
+                            });
+                        },
+                        for p in ColorPalette::ALL {
+                            option {
+                                value: p.key(),
+                                selected: palette() == p,
+                                {palette_label(p)}
+                            }
+                        }
+                    }
+                }
+                div { class: "settings-row",
+                    div { class: "settings-label-group",
+                        span { class: "settings-label", {t!("gs-settings-reduced-motion-label")} }
+                        span { class: "settings-hint", {t!("gs-settings-reduced-motion-hint")} }
+                    }
+                    label { class: "toggle-switch",
+                        input {
+                            r#type: "checkbox",
+                            checked: reduced_motion(),
+                            onchange: move |_| {
+                                let new_val = !reduced_motion();
+                                reduced_motion.set(new_val);
+                                save_msg.set(t!("gs-settings-saving"));
+                                spawn(async move {
+                                    let _ = save_user_setting(
+                                            SETTING_REDUCED_MOTION.to_string(),
+                                            if new_val { "true" } else { "false" }.to_string(),
+                                        )
+                                        .await;
+                                    save_msg.set(t!("gs-settings-saved"));
+                                });
+                            },
+                        }
+                        span { class: "toggle-slider" }
+                    }
+                }
+
+                // ── Keyboard shortcuts ─────────────────────────────────────────
+                div { class: "settings-label-group",
+                    span { class: "settings-label", {t!("gs-settings-hotkeys-label")} }
+                    span { class: "settings-hint", {t!("gs-settings-hotkeys-hint")} }
+                }
+                for action in HotkeyAction::ALL {
+                    div { key: "{action:?}", class: "settings-row",
+                        span { class: "settings-hint", {hotkey_action_label(action)} }
+                        button {
+                            class: "settings-hotkey",
+                            "data-hotkey-capture": "true",
+                            onclick: move |_| capturing_action.set(Some(action)),
+                            onkeydown: move |e: KeyboardEvent| {
+                                if capturing_action() != Some(action) {
+                                    return;
+                                }
+                                e.prevent_default();
+                                let key = e.key().to_string();
+                                capturing_action.set(None);
+                                // Escape aborts the capture
+                                if key == "Escape" {
+                                    return;
+                                }
+                                let mut bindings = hotkeys();
+                                match bindings.rebind(action, &key) {
+                                    Ok(()) => save_hotkeys(bindings),
+                                    Err(key) => {
+                                        save_msg.set(t!("gs-settings-hotkeys-reserved", key : key_label(& key)))
+                                    }
+                                }
+                            },
+                            onblur: move |_| {
+                                if capturing_action() == Some(action) {
+                                    capturing_action.set(None);
+                                }
+                            },
+                            if capturing_action() == Some(action) {
+                                {t!("gs-settings-hotkeys-press")}
+                            } else {
+                                {key_label(hotkeys.read().key_of(action))}
+                            }
+                        }
+                    }
+                }
+                Button {
+                    variant: ButtonVariant::Secondary,
+                    onclick: move |_| save_hotkeys(HotkeyBindings::default()),
+                    {t!("gs-settings-hotkeys-reset")}
+                }
+
+                // ── Gamepad ────────────────────────────────────────────────────
+                div { class: "settings-label-group",
+                    span { class: "settings-label", {t!("gs-settings-gamepad-label")} }
+                    span { class: "settings-hint",
+                        if (gamepad.connected)() {
+                            {t!("gs-settings-gamepad-connected")}
+                        } else {
+                            {t!("gs-settings-gamepad-none")}
+                        }
+                    }
+                }
+                for action in PadAction::ALL {
+                    div { key: "{action:?}", class: "settings-row",
+                        span { class: "settings-hint", {pad_action_label(action)} }
+                        button {
+                            class: "settings-hotkey",
+                            onclick: move |_| {
+                                let capture = capturing_pad_action() != Some(action);
+                                capturing_pad_action.set(capture.then_some(action));
+                                capturing_pad.set(capture);
+                            },
+                            if capturing_pad_action() == Some(action) {
+                                {t!("gs-settings-gamepad-press")}
+                            } else {
+                                {pad_bindings.read().button_of(action).glyph()}
+                            }
+                        }
+                    }
+                }
+                Button {
+                    variant: ButtonVariant::Secondary,
+                    onclick: move |_| save_pad_bindings(GamepadBindings::default()),
+                    {t!("gs-settings-gamepad-reset")}
+                }
+
                 if !save_msg().is_empty() {
                     p { class: "settings-save-msg", "{save_msg}" }
                 }
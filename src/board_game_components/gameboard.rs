@@ -3,8 +3,8 @@ use dioxus::{
     logger::tracing,
 };
 use lib_rpg::{
-    character_mod::buffers::BufKinds,
-    common::sound_cue::classify_result_atk,
+    character_mod::{buffers::BufKinds, character::CharacterKind},
+    common::{lang::Lang, sound_cue::classify_result_atk},
     server::{
         game_manager::ResultLaunchAttack, players_manager::GameAtkEffect,
         server_manager::ServerData,
@@ -12,16 +12,197 @@ use lib_rpg::{
 };
 
 use crate::{
-    audio,
-    board_game_components::character_page::{AttackList, CharacterPanel, PotionList},
+    accessibility::log_color,
+    audio::{self, Sfx, SfxCatalog, SfxSide},
+    auth_manager::server_fn::get_sfx_catalog,
+    board_game_components::character_page::{
+        AttackList, CharacterPanel, PotionList, consumable_slots, load_atk_panel_order,
+        panel_attacks, select_attack, select_consumable,
+    },
+    combat_animation::{CombatAnimation, play_strike},
     common::{
-        CtxAppLang, CtxAudioSettings, CtxToggleAtkAnimation, SERVER_NAME, lang_from_app_lang,
+        CtxA11y, CtxAppLang, CtxAtkPanelOrders, CtxAudioSettings, CtxCombatAnimation, CtxHotkeys,
+        CtxLogMessages, CtxToggleAtkAnimation, SERVER_NAME, lang_from_app_lang,
     },
     components::button::{Button, ButtonVariant},
+    gamepad::{PadAction, use_pad_action},
+    hotkeys::{HotkeyAction, cycle_selection, normalize_key, slot_for_key},
     websocket_handler::event::{ClientEvent, ServerEvent},
+    widgets::input_prompts::{InputPrompts, PromptContext},
 };
+use dioxus::html::Modifiers;
 use dioxus::prelude::*;
 use dioxus_i18n::t;
+use std::{collections::BTreeSet, rc::Rc};
+
+/// One combat command, from the keyboard or the gamepad.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CombatInput {
+    /// n-th attack (or potion while the potion list is open) in panel order
+    Slot(usize),
+    /// previous/next launchable attack (or potion), gamepad only
+    StepAttack(bool),
+    /// previous/next potential target of the selected attack or potion
+    StepTarget(bool),
+    Confirm,
+    Cancel,
+    ToggleAttacks,
+    TogglePotions,
+}
+
+/// Same rule as the ⚔️ button of `CharacterPanel`: only the hero whose turn it is,
+/// and in multiplayer only when that hero is `player_name`'s own character.
+fn local_player_can_act(snap: &ServerData, player_name: &str) -> bool {
+    let current = &snap.core_game_data.game_manager.pm.current_player;
+    current.kind == CharacterKind::Hero
+        && (snap.core_game_data.is_single_player
+            || snap
+                .players_data
+                .get_first_character_name(player_name)
+                .as_deref()
+                == Some(current.id_name.as_str()))
+}
+
+/// Attacks of `launcher` in panel order, with whether each can be launched now.
+async fn panel_attack_choices(
+    server_data: Signal<ServerData>,
+    atk_panel_orders: Signal<std::collections::HashMap<String, Vec<String>>>,
+    launcher: String,
+) -> Vec<(String, bool)> {
+    load_atk_panel_order(atk_panel_orders, launcher.clone()).await;
+    let snap = server_data.read();
+    let order = atk_panel_orders
+        .read()
+        .get(&launcher)
+        .cloned()
+        .unwrap_or_default();
+    snap.core_game_data
+        .game_manager
+        .pm
+        .get_active_character(&launcher)
+        .map(|c| {
+            panel_attacks(c, &order)
+                .into_iter()
+                .map(|atk| {
+                    let launchable = c
+                        .character_rounds_info
+                        .launchable_atks
+                        .iter()
+                        .any(|l| l.name == atk.name);
+                    (atk.name.clone(), launchable)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// What screen readers announce after each update: the last attack and its
+/// outcome, then whose turn it is.
+fn combat_announcement(snap: &ServerData, lang: Lang) -> String {
+    let gm = &snap.core_game_data.game_manager;
+    let ra = &gm.game_state.last_result_atk;
+    let mut parts = Vec::new();
+    if !ra.atk_name.is_empty() {
+        parts.push(t!(
+            "a11y-attack-result",
+            launcher : ra.launcher_id_name.clone(),
+            name : ra.atk_display_name_for(lang).to_owned()
+        ));
+        if ra.is_crit {
+            parts.push(t!("gameboard-critical-strike"));
+        }
+        let mut seen = std::collections::HashSet::new();
+        for d in ra
+            .all_dodging
+            .iter()
+            .filter(|d| seen.insert(d.name.clone()))
+        {
+            if d.is_dodging {
+                parts.push(t!("gameboard-is-dodging", name : d.name.clone()));
+            } else if d.is_blocking {
+                parts.push(t!("gameboard-is-blocking", name : d.name.clone()));
+            }
+        }
+        parts.extend(
+            ra.new_game_atk_effects
+                .iter()
+                .filter_map(|gae| gae.log_text())
+                .map(|text| text.to_string()),
+        );
+    }
+    let current = &gm.pm.current_player;
+    if !current.id_name.is_empty() {
+        parts.push(t!("a11y-turn", name : current.db_full_name.clone()));
+    }
+    parts.join(" ")
+}
+
+/// Target of the selected consumable: the locally-picked one, or the server's
+/// default `is_current_target` until the player picks another.
+fn consumable_target(snap: &ServerData, selected_target: &str) -> Option<String> {
+    if !selected_target.is_empty() {
+        return Some(selected_target.to_owned());
+    }
+    let pm = &snap.core_game_data.game_manager.pm;
+    pm.active_heroes
+        .iter()
+        .chain(pm.active_bosses.iter())
+        .find(|c| c.character_rounds_info.is_current_target)
+        .map(|c| c.id_name.clone())
+}
+
+/// Characters that can be picked as target right now, in board order (heroes then bosses).
+fn potential_targets(snap: &ServerData) -> Vec<String> {
+    let pm = &snap.core_game_data.game_manager.pm;
+    pm.active_heroes
+        .iter()
+        .chain(
+            pm.active_bosses
+                .iter()
+                .filter(|b| !b.stats.is_dead().unwrap_or(false)),
+        )
+        .filter(|c| c.character_rounds_info.is_potential_target)
+        .map(|c| c.id_name.clone())
+        .collect()
+}
+
+/// Uses the selected consumable ("personal:{name}" or "party:{name}") on `target_id`.
+async fn apply_consumable(
+    socket: UseWebsocket<ClientEvent, ServerEvent, CborEncoding>,
+    player: String,
+    mut selected_consumable: Signal<String>,
+    mut selected_consumable_target: Signal<String>,
+    target_id: String,
+) {
+    let consumable = selected_consumable();
+    let event = match consumable.strip_prefix("party:") {
+        Some(name) => {
+            ClientEvent::UsePartyPotion(SERVER_NAME(), player, name.to_owned(), target_id)
+        }
+        None => ClientEvent::UsePotion(
+            SERVER_NAME(),
+            player,
+            consumable.trim_start_matches("personal:").to_owned(),
+            target_id,
+        ),
+    };
+    let _ = socket.send(event).await;
+    selected_consumable.set("".to_owned());
+    selected_consumable_target.set("".to_owned());
+}
+
+async fn launch_selected_attack(
+    socket: UseWebsocket<ClientEvent, ServerEvent, CborEncoding>,
+    mut selected_atk_name: Signal<String>,
+) {
+    let _ = socket
+        .send(ClientEvent::LaunchAttack(
+            SERVER_NAME(),
+            selected_atk_name(),
+        ))
+        .await;
+    selected_atk_name.set("".to_owned());
+}
 
 #[component]
 pub fn GameBoard() -> Element {
@@ -29,35 +210,70 @@ pub fn GameBoard() -> Element {
     let socket = use_context::<UseWebsocket<ClientEvent, ServerEvent, CborEncoding>>();
     let server_data = use_context::<Signal<ServerData>>();
     let toggle_atk_animation = use_context::<CtxToggleAtkAnimation>().0;
+    let a11y = use_context::<CtxA11y>();
+    let app_lang = use_context::<CtxAppLang>().0;
+    let log_messages = use_context::<CtxLogMessages>().0;
 
     // eval server_data
     if server_data() == ServerData::default() {
         return rsx! {};
     }
 
-    let output_text_css_class = if toggle_atk_animation() {
+    let output_text_css_class = if toggle_atk_animation() || (a11y.reduced_motion)() {
         ""
     } else {
         "blink-1"
     };
 
     // local signals
-    let atk_menu_display = use_signal(|| false);
-    let potion_menu_display = use_signal(|| false);
+    let mut atk_menu_display = use_signal(|| false);
+    let mut potion_menu_display = use_signal(|| false);
     let mut selected_atk_name = use_signal(|| "".to_owned());
     // "personal:{name}" or "party:{name}" when waiting for a consumable target, "" otherwise
     let mut selected_consumable = use_signal(|| "".to_owned());
     // id_name of the character currently selected as the consumable target (empty = use server default)
     let mut selected_consumable_target = use_signal(|| "".to_owned());
 
+    // Keyboard layer: the board keeps the focus so the hotkeys work without clicking.
+    // The focused button often disappears once an attack, target or menu is picked,
+    // so focus is given back to the board whenever the selection changes.
+    let hotkeys = use_context::<CtxHotkeys>().0;
+    let atk_panel_orders = use_context::<CtxAtkPanelOrders>().0;
+    let mut board_ref: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    use_effect(move || {
+        let _ = (
+            selected_atk_name(),
+            selected_consumable(),
+            atk_menu_display(),
+            potion_menu_display(),
+        );
+        if let Some(md) = board_ref() {
+            spawn(async move {
+                let _ = md.set_focus(true).await;
+            });
+        }
+    });
+
     // Combat sound effects: fire the sfx for a new attack result exactly once. Deduped on
     // (turn_nb, round_nb, launcher_id_name) rather than watching `logs` text, since
     // `classify_result_atk` reads the same structured `ResultLaunchAttack` the server
     // already computes (see lib-rpg's `common::sound_cue`).
+    // Attacks with their own uploaded effect play it instead of the generic impact cues,
+    // panned towards the launcher's side of the board.
     let audio_settings = use_context::<CtxAudioSettings>();
+    let mut sfx_catalog: Signal<SfxCatalog> = use_signal(SfxCatalog::default);
+    use_effect(move || {
+        spawn(async move {
+            match get_sfx_catalog().await {
+                Ok(catalog) => sfx_catalog.set(catalog),
+                Err(e) => tracing::warn!("sfx catalog unavailable: {e}"),
+            }
+        });
+    });
     let mut last_sfx_result_key = use_signal(|| (0usize, 0usize, String::new()));
     use_effect(move || {
-        let ra = server_data()
+        let data = server_data();
+        let ra = data
             .core_game_data
             .game_manager
             .game_state
@@ -66,12 +282,94 @@ pub fn GameBoard() -> Element {
         let key = (ra.turn_nb, ra.round_nb, ra.launcher_id_name.clone());
         if !ra.launcher_id_name.is_empty() && key != last_sfx_result_key() {
             last_sfx_result_key.set(key);
-            for cue in classify_result_atk(&ra) {
-                audio::play_sfx(cue, audio_settings);
+            let side = if data
+                .core_game_data
+                .game_manager
+                .pm
+                .active_bosses
+                .iter()
+                .any(|b| b.id_name == ra.launcher_id_name)
+            {
+                SfxSide::Bosses
+            } else {
+                SfxSide::Heroes
+            };
+            let catalog = sfx_catalog.peek();
+            let own = catalog.attacks.get(&ra.atk_name).map(String::as_str);
+            for sfx in audio::attack_sfx(classify_result_atk(&ra), own) {
+                audio::play_sfx(&sfx, side, audio_settings);
             }
         }
     });
 
+    // Combat animation timeline: built once per attack result (same key as the sfx),
+    // its strike launched when the server's animation reset ends (`SetAtkAnimation`)
+    // so it starts with the portraits' cues, and cleared once its last cue is over.
+    let mut board_animation = use_context::<CtxCombatAnimation>().0;
+    let mut last_anim_key: Signal<Option<(usize, usize, String)>> = use_signal(|| None);
+    let mut played_strike_key = use_signal(|| None);
+    use_effect(move || {
+        let data = server_data();
+        let gm = &data.core_game_data.game_manager;
+        let ra = &gm.game_state.last_result_atk;
+        let key = (ra.turn_nb, ra.round_nb, ra.launcher_id_name.clone());
+        let previous = last_anim_key.peek().clone();
+        if previous.as_ref() == Some(&key) {
+            return;
+        }
+        last_anim_key.set(Some(key.clone()));
+        // The result already on the board when it mounts (reconnect) is not replayed.
+        if previous.is_none() || ra.launcher_id_name.is_empty() || (a11y.reduced_motion)() {
+            return;
+        }
+        let anim = CombatAnimation::from_result(ra, &gm.pm.active_heroes, &gm.pm.active_bosses);
+        let duration = anim.duration_ms();
+        board_animation.set(Some(anim));
+        spawn(async move {
+            dioxus_sdk_time::sleep(std::time::Duration::from_millis(u64::from(duration))).await;
+            if board_animation
+                .peek()
+                .as_ref()
+                .is_some_and(|a| a.key == key)
+            {
+                board_animation.set(None);
+            }
+        });
+    });
+    use_effect(move || {
+        if toggle_atk_animation() {
+            return;
+        }
+        if let Some(anim) = board_animation()
+            && played_strike_key.peek().as_ref() != Some(&anim.key)
+        {
+            played_strike_key.set(Some(anim.key.clone()));
+            play_strike(&anim);
+        }
+    });
+
+    // Boss entrance: a cue from the bosses' side whenever a boss joins the board. On
+    // mount (reconnect, spectating) only at the start of a fight, not mid-combat.
+    let mut known_bosses: Signal<Option<BTreeSet<String>>> = use_signal(|| None);
+    use_effect(move || {
+        let data = server_data();
+        let gm = &data.core_game_data.game_manager;
+        let bosses: BTreeSet<String> = gm
+            .pm
+            .active_bosses
+            .iter()
+            .map(|b| b.id_name.clone())
+            .collect();
+        let entered = match known_bosses.peek().as_ref() {
+            Some(known) => bosses.iter().any(|b| !known.contains(b)),
+            None => !bosses.is_empty() && gm.game_state.current_turn_nb <= 1,
+        };
+        if entered {
+            audio::play_sfx(&Sfx::BossEntrance, SfxSide::Bosses, audio_settings);
+        }
+        known_bosses.set(Some(bosses));
+    });
+
     // spectator: player has no character in active heroes
     let local_session_player_name = use_context::<Signal<String>>();
     let my_character = server_data()
@@ -92,12 +390,217 @@ pub fn GameBoard() -> Element {
         })
     };
 
+    // Shared by the keyboard (`on_keydown`) and the gamepad (`use_pad_action`).
+    let run_input = move |input: CombatInput| async move {
+        let player = local_session_player_name();
+        if !local_player_can_act(&server_data.read(), &player) {
+            return;
+        }
+        let (launcher, targets, atk_target, use_target) = {
+            let snap = server_data.read();
+            let pm = &snap.core_game_data.game_manager.pm;
+            let atk_target = pm
+                .active_heroes
+                .iter()
+                .chain(pm.active_bosses.iter())
+                .find(|c| c.character_rounds_info.is_current_target)
+                .map(|c| c.id_name.clone());
+            (
+                pm.current_player.id_name.clone(),
+                potential_targets(&snap),
+                atk_target,
+                consumable_target(&snap, &selected_consumable_target()),
+            )
+        };
+        // potions are picked instead of attacks while their list is open, and
+        // stepping keeps cycling potions once one is selected
+        let potion_mode = potion_menu_display()
+            || (!selected_consumable().is_empty() && matches!(input, CombatInput::StepAttack(_)));
+
+        match input {
+            CombatInput::Slot(_) | CombatInput::StepAttack(_) if potion_mode => {
+                let slots = consumable_slots(&server_data.read(), &launcher);
+                let choice = match input {
+                    CombatInput::Slot(slot) => slots.get(slot).cloned(),
+                    _ => cycle_selection(
+                        &slots,
+                        Some(selected_consumable().as_str()),
+                        input == CombatInput::StepAttack(true),
+                    ),
+                };
+                if let Some(choice) = choice {
+                    selected_atk_name.set("".to_owned());
+                    selected_consumable_target.set("".to_owned());
+                    select_consumable(
+                        socket,
+                        player,
+                        choice,
+                        selected_consumable,
+                        potion_menu_display,
+                    )
+                    .await;
+                }
+            }
+            CombatInput::Slot(_) | CombatInput::StepAttack(_) => {
+                let choices =
+                    panel_attack_choices(server_data, atk_panel_orders, launcher.clone()).await;
+                let atk_name = match input {
+                    CombatInput::Slot(slot) => choices
+                        .get(slot)
+                        .filter(|(_, launchable)| *launchable)
+                        .map(|(name, _)| name.clone()),
+                    _ => {
+                        let launchable: Vec<String> = choices
+                            .into_iter()
+                            .filter_map(|(name, launchable)| launchable.then_some(name))
+                            .collect();
+                        cycle_selection(
+                            &launchable,
+                            Some(selected_atk_name().as_str()),
+                            input == CombatInput::StepAttack(true),
+                        )
+                    }
+                };
+                if let Some(atk_name) = atk_name {
+                    selected_consumable.set("".to_owned());
+                    selected_consumable_target.set("".to_owned());
+                    select_attack(
+                        socket,
+                        launcher,
+                        atk_name,
+                        selected_atk_name,
+                        atk_menu_display,
+                    )
+                    .await;
+                }
+            }
+            CombatInput::StepTarget(forward) => {
+                if !selected_consumable().is_empty() {
+                    if let Some(target) = cycle_selection(&targets, use_target.as_deref(), forward)
+                    {
+                        selected_consumable_target.set(target);
+                    }
+                } else if !selected_atk_name().is_empty()
+                    && let Some(target) = cycle_selection(&targets, atk_target.as_deref(), forward)
+                {
+                    let _ = socket
+                        .send(ClientEvent::RequestSetOneTarget(
+                            SERVER_NAME(),
+                            launcher,
+                            selected_atk_name(),
+                            target,
+                        ))
+                        .await;
+                }
+            }
+            CombatInput::Confirm => {
+                if !selected_consumable().is_empty() {
+                    if let Some(target) = use_target {
+                        apply_consumable(
+                            socket,
+                            player,
+                            selected_consumable,
+                            selected_consumable_target,
+                            target,
+                        )
+                        .await;
+                    }
+                } else if !selected_atk_name().is_empty() {
+                    launch_selected_attack(socket, selected_atk_name).await;
+                } else {
+                    atk_menu_display.set(true);
+                    potion_menu_display.set(false);
+                }
+            }
+            CombatInput::Cancel => {
+                selected_atk_name.set("".to_owned());
+                selected_consumable.set("".to_owned());
+                selected_consumable_target.set("".to_owned());
+                atk_menu_display.set(false);
+                potion_menu_display.set(false);
+            }
+            CombatInput::ToggleAttacks => {
+                atk_menu_display.set(!atk_menu_display());
+                potion_menu_display.set(false);
+            }
+            CombatInput::TogglePotions => {
+                potion_menu_display.set(!potion_menu_display());
+                atk_menu_display.set(false);
+            }
+        }
+    };
+
+    let on_keydown = move |e: KeyboardEvent| async move {
+        let key = normalize_key(&e.key().to_string());
+        let shift = e.modifiers().contains(Modifiers::SHIFT);
+        // Tab and the arrows only change target while an attack or potion is
+        // selected, otherwise they keep their usual browser behaviour.
+        let targeting = !selected_atk_name().is_empty() || !selected_consumable().is_empty();
+        let input = match (slot_for_key(&key), hotkeys.read().action_for(&key)) {
+            (Some(slot), _) => Some(CombatInput::Slot(slot)),
+            (_, Some(HotkeyAction::Confirm)) => Some(CombatInput::Confirm),
+            (_, Some(HotkeyAction::Cancel)) => Some(CombatInput::Cancel),
+            (_, Some(HotkeyAction::ToggleAttacks)) => Some(CombatInput::ToggleAttacks),
+            (_, Some(HotkeyAction::TogglePotions)) => Some(CombatInput::TogglePotions),
+            _ if !targeting => None,
+            _ if matches!(key.as_str(), "ArrowRight" | "ArrowDown") => {
+                Some(CombatInput::StepTarget(true))
+            }
+            _ if matches!(key.as_str(), "ArrowLeft" | "ArrowUp") => {
+                Some(CombatInput::StepTarget(false))
+            }
+            (_, Some(HotkeyAction::NextTarget)) => Some(CombatInput::StepTarget(!shift)),
+            (_, Some(HotkeyAction::PreviousTarget)) => Some(CombatInput::StepTarget(shift)),
+            _ => None,
+        };
+        let Some(input) = input else {
+            return;
+        };
+        if !local_player_can_act(&server_data.read(), &local_session_player_name()) {
+            return;
+        }
+        e.prevent_default();
+        run_input(input).await;
+    };
+
+    use_pad_action(move |action| {
+        let input = match action {
+            PadAction::Up => CombatInput::StepAttack(false),
+            PadAction::Down => CombatInput::StepAttack(true),
+            PadAction::Left | PadAction::PreviousTarget => CombatInput::StepTarget(false),
+            PadAction::Right | PadAction::NextTarget => CombatInput::StepTarget(true),
+            PadAction::Confirm => CombatInput::Confirm,
+            PadAction::Cancel => CombatInput::Cancel,
+            PadAction::ToggleAttacks => CombatInput::ToggleAttacks,
+            PadAction::TogglePotions => CombatInput::TogglePotions,
+            PadAction::OpenMenu => return,
+        };
+        spawn(run_input(input));
+    });
+
     // Display the game board with characters and attacks
+    let announcement = combat_announcement(&server_data.read(), lang_from_app_lang(&app_lang()));
+
     rsx! {
+        // Screen readers announce each attack result and turn change from here.
+        div {
+            class: "sr-only",
+            role: "status",
+            aria_live: "polite",
+            aria_atomic: "true",
+            "{announcement}"
+        }
         if is_spectator {
             div { class: "spectator-banner", {t!("gameboard-spectator-mode")} }
+        } else {
+            InputPrompts { context: PromptContext::Combat }
         }
-        div { class: "grid-board",
+        div {
+            class: "grid-board",
+            tabindex: "0",
+            aria_label: t!("gameboard-keyboard-hint"),
+            onmounted: move |e| board_ref.set(Some(e.data())),
+            onkeydown: on_keydown,
             div {
                 // Heroes
                 for c in server_data.read().core_game_data.game_manager.pm.active_heroes.iter() {
@@ -132,50 +635,23 @@ pub fn GameBoard() -> Element {
                         }
                     } else if !selected_consumable().is_empty() {
                         {
-                            // Resolve target: prefer the locally-selected one, fall back
-                            // to the server's default is_current_target.
-                            let snap = server_data.read();
-                            let pm = &snap.core_game_data.game_manager.pm;
-                            let server_default = pm
-                                .active_heroes
-                                .iter()
-                                .chain(pm.active_bosses.iter())
-                                .find(|c| c.character_rounds_info.is_current_target)
-                                .map(|c| c.id_name.clone());
-                            drop(snap);
-                            let resolved_target = if !selected_consumable_target().is_empty() {
-                                Some(selected_consumable_target())
-                            } else {
-                                server_default
-                            };
-                            let local_player = use_context::<Signal<String>>()();
-                            let consumable_val = selected_consumable();
+                            let resolved_target = consumable_target(
+                                &server_data.read(),
+                                &selected_consumable_target(),
+                            );
+                            let local_player = local_session_player_name();
                             rsx! {
                                 if let Some(target_id) = resolved_target {
                                     Button {
                                         variant: ButtonVariant::Primary,
                                         onclick: move |_| {
-                                            let tid = target_id.clone();
-                                            let cval = consumable_val.clone();
-                                            let player = local_player.clone();
-                                            async move {
-                                                let (is_party, name) = if let Some(n) = cval.strip_prefix("party:") {
-                                                    (true, n.to_owned())
-                                                } else {
-                                                    (false, cval.trim_start_matches("personal:").to_owned())
-                                                };
-                                                if is_party {
-                                                    let _ = socket
-                                                        .send(ClientEvent::UsePartyPotion(SERVER_NAME(), player, name, tid))
-                                                        .await;
-                                                } else {
-                                                    let _ = socket
-                                                        .send(ClientEvent::UsePotion(SERVER_NAME(), player, name, tid))
-                                                        .await;
-                                                }
-                                                selected_consumable.set("".to_owned());
-                                                selected_consumable_target.set("".to_owned());
-                                            }
+                                            apply_consumable(
+                                                socket,
+                                                local_player.clone(),
+                                                selected_consumable,
+                                                selected_consumable_target,
+                                                target_id.clone(),
+                                            )
                                         },
                                         {t!("gameboard-use")}
                                     }
@@ -190,10 +666,7 @@ pub fn GameBoard() -> Element {
                                     "launcher {} {}", server_data.read().core_game_data.game_manager.game_state
                                     .last_result_atk.launcher_id_name, selected_atk_name()
                                 );
-                                let _ = socket
-                                    .send(ClientEvent::LaunchAttack(SERVER_NAME(), selected_atk_name()))
-                                    .await;
-                                selected_atk_name.set("".to_owned());
+                                launch_selected_attack(socket, selected_atk_name).await;
                             },
                             {t!("gameboard-launch-attack")}
                         }
@@ -208,7 +681,7 @@ pub fn GameBoard() -> Element {
                                 .active_bosses
                                 .iter()
                                 .any(|b| b.id_name == ra.launcher_id_name);
-                            let last_action_header = snap.core_game_data.last_action_header.clone();
+                            let last_action_header = log_messages.read().localize(&snap.core_game_data.last_action_header);
                             drop(snap);
                             let banner_class = if is_boss_atk {
                                 "boss-atk-banner"
@@ -250,7 +723,7 @@ pub fn GameBoard() -> Element {
                                                 let msg = log.message.replace('\n', "<br/>");
                                                 rsx! {
                                                     div {
-                                                        style: "color: {log.color}; font-size: 0.82rem; padding: 1px 0;",
+                                                        style: "color: {log_color((a11y.palette)(), &log.color)}; font-size: 0.82rem; padding: 1px 0;",
                                                         dangerous_inner_html: "{msg}",
                                                     }
                                                 }
@@ -274,7 +747,7 @@ pub fn GameBoard() -> Element {
                             .active_bosses
                             .iter()
                             .any(|b| b.id_name == ra.launcher_id_name);
-                        let last_action_header = snap.core_game_data.last_action_header.clone();
+                        let last_action_header = log_messages.read().localize(&snap.core_game_data.last_action_header);
                         drop(snap);
                         let banner_class = if is_boss_atk {
                             "boss-atk-banner"
@@ -317,6 +790,7 @@ pub fn GameBoard() -> Element {
 #[component]
 pub fn ResultAtkText(ra: ResultLaunchAttack) -> Element {
     let app_lang = use_context::<CtxAppLang>().0;
+    let palette = use_context::<CtxA11y>().palette;
     let lang = lang_from_app_lang(&app_lang());
     let display_atk_name = ra.atk_display_name_for(lang).to_owned();
 
@@ -378,7 +852,7 @@ pub fn ResultAtkText(ra: ResultLaunchAttack) -> Element {
                     let msg = log.message.replace('\n', "<br/>");
                     rsx! {
                         div {
-                            style: "color: {log.color}; font-size: 0.85rem; padding: 1px 0;",
+                            style: "color: {log_color(palette(), &log.color)}; font-size: 0.85rem; padding: 1px 0;",
                             dangerous_inner_html: "{msg}",
                         }
                     }
@@ -6,7 +6,7 @@ use dioxus_i18n::t;
 use lib_rpg::server::server_manager::{GamePhase, ServerData};
 
 use crate::{
-    board_game_components::login_page::LoginPage,
+    board_game_components::{daily_challenge_card::DailyChallengeCard, login_page::LoginPage},
     common::{DISCONNECTED_USER, Route},
     websocket_handler::event::{ClientEvent, ServerEvent},
 };
@@ -60,6 +60,7 @@ pub fn Home() -> Element {
                         p { class: "action-desc", {t!("home-join-game-desc")} }
                     }
                 }
+                DailyChallengeCard {}
             }
         }
     }
@@ -0,0 +1,186 @@
+use std::collections::BTreeMap;
+
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::auth_manager::server_fn::{
+    LeaderboardCategory, LeaderboardEntry, LeaderboardPeriod, LeaderboardQuery, get_leaderboard,
+    list_leaderboard_scopes,
+};
+
+fn category_label(category: LeaderboardCategory) -> String {
+    match category {
+        LeaderboardCategory::FastestClear => t!("leaderboard-category-fastest"),
+        LeaderboardCategory::MostDamage => t!("leaderboard-category-damage"),
+        LeaderboardCategory::FewestPotions => t!("leaderboard-category-potions"),
+    }
+}
+
+/// Best ranked clears of a scenario or of a whole universe, filtered by
+/// category, solo or multiplayer games, and week or all time.
+#[component]
+pub fn LeaderboardPage() -> Element {
+    let mut scopes: Signal<BTreeMap<String, Vec<String>>> = use_signal(BTreeMap::new);
+    let mut query: Signal<LeaderboardQuery> = use_signal(|| LeaderboardQuery {
+        single_player: true,
+        ..Default::default()
+    });
+    let mut entries: Signal<Vec<LeaderboardEntry>> = use_signal(Vec::new);
+    let mut loading = use_signal(|| true);
+    let mut error_msg = use_signal(String::new);
+
+    use_effect(move || {
+        spawn(async move {
+            match list_leaderboard_scopes().await {
+                Ok(list) => {
+                    if let Some(universe) = list.keys().next() {
+                        query.write().universe = universe.clone();
+                    } else {
+                        loading.set(false);
+                    }
+                    scopes.set(list);
+                }
+                Err(e) => {
+                    error_msg.set(t!("leaderboard-fetch-error", error : e.to_string()));
+                    loading.set(false);
+                }
+            }
+        });
+    });
+    // Re-fetch on every filter change
+    use_effect(move || {
+        let query = query();
+        if query.universe.is_empty() {
+            return;
+        }
+        loading.set(true);
+        spawn(async move {
+            match get_leaderboard(query).await {
+                Ok(list) => {
+                    entries.set(list);
+                    error_msg.set(String::new());
+                }
+                Err(e) => error_msg.set(t!("leaderboard-fetch-error", error : e.to_string())),
+            }
+            loading.set(false);
+        });
+    });
+
+    let current = query();
+    let scenarios = scopes().get(&current.universe).cloned().unwrap_or_default();
+
+    rsx! {
+        div { class: "home-container",
+            h2 { class: "rpg-title", {t!("leaderboard-title")} }
+            p { class: "settings-hint", {t!("leaderboard-hint")} }
+
+            div { class: "leaderboard-filters",
+                select {
+                    class: "admin-select",
+                    aria_label: t!("leaderboard-universe"),
+                    onchange: move |e| {
+                        let mut q = query.write();
+                        q.universe = e.value();
+                        q.scenario = String::new();
+                    },
+                    for universe in scopes().into_keys() {
+                        option {
+                            value: "{universe}",
+                            selected: universe == current.universe,
+                            "{universe}"
+                        }
+                    }
+                }
+                select {
+                    class: "admin-select",
+                    aria_label: t!("leaderboard-scenario"),
+                    onchange: move |e| query.write().scenario = e.value(),
+                    option { value: "", selected: current.scenario.is_empty(), {t!("leaderboard-whole-universe")} }
+                    for scenario in scenarios {
+                        option {
+                            value: "{scenario}",
+                            selected: scenario == current.scenario,
+                            "{scenario}"
+                        }
+                    }
+                }
+                select {
+                    class: "admin-select",
+                    aria_label: t!("leaderboard-category"),
+                    onchange: move |e| {
+                        if let Some(category) = LeaderboardCategory::ALL
+                            .into_iter()
+                            .find(|c| format!("{c:?}") == e.value())
+                        {
+                            query.write().category = category;
+                        }
+                    },
+                    for category in LeaderboardCategory::ALL {
+                        option {
+                            value: "{category:?}",
+                            selected: category == current.category,
+                            {category_label(category)}
+                        }
+                    }
+                }
+                select {
+                    class: "admin-select",
+                    aria_label: t!("leaderboard-mode"),
+                    onchange: move |e| query.write().single_player = e.value() == "solo",
+                    option { value: "solo", selected: current.single_player, {t!("leaderboard-solo")} }
+                    option { value: "multi", selected: !current.single_player, {t!("leaderboard-multiplayer")} }
+                }
+                select {
+                    class: "admin-select",
+                    aria_label: t!("leaderboard-period"),
+                    onchange: move |e| {
+                        query.write().period = if e.value() == "weekly" {
+                            LeaderboardPeriod::Weekly
+                        } else {
+                            LeaderboardPeriod::AllTime
+                        };
+                    },
+                    option { value: "weekly", selected: current.period == LeaderboardPeriod::Weekly, {t!("leaderboard-weekly")} }
+                    option { value: "all-time", selected: current.period == LeaderboardPeriod::AllTime, {t!("leaderboard-all-time")} }
+                }
+            }
+
+            if !error_msg().is_empty() {
+                p { class: "admin-answer-error", "{error_msg}" }
+            }
+
+            if loading() {
+                p { class: "rpg-no-data", {t!("common-loading")} }
+            } else if entries().is_empty() {
+                p { class: "rpg-no-data", {t!("leaderboard-empty")} }
+            } else {
+                table { class: "admin-table leaderboard-table",
+                    thead {
+                        tr {
+                            th { "#" }
+                            th { {t!("leaderboard-col-players")} }
+                            th { class: "col-description", {t!("leaderboard-col-heroes")} }
+                            th { {t!("leaderboard-col-turns")} }
+                            th { {t!("leaderboard-col-damage")} }
+                            th { {t!("leaderboard-col-potions")} }
+                            th { {t!("leaderboard-col-date")} }
+                        }
+                    }
+                    tbody {
+                        for (rank , entry) in entries().into_iter().enumerate() {
+                            tr { class: if rank < 3 { "leaderboard-podium" } else { "" },
+                                td { class: "leaderboard-rank", "{rank + 1}" }
+                                td { "{entry.players}" }
+                                td { class: "col-description", "{entry.heroes}" }
+                                td { "{entry.turns}" }
+                                td { "{entry.damage}" }
+                                td { "{entry.potions}" }
+                                td { "{entry.finished_at}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
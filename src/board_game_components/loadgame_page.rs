@@ -3,6 +3,7 @@ use dioxus::prelude::*;
 use dioxus::{fullstack::UseWebsocket, logger::tracing};
 use dioxus_i18n::t;
 
+use crate::board_game_components::difficulty_select::settings_badge;
 use crate::utils::server_file_utils::{SaveSlotInfo, delete_game, get_save_slots};
 use crate::websocket_handler::event::{ClientEvent, ServerEvent};
 use crate::{
@@ -95,6 +96,9 @@ pub fn LoadGame() -> Element {
                                                     {t!("loadgame-universe", universe : slot.universe.clone())}
                                                 }
                                             }
+                                            if !settings_badge(&slot.settings).is_empty() {
+                                                span { class: "save-slot-difficulty", "⚔️ {settings_badge(&slot.settings)}" }
+                                            }
                                         }
                                     }
                                     if is_selected {
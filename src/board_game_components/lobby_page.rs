@@ -8,9 +8,14 @@ use lib_rpg::server::server_manager::{GamePhase, ServerData};
 
 use crate::components::button::ButtonVariant;
 use crate::{
-    auth_manager::server_fn::list_universes_server,
+    auth_manager::server_fn::{
+        get_server_arena_match, get_server_daily_challenge, get_server_endless_run,
+        list_universes_server,
+    },
     board_game_components::{
-        character_select::CharacterSelect, common_comp::ButtonLink, startgame_page::RunningGamePage,
+        character_select::CharacterSelect, common_comp::ButtonLink,
+        daily_challenge_card::ChallengeRules, difficulty_select::DifficultySelect,
+        startgame_page::RunningGamePage,
     },
     common::{Route, SERVER_NAME},
     components::button::Button,
@@ -39,8 +44,45 @@ pub fn LobbyPage() -> Element {
             selected_universe.set(u);
         }
     });
-    // A loaded game already has a universe that should not be changed
-    let universe_locked = server_data().core_game_data.loaded_from_save;
+    // A daily challenge comes with its scenario, heroes and modifiers
+    // (re-fetched when the new game's universe arrives, not on every update)
+    let game_universe = use_memo(move || server_data.read().core_game_data.universe.clone());
+    let daily_challenge_resource = use_resource(move || {
+        game_universe();
+        get_server_daily_challenge(SERVER_NAME())
+    });
+    use_effect(move || {
+        if let Some(Ok(Some(challenge))) = &*daily_challenge_resource.read()
+            && *selected_universe.peek() != challenge.universe
+        {
+            selected_universe.set(challenge.universe.clone());
+        }
+    });
+    let daily_challenge = daily_challenge_resource
+        .read()
+        .as_ref()
+        .and_then(|r| r.as_ref().ok())
+        .cloned()
+        .flatten();
+    let endless_run_resource = use_resource(move || {
+        game_universe();
+        get_server_endless_run(SERVER_NAME())
+    });
+    let is_endless = matches!(&*endless_run_resource.read(), Some(Ok(Some(_))));
+    // An arena match comes with both parties
+    let arena_match_resource = use_resource(move || {
+        game_universe();
+        get_server_arena_match(SERVER_NAME())
+    });
+    let arena_match = arena_match_resource
+        .read()
+        .as_ref()
+        .and_then(|r| r.as_ref().ok())
+        .cloned()
+        .flatten();
+    // A loaded game or a daily challenge already has a universe that should not be changed
+    let universe_locked =
+        server_data().core_game_data.loaded_from_save || daily_challenge.is_some();
 
     // all players info have a character name
     let server_data_snap = server_data();
@@ -124,6 +166,47 @@ pub fn LobbyPage() -> Element {
                     }
                 }
 
+                if let Some(challenge) = &daily_challenge {
+                    div { class: "daily-card lobby-daily",
+                        h3 { class: "daily-title", {t!("daily-title")} }
+                        ChallengeRules { challenge: challenge.clone() }
+                    }
+                }
+                if is_endless {
+                    div { class: "endless-card lobby-endless",
+                        h3 { class: "endless-title", {t!("endless-title")} }
+                        p { class: "endless-desc", {t!("endless-lobby-desc")} }
+                    }
+                }
+                if let Some(arena_match) = &arena_match {
+                    div { class: "arena-card lobby-arena",
+                        h3 { class: "arena-section-title",
+                            {t!("arena-lobby-title", defender : arena_match.defender.clone())}
+                        }
+                        p { class: "settings-hint",
+                            {
+                                t!(
+                                    "arena-lobby-ratings", attacker : arena_match.attacker_rating, defender :
+                                    arena_match.defender_rating
+                                )
+                            }
+                        }
+                        div { class: "arena-lobby-parties",
+                            div {
+                                for hero in server_data_snap.core_game_data.game_manager.pm.active_heroes.iter() {
+                                    span { class: "arena-hero-chip", "{hero.db_full_name}" }
+                                }
+                            }
+                            span { class: "arena-versus", "⚔️" }
+                            div {
+                                for hero in arena_match.defender_heroes.iter() {
+                                    span { class: "arena-hero-chip", "{hero}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
                 // Start game button (host only, when all players have picked a character)
                 if SERVER_NAME() == local_login_name_session() && all_players_have_character_name
                     && (server_data_snap.core_game_data.game_phase == GamePhase::InitGame
@@ -140,7 +223,8 @@ pub fn LobbyPage() -> Element {
                     }
                 }
 
-                // Universe selector — hidden when loading a saved game (universe already fixed)
+                // Universe selector — hidden when loading a saved game (universe already fixed),
+                // left out of an arena match, fought in the universe of the party
                 {
                     let universes = universes_resource
                         .read()
@@ -148,10 +232,18 @@ pub fn LobbyPage() -> Element {
                         .and_then(|r| r.as_ref().ok())
                         .cloned()
                         .unwrap_or_default();
-                    if universe_locked {
+                    if arena_match.is_some() {
+                        rsx! {}
+                    } else if universe_locked {
                         rsx! {
                             div { class: "lobby-universe-select",
-                                label { class: "lobby-info-label", {t!("lobby-universe-saved-label")} }
+                                label { class: "lobby-info-label",
+                                    if daily_challenge.is_some() {
+                                        {t!("lobby-universe-daily-label")}
+                                    } else {
+                                        {t!("lobby-universe-saved-label")}
+                                    }
+                                }
                                 div { class: "lobby-universe-locked",
                                     {t!("lobby-universe-locked", universe : selected_universe())}
                                 }
@@ -183,9 +275,18 @@ pub fn LobbyPage() -> Element {
                     }
                 }
 
+                // A daily challenge is played as its rules were drawn, an arena match
+                // at the standard difficulty
+                if daily_challenge.is_none() && arena_match.is_none() {
+                    DifficultySelect { locked: server_data_snap.core_game_data.loaded_from_save }
+                }
+
                 // Character selection — only shown once a universe is chosen
-                if !selected_universe().is_empty() {
-                    CharacterSelect { universe: selected_universe() }
+                if !selected_universe().is_empty() && arena_match.is_none() {
+                    CharacterSelect {
+                        universe: selected_universe(),
+                        allowed_heroes: daily_challenge.as_ref().map(|c| c.heroes.clone()).unwrap_or_default(),
+                    }
                 }
             }
         } else if server_data_snap.core_game_data.game_phase == GamePhase::Running
@@ -13,6 +13,7 @@ use crate::{
         button::{Button, ButtonVariant},
         input::Input,
     },
+    server_messages::localize_error,
 };
 
 #[component]
@@ -124,7 +125,7 @@ pub fn LoginPage() -> Element {
                                 }
                                 Err(e) => {
                                     tracing::info!("{}", e.to_owned());
-                                    logon_answer.set(format!("{}", e.to_owned()));
+                                    logon_answer.set(localize_error(&e));
                                 }
                             }
                         },
@@ -196,7 +197,7 @@ pub fn LoginPage() -> Element {
                                 }
                                 Err(e) => {
                                     tracing::info!("{}", e.to_owned());
-                                    register_answer.set(t!("login-name-taken"));
+                                    register_answer.set(localize_error(&e));
                                 }
                             }
                         },
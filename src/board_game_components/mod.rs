@@ -1,21 +1,35 @@
 pub mod admin_page;
 pub mod admin_tab_attacks;
 pub mod admin_tab_characters;
+pub mod admin_tab_content_packs;
 pub mod admin_tab_equipment;
+pub mod admin_tab_history;
+pub mod admin_tab_music;
+pub mod admin_tab_recipes;
 pub mod admin_tab_scenarios;
+pub mod admin_tab_simulator;
+pub mod admin_tab_translations;
 pub mod admin_tab_users;
+pub mod arena_page;
 pub mod character_page;
 pub mod character_select;
 pub mod common_comp;
+pub mod crafting_panel;
 pub mod create_server_page;
+pub mod daily_challenge_card;
+pub mod difficulty_select;
+pub mod endless_run;
 pub mod game_sheets;
 pub mod gameboard;
 pub mod home_page;
 pub mod joinongoinggame_page;
+pub mod leaderboard_page;
 pub mod loadgame_page;
 pub mod lobby_page;
 pub mod login_page;
 pub mod navbar;
 pub mod overworld;
 pub mod popover_comp;
+pub mod profile_page;
 pub mod startgame_page;
+pub mod trade_panel;
@@ -8,13 +8,17 @@ use dioxus::{
     prelude::*,
 };
 use dioxus_i18n::t;
-use lib_rpg::server::server_manager::{GamePhase, ServerData};
+use lib_rpg::server::{
+    game_state::GameStatus,
+    server_manager::{GamePhase, ServerData},
+};
 
 use crate::{
-    audio::{self, MusicTrack},
-    auth_manager::server_fn::{change_password, get_use_password, logout},
+    audio,
+    auth_manager::server_fn::{change_password, get_music_catalog, get_use_password, logout},
     common::{
-        ADMIN, CtxAppLang, CtxAudioSettings, CtxSyncedInsecureCerts, CtxSyncedServerUrl, Route,
+        ADMIN, CtxAppLang, CtxAudioSettings, CtxLocales, CtxSyncedInsecureCerts,
+        CtxSyncedServerUrl, Route,
     },
     components::{
         alert_dialog::{
@@ -25,10 +29,14 @@ use crate::{
         input::Input,
         sidebar::{Sidebar, SidebarTrigger},
     },
+    locales::{LocaleInfo, available_locales},
+    music::{MusicCatalog, MusicScene, MusicSource},
+    server_messages::localize_error,
     websocket_handler::{
         event::{ClientEvent, ServerEvent},
         msg_from_client::send_disconnect_from_server_data as send_quit,
     },
+    widgets::achievement_toasts::AchievementToasts,
 };
 
 /// Whether the admin-panel link should be shown for this logged-in username.
@@ -48,6 +56,20 @@ fn is_signed_in(username: &str) -> bool {
 }
 
 /// Shared navbar component.
+/// Language selector entry: the locale's own name, with its completeness when some
+/// texts still fall back to English.
+fn locale_option_label(locale: &LocaleInfo) -> String {
+    if locale.completeness < 100 {
+        t!(
+            "lang-option-partial",
+            name : locale.name.clone(),
+            percent : locale.completeness
+        )
+    } else {
+        locale.name.clone()
+    }
+}
+
 #[component]
 pub fn Navbar() -> Element {
     // contexts
@@ -56,6 +78,8 @@ pub fn Navbar() -> Element {
     let mut local_login_id_session = use_context::<Signal<i64>>();
     let server_data = use_context::<Signal<ServerData>>();
     let mut app_lang = use_context::<CtxAppLang>().0;
+    // Bundled plus server-served locales, see locales.rs
+    let locales = available_locales(&use_context::<CtxLocales>().0.read());
     // Native clients only — see the doc comment on CtxSyncedServerUrl in common.rs for why
     // these are declared in App() and consumed here via context rather than called
     // directly with use_synced_storage in Navbar (a #[layout(...)] component).
@@ -72,22 +96,43 @@ pub fn Navbar() -> Element {
     let mut sound_settings_open = use_signal(|| false);
 
     // Which background track (if any) should be playing, decided from the current
-    // GamePhase. Navbar is the shared #[layout] component mounted on every route, so
-    // this is the one place music transitions are decided — avoids each page having to
-    // remember to stop music it started on unmount.
-    let mut current_music_track: Signal<Option<MusicTrack>> = use_signal(|| None);
+    // GamePhase and the server's music catalog. Navbar is the shared #[layout]
+    // component mounted on every route, so this is the one place music transitions
+    // are decided — avoids each page having to remember to stop music it started on
+    // unmount.
+    let mut music_catalog: Signal<MusicCatalog> = use_signal(MusicCatalog::default);
     use_effect(move || {
-        let desired = match server_data().core_game_data.game_phase {
-            GamePhase::Overworld => Some(MusicTrack::Overworld),
-            GamePhase::Running => None, // silence during combat — sfx read more clearly
-            _ => Some(MusicTrack::Home),
+        spawn(async move {
+            match get_music_catalog().await {
+                Ok(catalog) => music_catalog.set(catalog),
+                Err(e) => tracing::warn!("music catalog unavailable: {e}"),
+            }
+        });
+    });
+    let mut current_music: Signal<Option<MusicSource>> = use_signal(|| None);
+    use_effect(move || {
+        let data = server_data();
+        let core = &data.core_game_data;
+        let scene = match core.game_phase {
+            GamePhase::Overworld => Some(MusicScene::Overworld {
+                map_id: core.overworld.as_ref().map_or("", |ow| ow.map_id.as_str()),
+            }),
+            GamePhase::Running => match core.game_manager.game_state.status {
+                GameStatus::EndOfScenario => Some(MusicScene::Victory),
+                GameStatus::EndOfGame => None,
+                _ => Some(MusicScene::Combat {
+                    scenario: &core.game_manager.current_scenario.name,
+                }),
+            },
+            _ => Some(MusicScene::Menu),
         };
-        if desired != current_music_track() {
-            current_music_track.set(desired);
-            match desired {
-                Some(track) => audio::play_music(track, audio_settings),
+        let desired = scene.and_then(|scene| music_catalog().resolve(&core.universe, scene));
+        if desired != *current_music.peek() {
+            match &desired {
+                Some(source) => audio::play_music(source, audio_settings),
                 None => audio::stop_music(),
             }
+            current_music.set(desired);
         }
     });
 
@@ -105,7 +150,11 @@ pub fn Navbar() -> Element {
             .clone();
         if !consumable_use.launcher_id_name.is_empty() && consumable_use.seq != last_potion_seq() {
             last_potion_seq.set(consumable_use.seq);
-            audio::play_sfx(lib_rpg::common::sound_cue::SoundCue::Potion, audio_settings);
+            audio::play_sfx(
+                &audio::Sfx::Cue(lib_rpg::common::sound_cue::SoundCue::Potion),
+                audio::SfxSide::Heroes,
+                audio_settings,
+            );
         }
     });
 
@@ -163,6 +212,18 @@ pub fn Navbar() -> Element {
                             {t!("navbar-admin-panel")}
                         }
                     }
+                    if is_signed_in(&snap_local_login_name_session) {
+                        Link {
+                            class: "navbar-admin-link",
+                            to: Route::LeaderboardPage {},
+                            {t!("navbar-leaderboards")}
+                        }
+                        Link {
+                            class: "navbar-admin-link",
+                            to: Route::ArenaPage {},
+                            {t!("navbar-arena")}
+                        }
+                    }
                 }
                 // Mobile-only hamburger trigger — opens the Sidebar drawer below.
                 // Hidden on desktop, shown ≤768px (see .navbar-mobile-trigger in main.css).
@@ -179,8 +240,9 @@ pub fn Navbar() -> Element {
                         "aria-label": t!("lang-select-label"),
                         value: "{app_lang()}",
                         onchange: move |e| app_lang.set(e.value()),
-                        option { value: "en", "🇬🇧 English" }
-                        option { value: "fr", "🇫🇷 Français" }
+                        for locale in locales.iter() {
+                            option { value: "{locale.code}", {locale_option_label(locale)} }
+                        }
                     }
                     // Help trigger
                     Button {
@@ -232,7 +294,13 @@ pub fn Navbar() -> Element {
                         }
                     }
                     if is_signed_in(&snap_local_login_name_session) {
-                        span { class: "navbar-user", "👤 {snap_local_login_name_session}" }
+                        Link {
+                            class: "navbar-user",
+                            to: Route::ProfilePage {
+                                username: snap_local_login_name_session.clone(),
+                            },
+                            "👤 {snap_local_login_name_session}"
+                        }
                     }
                     Button {
                         style: "width: 160px; box-sizing: border-box; text-align: center; white-space: nowrap;",
@@ -302,6 +370,7 @@ pub fn Navbar() -> Element {
                             p { {t!("help-step-6")} }
                             p { {t!("help-step-7")} }
                             p { {t!("help-step-8")} }
+                            p { "    " {t!("help-combat-keyboard")} }
 
                             // Toolbar
                             p { style: "font-weight:700; color:var(--rpg-gold); margin-top:8px; margin-bottom:2px;",
@@ -325,6 +394,14 @@ pub fn Navbar() -> Element {
                                 "    "
                                 {t!("help-overworld-interact")}
                             }
+                            p {
+                                "    "
+                                {t!("help-gamepad")}
+                            }
+                            p {
+                                "    "
+                                {t!("help-accessibility")}
+                            }
                             p {
                                 "    "
                                 {t!("help-overworld-encounter")}
@@ -390,6 +467,22 @@ pub fn Navbar() -> Element {
                                 "    "
                                 {t!("help-admin-scenarios")}
                             }
+                            p {
+                                "    "
+                                {t!("help-admin-content-packs")}
+                            }
+                            p {
+                                "    "
+                                {t!("help-admin-history")}
+                            }
+                            p {
+                                "    "
+                                {t!("help-admin-simulator")}
+                            }
+                            p {
+                                "    "
+                                {t!("help-admin-translations")}
+                            }
                         }
                     }
                     AlertDialogAction {
@@ -442,7 +535,11 @@ pub fn Navbar() -> Element {
                                     oninput: move |e: FormEvent| {
                                         (audio_settings.sfx_volume).set(e.value().parse().unwrap_or(80));
                                     },
-                                    onchange: move |_| audio::play_sfx(lib_rpg::common::sound_cue::SoundCue::Hit, audio_settings),
+                                    onchange: move |_| audio::play_sfx(
+                                        &audio::Sfx::Cue(lib_rpg::common::sound_cue::SoundCue::Hit),
+                                        audio::SfxSide::Center,
+                                        audio_settings,
+                                    ),
                                 }
                             }
                         }
@@ -570,7 +667,7 @@ pub fn Navbar() -> Element {
                                             confirm_password_draft.set(String::new());
                                         }
                                         Err(e) => {
-                                            change_password_answer.set(format!("{}", e.to_owned()));
+                                            change_password_answer.set(localize_error(&e));
                                         }
                                     }
                                 },
@@ -612,8 +709,9 @@ pub fn Navbar() -> Element {
                     "aria-label": t!("lang-select-label"),
                     value: "{app_lang()}",
                     onchange: move |e| app_lang.set(e.value()),
-                    option { value: "en", "🇬🇧 English" }
-                    option { value: "fr", "🇫🇷 Français" }
+                    for locale in locales.iter() {
+                        option { value: "{locale.code}", {locale_option_label(locale)} }
+                    }
                 }
                 Button {
                     variant: ButtonVariant::Outline,
@@ -661,7 +759,30 @@ pub fn Navbar() -> Element {
                     }
                 }
                 if is_signed_in(&snap_local_login_name_session) {
-                    span { class: "navbar-user", "👤 {snap_local_login_name_session}" }
+                    Link {
+                        class: "navbar-admin-link",
+                        to: Route::LeaderboardPage {},
+                        onclick: move |_| mobile_nav_open.set(false),
+                        {t!("navbar-leaderboards")}
+                    }
+                }
+                if is_signed_in(&snap_local_login_name_session) {
+                    Link {
+                        class: "navbar-admin-link",
+                        to: Route::ArenaPage {},
+                        onclick: move |_| mobile_nav_open.set(false),
+                        {t!("navbar-arena")}
+                    }
+                }
+                if is_signed_in(&snap_local_login_name_session) {
+                    Link {
+                        class: "navbar-user",
+                        to: Route::ProfilePage {
+                            username: snap_local_login_name_session.clone(),
+                        },
+                        onclick: move |_| mobile_nav_open.set(false),
+                        "👤 {snap_local_login_name_session}"
+                    }
                 }
                 Button {
                     variant: if is_signed_in(&snap_local_login_name_session) { ButtonVariant::Destructive } else { ButtonVariant::Secondary },
@@ -695,6 +816,7 @@ pub fn Navbar() -> Element {
             }
 
             Outlet::<Route> {}
+            AchievementToasts {}
 
             // ── Footer ────────────────────────────────────────────────────────────
             footer { class: "app-footer",
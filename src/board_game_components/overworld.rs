@@ -9,8 +9,10 @@ use lib_rpg::{
 
 use crate::{
     auth_manager::server_fn::{get_user_setting, save_user_setting},
-    common::{CtxAppLang, PATH_IMG, SERVER_NAME},
+    common::{CtxAppLang, CtxQuests, PATH_IMG, SERVER_NAME},
+    gamepad::{PadAction, use_pad_action},
     websocket_handler::event::{ClientEvent, ServerEvent},
+    widgets::input_prompts::{InputPrompts, PromptContext},
 };
 
 const TILE_PX: i32 = 48;
@@ -94,6 +96,7 @@ pub fn OverworldMap() -> Element {
     let server_data = use_context::<Signal<ServerData>>();
     let local_login_name_session = use_context::<Signal<String>>();
     let app_lang = use_context::<CtxAppLang>().0;
+    let quests = use_context::<CtxQuests>().0;
 
     // Declared before the early return below so hook order stays stable across renders.
     let mut tile_zoom: Signal<f32> = use_signal(|| 0.85_f32);
@@ -109,6 +112,40 @@ pub fn OverworldMap() -> Element {
         });
     });
 
+    // Gamepad: directions move, Confirm interacts (or starts the offered fight),
+    // Cancel closes the open dialog.
+    use_pad_action(move |action| {
+        let server_name = SERVER_NAME();
+        let player_name = local_login_name_session();
+        let lang = app_lang();
+        let dialog_open = server_data
+            .peek()
+            .core_game_data
+            .overworld
+            .as_ref()
+            .is_some_and(|ow| !ow.active_dialog.is_empty());
+        let event = match action {
+            PadAction::Up => ClientEvent::MovePlayer(server_name, player_name, Direction::Up, lang),
+            PadAction::Down => {
+                ClientEvent::MovePlayer(server_name, player_name, Direction::Down, lang)
+            }
+            PadAction::Left => {
+                ClientEvent::MovePlayer(server_name, player_name, Direction::Left, lang)
+            }
+            PadAction::Right => {
+                ClientEvent::MovePlayer(server_name, player_name, Direction::Right, lang)
+            }
+            PadAction::Confirm => ClientEvent::Interact(server_name, player_name, lang),
+            PadAction::Cancel if dialog_open => {
+                ClientEvent::DismissDialog(server_name, player_name)
+            }
+            _ => return,
+        };
+        spawn(async move {
+            let _ = socket.send(event).await;
+        });
+    });
+
     let ow_state = server_data().core_game_data.overworld.clone();
     let Some(ow) = ow_state else {
         return rsx! {
@@ -128,6 +165,8 @@ pub fn OverworldMap() -> Element {
     let socket_interact = socket;
     let socket_confirm_fight = socket;
     let socket_dismiss = socket;
+    // Offered by the dialogue tree of the NPC talked to, if it has one
+    let choices = quests().choices;
 
     rsx! {
         div {
@@ -265,6 +304,27 @@ pub fn OverworldMap() -> Element {
                         for line in ow.active_dialog.iter() {
                             p { class: "ow-dialog-line", "{line}" }
                         }
+                        if !choices.is_empty() {
+                            div { class: "ow-dialog-choices",
+                                for (index , choice) in choices.iter().enumerate() {
+                                    button {
+                                        class: "ow-dialog-btn ow-dialog-choice",
+                                        tabindex: "-1",
+                                        onclick: move |_| {
+                                            let sn = SERVER_NAME();
+                                            let pn = local_login_name_session();
+                                            let lang = app_lang();
+                                            async move {
+                                                let _ = socket
+                                                    .send(ClientEvent::ChooseDialogue(sn, pn, index, lang))
+                                                    .await;
+                                            }
+                                        },
+                                        "{choice.get(&app_lang())}"
+                                    }
+                                }
+                            }
+                        }
                         if ow.pending_fight.is_some() {
                             p { class: "ow-dialog-question", {t!("overworld-start-fight-question")} }
                             div { class: "ow-dialog-actions",
@@ -304,6 +364,8 @@ pub fn OverworldMap() -> Element {
 
             } // ow-map-area
 
+            InputPrompts { context: PromptContext::Overworld }
+
             // Virtual D-pad — visible on touch screens, hidden on desktop (CSS media query).
             {
                 let sn_up = SERVER_NAME();
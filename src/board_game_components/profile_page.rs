@@ -0,0 +1,300 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::{
+    achievements::AchievementProgress,
+    auth_manager::server_fn::{
+        PlayerProfile, ProfileUsage, get_achievements, get_player_profile, list_profiles,
+    },
+    common::{CtxAppLang, Route},
+    components::tabs::{TabContent, TabList, TabTrigger, Tabs},
+    widgets::charts::{ProgressBar, StatCard},
+};
+
+/// `3h 05m`, `12m`, or `45s` under a minute.
+fn format_time_played(secs: i64) -> String {
+    let (hours, minutes) = (secs / 3600, secs % 3600 / 60);
+    match (hours, minutes) {
+        (0, 0) => format!("{secs}s"),
+        (0, m) => format!("{m}m"),
+        (h, m) => format!("{h}h {m:02}m"),
+    }
+}
+
+/// Lifetime statistics and achievements of `username`, reachable from the navbar
+/// for one's own profile and from the picker for the other players'.
+#[component]
+pub fn ProfilePage(username: String) -> Element {
+    let navigator = use_navigator();
+    let mut profile: Signal<Option<PlayerProfile>> = use_signal(|| None);
+    let mut achievements: Signal<Vec<AchievementProgress>> = use_signal(Vec::new);
+    let mut players: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut error_msg = use_signal(String::new);
+
+    // `username` is a route segment: re-fetch when it changes
+    let shown_user = use_reactive!(|username| username);
+    use_effect(move || {
+        let username = shown_user();
+        spawn(async move {
+            match get_player_profile(username).await {
+                Ok(p) => {
+                    profile.set(Some(p));
+                    error_msg.set(String::new());
+                }
+                Err(e) => error_msg.set(t!("profile-fetch-error", error : e.to_string())),
+            }
+        });
+        spawn(async move {
+            match get_achievements(shown_user()).await {
+                Ok(list) => achievements.set(list),
+                Err(e) => error_msg.set(t!("profile-fetch-error", error : e.to_string())),
+            }
+        });
+    });
+    use_effect(move || {
+        spawn(async move {
+            if let Ok(list) = list_profiles().await {
+                players.set(list);
+            }
+        });
+    });
+
+    rsx! {
+        div { class: "home-container",
+            h2 { class: "rpg-title", {t!("profile-title", username : username.clone())} }
+            div { class: "profile-picker",
+                span { class: "settings-hint", {t!("profile-view-other")} }
+                select {
+                    class: "admin-select",
+                    aria_label: t!("profile-view-other"),
+                    onchange: move |e| {
+                        navigator
+                            .push(Route::ProfilePage {
+                                username: e.value(),
+                            });
+                    },
+                    if !players().contains(&username) {
+                        option { value: "{username}", selected: true, "{username}" }
+                    }
+                    for name in players() {
+                        option { value: "{name}", selected: name == username, "{name}" }
+                    }
+                }
+            }
+
+            if !error_msg().is_empty() {
+                p { class: "admin-answer-error", "{error_msg}" }
+            }
+
+            Tabs {
+                default_value: "stats".to_owned(),
+                horizontal: true,
+                max_width: "60em",
+                TabList {
+                    TabTrigger { value: "stats".to_owned(), index: 0_usize, {t!("profile-tab-stats")} }
+                    TabTrigger { value: "achievements".to_owned(), index: 1_usize,
+                        {t!("profile-tab-achievements", unlocked : achievements().iter().filter(|a| a.unlocked_at.is_some()).count(), total : achievements().len())}
+                    }
+                }
+                TabContent { value: "stats".to_owned(), index: 0_usize,
+                    if let Some(p) = profile() {
+                        ProfileStats { profile: p }
+                    }
+                }
+                TabContent { value: "achievements".to_owned(), index: 1_usize,
+                    AchievementList { achievements: achievements() }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ProfileStats(profile: PlayerProfile) -> Element {
+    let fights = profile.fights_won + profile.fights_lost;
+    if fights == 0 {
+        return rsx! {
+            p { class: "rpg-no-data", {t!("profile-no-fights")} }
+        };
+    }
+    let win_rate = profile.fights_won * 100 / fights;
+    let universes = if profile.universes_completed.is_empty() {
+        "—".to_owned()
+    } else {
+        profile.universes_completed.join(", ")
+    };
+
+    rsx! {
+        div { class: "rpg-stats-panel profile-panel",
+            div { class: "rpg-stat-cards",
+                StatCard {
+                    label: t!("profile-fights-won"),
+                    value: format!("{}", profile.fights_won),
+                    color: "var(--secondary-success-color)".to_owned(),
+                    icon: "🏆".to_owned(),
+                }
+                StatCard {
+                    label: t!("profile-fights-lost"),
+                    value: format!("{}", profile.fights_lost),
+                    color: "var(--secondary-color-2)".to_owned(),
+                    icon: "💀".to_owned(),
+                }
+                StatCard {
+                    label: t!("profile-win-rate"),
+                    value: format!("{win_rate}%"),
+                    color: "var(--rpg-gold)".to_owned(),
+                    icon: "📈".to_owned(),
+                }
+                StatCard {
+                    label: t!("charts-total-damage"),
+                    value: format!("{}", profile.damage),
+                    color: "var(--secondary-color-2)".to_owned(),
+                    icon: "🗡️".to_owned(),
+                }
+                StatCard {
+                    label: t!("charts-total-heal"),
+                    value: format!("{}", profile.healing),
+                    color: "var(--secondary-success-color)".to_owned(),
+                    icon: "💚".to_owned(),
+                }
+                StatCard {
+                    label: t!("profile-time-played"),
+                    value: format_time_played(profile.time_played_secs),
+                    color: "var(--rpg-teal)".to_owned(),
+                    icon: "⏱️".to_owned(),
+                }
+                StatCard {
+                    label: t!("profile-universes-completed"),
+                    value: universes,
+                    color: "#9b59b6".to_owned(),
+                    icon: "🌍".to_owned(),
+                    wide: true,
+                }
+            }
+
+            UsageBars {
+                title: t!("profile-favourite-heroes"),
+                usages: profile.heroes.clone(),
+                color: "var(--rpg-hero)".to_owned(),
+                suffix: format!(" {}", t!("profile-suffix-fights")),
+            }
+            UsageBars {
+                title: t!("profile-favourite-attacks"),
+                usages: profile.attacks.clone(),
+                color: "var(--rpg-gold)".to_owned(),
+                suffix: "×".to_owned(),
+            }
+
+            div { class: "rpg-section",
+                h4 { class: "rpg-section-title", {t!("profile-recent-fights")} }
+                div { class: "profile-fights",
+                    for fight in profile.recent_fights {
+                        div { class: if fight.won { "profile-fight profile-fight--won" } else { "profile-fight profile-fight--lost" },
+                            span { class: "profile-fight-result",
+                                if fight.won {
+                                    {t!("profile-fight-won")}
+                                } else {
+                                    {t!("profile-fight-lost")}
+                                }
+                            }
+                            span { class: "profile-fight-name", "{fight.scenario} · {fight.universe}" }
+                            span { class: "settings-hint",
+                                {
+                                    t!(
+                                        "profile-fight-details", turns : fight.turns, damage : fight.damage, healing :
+                                        fight.healing
+                                    )
+                                }
+                            }
+                            span { class: "settings-hint", "{fight.finished_at}" }
+                        }
+                    }
+                }
+            }
+
+            if !profile.endless_runs.is_empty() {
+                div { class: "rpg-section",
+                    h4 { class: "rpg-section-title", {t!("profile-endless-runs")} }
+                    div { class: "profile-fights",
+                        for run in profile.endless_runs {
+                            div { class: "profile-fight profile-endless-run",
+                                span { class: "profile-fight-result", "♾️ {run.floors}" }
+                                span { class: "profile-fight-name", "{run.universe}" }
+                                span { class: "settings-hint", "{run.heroes}" }
+                                span { class: "settings-hint", "{run.finished_at}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn UsageBars(title: String, usages: Vec<ProfileUsage>, color: String, suffix: String) -> Element {
+    let max = usages.first().map_or(1, |u| u.count);
+    rsx! {
+        div { class: "rpg-section",
+            h4 { class: "rpg-section-title", "{title}" }
+            div { class: "rpg-bar-list",
+                for usage in usages {
+                    ProgressBar {
+                        label: usage.name.clone(),
+                        value: usage.count,
+                        max,
+                        color: color.clone(),
+                        suffix: suffix.clone(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn AchievementList(achievements: Vec<AchievementProgress>) -> Element {
+    let CtxAppLang(app_lang) = use_context::<CtxAppLang>();
+    let lang = app_lang();
+    if achievements.is_empty() {
+        return rsx! {
+            p { class: "rpg-no-data", {t!("profile-no-achievements")} }
+        };
+    }
+    rsx! {
+        div { class: "achievement-list",
+            for a in achievements {
+                div { class: if a.unlocked_at.is_some() { "achievement achievement--unlocked" } else { "achievement" },
+                    span { class: "achievement-icon", "{a.def.icon}" }
+                    div { class: "achievement-body",
+                        span { class: "achievement-name", "{a.def.name.get(&lang)}" }
+                        span { class: "settings-hint", "{a.def.description.get(&lang)}" }
+                        if let Some(date) = &a.unlocked_at {
+                            span { class: "achievement-date", {t!("profile-achievement-unlocked-at", date : date.clone())} }
+                        } else {
+                            ProgressBar {
+                                label: String::new(),
+                                value: a.progress,
+                                max: a.def.target,
+                                color: "var(--rpg-gold)".to_owned(),
+                                suffix: format!(" / {}", a.def.target),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_played_is_rounded_to_the_minute() {
+        assert_eq!(format_time_played(45), "45s");
+        assert_eq!(format_time_played(12 * 60 + 30), "12m");
+        assert_eq!(format_time_played(3 * 3600 + 5 * 60), "3h 05m");
+    }
+}
@@ -1,10 +1,15 @@
-use crate::audio;
+use crate::audio::{self, Sfx, SfxSide};
+use crate::auth_manager::server_fn::{
+    get_server_arena_match, get_server_endless_run, get_universe_start_map,
+};
 use crate::board_game_components::character_page::{BarComponent, CharacterPanel};
+use crate::board_game_components::difficulty_select::settings_badge;
+use crate::board_game_components::endless_run::EndlessRewardPicker;
 use crate::board_game_components::game_sheets::{GameSheets, StoreSheet, rank_color, rank_label};
 use crate::board_game_components::overworld::OverworldMap;
 use crate::common::{
-    CtxAppLang, CtxAudioSettings, CtxAutoSaveScenario, Route, SERVER_NAME, lang_from_app_lang,
-    photo_src,
+    CtxAppLang, CtxAudioSettings, CtxAutoSaveScenario, CtxGameSettings, Route, SERVER_NAME,
+    lang_from_app_lang, photo_src,
 };
 use crate::websocket_handler::event::{ClientEvent, ServerEvent};
 use crate::websocket_handler::msg_from_client::send_disconnect_from_server_data;
@@ -135,50 +140,96 @@ pub fn RunningGamePage() -> Element {
     let local_login_name_session = use_context::<Signal<String>>();
     let _auto_save_scenario = use_context::<CtxAutoSaveScenario>().0;
     let app_lang = use_context::<CtxAppLang>().0;
+    let game_settings = use_context::<CtxGameSettings>();
     // Shop panel — only open at end-of-scenario
     let mut shop_open = use_signal(|| false);
 
     // Victory/game-over sound effect — fires once per transition into that GameStatus
     // (not on every re-render while already in it, and not on the harmless StartGame
-    // reset a replay/new scenario does in between).
+    // reset a replay/new scenario does in between). A victory that levels a hero up
+    // adds the level-up cue from the heroes' side.
     let audio_settings = use_context::<CtxAudioSettings>();
     let mut last_announced_status = use_signal(GameStatus::default);
     use_effect(move || {
-        let status = server_data()
-            .core_game_data
-            .game_manager
-            .game_state
-            .status
-            .clone();
+        let data = server_data();
+        let gm = &data.core_game_data.game_manager;
+        let status = gm.game_state.status.clone();
         if status != last_announced_status() {
             last_announced_status.set(status.clone());
             match status {
                 GameStatus::EndOfGame => audio::play_sfx(
-                    lib_rpg::common::sound_cue::SoundCue::GameOver,
-                    audio_settings,
-                ),
-                GameStatus::EndOfScenario => audio::play_sfx(
-                    lib_rpg::common::sound_cue::SoundCue::Victory,
+                    &Sfx::Cue(lib_rpg::common::sound_cue::SoundCue::GameOver),
+                    SfxSide::Center,
                     audio_settings,
                 ),
+                GameStatus::EndOfScenario => {
+                    audio::play_sfx(
+                        &Sfx::Cue(lib_rpg::common::sound_cue::SoundCue::Victory),
+                        SfxSide::Center,
+                        audio_settings,
+                    );
+                    if gm
+                        .end_of_scenario
+                        .characters_levelup
+                        .iter()
+                        .any(|l| l.new_level > l.old_level)
+                    {
+                        audio::play_sfx(&Sfx::LevelUp, SfxSide::Heroes, audio_settings);
+                    }
+                }
                 _ => {}
             }
         }
     });
 
+    // An endless run has no overworld and offers rewards instead of loots
+    // (re-fetched when a floor starts or ends, not on every update)
+    let floor_key = use_memo(move || {
+        let gm = &server_data.read().core_game_data.game_manager;
+        (
+            gm.game_state.status.clone(),
+            gm.current_scenario.name.clone(),
+        )
+    });
+    let endless_run_resource = use_resource(move || {
+        floor_key();
+        get_server_endless_run(SERVER_NAME())
+    });
+    let endless_run = endless_run_resource
+        .read()
+        .as_ref()
+        .and_then(|r| r.as_ref().ok())
+        .cloned()
+        .flatten();
+    let is_endless = endless_run.is_some();
+    // Nor has an arena match, whose result goes to the Arena page
+    let arena_match_resource = use_resource(move || {
+        floor_key();
+        get_server_arena_match(SERVER_NAME())
+    });
+    let arena_match = arena_match_resource
+        .read()
+        .as_ref()
+        .and_then(|r| r.as_ref().ok())
+        .cloned()
+        .flatten();
+    let no_overworld = is_endless || arena_match.is_some();
+
     let snap_server_data = server_data();
 
     // Use the server's game_phase as the single source of truth for the overworld view.
     let in_overworld = server_data().core_game_data.game_phase == GamePhase::Overworld;
 
-    // Map the universe to its starting overworld map id.
-    let universe_map = |universe: &str| -> Option<&'static str> {
-        match universe {
-            "lotr" => Some("lotr_shire"),
-            "pokemon" => Some("pallet_town"),
-            _ => None,
-        }
-    };
+    // The universe's starting overworld map, the one whose `start_of` names it.
+    let universe_key = use_memo(move || server_data.read().core_game_data.universe.clone());
+    let start_map_resource = use_resource(move || get_universe_start_map(universe_key()));
+    let start_map_loading = start_map_resource.read().is_none();
+    let start_map = start_map_resource
+        .read()
+        .as_ref()
+        .and_then(|r| r.as_ref().ok())
+        .cloned()
+        .flatten();
 
     // Map to return to when the player clicks "Overworld": prefer the current saved map
     // (so a fight triggered on route_1 returns to route_1, not pallet_town), fall back
@@ -188,14 +239,17 @@ pub fn RunningGamePage() -> Element {
         .overworld
         .as_ref()
         .map(|ow| ow.map_id.clone())
-        .or_else(|| universe_map(&snap_server_data.core_game_data.universe).map(str::to_owned));
+        .or_else(|| start_map.clone())
+        .filter(|_| !no_overworld);
 
     // The game is about to auto-enter the overworld (Running phase, no overworld state
-    // yet, universe has a starting map) but the server hasn't confirmed it yet. Skip
-    // rendering the gameboard during this window to avoid a brief combat-view flash.
+    // yet, universe has - or may have - a starting map) but the server hasn't confirmed
+    // it yet. Skip rendering the gameboard during this window to avoid a brief
+    // combat-view flash.
     let pending_overworld_entry = !in_overworld
         && snap_server_data.core_game_data.overworld.is_none()
-        && universe_map(&snap_server_data.core_game_data.universe).is_some();
+        && (start_map_loading || start_map.is_some())
+        && !no_overworld;
 
     // Auto-enter overworld the first time the game reaches Running phase for
     // universes that have an overworld map, and no saved overworld state exists yet.
@@ -204,16 +258,27 @@ pub fn RunningGamePage() -> Element {
         if auto_entered() {
             return;
         }
+        // Wait to know whether the game is an endless run or an arena match
+        let Some(Ok(run)) = &*endless_run_resource.read() else {
+            return;
+        };
+        let Some(Ok(arena)) = &*arena_match_resource.read() else {
+            return;
+        };
+        if run.is_some() || arena.is_some() {
+            return;
+        }
+        let Some(Ok(start_map)) = &*start_map_resource.read() else {
+            return;
+        };
         let phase = server_data().core_game_data.game_phase.clone();
         let no_overworld = server_data().core_game_data.overworld.is_none();
-        let universe = server_data().core_game_data.universe.clone();
         if phase == GamePhase::Running
             && no_overworld
-            && let Some(map_id) = universe_map(&universe)
+            && let Some(map_id) = start_map.clone()
         {
             auto_entered.set(true);
             let server_name = SERVER_NAME();
-            let map_id = map_id.to_owned();
             let socket = socket;
             spawn(async move {
                 let _ = socket
@@ -242,10 +307,41 @@ pub fn RunningGamePage() -> Element {
                             )
                         }
                     }
+                    if let Some(run) = &endless_run {
+                        p { class: "endless-result",
+                            {t!("endless-run-over", floors : run.floors_cleared as i64)}
+                        }
+                    }
+                    if let Some(arena_match) = &arena_match {
+                        {
+                            let lost = snap_server_data
+                                .core_game_data
+                                .game_manager
+                                .pm
+                                .active_heroes
+                                .iter()
+                                .all(|h| h.stats.is_dead().unwrap_or(false));
+                            rsx! {
+                                p { class: if lost { "arena-result arena-lost" } else { "arena-result arena-won" },
+                                    if lost {
+                                        {t!("arena-result-lost", defender : arena_match.defender.clone())}
+                                    } else {
+                                        {t!("arena-result-won", defender : arena_match.defender.clone())}
+                                    }
+                                }
+                                Link { class: "navbar-admin-link", to: Route::ArenaPage {}, {t!("arena-back")} }
+                            }
+                        }
+                    }
+                    if (game_settings.new_game_plus_available)() {
+                        p { class: "gameover-sub", {t!("startgame-new-game-plus-hint")} }
+                    }
                     EndStatePanels {}
                     div { class: "scenario-actions",
                         QuitGameButton {}
-                        if server_data().players_data.owner_player_name == local_login_name_session() {
+                        if !is_endless && arena_match.is_none()
+                            && server_data().players_data.owner_player_name == local_login_name_session()
+                        {
                             Button {
                                 variant: ButtonVariant::GreenType,
                                 onclick: move |_| async move {
@@ -253,6 +349,22 @@ pub fn RunningGamePage() -> Element {
                                 },
                                 {t!("startgame-replay-game")}
                             }
+                            if (game_settings.new_game_plus_available)() {
+                                Button {
+                                    variant: ButtonVariant::Primary,
+                                    onclick: move |_| async move {
+                                        let _ = socket
+                                            .send(
+                                                ClientEvent::StartNewGamePlus(
+                                                    SERVER_NAME(),
+                                                    local_login_name_session(),
+                                                ),
+                                            )
+                                            .await;
+                                    },
+                                    {t!("startgame-new-game-plus")}
+                                }
+                            }
                         }
                     }
                 }
@@ -328,30 +440,34 @@ pub fn RunningGamePage() -> Element {
                         on_open_change: move |v| shop_open.set(v),
                         StoreSheet { s: SheetSide::Right }
                     }
-                    div { class: "scenario-section",
-                        h3 { class: "scenario-section-title", {t!("startgame-loots")} }
-                        if snap_server_data.core_game_data.game_manager.current_scenario.loots.is_empty() {
-                            p { class: "scenario-empty", {t!("startgame-no-loots")} }
-                        } else {
-                            div { class: "loot-grid",
-                                for l in snap_server_data.core_game_data.game_manager.current_scenario.loots.iter() {
-                                    div { class: "loot-item",
-                                        div { class: "loot-item-header",
-                                            span { class: "loot-icon", "{loot_kind_icon(&l.kind)}" }
-                                            span { class: "loot-name", "{l.name}" }
-                                        }
-                                        div { class: "loot-badges",
-                                            span {
-                                                class: "loot-badge",
-                                                style: "color:{rank_color(&l.rank)};border-color:{rank_color(&l.rank)};",
-                                                "{rank_label(&l.rank)}"
+                    if let Some(run) = endless_run.clone() {
+                        EndlessRewardPicker { run }
+                    } else {
+                        div { class: "scenario-section",
+                            h3 { class: "scenario-section-title", {t!("startgame-loots")} }
+                            if snap_server_data.core_game_data.game_manager.current_scenario.loots.is_empty() {
+                                p { class: "scenario-empty", {t!("startgame-no-loots")} }
+                            } else {
+                                div { class: "loot-grid",
+                                    for l in snap_server_data.core_game_data.game_manager.current_scenario.loots.iter() {
+                                        div { class: "loot-item",
+                                            div { class: "loot-item-header",
+                                                span { class: "loot-icon", "{loot_kind_icon(&l.kind)}" }
+                                                span { class: "loot-name", "{l.name}" }
                                             }
-                                            span { class: "loot-badge loot-badge-amount",
-                                                "×{l.level}"
+                                            div { class: "loot-badges",
+                                                span {
+                                                    class: "loot-badge",
+                                                    style: "color:{rank_color(&l.rank)};border-color:{rank_color(&l.rank)};",
+                                                    "{rank_label(&l.rank)}"
+                                                }
+                                                span { class: "loot-badge loot-badge-amount",
+                                                    "×{l.level}"
+                                                }
+                                            }
+                                            if !l.classes.is_empty() {
+                                                div { class: "loot-classes", "{l.format_classes()}" }
                                             }
-                                        }
-                                        if !l.classes.is_empty() {
-                                            div { class: "loot-classes", "{l.format_classes()}" }
                                         }
                                     }
                                 }
@@ -449,6 +565,11 @@ pub fn RunningGamePage() -> Element {
                                 )
                             }
                         }
+                        if !settings_badge(&(game_settings.settings)()).is_empty() {
+                            div { class: "turn-badge difficulty-badge",
+                                "⚔️ {settings_badge(&(game_settings.settings)())}"
+                            }
+                        }
                         if server_data().players_data.owner_player_name == local_login_name_session() {
                             if let Some(map_id) = return_map_id.clone() {
                                 Button {
@@ -0,0 +1,335 @@
+use dioxus::{
+    fullstack::{CborEncoding, UseWebsocket},
+    prelude::*,
+};
+use dioxus_i18n::t;
+use lib_rpg::{character_mod::character::Character, server::server_manager::ServerData};
+
+use crate::{
+    common::{CtxTrades, SERVER_NAME},
+    components::{
+        button::{Button, ButtonVariant},
+        input::Input,
+    },
+    trading::{TradeItem, TradeItemKind, TradeOffer, TradeProposal, tradable_items},
+    websocket_handler::event::{ClientEvent, ServerEvent},
+};
+
+/// Offer made of the `picked` indexes of `items` and the `gold` typed.
+fn picked_offer(items: &[TradeItem], picked: &[usize], gold: &str) -> TradeOffer {
+    TradeOffer {
+        items: picked
+            .iter()
+            .filter_map(|&i| items.get(i).cloned())
+            .collect(),
+        gold: gold.trim().parse().unwrap_or(0),
+    }
+}
+
+fn offer_text(offer: &TradeOffer) -> String {
+    let mut parts: Vec<String> = offer.items.iter().map(|i| i.name.clone()).collect();
+    if offer.gold > 0 {
+        parts.push(t!("gs-gold-amount", amount : offer.gold as i64));
+    }
+    if parts.is_empty() {
+        t!("trade-nothing")
+    } else {
+        parts.join(", ")
+    }
+}
+
+/// Checkboxes over `items`, the ones ticked going to `picked`, and the gold
+/// handed over.
+#[component]
+fn OfferPicker(
+    items: Vec<TradeItem>,
+    mut picked: Signal<Vec<usize>>,
+    mut gold: Signal<String>,
+    max_gold: u64,
+) -> Element {
+    rsx! {
+        div { class: "trade-items",
+            if items.is_empty() {
+                span { class: "settings-hint", {t!("trade-no-items")} }
+            }
+            for (i , item) in items.into_iter().enumerate() {
+                label { class: "trade-item",
+                    input {
+                        r#type: "checkbox",
+                        checked: picked().contains(&i),
+                        onchange: move |_| {
+                            let mut list = picked.write();
+                            match list.iter().position(|&p| p == i) {
+                                Some(pos) => {
+                                    list.remove(pos);
+                                }
+                                None => list.push(i),
+                            }
+                        },
+                    }
+                    if item.kind == TradeItemKind::Equipment {
+                        "🛡️ {item.name}"
+                    } else {
+                        "🧪 {item.name}"
+                    }
+                }
+            }
+            Input {
+                r#type: "number",
+                min: "0",
+                max: "{max_gold}",
+                aria_label: t!("trade-gold"),
+                placeholder: t!("trade-gold"),
+                value: "{gold}",
+                oninput: move |e: FormEvent| gold.set(e.value()),
+            }
+        }
+    }
+}
+
+/// Multiplayer trades of `character`, the hero of the local player: a form to
+/// propose one to another player of the party, and the trades waiting for an
+/// answer.
+#[component]
+pub fn TradePanel(character: Character) -> Element {
+    let socket = use_context::<UseWebsocket<ClientEvent, ServerEvent, CborEncoding>>();
+    let server_data = use_context::<Signal<ServerData>>();
+    let local_login_name_session = use_context::<Signal<String>>();
+    let trades = use_context::<CtxTrades>().0;
+
+    let mut partner_idx = use_signal(|| 0usize);
+    let mut give_picked = use_signal(Vec::<usize>::new);
+    let mut ask_picked = use_signal(Vec::<usize>::new);
+    let mut give_gold = use_signal(String::new);
+    let mut ask_gold = use_signal(String::new);
+
+    let me = local_login_name_session();
+    let snap = server_data();
+    // Heroes of the other players
+    let partners: Vec<Character> = snap
+        .players_data
+        .players_info
+        .iter()
+        .filter(|(player, _)| **player != me)
+        .flat_map(|(_, info)| info.character_id_names.clone())
+        .filter_map(|id| {
+            snap.core_game_data
+                .game_manager
+                .pm
+                .get_active_hero_character(&id)
+                .cloned()
+        })
+        .collect();
+    let partner = partners.get(partner_idx()).cloned();
+    let my_items = tradable_items(&character);
+    let my_gold: u64 = character.inventory.money as _;
+    let partner_gold: u64 = partner.as_ref().map_or(0, |p| p.inventory.money as _);
+    let their_items = partner.as_ref().map(tradable_items).unwrap_or_default();
+    let proposal = TradeProposal {
+        from_hero: character.id_name.clone(),
+        to_hero: partner
+            .as_ref()
+            .map(|p| p.id_name.clone())
+            .unwrap_or_default(),
+        give: picked_offer(&my_items, &give_picked(), &give_gold()),
+        ask: picked_offer(&their_items, &ask_picked(), &ask_gold()),
+        ..Default::default()
+    };
+    let can_propose =
+        partner.is_some() && proposal.is_valid() && proposal.give.check(&my_items, my_gold).is_ok();
+    let all_trades = trades();
+    let incoming: Vec<TradeProposal> = all_trades
+        .iter()
+        .filter(|t| t.to_player == me)
+        .cloned()
+        .collect();
+    let outgoing: Vec<TradeProposal> = all_trades
+        .iter()
+        .filter(|t| t.from_player == me)
+        .cloned()
+        .collect();
+
+    rsx! {
+        div { class: "trade-panel",
+            span { class: "trade-title", {t!("trade-title")} }
+            if partners.is_empty() {
+                p { class: "settings-hint", {t!("trade-no-partner")} }
+            } else {
+                label { class: "trade-label", {t!("trade-partner")} }
+                select {
+                    class: "admin-select",
+                    onchange: move |e| {
+                        partner_idx.set(e.value().parse().unwrap_or(0));
+                        ask_picked.set(Vec::new());
+                    },
+                    for (i , hero) in partners.iter().enumerate() {
+                        option { value: "{i}", selected: i == partner_idx(), "{hero.db_full_name}" }
+                    }
+                }
+                label { class: "trade-label", {t!("trade-give")} }
+                OfferPicker {
+                    items: my_items.clone(),
+                    picked: give_picked,
+                    gold: give_gold,
+                    max_gold: my_gold,
+                }
+                label { class: "trade-label", {t!("trade-ask")} }
+                OfferPicker {
+                    items: their_items,
+                    picked: ask_picked,
+                    gold: ask_gold,
+                    max_gold: partner_gold,
+                }
+                Button {
+                    variant: if can_propose { ButtonVariant::Primary } else { ButtonVariant::Secondary },
+                    disabled: !can_propose,
+                    onclick: move |_| {
+                        let proposal = proposal.clone();
+                        give_picked.set(Vec::new());
+                        ask_picked.set(Vec::new());
+                        give_gold.set(String::new());
+                        ask_gold.set(String::new());
+                        async move {
+                            let _ = socket
+                                .send(ClientEvent::ProposeTrade(SERVER_NAME(), proposal))
+                                .await;
+                        }
+                    },
+                    {t!("trade-propose")}
+                }
+            }
+
+            if !incoming.is_empty() {
+                span { class: "trade-label", {t!("trade-incoming")} }
+            }
+            for trade in incoming {
+                div { key: "{trade.id}", class: "trade-row",
+                    span {
+                        {
+                            t!(
+                                "trade-summary", from : trade.from_hero.clone(), give : offer_text(& trade.give),
+                                ask : offer_text(& trade.ask)
+                            )
+                        }
+                    }
+                    div { class: "trade-row-actions",
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            onclick: move |_| async move {
+                                let _ = socket
+                                    .send(ClientEvent::AnswerTrade(SERVER_NAME(), trade.id, true))
+                                    .await;
+                            },
+                            {t!("trade-accept")}
+                        }
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            onclick: move |_| async move {
+                                let _ = socket
+                                    .send(ClientEvent::AnswerTrade(SERVER_NAME(), trade.id, false))
+                                    .await;
+                            },
+                            {t!("trade-decline")}
+                        }
+                    }
+                }
+            }
+
+            if !outgoing.is_empty() {
+                span { class: "trade-label", {t!("trade-outgoing")} }
+            }
+            for trade in outgoing {
+                div { key: "{trade.id}", class: "trade-row",
+                    span {
+                        {
+                            t!(
+                                "trade-summary", from : trade.from_hero.clone(), give : offer_text(& trade.give),
+                                ask : offer_text(& trade.ask)
+                            )
+                        }
+                    }
+                    Button {
+                        variant: ButtonVariant::Secondary,
+                        onclick: move |_| async move {
+                            let _ = socket
+                                .send(ClientEvent::AnswerTrade(SERVER_NAME(), trade.id, false))
+                                .await;
+                        },
+                        {t!("common-cancel")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Single-player transfer of items and gold from `character` to another hero
+/// of the party.
+#[component]
+pub fn TransferPanel(character: Character) -> Element {
+    let socket = use_context::<UseWebsocket<ClientEvent, ServerEvent, CborEncoding>>();
+    let server_data = use_context::<Signal<ServerData>>();
+
+    let mut target_idx = use_signal(|| 0usize);
+    let mut picked = use_signal(Vec::<usize>::new);
+    let mut gold = use_signal(String::new);
+
+    let others: Vec<Character> = server_data()
+        .core_game_data
+        .game_manager
+        .pm
+        .active_heroes
+        .into_iter()
+        .filter(|h| h.id_name != character.id_name)
+        .collect();
+    let items = tradable_items(&character);
+    let gold_owned: u64 = character.inventory.money as _;
+    let offer = picked_offer(&items, &picked(), &gold());
+    let target = others.get(target_idx()).map(|h| h.id_name.clone());
+    let from_hero = character.id_name.clone();
+    let can_give = target.is_some() && !offer.is_empty() && offer.check(&items, gold_owned).is_ok();
+
+    rsx! {
+        div { class: "trade-panel",
+            span { class: "trade-title", {t!("transfer-title")} }
+            label { class: "trade-label", {t!("transfer-to")} }
+            select {
+                class: "admin-select",
+                onchange: move |e| target_idx.set(e.value().parse().unwrap_or(0)),
+                for (i , hero) in others.iter().enumerate() {
+                    option { value: "{i}", selected: i == target_idx(), "{hero.db_full_name}" }
+                }
+            }
+            OfferPicker {
+                items,
+                picked,
+                gold,
+                max_gold: gold_owned,
+            }
+            Button {
+                variant: if can_give { ButtonVariant::Primary } else { ButtonVariant::Secondary },
+                disabled: !can_give,
+                onclick: move |_| {
+                    let from = from_hero.clone();
+                    let to = target.clone().unwrap_or_default();
+                    let offer = offer.clone();
+                    picked.set(Vec::new());
+                    gold.set(String::new());
+                    async move {
+                        let _ = socket
+                            .send(
+                                ClientEvent::TransferItems(
+                                    SERVER_NAME(),
+                                    from,
+                                    to,
+                                    offer,
+                                ),
+                            )
+                            .await;
+                    }
+                },
+                {t!("transfer-give")}
+            }
+        }
+    }
+}
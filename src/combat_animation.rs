@@ -0,0 +1,403 @@
+//! Combat animation timeline. Each new `ResultLaunchAttack` becomes a
+//! `CombatAnimation`: a strike (slash, projectile or heal) from the launcher to
+//! its targets, then per-target cues — floating damage/heal numbers, dodge and
+//! block labels, buff/debuff icons popping onto the portrait and death fades —
+//! each with a delay from the start of the strike. The whole timeline fits in
+//! `TIMELINE_BUDGET_MS`, so it ends before the next enemy attack
+//! (`ENEMY_ATK_INTERVAL_MS`) arrives.
+//!
+//! The strike is drawn by a small JS bridge (`init_combat_fx`) since it travels
+//! between two portraits; the cues are rendered by `CharacterPanel` from
+//! `CtxCombatAnimation`.
+
+use dioxus::prelude::*;
+use lib_rpg::{
+    character_mod::{buffers::BufKinds, character::Character},
+    server::game_manager::ResultLaunchAttack,
+};
+
+/// Delay between two enemy attacks of a boss turn, on the server.
+pub const ENEMY_ATK_INTERVAL_MS: u64 = 3000;
+
+/// Longest a timeline may last: the enemy pacing minus a margin for the network.
+pub const TIMELINE_BUDGET_MS: u32 = (ENEMY_ATK_INTERVAL_MS as u32) * 4 / 5;
+
+/// Travel time of the strike from the launcher to its targets.
+pub const STRIKE_MS: u32 = 450;
+
+/// Delay between the cues of two successive targets, shortened for large groups.
+const TARGET_STAGGER_MS: u32 = 90;
+
+/// Delay of the status icons and of the death fade after the impact.
+const STATUS_DELAY_MS: u32 = 150;
+const DEATH_DELAY_MS: u32 = 400;
+
+/// Look of the strike.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrikeKind {
+    Slash,
+    Projectile,
+    Heal,
+}
+
+impl StrikeKind {
+    fn js_name(self) -> &'static str {
+        match self {
+            StrikeKind::Slash => "slash",
+            StrikeKind::Projectile => "projectile",
+            StrikeKind::Heal => "heal",
+        }
+    }
+}
+
+/// Something shown on a portrait during the timeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CueKind {
+    Damage { amount: i64, critical: bool },
+    Heal { amount: i64 },
+    Dodge,
+    Block,
+    Buff,
+    Debuff,
+    Death,
+}
+
+impl CueKind {
+    /// Length of the cue's CSS animation (see `.fx-*` in main.css).
+    pub fn duration_ms(&self) -> u32 {
+        match self {
+            CueKind::Damage { .. } | CueKind::Heal { .. } | CueKind::Dodge | CueKind::Block => 1100,
+            CueKind::Buff | CueKind::Debuff => 900,
+            CueKind::Death => 900,
+        }
+    }
+
+    /// CSS class of the cue's element.
+    pub fn class(&self) -> &'static str {
+        match self {
+            CueKind::Damage {
+                critical: false, ..
+            } => "fx-float fx-damage",
+            CueKind::Damage { critical: true, .. } => "fx-float fx-damage fx-critical",
+            CueKind::Heal { .. } => "fx-float fx-heal",
+            CueKind::Dodge | CueKind::Block => "fx-float fx-miss",
+            CueKind::Buff => "fx-status fx-buff",
+            CueKind::Debuff => "fx-status fx-debuff",
+            CueKind::Death => "fx-death-veil",
+        }
+    }
+}
+
+/// A cue on one portrait, `delay_ms` after the start of the strike.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnimCue {
+    pub target: String,
+    pub delay_ms: u32,
+    pub kind: CueKind,
+}
+
+/// What happened to one target, in the order effects were applied.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TargetOutcome {
+    pub target: String,
+    pub hits: Vec<CueKind>,
+    pub dies: bool,
+}
+
+/// Timeline of one attack result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CombatAnimation {
+    /// `(turn_nb, round_nb, launcher_id_name)` of the result, unique per attack
+    pub key: (usize, usize, String),
+    pub launcher: String,
+    pub strike: StrikeKind,
+    pub targets: Vec<String>,
+    pub cues: Vec<AnimCue>,
+}
+
+impl CombatAnimation {
+    /// Lays out the cues of `outcomes`: floating numbers at the impact, status
+    /// icons and deaths shortly after, target after target.
+    pub fn new(
+        key: (usize, usize, String),
+        launcher: String,
+        strike: StrikeKind,
+        outcomes: Vec<TargetOutcome>,
+    ) -> Self {
+        let longest_cue = CueKind::Damage {
+            amount: 0,
+            critical: false,
+        }
+        .duration_ms();
+        let room = TIMELINE_BUDGET_MS - STRIKE_MS - DEATH_DELAY_MS - longest_cue;
+        let stagger = match outcomes.len() {
+            0 | 1 => 0,
+            n => TARGET_STAGGER_MS.min(room / (n as u32 - 1)),
+        };
+        let mut cues = Vec::new();
+        for (i, outcome) in outcomes.iter().enumerate() {
+            let impact = STRIKE_MS + stagger * i as u32;
+            for hit in &outcome.hits {
+                let delay = match hit {
+                    CueKind::Buff | CueKind::Debuff => impact + STATUS_DELAY_MS,
+                    _ => impact,
+                };
+                cues.push(AnimCue {
+                    target: outcome.target.clone(),
+                    delay_ms: delay,
+                    kind: hit.clone(),
+                });
+            }
+            if outcome.dies {
+                cues.push(AnimCue {
+                    target: outcome.target.clone(),
+                    delay_ms: impact + DEATH_DELAY_MS,
+                    kind: CueKind::Death,
+                });
+            }
+        }
+        CombatAnimation {
+            key,
+            launcher,
+            strike,
+            targets: outcomes.into_iter().map(|o| o.target).collect(),
+            cues,
+        }
+    }
+
+    /// Builds the timeline of `ra` from the board after the attack.
+    pub fn from_result(
+        ra: &ResultLaunchAttack,
+        heroes: &[Character],
+        bosses: &[Character],
+    ) -> Self {
+        let characters = || heroes.iter().chain(bosses.iter());
+        let mut outcomes: Vec<TargetOutcome> = Vec::new();
+        let mut outcome_of = |target: &str| -> usize {
+            match outcomes.iter().position(|o| o.target == target) {
+                Some(i) => i,
+                None => {
+                    outcomes.push(TargetOutcome {
+                        target: target.to_owned(),
+                        ..Default::default()
+                    });
+                    outcomes.len() - 1
+                }
+            }
+        };
+        let mut indexes = Vec::new();
+        for gae in &ra.new_game_atk_effects {
+            let outcome = &gae.effect_outcome;
+            let amount = i64::from(outcome.real_amount_tx);
+            let cue = match gae.processed_effect_param.input_effect_param.buffer.kind {
+                // Cooldowns land on the launcher; they are not an outcome of the attack.
+                BufKinds::CooldownTurnsNumber => None,
+                BufKinds::ChangeCurrentStat if amount < 0 => Some(CueKind::Damage {
+                    amount: -amount,
+                    critical: outcome.is_critical,
+                }),
+                BufKinds::ChangeCurrentStat if amount > 0 => Some(CueKind::Heal { amount }),
+                BufKinds::ChangeCurrentStat => None,
+                _ if amount < 0 => Some(CueKind::Debuff),
+                _ => Some(CueKind::Buff),
+            };
+            if let Some(cue) = cue {
+                indexes.push((outcome_of(&outcome.target_id_name), cue));
+            }
+        }
+        // `all_dodging` has one entry per effect: keep one label per target.
+        for d in ra
+            .all_dodging
+            .iter()
+            .filter(|d| d.is_dodging || d.is_blocking)
+        {
+            let cue = if d.is_blocking {
+                CueKind::Block
+            } else {
+                CueKind::Dodge
+            };
+            let i = outcome_of(&d.name);
+            if !indexes.iter().any(|(j, c)| *j == i && *c == cue) {
+                indexes.push((i, cue));
+            }
+        }
+        for (i, cue) in indexes {
+            outcomes[i].hits.push(cue);
+        }
+        for outcome in outcomes.iter_mut() {
+            outcome.dies = characters()
+                .find(|c| c.id_name == outcome.target)
+                .is_some_and(|c| c.stats.is_dead().unwrap_or(false));
+        }
+
+        let heals_only = outcomes.iter().flat_map(|o| &o.hits).all(|h| {
+            !matches!(
+                h,
+                CueKind::Damage { .. } | CueKind::Debuff | CueKind::Dodge | CueKind::Block
+            )
+        });
+        let is_spell = characters()
+            .find(|c| c.id_name == ra.launcher_id_name)
+            .and_then(|c| c.attacks_list.values().find(|a| a.name == ra.atk_name))
+            .is_some_and(|a| a.mana_cost > 0);
+        let strike = if heals_only {
+            StrikeKind::Heal
+        } else if is_spell {
+            StrikeKind::Projectile
+        } else {
+            StrikeKind::Slash
+        };
+        CombatAnimation::new(
+            (ra.turn_nb, ra.round_nb, ra.launcher_id_name.clone()),
+            ra.launcher_id_name.clone(),
+            strike,
+            outcomes,
+        )
+    }
+
+    /// Time from the start of the strike to the end of the last cue.
+    pub fn duration_ms(&self) -> u32 {
+        self.cues
+            .iter()
+            .map(|c| c.delay_ms + c.kind.duration_ms())
+            .max()
+            .unwrap_or(0)
+            .max(STRIKE_MS)
+    }
+
+    /// Cues shown on the portrait of `id_name`.
+    pub fn cues_for<'a>(&'a self, id_name: &'a str) -> impl Iterator<Item = &'a AnimCue> + 'a {
+        self.cues.iter().filter(move |c| c.target == id_name)
+    }
+
+    /// Whether `id_name` fades out during this timeline.
+    pub fn is_dying(&self, id_name: &str) -> bool {
+        self.cues_for(id_name).any(|c| c.kind == CueKind::Death)
+    }
+}
+
+/// Injects the JS strike bridge once: `strike(kind, launcher, targets, ms)` draws
+/// a slash, a projectile or a heal glow travelling from the launcher's portrait
+/// to each target's (found by their `data-char-id`), with the Web Animations API.
+/// Called from `App()` next to the audio bridge.
+pub fn init_combat_fx() {
+    document::eval(
+        r#"
+        if (!window.__dxCombatFx) {
+            const centre = (id) => {
+                const el = document.querySelector(`[data-char-id="${CSS.escape(id)}"]`);
+                if (!el) {
+                    return null;
+                }
+                const r = el.getBoundingClientRect();
+                return { x: r.left + r.width / 2, y: r.top + r.height / 2 };
+            };
+            const spawnFx = (cls, at) => {
+                const fx = document.createElement('div');
+                fx.className = `fx-strike fx-strike-${cls}`;
+                fx.style.left = `${at.x}px`;
+                fx.style.top = `${at.y}px`;
+                document.body.appendChild(fx);
+                return fx;
+            };
+            window.__dxCombatFx = {
+                strike(kind, launcher, targets, ms) {
+                    const from = centre(launcher);
+                    targets.forEach((id) => {
+                        const to = centre(id);
+                        if (!to) {
+                            return;
+                        }
+                        const start = from && id !== launcher ? from : to;
+                        const fx = spawnFx(kind, start);
+                        const dx = to.x - start.x;
+                        const dy = to.y - start.y;
+                        const frames = kind === 'slash'
+                            ? [
+                                { transform: `translate(${dx * 0.7}px, ${dy * 0.7}px) scale(0.4) rotate(-30deg)`, opacity: 0 },
+                                { transform: `translate(${dx}px, ${dy}px) scale(1.2) rotate(20deg)`, opacity: 1, offset: 0.7 },
+                                { transform: `translate(${dx}px, ${dy}px) scale(1.4) rotate(40deg)`, opacity: 0 },
+                            ]
+                            : [
+                                { transform: 'translate(0, 0) scale(0.6)', opacity: 0.9 },
+                                { transform: `translate(${dx}px, ${dy}px) scale(1)`, opacity: 1, offset: 0.85 },
+                                { transform: `translate(${dx}px, ${dy}px) scale(1.8)`, opacity: 0 },
+                            ];
+                        fx.animate(frames, { duration: ms, easing: 'ease-in' }).onfinish = () => fx.remove();
+                    });
+                },
+            };
+        }
+        "#,
+    );
+}
+
+/// Plays the strike of `anim` on the board.
+pub fn play_strike(anim: &CombatAnimation) {
+    let launcher = serde_json::to_string(&anim.launcher).unwrap_or_default();
+    let targets = serde_json::to_string(&anim.targets).unwrap_or_default();
+    let kind = anim.strike.js_name();
+    document::eval(&format!(
+        "window.__dxCombatFx && window.__dxCombatFx.strike('{kind}', {launcher}, {targets}, {STRIKE_MS});"
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(target: &str, hits: Vec<CueKind>, dies: bool) -> TargetOutcome {
+        TargetOutcome {
+            target: target.to_owned(),
+            hits,
+            dies,
+        }
+    }
+
+    #[test]
+    fn cues_follow_the_strike_target_after_target() {
+        let hit = CueKind::Damage {
+            amount: 40,
+            critical: false,
+        };
+        let anim = CombatAnimation::new(
+            (1, 1, "Thalia".to_owned()),
+            "Thalia".to_owned(),
+            StrikeKind::Slash,
+            vec![
+                outcome("Orc", vec![hit.clone(), CueKind::Debuff], true),
+                outcome("Goblin", vec![hit], false),
+            ],
+        );
+        let delays: Vec<(&str, u32)> = anim
+            .cues
+            .iter()
+            .map(|c| (c.target.as_str(), c.delay_ms))
+            .collect();
+        assert_eq!(
+            delays,
+            vec![
+                ("Orc", STRIKE_MS),
+                ("Orc", STRIKE_MS + STATUS_DELAY_MS),
+                ("Orc", STRIKE_MS + DEATH_DELAY_MS),
+                ("Goblin", STRIKE_MS + TARGET_STAGGER_MS),
+            ]
+        );
+        assert!(anim.is_dying("Orc"));
+        assert!(!anim.is_dying("Goblin"));
+    }
+
+    #[test]
+    fn large_groups_still_fit_the_enemy_pacing() {
+        let outcomes = (0..40)
+            .map(|i| outcome(&format!("hero{i}"), vec![CueKind::Heal { amount: 5 }], true))
+            .collect();
+        let anim = CombatAnimation::new(
+            (1, 1, "Sauron".to_owned()),
+            "Sauron".to_owned(),
+            StrikeKind::Projectile,
+            outcomes,
+        );
+        assert!(anim.duration_ms() <= TIMELINE_BUDGET_MS);
+    }
+}
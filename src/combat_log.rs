@@ -0,0 +1,424 @@
+//! Structure of the combat log. `LogData` only carries a text and a colour, so
+//! the server heads the lines of each attack with an `AttackLaunched` line (turn,
+//! round, launcher, attack, targets) and opens each scenario with a
+//! `ScenarioStarted` one, whose messages it files in `LogMessages` (see
+//! `server_messages`). `annotate` walks those markers to give every line its
+//! context, which the Logs sheet filters on and the export (`export_combat_log`)
+//! writes out as text, JSON or CSV.
+
+use lib_rpg::{
+    character_mod::buffers::BufKinds,
+    common::log_data::{
+        LogData,
+        const_colors::{DARK_RED, LIGHT_BLUE, LIGHT_GREEN},
+    },
+    server::game_manager::ResultLaunchAttack,
+};
+
+use crate::server_messages::{self, LogMessage, LogMessages, ServerMessage};
+
+/// Attack a log line belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AttackContext {
+    pub turn: usize,
+    pub round: usize,
+    pub launcher: String,
+    /// Canonical (French) attack name, as in the lib-rpg lines
+    pub attack: String,
+    pub targets: Vec<String>,
+}
+
+impl AttackContext {
+    /// Context of `ra`: its targets are the characters hit by an effect (cooldowns
+    /// excepted, they land on the launcher) or dodging/blocking it.
+    pub fn from_result(ra: &ResultLaunchAttack) -> Self {
+        let mut targets: Vec<String> = Vec::new();
+        let hit = ra
+            .new_game_atk_effects
+            .iter()
+            .filter(|gae| {
+                gae.processed_effect_param.input_effect_param.buffer.kind
+                    != BufKinds::CooldownTurnsNumber
+            })
+            .map(|gae| &gae.effect_outcome.target_id_name);
+        let dodging = ra
+            .all_dodging
+            .iter()
+            .filter(|d| d.is_dodging || d.is_blocking)
+            .map(|d| &d.name);
+        for target in hit.chain(dodging) {
+            if !targets.contains(target) {
+                targets.push(target.clone());
+            }
+        }
+        AttackContext {
+            turn: ra.turn_nb,
+            round: ra.round_nb,
+            launcher: ra.launcher_id_name.clone(),
+            attack: ra.atk_name.clone(),
+            targets,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LogKind {
+    Combat,
+    Heal,
+    Event,
+    Other,
+    /// `AttackLaunched` header
+    AttackMarker,
+    /// `ScenarioStarted` header
+    ScenarioMarker,
+}
+
+impl LogKind {
+    pub fn is_marker(self) -> bool {
+        matches!(self, LogKind::AttackMarker | LogKind::ScenarioMarker)
+    }
+
+    /// Tab of the Logs sheet (`all`, `combat`, `heal`, `event`) showing this kind.
+    pub fn in_tab(self, tab: &str) -> bool {
+        match tab {
+            "combat" => self == LogKind::Combat,
+            "heal" => self == LogKind::Heal,
+            "event" => self == LogKind::Event,
+            _ => true,
+        }
+    }
+}
+
+/// A log line with its server message and the attack it belongs to, if any.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    /// Text of the line, as in the game log
+    pub message: String,
+    pub color: String,
+    /// Message of the line when the server wrote it
+    pub server_message: Option<LogMessage>,
+    pub kind: LogKind,
+    pub context: Option<AttackContext>,
+}
+
+impl LogEntry {
+    /// Text of the line with its server message rendered by `render`.
+    pub fn text_with(&self, render: impl Fn(&ServerMessage) -> String) -> String {
+        self.server_message.as_ref().map_or_else(
+            || self.message.clone(),
+            |m| m.localize_with(&self.message, render),
+        )
+    }
+
+    /// Text of the line in the current UI language.
+    pub fn text(&self) -> String {
+        self.text_with(server_messages::render)
+    }
+}
+
+/// Marker line heading the lines of an attack, pushed by the server before them.
+#[cfg(feature = "server")]
+pub fn attack_marker(messages: &mut LogMessages, context: AttackContext) -> LogData {
+    let AttackContext {
+        turn,
+        round,
+        launcher,
+        attack,
+        targets,
+    } = context;
+    let message = ServerMessage::AttackLaunched {
+        turn,
+        round,
+        launcher,
+        attack,
+        targets,
+    };
+    messages.line("⚔️", message, "")
+}
+
+/// Marker line opening the lines of `scenario`.
+#[cfg(feature = "server")]
+pub fn scenario_marker(messages: &mut LogMessages, scenario: &str) -> LogData {
+    let message = ServerMessage::ScenarioStarted {
+        scenario: scenario.to_owned(),
+    };
+    messages.line("", message, LIGHT_BLUE)
+}
+
+/// Lines of the scenario being played: from its `ScenarioStarted` marker on, or
+/// the whole log when there is none (games started before the markers existed).
+pub fn current_scenario<'a>(logs: &'a [LogData], messages: &LogMessages) -> &'a [LogData] {
+    let start = logs
+        .iter()
+        .rposition(|log| {
+            messages
+                .get(&log.message)
+                .is_some_and(|m| matches!(m.message, ServerMessage::ScenarioStarted { .. }))
+        })
+        .unwrap_or(0);
+    &logs[start..]
+}
+
+/// Gives every line of `logs` its server message, its kind and the context of the
+/// last attack marker before it. A scenario marker ends the context.
+pub fn annotate(logs: &[LogData], messages: &LogMessages) -> Vec<LogEntry> {
+    let mut context: Option<AttackContext> = None;
+    logs.iter()
+        .map(|log| {
+            let mut kind = match log.color.as_str() {
+                c if c == DARK_RED => LogKind::Combat,
+                c if c == LIGHT_GREEN => LogKind::Heal,
+                c if c == LIGHT_BLUE => LogKind::Event,
+                _ => LogKind::Other,
+            };
+            let server_message = messages.get(&log.message).cloned();
+            match server_message.as_ref().map(|m| &m.message) {
+                Some(ServerMessage::AttackLaunched {
+                    turn,
+                    round,
+                    launcher,
+                    attack,
+                    targets,
+                }) => {
+                    kind = LogKind::AttackMarker;
+                    context = Some(AttackContext {
+                        turn: *turn,
+                        round: *round,
+                        launcher: launcher.clone(),
+                        attack: attack.clone(),
+                        targets: targets.clone(),
+                    });
+                }
+                Some(ServerMessage::ScenarioStarted { .. }) => {
+                    kind = LogKind::ScenarioMarker;
+                    context = None;
+                }
+                _ => {}
+            }
+            LogEntry {
+                message: log.message.clone(),
+                color: log.color.clone(),
+                server_message,
+                kind,
+                context: context.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Filters of the Logs sheet. Empty fields don't filter.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogFilter {
+    /// Searched in the displayed text, case-insensitive
+    pub text: String,
+    pub actor: String,
+    pub target: String,
+    pub attack: String,
+    pub turn_min: Option<usize>,
+    pub turn_max: Option<usize>,
+}
+
+impl LogFilter {
+    pub fn is_empty(&self) -> bool {
+        *self == LogFilter::default()
+    }
+
+    /// Whether `entry`, displayed as `text`, passes the filters. Lines outside of
+    /// any attack only pass when no actor/target/attack/turn filter is set.
+    pub fn matches(&self, entry: &LogEntry, text: &str) -> bool {
+        let text_ok = self.text.trim().is_empty()
+            || text
+                .to_lowercase()
+                .contains(&self.text.trim().to_lowercase());
+        let context_filtered = !self.actor.is_empty()
+            || !self.target.is_empty()
+            || !self.attack.is_empty()
+            || self.turn_min.is_some()
+            || self.turn_max.is_some();
+        let context_ok = match &entry.context {
+            _ if !context_filtered => true,
+            None => false,
+            Some(c) => {
+                (self.actor.is_empty() || c.launcher == self.actor)
+                    && (self.target.is_empty() || c.targets.contains(&self.target))
+                    && (self.attack.is_empty() || c.attack == self.attack)
+                    && self.turn_min.is_none_or(|min| c.turn >= min)
+                    && self.turn_max.is_none_or(|max| c.turn <= max)
+            }
+        };
+        text_ok && context_ok
+    }
+}
+
+/// Entries kept by `keep`, plus the markers heading at least one kept line, so
+/// the results stay grouped by attack. Markers themselves are never passed to
+/// `keep`.
+pub fn filter_entries(
+    entries: &[LogEntry],
+    mut keep: impl FnMut(&LogEntry) -> bool,
+) -> Vec<&LogEntry> {
+    let mut kept: Vec<&LogEntry> = Vec::new();
+    let mut pending_markers: Vec<&LogEntry> = Vec::new();
+    for entry in entries {
+        if entry.kind.is_marker() {
+            // A scenario marker stays above the attacks it opens; an attack marker
+            // replaces the previous one when nothing was kept in between.
+            if entry.kind == LogKind::ScenarioMarker {
+                pending_markers.clear();
+            } else {
+                pending_markers.retain(|m| m.kind == LogKind::ScenarioMarker);
+            }
+            pending_markers.push(entry);
+        } else if keep(entry) {
+            kept.append(&mut pending_markers);
+            kept.push(entry);
+        }
+    }
+    kept
+}
+
+/// `entries` (as returned by `filter_entries`) newest attack first, each attack
+/// still reading top-down from its marker.
+pub fn newest_first(entries: Vec<&LogEntry>) -> Vec<&LogEntry> {
+    let mut groups: Vec<Vec<&LogEntry>> = Vec::new();
+    for entry in entries {
+        match groups.last_mut() {
+            Some(group) if !entry.kind.is_marker() => group.push(entry),
+            _ => groups.push(vec![entry]),
+        }
+    }
+    groups.into_iter().rev().flatten().collect()
+}
+
+/// Launchers, targets and attacks found in the contexts of `entries`, sorted, for
+/// the filter pickers; and the last turn.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogFacets {
+    pub actors: Vec<String>,
+    pub targets: Vec<String>,
+    pub attacks: Vec<String>,
+    pub last_turn: usize,
+}
+
+pub fn facets(entries: &[LogEntry]) -> LogFacets {
+    let mut facets = LogFacets::default();
+    for c in entries.iter().filter_map(|e| e.context.as_ref()) {
+        facets.actors.push(c.launcher.clone());
+        facets.targets.extend(c.targets.iter().cloned());
+        facets.attacks.push(c.attack.clone());
+        facets.last_turn = facets.last_turn.max(c.turn);
+    }
+    for list in [&mut facets.actors, &mut facets.targets, &mut facets.attacks] {
+        list.sort();
+        list.dedup();
+    }
+    facets
+}
+
+/// File formats of the log export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum LogExportFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+impl LogExportFormat {
+    pub const ALL: [LogExportFormat; 3] = [
+        LogExportFormat::Text,
+        LogExportFormat::Json,
+        LogExportFormat::Csv,
+    ];
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            LogExportFormat::Text => "txt",
+            LogExportFormat::Json => "json",
+            LogExportFormat::Csv => "csv",
+        }
+    }
+
+    pub fn mime(self) -> &'static str {
+        match self {
+            LogExportFormat::Text => "text/plain",
+            LogExportFormat::Json => "application/json",
+            LogExportFormat::Csv => "text/csv",
+        }
+    }
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+
+    fn line(message: &str, color: &str) -> LogData {
+        LogData {
+            message: message.to_owned(),
+            color: color.to_owned(),
+        }
+    }
+
+    fn context(turn: usize, launcher: &str, target: &str) -> AttackContext {
+        AttackContext {
+            turn,
+            round: 1,
+            launcher: launcher.to_owned(),
+            attack: "Boule de feu".to_owned(),
+            targets: vec![target.to_owned()],
+        }
+    }
+
+    fn sample(messages: &mut LogMessages) -> Vec<LogData> {
+        vec![
+            line("old scenario", LIGHT_BLUE),
+            scenario_marker(messages, "La Moria"),
+            attack_marker(messages, context(1, "Elara", "Orc")),
+            line("Elara hits Orc", DARK_RED),
+            attack_marker(messages, context(2, "Orc", "Elara")),
+            line("Orc hits Elara", DARK_RED),
+            line("Elara heals", LIGHT_GREEN),
+        ]
+    }
+
+    #[test]
+    fn lines_take_the_context_of_their_attack() {
+        let mut messages = LogMessages::default();
+        let logs = sample(&mut messages);
+        let entries = annotate(current_scenario(&logs, &messages), &messages);
+        assert_eq!(entries.len(), 6);
+        assert_eq!(entries[0].kind, LogKind::ScenarioMarker);
+        assert_eq!(entries[2].context, Some(context(1, "Elara", "Orc")));
+        assert_eq!(entries[5].kind, LogKind::Heal);
+        assert_eq!(entries[5].context.as_ref().map(|c| c.turn), Some(2));
+
+        let filter = LogFilter {
+            actor: "Orc".to_owned(),
+            ..Default::default()
+        };
+        let kept = filter_entries(&entries, |e| filter.matches(e, &e.message));
+        assert_eq!(
+            kept.iter().map(|e| e.message.as_str()).collect::<Vec<_>>(),
+            [
+                entries[0].message.as_str(),
+                entries[3].message.as_str(),
+                "Orc hits Elara",
+                "Elara heals"
+            ]
+        );
+
+        let newest: Vec<&str> = newest_first(kept)
+            .iter()
+            .map(|e| e.message.as_str())
+            .collect();
+        assert_eq!(newest[0], entries[3].message);
+        assert_eq!(newest[3], entries[0].message);
+
+        let filter = LogFilter {
+            text: "HEALS".to_owned(),
+            turn_max: Some(1),
+            ..Default::default()
+        };
+        assert!(filter_entries(&entries, |e| filter.matches(e, &e.message)).is_empty());
+    }
+}
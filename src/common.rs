@@ -7,14 +7,16 @@ use dioxus::prelude::*;
 use lib_rpg::server::data_manager::DataManager;
 
 use crate::board_game_components::admin_page::AdminPage;
+use crate::board_game_components::arena_page::ArenaPage;
 use crate::board_game_components::create_server_page::CreateServer;
 use crate::board_game_components::home_page::Home;
 use crate::board_game_components::joinongoinggame_page::JoinOngoingGame;
+use crate::board_game_components::leaderboard_page::LeaderboardPage;
 use crate::board_game_components::loadgame_page::LoadGame;
 use crate::board_game_components::lobby_page::LobbyPage;
 use crate::board_game_components::navbar::Navbar;
+use crate::board_game_components::profile_page::ProfilePage;
 use crate::board_game_components::startgame_page::RunningGamePage;
-use colorgrad::{GradientBuilder, LinearGradient};
 use once_cell::sync::Lazy;
 
 // Global signals
@@ -30,13 +32,6 @@ pub static ADMIN: Lazy<String> = Lazy::new(|| "Admin".to_owned());
 pub static DISCONNECTED_USER: Lazy<String> = Lazy::new(|| "not connected".to_owned());
 pub static SAVED_DATA: Lazy<PathBuf> = Lazy::new(|| PathBuf::from("saved_data"));
 
-pub static ENERGY_GRAD: Lazy<LinearGradient> = Lazy::new(|| {
-    GradientBuilder::new()
-        .html_colors(&["#ff2600ff", "#f2ff00ff", "#11c426ff"])
-        .build::<colorgrad::LinearGradient>()
-        .expect("Failed to build gradient")
-});
-
 #[derive(Debug, Clone, Routable, PartialEq, serde::Serialize, serde::Deserialize,)]
 #[rustfmt::skip]
 pub enum Route {
@@ -55,6 +50,12 @@ pub enum Route {
     LoadGame {},
     #[route("/current-game")]
     JoinOngoingGame {},
+    #[route("/profile/:username")]
+    ProfilePage { username: String },
+    #[route("/leaderboards")]
+    LeaderboardPage {},
+    #[route("/arena")]
+    ArenaPage {},
 }
 
 pub const PATH_IMG: Asset = asset!("/assets/img");
@@ -90,6 +91,44 @@ pub const SYNCED_AUDIO_MUTED_KEY: &str = "synced_audio_muted";
 #[derive(Clone, Copy)]
 pub struct CtxToggleAtkAnimation(pub Signal<bool>);
 
+/// Timeline of the last attack result while it plays on the board (see
+/// `combat_animation.rs`); set by `GameBoard`, read by `CharacterPanel`.
+#[derive(Clone, Copy)]
+pub struct CtxCombatAnimation(pub Signal<Option<crate::combat_animation::CombatAnimation>>);
+
+/// Achievements unlocked during this session and not dismissed yet, shown as
+/// toasts by `widgets::achievement_toasts`; filled by `App()`.
+#[derive(Clone, Copy)]
+pub struct CtxAchievementToasts(pub Signal<Vec<crate::achievements::AchievementDef>>);
+
+/// Difficulty of the game joined, and whether its owner can start New Game
+/// Plus; sent by the server with each full update, filled by `App()`.
+#[derive(Clone, Copy)]
+pub struct CtxGameSettings {
+    pub settings: Signal<crate::difficulty::GameSettings>,
+    pub new_game_plus_available: Signal<bool>,
+}
+
+/// Materials owned by the party of the game joined, spent on crafting; sent by
+/// the server with each full update, filled by `App()`.
+#[derive(Clone, Copy)]
+pub struct CtxMaterials(pub Signal<crate::crafting::MaterialStash>);
+
+/// Trades waiting for an answer in the game joined; sent by the server with
+/// each full update, filled by `App()`.
+#[derive(Clone, Copy)]
+pub struct CtxTrades(pub Signal<Vec<crate::trading::TradeProposal>>);
+
+/// Quests of the game joined and the choices of the dialogue going on in its
+/// overworld; sent by the server with each full update, filled by `App()`.
+#[derive(Clone, Copy)]
+pub struct CtxQuests(pub Signal<crate::quests::QuestsUpdate>);
+
+/// Messages of the lines the server wrote in the log of the game joined, to show
+/// them (and the action banners) in the UI language; filled by `App()`.
+#[derive(Clone, Copy)]
+pub struct CtxLogMessages(pub Signal<crate::server_messages::LogMessages>);
+
 /// Whether boss energy (mana/vigor/berserk) bars are shown
 #[derive(Clone, Copy)]
 pub struct CtxShowBossEnergy(pub Signal<bool>);
@@ -122,13 +161,55 @@ pub struct CtxShopEnabled(pub Signal<bool>);
 #[derive(Clone, Copy)]
 pub struct CtxAtkPanelOrders(pub Signal<std::collections::HashMap<String, Vec<String>>>);
 
-/// Current UI language ("en" or "fr"), synced to browser localStorage (works
+/// Player key bindings for combat and the game sheets. Defaults until `GameSheets`
+/// loads the saved table from `user_settings` (see `hotkeys::SETTING_HOTKEYS`).
+#[derive(Clone, Copy)]
+pub struct CtxHotkeys(pub Signal<crate::hotkeys::HotkeyBindings>);
+
+/// Accessibility settings: colour palette of bars/logs and reduced motion (no attack
+/// animations). Loaded by `GameSheets`, mirrored on `<html>` by `App()` (see
+/// `accessibility::apply_to_document`).
+#[derive(Clone, Copy)]
+pub struct CtxA11y {
+    pub palette: Signal<crate::accessibility::ColorPalette>,
+    pub reduced_motion: Signal<bool>,
+}
+
+/// Theme options: light/dark/system mode and whether the open game's universe
+/// skin (see `theme::UniverseSkin`) is applied. Loaded by `GameSheets`, mirrored
+/// on `<html>` by `App()` (see `theme::apply_to_document`).
+#[derive(Clone, Copy)]
+pub struct CtxTheme {
+    pub mode: Signal<crate::theme::ThemeMode>,
+    pub universe_skins: Signal<bool>,
+}
+
+/// Gamepad state fed by `gamepad::run_gamepad_bridge`: the last button press, the
+/// device used last (for button prompts), whether a pad was seen, the bindings
+/// (loaded by `GameSheets`, see `gamepad::SETTING_GAMEPAD`), and whether the
+/// Settings sheet is capturing a button for remapping.
+#[derive(Clone, Copy)]
+pub struct CtxGamepad {
+    pub pressed: Signal<crate::gamepad::PadPress>,
+    pub input_mode: Signal<crate::gamepad::InputMode>,
+    pub connected: Signal<bool>,
+    pub bindings: Signal<crate::gamepad::GamepadBindings>,
+    pub capturing: Signal<bool>,
+}
+
+/// Current UI language ("en", "fr" or a server-served language identifier such as
+/// "de-DE", see `locales::LocaleInfo::code`), synced to browser localStorage (works
 /// pre-login, unlike the SQLite-backed CtxShow* settings above). Drives both
 /// dioxus-i18n's t!() chrome strings (via the sync effect in main.rs) and
 /// lib-rpg's Lang resolver for bilingual descriptions (see lang_from_app_lang).
 #[derive(Clone, Copy)]
 pub struct CtxAppLang(pub Signal<String>);
 
+/// Locales served by the server on top of the bundled ones, fetched once by `App()`
+/// (see `locales::available_locales`).
+#[derive(Clone, Copy)]
+pub struct CtxLocales(pub Signal<Vec<crate::locales::LocaleBundle>>);
+
 /// Native clients only: the user-chosen server address, persisted via
 /// `SYNCED_SERVER_URL_KEY` and read with priority over the compile-time-baked default
 /// on the *next* launch (see `main.rs`). Declared in `App()` and provided via context
@@ -162,7 +243,8 @@ pub struct CtxAudioSettings {
 
 /// Converts the app's "en"/"fr" locale string into lib-rpg's `Lang` enum —
 /// the one conversion boundary between the two crates' locale representations
-/// (lib-rpg has no dioxus/unic_langid dependency).
+/// (lib-rpg has no dioxus/unic_langid dependency). lib-rpg only knows English and
+/// French, so the game data of server-served locales is shown in English.
 pub fn lang_from_app_lang(app_lang: &str) -> lib_rpg::common::lang::Lang {
     if app_lang == "fr" {
         lib_rpg::common::lang::Lang::Fr
@@ -189,6 +271,22 @@ pub fn photo_src(photo_name: &str) -> String {
     } else {
         format!("/img-srv/{}.png", photo_name)
     };
+    with_server_base(path)
+}
+
+/// Returns the URL of an uploaded music track (the `/music-srv` route), prefixed
+/// like `photo_src` on native clients.
+pub fn music_src(track: &str) -> String {
+    with_server_base(format!("/music-srv/{track}"))
+}
+
+/// Returns the URL of an uploaded attack sound effect (the `/sfx-srv` route),
+/// prefixed like `photo_src` on native clients.
+pub fn sfx_src(file: &str) -> String {
+    with_server_base(format!("/sfx-srv/{file}"))
+}
+
+fn with_server_base(path: String) -> String {
     let base = dioxus::fullstack::get_server_url();
     if base.is_empty() {
         path
@@ -0,0 +1,225 @@
+//! Crafting. The Material loots won in scenarios go to a stash shared by the
+//! party, kept with the game save (`SAVED_MATERIALS`); recipes, one JSON file
+//! each in `offlines/recipes/`, turn materials plus gold into equipment or
+//! consumables. The server side is in `websocket_handler/event_crafting.rs`.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Directory of the recipes, under `OFFLINE_PATH`.
+pub const RECIPES_DIR: &str = "recipes";
+/// File of the material stash, in the directory of a saved game.
+pub const SAVED_MATERIALS: &str = "materials.json";
+
+/// Amount of each material owned by a party, by material name.
+pub type MaterialStash = BTreeMap<String, u64>;
+
+/// What a recipe makes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CraftedKind {
+    #[default]
+    Equipment,
+    Consumable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ingredient {
+    pub material: String,
+    pub amount: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Recipe {
+    pub name: String,
+    pub kind: CraftedKind,
+    /// Unique name of the equipment, or name of the consumable, crafted
+    pub output: String,
+    /// Paid by the hero who crafts
+    pub gold: u64,
+    /// Taken from the party stash
+    pub materials: Vec<Ingredient>,
+}
+
+impl Recipe {
+    /// Materials the party still lacks to craft it, with the amounts lacking.
+    pub fn missing_materials(&self, stash: &MaterialStash) -> Vec<Ingredient> {
+        self.materials
+            .iter()
+            .filter_map(|ingredient| {
+                let owned = stash.get(&ingredient.material).copied().unwrap_or(0);
+                (owned < ingredient.amount).then(|| Ingredient {
+                    material: ingredient.material.clone(),
+                    amount: ingredient.amount - owned,
+                })
+            })
+            .collect()
+    }
+
+    pub fn can_craft(&self, stash: &MaterialStash, gold: u64) -> bool {
+        gold >= self.gold && self.missing_materials(stash).is_empty()
+    }
+
+    /// Checks a recipe written in the admin editor.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("The recipe has no name".to_owned());
+        }
+        if self.output.trim().is_empty() {
+            return Err("The recipe makes nothing".to_owned());
+        }
+        if self.materials.is_empty() {
+            return Err("The recipe needs at least one material".to_owned());
+        }
+        for (i, ingredient) in self.materials.iter().enumerate() {
+            if ingredient.material.trim().is_empty() || ingredient.amount == 0 {
+                return Err(format!("Invalid material at line {}", i + 1));
+            }
+            if self.materials[..i]
+                .iter()
+                .any(|other| other.material == ingredient.material)
+            {
+                return Err(format!("{} is listed twice", ingredient.material));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Adds `amount` of `material` to `stash`.
+pub fn add_material(stash: &mut MaterialStash, material: &str, amount: u64) {
+    if amount > 0 {
+        *stash.entry(material.to_owned()).or_insert(0) += amount;
+    }
+}
+
+/// Takes the materials of `recipe` out of `stash`. Returns `false`, leaving
+/// `stash` as it is, when one of them is lacking.
+pub fn take_materials(stash: &mut MaterialStash, recipe: &Recipe) -> bool {
+    if !recipe.missing_materials(stash).is_empty() {
+        return false;
+    }
+    for ingredient in &recipe.materials {
+        if let Some(owned) = stash.get_mut(&ingredient.material) {
+            *owned -= ingredient.amount;
+            if *owned == 0 {
+                stash.remove(&ingredient.material);
+            }
+        }
+    }
+    true
+}
+
+/// Materials of the admin editor, one per line: "Material: amount", or
+/// "Material" for one of it.
+pub fn parse_materials(text: &str) -> Vec<Ingredient> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| match line.rsplit_once(':') {
+            Some((material, amount)) => Ingredient {
+                material: material.trim().to_owned(),
+                amount: amount.trim().parse().unwrap_or(0),
+            },
+            None => Ingredient {
+                material: line.to_owned(),
+                amount: 1,
+            },
+        })
+        .collect()
+}
+
+pub fn format_materials(materials: &[Ingredient]) -> String {
+    materials
+        .iter()
+        .map(|ingredient| format!("{}: {}", ingredient.material, ingredient.amount))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Recipes of `dir` with their file stems, sorted by name. Files that aren't
+/// recipes are skipped.
+#[cfg(feature = "server")]
+pub fn read_recipes(dir: &std::path::Path) -> Vec<(String, Recipe)> {
+    let mut recipes: Vec<(String, Recipe)> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|x| x == "json"))
+        .filter_map(|path| {
+            let stem = path.file_stem()?.to_string_lossy().to_string();
+            let recipe = serde_json::from_str::<Recipe>(&std::fs::read_to_string(&path).ok()?);
+            Some((stem, recipe.ok()?))
+        })
+        .collect();
+    recipes.sort_by(|a, b| a.1.name.cmp(&b.1.name));
+    recipes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sword() -> Recipe {
+        Recipe {
+            name: "Forged sword".to_owned(),
+            kind: CraftedKind::Equipment,
+            output: "Epee".to_owned(),
+            gold: 50,
+            materials: parse_materials("Iron ore: 3\nLeather"),
+        }
+    }
+
+    #[test]
+    fn crafting_takes_the_materials_only_when_all_are_there() {
+        let recipe = sword();
+        let mut stash = MaterialStash::new();
+        add_material(&mut stash, "Iron ore", 2);
+        assert_eq!(
+            recipe.missing_materials(&stash),
+            vec![
+                Ingredient {
+                    material: "Iron ore".to_owned(),
+                    amount: 1,
+                },
+                Ingredient {
+                    material: "Leather".to_owned(),
+                    amount: 1,
+                },
+            ]
+        );
+        assert!(!take_materials(&mut stash, &recipe));
+        assert_eq!(stash.get("Iron ore"), Some(&2));
+
+        add_material(&mut stash, "Iron ore", 2);
+        add_material(&mut stash, "Leather", 1);
+        assert!(recipe.can_craft(&stash, 50));
+        assert!(!recipe.can_craft(&stash, 49));
+        assert!(take_materials(&mut stash, &recipe));
+        assert_eq!(stash.get("Iron ore"), Some(&1));
+        assert_eq!(stash.get("Leather"), None);
+    }
+
+    #[test]
+    fn recipes_are_checked_and_round_trip() {
+        let recipe = sword();
+        assert_eq!(recipe.validate(), Ok(()));
+        assert_eq!(
+            parse_materials(&format_materials(&recipe.materials)),
+            recipe.materials
+        );
+
+        let mut twice = sword();
+        twice.materials = parse_materials("Leather\nLeather: 2");
+        assert!(twice.validate().is_err());
+        let mut empty = sword();
+        empty.materials = parse_materials("Iron ore: none");
+        assert!(empty.validate().is_err());
+
+        let json = r#"{"name":"Potion","kind":"Consumable","output":"Super potion","materials":[{"material":"Herb","amount":2}]}"#;
+        let potion: Recipe = serde_json::from_str(json).unwrap();
+        assert_eq!(potion.gold, 0);
+        assert_eq!(potion.kind, CraftedKind::Consumable);
+    }
+}
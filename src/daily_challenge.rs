@@ -0,0 +1,200 @@
+//! Daily challenge. Every day a seed derived from the date draws one scenario,
+//! the heroes its party is picked from, the party size and some modifiers: all
+//! the players get the same challenge and can start it once a day. The results
+//! feed the day's leaderboard (see `server_fn/daily_challenges.rs`); the
+//! restrictions are enforced in `websocket_handler/event_daily_challenge.rs`.
+//!
+//! Only the challenge is drawn from the seed: the fight rolls its critical
+//! hits, dodges and enemy choices with the RNG of lib-rpg, which has no seed to
+//! take, so each attempt has its own luck.
+
+use rand::{
+    Rng, SeedableRng,
+    rngs::StdRng,
+    seq::{IndexedRandom, SliceRandom},
+};
+
+/// Most heroes a challenge offers to pick the party from.
+pub const DAILY_CHALLENGE_HERO_POOL: usize = 4;
+/// Largest party a challenge allows.
+pub const DAILY_CHALLENGE_MAX_PARTY: usize = 3;
+
+/// Extra rule of a daily challenge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ChallengeModifier {
+    /// No potion or other consumable can be used
+    NoPotions,
+    /// No talent can be unlocked
+    NoTalents,
+    /// The scenario must be won within this many turns
+    TurnLimit(i64),
+}
+
+/// A scenario a challenge can be drawn on, with the heroes of its universe.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChallengeCandidate {
+    pub universe: String,
+    pub scenario: String,
+    pub heroes: Vec<String>,
+}
+
+/// The challenge of one day.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DailyChallenge {
+    /// `%Y-%m-%d`, server time
+    pub date: String,
+    pub universe: String,
+    pub scenario: String,
+    /// Heroes the party is picked from
+    pub heroes: Vec<String>,
+    pub max_heroes: usize,
+    pub modifiers: Vec<ChallengeModifier>,
+}
+
+impl DailyChallenge {
+    pub fn has(&self, modifier: ChallengeModifier) -> bool {
+        self.modifiers.contains(&modifier)
+    }
+
+    pub fn turn_limit(&self) -> Option<i64> {
+        self.modifiers.iter().find_map(|m| match m {
+            ChallengeModifier::TurnLimit(turns) => Some(*turns),
+            _ => None,
+        })
+    }
+
+    /// Whether `character_name` can join a party already holding `party_size`
+    /// heroes.
+    pub fn allows_hero(&self, character_name: &str, party_size: usize) -> bool {
+        party_size < self.max_heroes && self.heroes.iter().any(|h| h == character_name)
+    }
+
+    /// Whether a scenario ending after `turns` turns beats the challenge.
+    pub fn is_beaten(&self, won: bool, turns: i64) -> bool {
+        won && self.turn_limit().is_none_or(|limit| turns <= limit)
+    }
+}
+
+/// Seed of the challenge of `date`, the same on every server.
+pub fn daily_seed(date: chrono::NaiveDate) -> u64 {
+    use chrono::Datelike;
+    // splitmix64: consecutive days get unrelated seeds
+    let mut z = (date.num_days_from_ce() as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Challenge of `date` among `candidates`, or `None` when none of them has a
+/// hero to play it with. The draw doesn't depend on the order of `candidates`.
+pub fn generate_daily_challenge(
+    date: chrono::NaiveDate,
+    candidates: &[ChallengeCandidate],
+) -> Option<DailyChallenge> {
+    let mut rng = StdRng::seed_from_u64(daily_seed(date));
+
+    let mut candidates: Vec<&ChallengeCandidate> =
+        candidates.iter().filter(|c| !c.heroes.is_empty()).collect();
+    candidates.sort_by(|a, b| (&a.universe, &a.scenario).cmp(&(&b.universe, &b.scenario)));
+    let candidate = candidates.choose(&mut rng)?;
+
+    let mut heroes = candidate.heroes.clone();
+    heroes.sort();
+    heroes.dedup();
+    heroes.shuffle(&mut rng);
+    heroes.truncate(DAILY_CHALLENGE_HERO_POOL);
+    let max_heroes = rng.random_range(1..=heroes.len().min(DAILY_CHALLENGE_MAX_PARTY));
+
+    let mut modifiers = Vec::new();
+    if rng.random_bool(0.5) {
+        modifiers.push(ChallengeModifier::NoPotions);
+    }
+    if rng.random_bool(0.5) {
+        modifiers.push(ChallengeModifier::NoTalents);
+    }
+    if rng.random_bool(0.3) {
+        modifiers.push(ChallengeModifier::TurnLimit(rng.random_range(15..=30)));
+    }
+
+    Some(DailyChallenge {
+        date: date.format("%Y-%m-%d").to_string(),
+        universe: candidate.universe.clone(),
+        scenario: candidate.scenario.clone(),
+        heroes,
+        max_heroes,
+        modifiers,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(universe: &str, scenario: &str, heroes: &[&str]) -> ChallengeCandidate {
+        ChallengeCandidate {
+            universe: universe.to_owned(),
+            scenario: scenario.to_owned(),
+            heroes: heroes.iter().map(|h| h.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn every_server_draws_the_same_challenge() {
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+        let mut candidates = vec![
+            candidate("kanto", "Route 1", &["Pikachu", "Bulbizarre", "Salamèche"]),
+            candidate(
+                "kanto",
+                "Arène d'Argenta",
+                &["Pikachu", "Bulbizarre", "Salamèche"],
+            ),
+            candidate(
+                "fantasy",
+                "Crypte",
+                &["Elara", "Thorin", "Azrak", "Lyra", "Kael"],
+            ),
+            candidate("empty", "Nobody", &[]),
+        ];
+        let challenge = generate_daily_challenge(date, &candidates).unwrap();
+        candidates.reverse();
+        assert_eq!(
+            generate_daily_challenge(date, &candidates),
+            Some(challenge.clone())
+        );
+
+        assert_eq!(challenge.date, "2026-10-19");
+        assert_ne!(challenge.universe, "empty");
+        assert!(
+            !challenge.heroes.is_empty() && challenge.heroes.len() <= DAILY_CHALLENGE_HERO_POOL
+        );
+        assert!(
+            (1..=challenge.heroes.len().min(DAILY_CHALLENGE_MAX_PARTY))
+                .contains(&challenge.max_heroes)
+        );
+        assert_ne!(daily_seed(date), daily_seed(date.succ_opt().unwrap()));
+        assert_eq!(generate_daily_challenge(date, &candidates[..1]), None);
+    }
+
+    #[test]
+    fn restrictions_of_a_challenge() {
+        let challenge = DailyChallenge {
+            date: "2026-10-19".to_owned(),
+            universe: "kanto".to_owned(),
+            scenario: "Route 1".to_owned(),
+            heroes: vec!["Pikachu".to_owned(), "Bulbizarre".to_owned()],
+            max_heroes: 1,
+            modifiers: vec![
+                ChallengeModifier::NoPotions,
+                ChallengeModifier::TurnLimit(20),
+            ],
+        };
+        assert!(challenge.allows_hero("Pikachu", 0));
+        assert!(!challenge.allows_hero("Pikachu", 1));
+        assert!(!challenge.allows_hero("Salamèche", 0));
+        assert!(challenge.has(ChallengeModifier::NoPotions));
+        assert!(!challenge.has(ChallengeModifier::NoTalents));
+        assert!(challenge.is_beaten(true, 20));
+        assert!(!challenge.is_beaten(true, 21));
+        assert!(!challenge.is_beaten(false, 5));
+    }
+}
@@ -0,0 +1,173 @@
+//! Difficulty levels and New Game Plus. The difficulty picked in the lobby
+//! scales the bosses of every scenario when it starts, and the loots it gives;
+//! each New Game Plus cycle, started once the last stage of the universe is
+//! won, scales the bosses further for the same party. Both are kept for the
+//! whole game, next to its save (`SAVED_GAME_SETTINGS`); the server side is in
+//! `websocket_handler/event_difficulty.rs`.
+
+use serde::{Deserialize, Serialize};
+
+/// File of the game settings, in the directory of a saved game.
+pub const SAVED_GAME_SETTINGS: &str = "game_settings.json";
+/// Boss HP and power gained on each New Game Plus cycle.
+pub const NEW_GAME_PLUS_SCALING: f64 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    Story,
+    #[default]
+    Normal,
+    Hard,
+    Nightmare,
+}
+
+impl Difficulty {
+    /// From the easiest to the hardest
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Story,
+        Difficulty::Normal,
+        Difficulty::Hard,
+        Difficulty::Nightmare,
+    ];
+
+    /// Multiplier of the boss HP.
+    pub fn boss_hp(self) -> f64 {
+        match self {
+            Difficulty::Story => 0.6,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.4,
+            Difficulty::Nightmare => 2.0,
+        }
+    }
+
+    /// Multiplier of the boss physical and magical power.
+    pub fn boss_damage(self) -> f64 {
+        match self {
+            Difficulty::Story => 0.7,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.25,
+            Difficulty::Nightmare => 1.6,
+        }
+    }
+
+    /// Multiplier of the boss speed and critical strike. lib-rpg's AI picks
+    /// its attacks and targets on its own: aggressive bosses act sooner and
+    /// hit harder more often.
+    pub fn boss_aggressiveness(self) -> f64 {
+        match self {
+            Difficulty::Story => 0.8,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.15,
+            Difficulty::Nightmare => 1.3,
+        }
+    }
+
+    /// Ranks the loots go up (Common → Intermediate → Advanced).
+    pub fn loot_rank_bonus(self) -> u8 {
+        match self {
+            Difficulty::Story | Difficulty::Normal => 0,
+            Difficulty::Hard => 1,
+            Difficulty::Nightmare => 2,
+        }
+    }
+
+    /// Multiplier of the amount of each loot, at least one is kept.
+    pub fn loot_amount(self) -> f64 {
+        match self {
+            Difficulty::Story => 0.5,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.5,
+            Difficulty::Nightmare => 2.0,
+        }
+    }
+}
+
+/// How hard a game is, picked in the lobby and kept with its save.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameSettings {
+    pub difficulty: Difficulty,
+    /// New Game Plus cycles started, 0 for the first run of the universe
+    pub new_game_plus: u32,
+}
+
+impl GameSettings {
+    fn cycle_multiplier(&self) -> f64 {
+        1.0 + NEW_GAME_PLUS_SCALING * f64::from(self.new_game_plus)
+    }
+
+    pub fn boss_hp_multiplier(&self) -> f64 {
+        self.difficulty.boss_hp() * self.cycle_multiplier()
+    }
+
+    pub fn boss_damage_multiplier(&self) -> f64 {
+        self.difficulty.boss_damage() * self.cycle_multiplier()
+    }
+
+    pub fn boss_aggressiveness_multiplier(&self) -> f64 {
+        self.difficulty.boss_aggressiveness()
+    }
+
+    /// A New Game Plus cycle raises the loots one more rank.
+    pub fn loot_rank_bonus(&self) -> u8 {
+        let cycles = u8::try_from(self.new_game_plus).unwrap_or(u8::MAX);
+        self.difficulty.loot_rank_bonus().saturating_add(cycles)
+    }
+
+    /// Scaled amount of a loot of `amount`.
+    pub fn loot_amount(&self, amount: u64) -> u64 {
+        ((amount as f64 * self.difficulty.loot_amount()).round() as u64).max(1)
+    }
+
+    /// Bosses and loots as the scenarios define them: the only games ranked
+    /// on the leaderboards.
+    pub fn is_standard(&self) -> bool {
+        *self == GameSettings::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn harder_levels_scale_everything_up() {
+        for pair in Difficulty::ALL.windows(2) {
+            let (easier, harder) = (pair[0], pair[1]);
+            assert!(harder.boss_hp() > easier.boss_hp());
+            assert!(harder.boss_damage() > easier.boss_damage());
+            assert!(harder.boss_aggressiveness() > easier.boss_aggressiveness());
+            assert!(harder.loot_rank_bonus() >= easier.loot_rank_bonus());
+            assert!(harder.loot_amount() > easier.loot_amount());
+        }
+        let normal = GameSettings::default();
+        assert_eq!(normal.difficulty, Difficulty::Normal);
+        assert_eq!(normal.boss_hp_multiplier(), 1.0);
+        assert_eq!(normal.loot_amount(3), 3);
+        assert!(normal.is_standard());
+
+        let story = GameSettings {
+            difficulty: Difficulty::Story,
+            ..Default::default()
+        };
+        assert_eq!(story.loot_amount(1), 1);
+        assert!(!story.is_standard());
+    }
+
+    #[test]
+    fn new_game_plus_scales_bosses_and_loots() {
+        let cycle_2 = GameSettings {
+            difficulty: Difficulty::Hard,
+            new_game_plus: 2,
+        };
+        assert_eq!(cycle_2.boss_hp_multiplier(), 1.4 * 2.0);
+        assert_eq!(cycle_2.boss_damage_multiplier(), 1.25 * 2.0);
+        assert_eq!(cycle_2.boss_aggressiveness_multiplier(), 1.15);
+        assert_eq!(cycle_2.loot_rank_bonus(), 3);
+        assert!(!cycle_2.is_standard());
+
+        // Saves made before difficulty levels existed are Normal games
+        let old_save: GameSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(old_save, GameSettings::default());
+    }
+}
@@ -0,0 +1,227 @@
+//! Endless mode. Instead of the ten fixed stages of its universe, a run chains
+//! floors built from the universe's boss pool: each floor is drawn from the seed
+//! of the run and its depth, and its bosses get stronger the deeper it is.
+//! Between floors the party picks one of `ENDLESS_REWARD_CHOICES` rewards, the
+//! floors having no loots. The run ends when the party falls and is recorded to
+//! the profile of its players; the server side is in
+//! `websocket_handler/event_endless.rs`.
+
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::IndexedRandom};
+
+/// Rewards offered after each floor.
+pub const ENDLESS_REWARD_CHOICES: usize = 3;
+/// Most bosses fought on one floor.
+pub const ENDLESS_MAX_BOSSES: usize = 3;
+/// Boss HP and power gained on each floor below the first.
+pub const ENDLESS_SCALING_PER_FLOOR: f64 = 0.12;
+
+/// A boss of the universe a floor can be drawn with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndlessBoss {
+    pub name: String,
+    pub level: u64,
+}
+
+/// One procedural fight of a run.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EndlessFloor {
+    /// From 1
+    pub depth: u32,
+    pub bosses: Vec<String>,
+}
+
+impl EndlessFloor {
+    /// Name of the scenario playing the floor, unique within the run.
+    pub fn scenario_name(&self) -> String {
+        format!("♾️ {}", self.depth)
+    }
+}
+
+/// What the party can pick after a floor. Every hero of the party gets it.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum EndlessReward {
+    /// Unique name of a piece of equipment
+    Equipment(String),
+    /// Name of a consumable
+    Consumable(String),
+    TalentPoints(u32),
+}
+
+/// State of the run played on a server.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EndlessRun {
+    /// Set when the game starts, from the universe chosen in the lobby
+    pub universe: String,
+    pub seed: u64,
+    /// Floor being fought, or just cleared while `offered_rewards` waits for a pick
+    pub depth: u32,
+    pub floors_cleared: u32,
+    /// Rewards of the floor just cleared, until one is picked
+    pub offered_rewards: Vec<EndlessReward>,
+    pub rewards_taken: Vec<EndlessReward>,
+    /// The party has fallen
+    pub is_over: bool,
+}
+
+impl EndlessRun {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            universe: String::new(),
+            seed,
+            depth: 1,
+            floors_cleared: 0,
+            offered_rewards: Vec::new(),
+            rewards_taken: Vec::new(),
+            is_over: false,
+        }
+    }
+
+    /// The floor of `depth` was won: its rewards are offered.
+    pub fn clear_floor(&mut self, rewards: Vec<EndlessReward>) {
+        self.floors_cleared = self.depth;
+        self.offered_rewards = rewards;
+    }
+
+    /// Takes the offered reward `index` and goes down to the next floor.
+    pub fn pick_reward(&mut self, index: usize) -> Option<EndlessReward> {
+        if self.is_over || index >= self.offered_rewards.len() {
+            return None;
+        }
+        let reward = self.offered_rewards.swap_remove(index);
+        self.offered_rewards.clear();
+        self.rewards_taken.push(reward.clone());
+        self.depth += 1;
+        Some(reward)
+    }
+}
+
+/// Multiplier of the boss HP and power on the floor of `depth`.
+pub fn depth_multiplier(depth: u32) -> f64 {
+    1.0 + ENDLESS_SCALING_PER_FLOOR * f64::from(depth.saturating_sub(1))
+}
+
+/// Same draws for the same run, floor and purpose, whatever the server.
+fn floor_rng(seed: u64, depth: u32, purpose: u64) -> StdRng {
+    StdRng::seed_from_u64(
+        seed ^ u64::from(depth).wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ purpose.rotate_left(32),
+    )
+}
+
+/// Floor of `depth` for the run of `seed`, or `None` when `pool` is empty. The
+/// bosses are drawn among those whose level fits the depth, and there are more
+/// of them deeper down.
+pub fn generate_floor(seed: u64, depth: u32, pool: &[EndlessBoss]) -> Option<EndlessFloor> {
+    let mut rng = floor_rng(seed, depth, 0);
+    let mut pool: Vec<&EndlessBoss> = pool.iter().collect();
+    pool.sort_by(|a, b| a.name.cmp(&b.name));
+    pool.dedup_by(|a, b| a.name == b.name);
+    let lowest_level = pool.iter().map(|b| b.level).min()?;
+    let max_level = u64::from(depth).max(lowest_level);
+    let fitting: Vec<&EndlessBoss> = pool.into_iter().filter(|b| b.level <= max_level).collect();
+    let count = (1 + depth as usize / 4)
+        .min(ENDLESS_MAX_BOSSES)
+        .min(fitting.len());
+    let bosses = fitting
+        .choose_multiple(&mut rng, count)
+        .map(|b| b.name.clone())
+        .collect();
+    Some(EndlessFloor { depth, bosses })
+}
+
+/// Draws one of `items`, sorted from the worst to the best: the better ones
+/// come within reach deeper down.
+fn draw_item(rng: &mut StdRng, items: &[String], depth: u32) -> Option<String> {
+    if items.is_empty() {
+        return None;
+    }
+    let reach = (items.len() * depth as usize)
+        .div_ceil(10)
+        .clamp(1, items.len());
+    items[..reach].choose(rng).cloned()
+}
+
+/// The `ENDLESS_REWARD_CHOICES` rewards offered after the floor of `depth`,
+/// no two of them the same. `equipments` and `consumables` are sorted from the
+/// worst to the best.
+pub fn offer_rewards(
+    seed: u64,
+    depth: u32,
+    equipments: &[String],
+    consumables: &[String],
+) -> Vec<EndlessReward> {
+    let mut rng = floor_rng(seed, depth, 1);
+    let mut rewards = Vec::new();
+    // A few tries per slot: the pools may be small
+    for _ in 0..ENDLESS_REWARD_CHOICES * 4 {
+        if rewards.len() == ENDLESS_REWARD_CHOICES {
+            break;
+        }
+        let reward = match rng.random_range(0..3) {
+            0 => draw_item(&mut rng, equipments, depth).map(EndlessReward::Equipment),
+            1 => draw_item(&mut rng, consumables, depth).map(EndlessReward::Consumable),
+            _ => Some(EndlessReward::TalentPoints(1 + depth / 5)),
+        };
+        if let Some(reward) = reward
+            && !rewards.contains(&reward)
+        {
+            rewards.push(reward);
+        }
+    }
+    rewards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn boss(name: &str, level: u64) -> EndlessBoss {
+        EndlessBoss {
+            name: name.to_owned(),
+            level,
+        }
+    }
+
+    #[test]
+    fn floors_get_harder_deeper_down() {
+        let pool = vec![
+            boss("Rattata", 1),
+            boss("Pidgey", 2),
+            boss("Mankey", 3),
+            boss("Gengar", 6),
+            boss("Mewtwo", 10),
+        ];
+        let first = generate_floor(7, 1, &pool).unwrap();
+        assert_eq!(first.bosses, vec!["Rattata".to_owned()]);
+        assert_eq!(generate_floor(7, 1, &pool), Some(first));
+
+        let deep = generate_floor(7, 12, &pool).unwrap();
+        assert_eq!(deep.bosses.len(), ENDLESS_MAX_BOSSES);
+        let mut reversed = pool.clone();
+        reversed.reverse();
+        assert_eq!(generate_floor(7, 12, &reversed), Some(deep));
+
+        assert_eq!(depth_multiplier(1), 1.0);
+        assert!(depth_multiplier(10) > depth_multiplier(9));
+        assert_eq!(generate_floor(7, 1, &[]), None);
+    }
+
+    #[test]
+    fn rewards_are_distinct_and_picked_once() {
+        let equipments = vec!["wooden sword".to_owned(), "mithril shirt".to_owned()];
+        let consumables = vec!["Common potion".to_owned()];
+        let rewards = offer_rewards(3, 2, &equipments, &consumables);
+        assert_eq!(rewards.len(), ENDLESS_REWARD_CHOICES);
+        assert_eq!(rewards, offer_rewards(3, 2, &equipments, &consumables));
+        // the best item is out of reach on the first floors
+        assert!(!rewards.contains(&EndlessReward::Equipment("mithril shirt".to_owned())));
+
+        let mut run = EndlessRun::new(3);
+        run.clear_floor(rewards.clone());
+        assert_eq!(run.floors_cleared, 1);
+        assert_eq!(run.pick_reward(ENDLESS_REWARD_CHOICES), None);
+        assert_eq!(run.pick_reward(1), Some(rewards[1].clone()));
+        assert_eq!(run.depth, 2);
+        assert!(run.offered_rewards.is_empty());
+        assert_eq!(run.pick_reward(0), None);
+    }
+}
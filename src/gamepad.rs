@@ -0,0 +1,362 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::common::CtxGamepad;
+
+/// `user_settings` key holding the player's gamepad bindings as JSON (see `GamepadBindings`).
+pub const SETTING_GAMEPAD: &str = "gamepad_bindings";
+
+/// Left-stick tilt (0..1, radial) below which the stick counts as centred.
+pub const STICK_DEADZONE: f64 = 0.35;
+/// Held directions repeat after this delay, then every `REPEAT_RATE_MS`.
+const REPEAT_DELAY_MS: u32 = 350;
+const REPEAT_RATE_MS: u32 = 150;
+
+/// Buttons of the W3C "standard" gamepad mapping, named after the Xbox layout.
+/// The left stick is folded into `Up`/`Down`/`Left`/`Right` by the JS bridge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum PadButton {
+    A,
+    B,
+    X,
+    Y,
+    LeftBumper,
+    RightBumper,
+    LeftTrigger,
+    RightTrigger,
+    Back,
+    Start,
+    LeftStick,
+    RightStick,
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl PadButton {
+    /// Indexed like `Gamepad.buttons` in the standard mapping.
+    const BY_INDEX: [PadButton; 16] = [
+        PadButton::A,
+        PadButton::B,
+        PadButton::X,
+        PadButton::Y,
+        PadButton::LeftBumper,
+        PadButton::RightBumper,
+        PadButton::LeftTrigger,
+        PadButton::RightTrigger,
+        PadButton::Back,
+        PadButton::Start,
+        PadButton::LeftStick,
+        PadButton::RightStick,
+        PadButton::Up,
+        PadButton::Down,
+        PadButton::Left,
+        PadButton::Right,
+    ];
+
+    pub fn from_index(index: usize) -> Option<Self> {
+        Self::BY_INDEX.get(index).copied()
+    }
+
+    /// Glyph shown in button prompts and in the Settings sheet.
+    pub fn glyph(self) -> &'static str {
+        match self {
+            PadButton::A => "Ⓐ",
+            PadButton::B => "Ⓑ",
+            PadButton::X => "Ⓧ",
+            PadButton::Y => "Ⓨ",
+            PadButton::LeftBumper => "LB",
+            PadButton::RightBumper => "RB",
+            PadButton::LeftTrigger => "LT",
+            PadButton::RightTrigger => "RT",
+            PadButton::Back => "⧉",
+            PadButton::Start => "☰",
+            PadButton::LeftStick => "L3",
+            PadButton::RightStick => "R3",
+            PadButton::Up => "▲",
+            PadButton::Down => "▼",
+            PadButton::Left => "◀",
+            PadButton::Right => "▶",
+        }
+    }
+}
+
+/// Remappable gamepad actions. Directions move the hero in the overworld; in
+/// combat up/down cycle the attacks and left/right the targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum PadAction {
+    Up,
+    Down,
+    Left,
+    Right,
+    Confirm,
+    Cancel,
+    ToggleAttacks,
+    TogglePotions,
+    PreviousTarget,
+    NextTarget,
+    OpenMenu,
+}
+
+impl PadAction {
+    pub const ALL: [PadAction; 11] = [
+        PadAction::Up,
+        PadAction::Down,
+        PadAction::Left,
+        PadAction::Right,
+        PadAction::Confirm,
+        PadAction::Cancel,
+        PadAction::ToggleAttacks,
+        PadAction::TogglePotions,
+        PadAction::PreviousTarget,
+        PadAction::NextTarget,
+        PadAction::OpenMenu,
+    ];
+
+    fn default_button(self) -> PadButton {
+        match self {
+            PadAction::Up => PadButton::Up,
+            PadAction::Down => PadButton::Down,
+            PadAction::Left => PadButton::Left,
+            PadAction::Right => PadButton::Right,
+            PadAction::Confirm => PadButton::A,
+            PadAction::Cancel => PadButton::B,
+            PadAction::ToggleAttacks => PadButton::X,
+            PadAction::TogglePotions => PadButton::Y,
+            PadAction::PreviousTarget => PadButton::LeftBumper,
+            PadAction::NextTarget => PadButton::RightBumper,
+            PadAction::OpenMenu => PadButton::Start,
+        }
+    }
+}
+
+/// Player gamepad bindings, one button per action. Persisted under `SETTING_GAMEPAD`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GamepadBindings(BTreeMap<PadAction, PadButton>);
+
+impl Default for GamepadBindings {
+    fn default() -> Self {
+        GamepadBindings(
+            PadAction::ALL
+                .iter()
+                .map(|a| (*a, a.default_button()))
+                .collect(),
+        )
+    }
+}
+
+impl GamepadBindings {
+    /// Reads the saved JSON, keeping the defaults for anything missing or invalid
+    /// (same rules as `HotkeyBindings::from_setting`).
+    pub fn from_setting(raw: &str) -> Self {
+        let mut bindings = GamepadBindings::default();
+        let saved: BTreeMap<String, String> = serde_json::from_str(raw).unwrap_or_default();
+        for (action, button) in saved {
+            let action = serde_json::from_value(serde_json::Value::String(action));
+            let button = serde_json::from_value(serde_json::Value::String(button));
+            if let (Ok(action), Ok(button)) = (action, button) {
+                bindings.rebind(action, button);
+            }
+        }
+        bindings
+    }
+
+    pub fn to_setting(&self) -> String {
+        serde_json::to_string(&self.0).unwrap_or_default()
+    }
+
+    pub fn button_of(&self, action: PadAction) -> PadButton {
+        self.0
+            .get(&action)
+            .copied()
+            .unwrap_or_else(|| action.default_button())
+    }
+
+    pub fn action_for(&self, button: PadButton) -> Option<PadAction> {
+        self.0.iter().find(|(_, b)| **b == button).map(|(a, _)| *a)
+    }
+
+    /// Binds `button` to `action`, swapping with the action that used it before.
+    pub fn rebind(&mut self, action: PadAction, button: PadButton) {
+        let previous = self.button_of(action);
+        if let Some(other) = self.action_for(button).filter(|other| *other != action) {
+            self.0.insert(other, previous);
+        }
+        self.0.insert(action, button);
+    }
+}
+
+/// Last pad button press. `seq` grows on every press so consumers can tell a new
+/// press from a re-render; `captured` presses happened while the Settings sheet was
+/// waiting for a button to remap and are not actions.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PadPress {
+    pub seq: u64,
+    pub button: Option<PadButton>,
+    pub captured: bool,
+}
+
+/// Device the player used last, picks the glyphs of the button prompts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputMode {
+    #[default]
+    Keyboard,
+    Gamepad,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+enum BridgeMessage {
+    Button { index: usize },
+    Connected,
+    Disconnected,
+    Keyboard,
+}
+
+/// Polls the browser Gamepad API and feeds `CtxGamepad` until the app unmounts.
+/// Spawn once from `App()`, next to `audio::init_audio_bridge`. The JS side applies
+/// the stick deadzone, only reports new presses (plus auto-repeat on held
+/// directions) and tells when the player goes back to keyboard or mouse.
+pub async fn run_gamepad_bridge(gamepad: CtxGamepad) {
+    let CtxGamepad {
+        mut pressed,
+        mut input_mode,
+        mut connected,
+        capturing,
+        ..
+    } = gamepad;
+    let mut bridge = document::eval(&format!(
+        r#"
+        const gen = (window.__dxGamepadGen = (window.__dxGamepadGen || 0) + 1);
+        const alive = () => window.__dxGamepadGen === gen;
+        let padMode = false;
+        const toKeyboard = (e) => {{
+            if (alive() && padMode && e.isTrusted) {{
+                padMode = false;
+                dioxus.send({{ kind: 'keyboard' }});
+            }}
+        }};
+        document.addEventListener('keydown', toKeyboard);
+        document.addEventListener('pointerdown', toKeyboard);
+        window.addEventListener('gamepadconnected', () => alive() && dioxus.send({{ kind: 'connected' }}));
+        window.addEventListener('gamepaddisconnected', () => alive() && dioxus.send({{ kind: 'disconnected' }}));
+        const held = new Map();
+        const poll = (now) => {{
+            if (!alive()) return;
+            const down = new Set();
+            for (const pad of (navigator.getGamepads ? navigator.getGamepads() : [])) {{
+                if (!pad) continue;
+                pad.buttons.forEach((b, i) => b.pressed && down.add(i));
+                const x = pad.axes[0] || 0, y = pad.axes[1] || 0;
+                if (Math.hypot(x, y) > {STICK_DEADZONE}) {{
+                    if (Math.abs(x) > Math.abs(y)) down.add(x < 0 ? 14 : 15);
+                    else down.add(y < 0 ? 12 : 13);
+                }}
+            }}
+            for (const i of down) {{
+                const next = held.get(i);
+                const repeats = i >= 12 && i <= 15;
+                if (next === undefined || (repeats && now >= next)) {{
+                    held.set(i, now + (next === undefined ? {REPEAT_DELAY_MS} : {REPEAT_RATE_MS}));
+                    padMode = true;
+                    dioxus.send({{ kind: 'button', index: i }});
+                }}
+            }}
+            for (const i of [...held.keys()]) {{
+                if (!down.has(i)) held.delete(i);
+            }}
+            requestAnimationFrame(poll);
+        }};
+        requestAnimationFrame(poll);
+        await new Promise(() => {{}});
+        "#
+    ));
+    while let Ok(message) = bridge.recv::<BridgeMessage>().await {
+        match message {
+            BridgeMessage::Button { index } => {
+                let seq = pressed.peek().seq + 1;
+                pressed.set(PadPress {
+                    seq,
+                    button: PadButton::from_index(index),
+                    captured: *capturing.peek(),
+                });
+                connected.set(true);
+                if *input_mode.peek() != InputMode::Gamepad {
+                    input_mode.set(InputMode::Gamepad);
+                }
+            }
+            BridgeMessage::Connected => connected.set(true),
+            BridgeMessage::Disconnected => connected.set(false),
+            BridgeMessage::Keyboard => input_mode.set(InputMode::Keyboard),
+        }
+    }
+}
+
+/// Calls `on_action` for every gamepad press bound to an action, from the mount
+/// of the calling component on. Presses are ignored while the Settings sheet
+/// captures a button for remapping.
+pub fn use_pad_action(mut on_action: impl FnMut(PadAction) + 'static) {
+    let gamepad = use_context::<CtxGamepad>();
+    let mut last_seq = use_signal(|| gamepad.pressed.peek().seq);
+    use_effect(move || {
+        let press = (gamepad.pressed)();
+        if press.seq == *last_seq.peek() {
+            return;
+        }
+        last_seq.set(press.seq);
+        if press.captured {
+            return;
+        }
+        if let Some(action) = press
+            .button
+            .and_then(|b| gamepad.bindings.peek().action_for(b))
+        {
+            on_action(action);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_mapping_indexes() {
+        assert_eq!(PadButton::from_index(0), Some(PadButton::A));
+        assert_eq!(PadButton::from_index(9), Some(PadButton::Start));
+        assert_eq!(PadButton::from_index(15), Some(PadButton::Right));
+        assert_eq!(PadButton::from_index(16), None);
+    }
+
+    #[test]
+    fn rebind_swaps_buttons_and_setting_round_trips() {
+        let mut bindings = GamepadBindings::default();
+        bindings.rebind(PadAction::Confirm, PadButton::B);
+        assert_eq!(bindings.button_of(PadAction::Confirm), PadButton::B);
+        assert_eq!(bindings.button_of(PadAction::Cancel), PadButton::A);
+        for action in PadAction::ALL {
+            assert_eq!(
+                bindings.action_for(bindings.button_of(action)),
+                Some(action)
+            );
+        }
+        assert_eq!(
+            GamepadBindings::from_setting(&bindings.to_setting()),
+            bindings
+        );
+        let restored =
+            GamepadBindings::from_setting(r#"{"Confirm":"Turbo","OpenMenu":"Back","Jump":"A"}"#);
+        assert_eq!(restored.button_of(PadAction::Confirm), PadButton::A);
+        assert_eq!(restored.button_of(PadAction::OpenMenu), PadButton::Back);
+    }
+
+    #[test]
+    fn bridge_messages_parse() {
+        let msg: BridgeMessage = serde_json::from_str(r#"{"kind":"button","index":3}"#).unwrap();
+        assert_eq!(msg, BridgeMessage::Button { index: 3 });
+        let msg: BridgeMessage = serde_json::from_str(r#"{"kind":"keyboard"}"#).unwrap();
+        assert_eq!(msg, BridgeMessage::Keyboard);
+    }
+}
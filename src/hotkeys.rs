@@ -0,0 +1,294 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// `user_settings` key holding the player's key bindings as JSON (see `HotkeyBindings`).
+pub const SETTING_HOTKEYS: &str = "hotkey_bindings";
+
+/// Number of attack/potion slots reachable with the digit keys `1`..`9`.
+pub const NB_SLOT_KEYS: usize = 9;
+
+/// Remappable keyboard actions. Digit keys (attack/potion slots) and the arrow
+/// keys (target cycling) are fixed and come on top of these bindings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum HotkeyAction {
+    // combat
+    ToggleAttacks,
+    TogglePotions,
+    NextTarget,
+    PreviousTarget,
+    Confirm,
+    Cancel,
+    // game sheets
+    OpenMenu,
+    OpenTalents,
+    OpenInventory,
+    OpenLogs,
+    OpenStats,
+    OpenScenarios,
+    OpenSettings,
+    OpenStore,
+}
+
+impl HotkeyAction {
+    pub const ALL: [HotkeyAction; 14] = [
+        HotkeyAction::ToggleAttacks,
+        HotkeyAction::TogglePotions,
+        HotkeyAction::NextTarget,
+        HotkeyAction::PreviousTarget,
+        HotkeyAction::Confirm,
+        HotkeyAction::Cancel,
+        HotkeyAction::OpenMenu,
+        HotkeyAction::OpenTalents,
+        HotkeyAction::OpenInventory,
+        HotkeyAction::OpenLogs,
+        HotkeyAction::OpenStats,
+        HotkeyAction::OpenScenarios,
+        HotkeyAction::OpenSettings,
+        HotkeyAction::OpenStore,
+    ];
+
+    fn default_key(self) -> &'static str {
+        match self {
+            HotkeyAction::ToggleAttacks => "a",
+            HotkeyAction::TogglePotions => "p",
+            HotkeyAction::NextTarget => "Tab",
+            HotkeyAction::PreviousTarget => "Backspace",
+            HotkeyAction::Confirm => "Enter",
+            HotkeyAction::Cancel => "Escape",
+            HotkeyAction::OpenMenu => "m",
+            HotkeyAction::OpenTalents => "t",
+            HotkeyAction::OpenInventory => "i",
+            HotkeyAction::OpenLogs => "l",
+            HotkeyAction::OpenStats => "g",
+            HotkeyAction::OpenScenarios => "j",
+            HotkeyAction::OpenSettings => "o",
+            HotkeyAction::OpenStore => "b",
+        }
+    }
+}
+
+/// Normalised `KeyboardEvent.key` value: letters are lower-cased so bindings
+/// ignore Caps Lock/Shift, named keys ("Enter", "ArrowUp"…) are kept as is.
+pub fn normalize_key(key: &str) -> String {
+    if key.chars().count() == 1 {
+        key.to_lowercase()
+    } else {
+        key.to_owned()
+    }
+}
+
+/// Key name shown to the player, e.g. `Space` or `A`.
+pub fn key_label(key: &str) -> String {
+    match key {
+        " " => "Space".to_owned(),
+        k if k.chars().count() == 1 => k.to_uppercase(),
+        k => k.to_owned(),
+    }
+}
+
+/// Attack/potion slot (0-based) picked by a digit key, `None` for other keys.
+pub fn slot_for_key(key: &str) -> Option<usize> {
+    match key.parse::<usize>() {
+        Ok(n) if (1..=NB_SLOT_KEYS).contains(&n) && key.len() == 1 => Some(n - 1),
+        _ => None,
+    }
+}
+
+/// Keys that are never remappable since they already have a fixed meaning.
+fn is_reserved_key(key: &str) -> bool {
+    slot_for_key(key).is_some()
+        || matches!(
+            key,
+            "ArrowUp" | "ArrowDown" | "ArrowLeft" | "ArrowRight" | "Shift" | "Control" | "Alt"
+        )
+}
+
+/// Player key bindings, one key per action. Persisted under `SETTING_HOTKEYS`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HotkeyBindings(BTreeMap<HotkeyAction, String>);
+
+impl Default for HotkeyBindings {
+    fn default() -> Self {
+        HotkeyBindings(
+            HotkeyAction::ALL
+                .iter()
+                .map(|a| (*a, a.default_key().to_owned()))
+                .collect(),
+        )
+    }
+}
+
+impl HotkeyBindings {
+    /// Reads the saved JSON. Unknown actions are dropped and missing or clashing
+    /// ones fall back to their default key, so a stale setting never locks a player out.
+    pub fn from_setting(raw: &str) -> Self {
+        let mut bindings = HotkeyBindings::default();
+        let saved: BTreeMap<String, String> = serde_json::from_str(raw).unwrap_or_default();
+        for (action, key) in saved {
+            if let Ok(action) = serde_json::from_value(serde_json::Value::String(action)) {
+                let _ = bindings.rebind(action, &key);
+            }
+        }
+        bindings
+    }
+
+    pub fn to_setting(&self) -> String {
+        serde_json::to_string(&self.0).unwrap_or_default()
+    }
+
+    pub fn key_of(&self, action: HotkeyAction) -> &str {
+        self.0.get(&action).map(String::as_str).unwrap_or_default()
+    }
+
+    pub fn action_for(&self, key: &str) -> Option<HotkeyAction> {
+        let key = normalize_key(key);
+        self.0.iter().find(|(_, k)| **k == key).map(|(a, _)| *a)
+    }
+
+    /// Binds `key` to `action`. If another action already used `key`, the two swap
+    /// keys so every action stays reachable. Fails on reserved keys.
+    pub fn rebind(&mut self, action: HotkeyAction, key: &str) -> Result<(), String> {
+        let key = normalize_key(key);
+        if key.is_empty() || is_reserved_key(&key) {
+            return Err(key);
+        }
+        let previous = self.key_of(action).to_owned();
+        if let Some(other) = self.action_for(&key).filter(|other| *other != action) {
+            self.0.insert(other, previous);
+        }
+        self.0.insert(action, key);
+        Ok(())
+    }
+}
+
+/// Next (or previous) id in `ids` (targets, attacks…), starting from `current`.
+/// Starts at the first (or last) id when `current` is not in the list.
+pub fn cycle_selection(ids: &[String], current: Option<&str>, forward: bool) -> Option<String> {
+    if ids.is_empty() {
+        return None;
+    }
+    let len = ids.len();
+    let next = match current.and_then(|c| ids.iter().position(|t| t == c)) {
+        Some(i) if forward => (i + 1) % len,
+        Some(i) => (i + len - 1) % len,
+        None if forward => 0,
+        None => len - 1,
+    };
+    Some(ids[next].clone())
+}
+
+/// Installs a document-wide `keydown` listener and forwards each key (with the Shift
+/// state) to the returned eval as `[key, shift]`. Keys typed in form fields, with
+/// Ctrl/Alt/Meta held, or while a `[data-hotkey-capture]` element has focus (the
+/// remapping buttons of the Settings sheet) are ignored. The keys given to
+/// `set_document_consumed_keys` lose their default action (scrolling, quick
+/// find…). `name` identifies the listener so re-mounting a component replaces its
+/// previous listener.
+pub fn document_key_listener(name: &str) -> document::Eval {
+    document::eval(&format!(
+        r#"
+        window.__dxHotkeys = window.__dxHotkeys || {{}};
+        const previous = window.__dxHotkeys['{name}'];
+        if (previous) {{
+            document.removeEventListener('keydown', previous);
+        }}
+        const listener = (e) => {{
+            const t = e.target;
+            if (e.ctrlKey || e.altKey || e.metaKey || e.repeat) return;
+            if (t && (t.isContentEditable || ['INPUT', 'TEXTAREA', 'SELECT'].includes(t.tagName))) return;
+            if (t && t.closest && t.closest('[data-hotkey-capture]')) return;
+            const key = [...e.key].length === 1 ? e.key.toLowerCase() : e.key;
+            const consumed = (window.__dxHotkeyKeys || {{}})['{name}'] || [];
+            if (consumed.includes(key)) e.preventDefault();
+            dioxus.send([e.key, e.shiftKey]);
+        }};
+        window.__dxHotkeys['{name}'] = listener;
+        document.addEventListener('keydown', listener);
+        await new Promise(() => {{}});
+        "#
+    ))
+}
+
+/// Sets the keys (normalised, see `normalize_key`) the listener `name` acts on, so
+/// that the browser doesn't act on them too.
+pub fn set_document_consumed_keys(name: &str, keys: &[&str]) {
+    let keys = serde_json::to_string(keys).unwrap_or_else(|_| "[]".to_owned());
+    document::eval(&format!(
+        "window.__dxHotkeyKeys = window.__dxHotkeyKeys || {{}}; \
+         window.__dxHotkeyKeys['{name}'] = {keys};"
+    ));
+}
+
+/// Removes a listener installed by `document_key_listener`.
+pub fn remove_document_key_listener(name: &str) {
+    document::eval(&format!(
+        "const l = window.__dxHotkeys && window.__dxHotkeys['{name}']; \
+         if (l) {{ document.removeEventListener('keydown', l); delete window.__dxHotkeys['{name}']; }} \
+         if (window.__dxHotkeyKeys) delete window.__dxHotkeyKeys['{name}'];"
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_bindings_use_distinct_keys() {
+        let bindings = HotkeyBindings::default();
+        for action in HotkeyAction::ALL {
+            assert_eq!(bindings.action_for(bindings.key_of(action)), Some(action));
+        }
+    }
+
+    #[test]
+    fn rebind_swaps_keys_and_refuses_reserved_ones() {
+        let mut bindings = HotkeyBindings::default();
+        bindings.rebind(HotkeyAction::OpenMenu, "I").unwrap();
+        assert_eq!(bindings.key_of(HotkeyAction::OpenMenu), "i");
+        assert_eq!(bindings.key_of(HotkeyAction::OpenInventory), "m");
+        assert!(bindings.rebind(HotkeyAction::Confirm, "3").is_err());
+        assert!(bindings.rebind(HotkeyAction::Confirm, "ArrowUp").is_err());
+        assert_eq!(bindings.key_of(HotkeyAction::Confirm), "Enter");
+    }
+
+    #[test]
+    fn saved_bindings_round_trip_and_survive_garbage() {
+        let mut bindings = HotkeyBindings::default();
+        bindings.rebind(HotkeyAction::Confirm, " ").unwrap();
+        assert_eq!(
+            HotkeyBindings::from_setting(&bindings.to_setting()),
+            bindings
+        );
+        assert_eq!(
+            HotkeyBindings::from_setting("not json"),
+            HotkeyBindings::default()
+        );
+        // reserved keys and unknown actions are dropped, the rest is kept
+        let restored =
+            HotkeyBindings::from_setting(r#"{"Confirm":"1","Unknown":"x","OpenMenu":"q"}"#);
+        assert_eq!(restored.key_of(HotkeyAction::Confirm), "Enter");
+        assert_eq!(restored.key_of(HotkeyAction::OpenMenu), "q");
+    }
+
+    #[test]
+    fn slots_and_target_cycling() {
+        assert_eq!(slot_for_key("1"), Some(0));
+        assert_eq!(slot_for_key("9"), Some(8));
+        assert_eq!(slot_for_key("0"), None);
+        assert_eq!(slot_for_key("a"), None);
+
+        let targets = vec!["a".to_owned(), "b".to_owned(), "c".to_owned()];
+        assert_eq!(
+            cycle_selection(&targets, Some("c"), true).as_deref(),
+            Some("a")
+        );
+        assert_eq!(
+            cycle_selection(&targets, Some("a"), false).as_deref(),
+            Some("c")
+        );
+        assert_eq!(cycle_selection(&targets, None, true).as_deref(), Some("a"));
+        assert_eq!(cycle_selection(&targets, None, false).as_deref(), Some("c"));
+        assert_eq!(cycle_selection(&[], None, true), None);
+    }
+}
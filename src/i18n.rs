@@ -1,31 +1,53 @@
-//! Locale-file parity test — ensures `en-US.ftl` and `fr-FR.ftl` (the
-//! dioxus-i18n bundles loaded in `main.rs`) define the same key set, so a
-//! new key added to one locale can't silently be missing from the other.
+//! Locale-file completeness test — every bundled locale (see `locales.rs`) must
+//! define the same key set as `en-US.ftl`, so a new key can't silently be missing
+//! from one of them. Server-served bundles may leave keys out, English covering
+//! them (see `locales.rs`), so they are checked for unknown keys and their
+//! completeness is reported, on the fixtures of `i18n/fixtures/`.
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashSet;
+    use crate::locales::{BUNDLED_LOCALES, completeness, ftl_keys};
 
-    fn keys(ftl: &str) -> HashSet<String> {
-        ftl.lines()
-            .map(str::trim)
-            .filter(|line| !line.is_empty() && !line.starts_with('#'))
-            .filter_map(|line| line.split_once('='))
-            .map(|(key, _)| key.trim().to_owned())
-            .collect()
+    #[test]
+    fn unit_locale_files_have_matching_keys() {
+        let en = ftl_keys(BUNDLED_LOCALES[0].ftl);
+        for locale in &BUNDLED_LOCALES[1..] {
+            let keys = ftl_keys(locale.ftl);
+            let only_en: Vec<_> = en.difference(&keys).collect();
+            let only_other: Vec<_> = keys.difference(&en).collect();
+            assert!(
+                only_en.is_empty() && only_other.is_empty(),
+                "locale key mismatch — only in en-US.ftl: {:?}, only in {}.ftl: {:?}",
+                only_en,
+                locale.lang_id,
+                only_other
+            );
+        }
     }
 
+    /// Served bundles, as an admin would drop them in `offlines/locales/`.
+    const SERVED_FIXTURES: [(&str, &str); 2] = [
+        ("de-DE", include_str!("./i18n/fixtures/de-DE.ftl")),
+        ("es-ES", include_str!("./i18n/fixtures/es-ES.ftl")),
+    ];
+
     #[test]
-    fn unit_locale_files_have_matching_keys() {
-        let en = keys(include_str!("./i18n/en-US.ftl"));
-        let fr = keys(include_str!("./i18n/fr-FR.ftl"));
-        let only_en: Vec<_> = en.difference(&fr).collect();
-        let only_fr: Vec<_> = fr.difference(&en).collect();
-        assert!(
-            only_en.is_empty() && only_fr.is_empty(),
-            "locale key mismatch — only in en-US.ftl: {:?}, only in fr-FR.ftl: {:?}",
-            only_en,
-            only_fr
-        );
+    fn unit_served_locales_completeness() {
+        let en = ftl_keys(BUNDLED_LOCALES[0].ftl);
+        let reports: Vec<_> = SERVED_FIXTURES
+            .iter()
+            .map(|(lang_id, ftl)| {
+                let percent = completeness(&en, ftl);
+                println!("{lang_id}.ftl: {percent}% translated");
+                let mut unknown: Vec<_> = ftl_keys(ftl).difference(&en).cloned().collect();
+                unknown.sort();
+                (percent, unknown)
+            })
+            .collect();
+
+        let de_keys = ftl_keys(SERVED_FIXTURES[0].1).len();
+        assert_eq!(reports[0], ((de_keys * 100 / en.len()) as u8, vec![]));
+        assert_eq!(reports[1].1, ["home-subtitle"]);
+        assert_eq!(completeness(&en, BUNDLED_LOCALES[1].ftl), 100);
     }
 }
@@ -1,10 +1,15 @@
 navbar-admin-panel = 🛡️ Panel
+navbar-leaderboards = 🏆 Leaderboards
+navbar-arena = 🏟️ Arena
 navbar-quit-game = Quit game
 navbar-sign-in = Sign in
 navbar-sign-out = Sign out
 navbar-menu-title = Options
 navbar-menu-open = Open options
 lang-select-label = Language
+# display name of this bundle in the language selector (see locales.rs)
+locale-name = 🇬🇧 English
+lang-option-partial = { $name } ({ $percent }% translated)
 
 common-close = Close
 common-cancel = Cancel
@@ -56,6 +61,7 @@ help-section-combat = ⚔️ Combat
 help-step-6 = 6. On your turn, click ⚔️ on your character card to open the attack list, then pick an attack.
 help-step-7 = 7. 🎯 Click target buttons to select your target(s), then confirm with '⚔️ Launch Attack'.
 help-step-8 = 8. 💊 Click 💊 on your character card to use a potion (counts as your turn action).
+help-combat-keyboard = • ⌨️ Keyboard — 1-9 pick an attack (or a potion when the 💊 list is open), Tab / arrows change target, Enter confirms, Escape cancels. Remap the shortcuts in ⚙️ Settings.
 
 help-section-toolbar = 🛠️ Game toolbar
 help-step-9 = 9. 📦 Inventory — view your hero's stats and equipment.
@@ -67,6 +73,8 @@ help-section-overworld = 🗺️ Overworld exploration
 help-step-13 = 13. The host clicks '🗺 Overworld' to enter the tile-map exploration mode.
 help-overworld-move = • Arrow keys / D-pad — move your hero.
 help-overworld-interact = • Enter or Space — interact with adjacent NPCs.
+help-gamepad = • 🎮 Gamepad — D-pad / left stick move (in combat: ▲▼ attack, ◀▶ target), Ⓐ confirms, Ⓑ cancels, ☰ opens the menu. Remap in ⚙️ Settings.
+help-accessibility = • ♿ Accessibility — colour-blind palettes, high contrast and reduced motion are in ⚙️ Settings. Combat events are announced to screen readers.
 help-overworld-encounter = • Walking on grass may trigger a random encounter (50 % chance per step).
 help-overworld-boss = • Interact with a boss NPC to start its pre-fight dialog, then confirm to begin the fight.
 help-overworld-unlock = • Defeating a boss NPC unlocks the next door and removes the NPC from the map.
@@ -88,8 +96,12 @@ help-step-18 = 18. ⚙️ Settings — toggle auto-save, attack tooltips, boss H
 help-section-admin = 🛡️ Admin panel
 help-step-19 = 19. If you are an admin, access the 🛡️ Panel link in the navbar.
 help-admin-users = • Users tab: manage accounts and connection status.
-help-admin-characters = • Characters tab: browse all heroes and bosses by universe.
+help-admin-characters = • Characters tab: browse all heroes and bosses by universe, create a universe or clone an existing one.
 help-admin-scenarios = • Scenarios tab: add, edit or delete scenarios via inline JSON editor.
+help-admin-content-packs = • Content Packs tab: export a whole universe as an archive and import packs from other servers after a dry run.
+help-admin-history = • History tab: every content save or delete is recorded with its author; view the diff and undo any change.
+help-admin-simulator = • Simulator tab: run many headless fights of a scenario with a chosen party and read win rate, turns, deaths and damage/heal per hero and attack.
+help-admin-translations = • Translations tab: list attack, character, equipment, talent and NPC dialog fields that are missing or identical in English and French, edit them side by side, or export them as CSV/XLIFF for translators and import the result.
 
 footer-about = About
 footer-lib-rpg-engine = lib-rpg engine
@@ -117,6 +129,12 @@ admin-tab-users = 👤 Users
 admin-tab-scenarios = 📜 Scenarios
 admin-tab-characters = 🧙 Characters
 admin-tab-equipment = 🔧 Equipment
+admin-tab-recipes = ⚒️ Recipes
+admin-tab-content-packs = 📦 Content Packs
+admin-tab-history = 🕘 History
+admin-tab-simulator = 🎲 Simulator
+admin-tab-translations = 🌐 Translations
+admin-tab-music = 🎵 Music
 
 ## common (reused across many pages)
 common-loading = Loading…
@@ -160,7 +178,6 @@ login-create-account-title = Create Account
 login-choose-username-placeholder = Choose a username
 login-choose-password-placeholder = Choose a password
 login-invalid-login = Invalid login
-login-name-taken = This name is already taken.
 login-sign-up-button = Sign Up →
 
 ## create server page
@@ -175,6 +192,8 @@ create-server-empty-slot = Empty Slot { $index }
 create-server-overwrite-play = ▶ Overwrite & Play
 create-server-load-game = Load Game
 create-server-load-game-desc = Continue a saved adventure
+create-server-endless = ♾️ Endless mode
+create-server-endless-desc = Go down floor after floor until the party falls. No saves.
 
 ## lobby page
 lobby-title = ⚔️ Lobby
@@ -185,7 +204,9 @@ lobby-universe-label = Universe
 lobby-scenarios-label = Scenarios
 lobby-start-game = ▶ Start Game
 lobby-universe-saved-label = Universe (saved)
+lobby-universe-daily-label = Universe (daily challenge)
 lobby-universe-locked = 🔒 { $universe }
+lobby-difficulty-label = Difficulty
 lobby-choose-universe-label = Choose Universe
 lobby-select-universe-option = — select a universe —
 lobby-not-enough-players = Not enough players
@@ -231,6 +252,128 @@ charts-attacks-cast = Attacks cast
 charts-favourite = Favourite
 charts-party-tab = 🌐 Party
 
+## profile page (profile_page.rs)
+profile-title = 👤 Profile of { $username }
+profile-view-other = View the profile of
+profile-fetch-error = Cannot load the profile: { $error }
+profile-no-fights = No finished scenario recorded yet.
+profile-fights-won = Fights won
+profile-fights-lost = Fights lost
+profile-win-rate = Win rate
+profile-time-played = Time played
+profile-universes-completed = Universes completed
+profile-favourite-heroes = 🛡️ Favourite heroes
+profile-favourite-attacks = ⚔️ Favourite attacks
+profile-suffix-fights = fights
+profile-recent-fights = 📜 Recent fights
+profile-fight-won = Victory
+profile-fight-lost = Defeat
+profile-fight-details = { $turns } turns · { $damage } dmg · { $healing } hp
+profile-endless-runs = ♾️ Deepest endless runs
+profile-tab-stats = 📊 Statistics
+profile-tab-achievements = 🏅 Achievements ({ $unlocked }/{ $total })
+profile-no-achievements = No achievement is defined on this server.
+profile-achievement-unlocked-at = Unlocked on { $date }
+achievement-unlocked = Achievement unlocked
+
+## leaderboard page (leaderboard_page.rs)
+leaderboard-title = 🏆 Leaderboards
+leaderboard-hint = Only scenarios played from their first turn and won are ranked; loaded and replayed games are not. A universe run counts when every scenario of it was ranked.
+leaderboard-fetch-error = Cannot load the leaderboard: { $error }
+leaderboard-universe = Universe
+leaderboard-scenario = Scenario
+leaderboard-whole-universe = 🌍 Whole universe
+leaderboard-category = Category
+leaderboard-category-fastest = ⏱️ Fastest clear
+leaderboard-category-damage = 🗡️ Most damage
+leaderboard-category-potions = 🧪 Fewest potions
+leaderboard-mode = Game mode
+leaderboard-solo = Solo
+leaderboard-multiplayer = Multiplayer
+leaderboard-period = Period
+leaderboard-weekly = This week
+leaderboard-all-time = All time
+leaderboard-empty = No ranked result yet.
+leaderboard-col-players = Players
+leaderboard-col-heroes = Heroes
+leaderboard-col-turns = Turns
+leaderboard-col-damage = Damage
+leaderboard-col-potions = Potions
+leaderboard-col-date = Date
+
+## daily challenge (daily_challenge_card.rs)
+daily-title = 📅 Daily challenge
+daily-heroes = Party of { $max } at most, among: { $heroes }
+daily-no-modifier = No modifier today
+daily-modifier-no-potions = 🚫🧪 No potions
+daily-modifier-no-talents = 🚫🌳 No talents
+daily-modifier-turn-limit = ⏱️ Win within { $turns } turns
+daily-luck = Same scenario, heroes and rules for everyone; critical hits and dodges are rolled for each attempt.
+daily-play = ▶ Play today's challenge
+daily-in-progress = ⏳ Your attempt of the day has started. It counts once the fight ends.
+daily-result-won = 🏆 Beaten in { $turns } turns ({ $damage } damage) · rank #{ $rank }
+daily-result-lost = 💀 Failed today. Come back tomorrow!
+daily-best-rank = Best finish: #{ $rank } on { $date }
+
+## difficulty (difficulty_select.rs)
+difficulty-story = 📖 Story
+difficulty-normal = ⚔️ Normal
+difficulty-hard = 🔥 Hard
+difficulty-nightmare = 💀 Nightmare
+difficulty-story-hint = Weaker bosses and smaller loots, to enjoy the story.
+difficulty-normal-hint = Bosses and loots as the scenarios define them. The only level ranked on the leaderboards.
+difficulty-hard-hint = Tougher, faster bosses that hit harder. Loots one rank better and more plentiful.
+difficulty-nightmare-hint = Bosses with twice their HP. Loots two ranks better and doubled.
+difficulty-new-game-plus = NG+{ $cycle }
+
+## endless mode (endless_run.rs)
+endless-title = ♾️ Endless mode
+endless-lobby-desc = Procedural floors drawn from the bosses of the universe, stronger the deeper you go. One reward after each floor, no saves: the run ends when the party falls.
+endless-floor-cleared = Floor { $floor } cleared
+endless-pick-reward = Pick a reward for the whole party, then go down to the next floor.
+endless-host-picks-reward = The host picks a reward for the whole party.
+endless-reward-talent-points = 🌳 +{ $points } talent points
+endless-rewards-taken = Rewards so far:
+endless-run-over = The run ends after { $floors } floors cleared.
+
+## arena (arena_page.rs)
+arena-title = 🏟️ Arena
+arena-hint = Register the party of one of your saves, then fight the party of a player with a close rating. Their heroes are played by the boss AI, and ratings follow the Elo system.
+arena-fetch-error = Cannot load the arena: { $error }
+arena-rating = Rating { $rating }
+arena-record = { $wins } wins, { $losses } losses
+arena-registered-at = { $universe } party, registered on { $date }
+arena-no-party = No party registered yet.
+arena-no-save = Save a game to register its party.
+arena-save-slot = Save to register
+arena-register = Register this party
+arena-register-again = Register this party instead
+arena-registered = Party registered.
+arena-register-error = Cannot register the party: { $error }
+arena-find-opponent = ⚔️ Find an opponent
+arena-no-opponent = No other party registered yet.
+arena-standings = Standings
+arena-standings-empty = No party registered yet.
+arena-col-rating = Rating
+arena-col-record = W - L
+arena-history = Your matches
+arena-history-empty = No match played yet.
+arena-col-opponent = Opponent
+arena-col-result = Result
+arena-col-change = Rating
+arena-role-attacker = attacking
+arena-role-defender = defending
+arena-won = Won
+arena-lost = Lost
+arena-replay = ▶ Replay
+arena-replay-step = Attack { $step } / { $total }
+arena-replay-end = To the end
+arena-lobby-title = 🏟️ Arena match against { $defender }
+arena-lobby-ratings = Your rating { $attacker }, theirs { $defender }
+arena-result-won = Victory against the party of { $defender }!
+arena-result-lost = Defeat against the party of { $defender }.
+arena-back = 🏟️ Back to the Arena
+
 ## startgame / running game page
 startgame-lvl = Lvl { $level }
 startgame-defeated = 💀 Defeated
@@ -238,6 +381,8 @@ startgame-quit = 🚪 Quit
 startgame-game-over = 💀 Game Over
 startgame-remaining-players = Remaining players: { $count }
 startgame-replay-game = 🔄 Replay Game
+startgame-new-game-plus = ⭐ Start New Game Plus
+startgame-new-game-plus-hint = Universe completed! New Game Plus starts it over with the same heroes, levels, equipment and talents, against stronger bosses.
 startgame-scenario-complete = 🏆 Scenario Complete!
 startgame-finishing-blow-dot = ⚔️ Finishing Blow (DOT)
 startgame-finishing-blow = ⚔️ Finishing Blow
@@ -255,6 +400,7 @@ startgame-run-away = 🗺 Run away
 
 ## gameboard
 gameboard-spectator-mode = 👁 Spectator mode — you have no active character in this game
+gameboard-keyboard-hint = Combat board: 1-9 pick an attack, Tab or arrows change target, Enter confirms, Escape cancels
 gameboard-use = ✅ Use
 gameboard-launch-attack = ⚔️ Launch Attack
 gameboard-attacks = ⚔️ { $launcher } attacks!
@@ -322,12 +468,26 @@ admin-scenarios-name-placeholder = Scenario name
 admin-scenarios-description-placeholder = Describe the scenario…
 admin-scenarios-level-label = Level
 admin-scenarios-bosses-label = Bosses (one per line — "BossName" or "BossName: 0, 1, 2")
+admin-scenarios-music-label = Music
+admin-scenarios-boss-fight-label = Boss fight (plays the boss track)
 admin-scenarios-loots-label = Loots
 admin-scenarios-loot-level-placeholder = Lvl
 admin-scenarios-loot-classes-placeholder = Classes (Standard, Warrior…)
 admin-scenarios-remove-loot = ✕
 admin-scenarios-add-loot = ＋ Add Loot
 admin-scenarios-file-stem-empty = ❌ File stem cannot be empty.
+admin-recipes-title = Crafting recipes
+admin-recipes-hint = Recipes turn the party's Material loots plus the gold of the crafting hero into equipment or consumables.
+admin-recipes-empty = No recipes yet.
+admin-recipes-add = + New recipe
+admin-recipes-new-title = New recipe
+admin-recipes-edit-title = Edit recipe
+admin-recipes-col-output = Makes
+admin-recipes-col-materials = Materials
+admin-recipes-col-gold = Gold
+admin-recipes-name-placeholder = e.g. Herbal brew
+admin-recipes-output-placeholder = Equipment unique name or consumable name
+admin-recipes-materials-label = Materials (one per line: "Material: amount")
 
 ## loot kind / rank labels (shared across admin tabs)
 loot-kind-equipment = Equipment
@@ -350,6 +510,9 @@ admin-atk-form-label = Form
 admin-atk-photo-label = Photo
 admin-atk-photo-placeholder = e.g. Fireball.png
 admin-atk-upload-photo-label = Upload Photo
+admin-atk-sfx-label = Sound effect
+admin-atk-sfx-none = — Generic impact sounds —
+admin-atk-upload-sfx-label = Upload sound effect (.ogg, .opus, .mp3, .wav)
 admin-atk-cost-mana-label = Mana Cost
 admin-atk-cost-rage-label = Rage Cost
 admin-atk-cost-vigor-label = Vigor Cost
@@ -384,6 +547,8 @@ character-page-no-potions = No potions available
 character-page-lvl = Lvl { $level }
 character-page-extra-round-title = Extra round from speed advantage
 character-page-aggro-title = Aggro
+character-page-fx-dodge = Dodge
+character-page-fx-block = Block
 character-page-sort-by-level = ⇅ Lvl
 character-page-sort-by-cost = ⇅ Cost
 character-page-configure-atk-panel = ⚙ Configure
@@ -399,6 +564,10 @@ admin-chars-all-universes = — all universes —
 admin-chars-create-universe-title = 🌍 Create Universe
 admin-chars-universe-name-placeholder = Universe name (e.g. pokemon)
 admin-chars-universe-created = ✅ Universe created.
+admin-chars-clone-universe-title = 🧬 Clone Universe
+admin-chars-clone-universe-hint = Copies characters, attacks, talents, scenarios and maps under a new universe name. Copied characters and scenarios are suffixed with the new name, maps are prefixed with it.
+admin-chars-clone-universe-button = 🧬 Clone
+admin-chars-universe-cloned = ✅ { $source } cloned as { $target }: { $characters } characters, { $scenarios } scenarios, { $maps } maps.
 admin-chars-heroes = 🧙 Heroes
 admin-chars-bosses = 👹 Bosses
 admin-chars-none-found = No { $kind } found.
@@ -425,6 +594,163 @@ admin-chars-current-col = Current
 admin-chars-max-col = Max
 admin-chars-json-title = ✏️ JSON: { $name }
 
+## admin music tab
+admin-music-tracks-title = Tracks
+admin-music-upload-hint = Upload an .ogg, .opus, .mp3 or .wav file; a file with the same name is replaced.
+admin-music-uploaded = ✅ { $track } uploaded.
+admin-music-no-tracks = No track uploaded yet.
+admin-music-slots-title = Tracks by moment
+admin-music-scope-default = All universes (defaults)
+admin-music-slot-default = — fallback —
+admin-music-slot-home = Menus
+admin-music-slot-overworld = Overworld
+admin-music-slot-combat = Combat
+admin-music-slot-boss = Boss fight
+admin-music-slot-victory = Victory
+admin-music-maps-title = Tracks by map
+admin-music-col-map = Map
+admin-music-col-track = Track
+
+## admin content packs tab
+admin-packs-export-title = 📦 Export Universe
+admin-packs-export-button = ⬇️ Export
+admin-packs-exported = ✅ Content pack for '{ $universe }' exported.
+admin-packs-import-title = 📥 Import Content Pack
+admin-packs-import-hint = Choose a .tar content pack. A dry run is shown before anything is written.
+admin-packs-manifest = Universe '{ $universe }' · format v{ $version } · exported { $exported_at } (dx-rpg { $app_version })
+admin-packs-summary = { $new } new · { $conflicts } conflicts · { $unchanged } unchanged
+admin-packs-col-status = Status
+admin-packs-status-new = New
+admin-packs-status-conflict = Conflict
+admin-packs-status-unchanged = Unchanged
+admin-packs-conflict-label = On conflict:
+admin-packs-conflict-skip = Keep server version
+admin-packs-conflict-overwrite = Overwrite with pack
+admin-packs-import-button = 📥 Import
+admin-packs-imported = ✅ Imported: { $written } written, { $skipped } skipped.
+
+## admin history tab
+admin-history-title = 🕘 Content History
+admin-history-filter-placeholder = Filter by file path…
+admin-history-empty = No content changes recorded yet.
+admin-history-col-revisions = Revisions
+admin-history-col-last-edit = Last edit
+admin-history-col-user = User
+admin-history-col-date = Date
+admin-history-col-action = Action
+admin-history-view = 🕘 History
+admin-history-file-title = 🕘 { $path }
+admin-history-action-save = Saved
+admin-history-action-delete = Deleted
+admin-history-action-restore = Restored
+admin-history-diff = 🔍 Diff
+admin-history-undo = ↩️ Undo
+admin-history-confirm-undo = ⚠️ Confirm
+admin-history-restored = ✅ Change undone.
+admin-history-diff-title = Changes
+admin-history-no-change = No difference.
+
+## admin simulator tab
+admin-sim-title = 🎲 Balance Simulator
+admin-sim-hint = Plays headless fights of a scenario with the chosen party, using the real combat rules. Greedy heroes pick the attack dealing the most damage or healing; scripted heroes follow your attack priority list.
+admin-sim-choose-scenario = — choose a scenario —
+admin-sim-fights = fights
+admin-sim-choose-hero = — choose a hero —
+admin-sim-add-hero = ➕ Add to party
+admin-sim-policy = Hero policy:
+admin-sim-policy-greedy = Greedy
+admin-sim-policy-scripted = Scripted
+admin-sim-script-placeholder = Attack names by priority, comma-separated
+admin-sim-run = ▶️ Run simulation
+admin-sim-running = ⏳ Simulating…
+admin-sim-report-title = 📊 { $scenario } — { $fights } fights
+admin-sim-win-rate = Win rate
+admin-sim-avg-turns = Avg. turns
+admin-sim-deaths = Hero deaths
+admin-sim-timeouts = Timeouts
+admin-sim-hero-title = { $name } — { $deaths } deaths
+
+## admin translations tab
+admin-translations-title = 🌐 Translations
+admin-translations-count-missing = { $count } missing
+admin-translations-count-identical = { $count } identical
+admin-translations-count-translated = { $count } translated
+admin-translations-filter-placeholder = Filter by file, field or text…
+admin-translations-show-incomplete = Missing or identical
+admin-translations-show-missing = Missing only
+admin-translations-show-identical = Identical only
+admin-translations-show-all = All fields
+admin-translations-save-all = 💾 Save { $count } edits
+admin-translations-save = 💾 Save
+admin-translations-empty = No field matches.
+admin-translations-truncated = Showing { $shown } of { $total } fields, refine the filter to see the others.
+admin-translations-col-field = File / field
+admin-translations-col-en = English
+admin-translations-col-fr = French
+admin-translations-status-missing = Missing
+admin-translations-status-identical = Identical
+admin-translations-status-translated = Translated
+admin-translations-saved = ✅ { $updated } fields saved, { $unchanged } unchanged.
+admin-translations-transfer-title = 📤 Offline translation
+admin-translations-transfer-hint = Export the fields as CSV or XLIFF for translators, then import the file back. Rows are matched by their id; empty cells leave the field untouched.
+admin-translations-export-incomplete = Only missing or identical fields
+admin-translations-export-button = ⬇️ Export
+admin-translations-exported = ✅ Translation file exported.
+admin-translations-import-label = Import a translated file:
+
+## input prompts (widgets/input_prompts.rs)
+prompt-attack = Attack
+prompt-target = Target
+prompt-confirm = Confirm
+prompt-cancel = Cancel
+prompt-attacks = Attacks
+prompt-potions = Potions
+prompt-menu = Menu
+prompt-move = Move
+prompt-interact = Interact
+prompt-dismiss = Close
+
+## themes (theme.rs)
+theme-dark = Dark
+theme-light = Light
+theme-system = System
+
+## accessibility (screen reader texts, colour palettes)
+a11y-attack-menu = Attack menu
+a11y-potion-menu = Potion menu
+a11y-target = Target { $name }
+a11y-bar-value = { $current } of { $max } ({ $percent }%)
+a11y-turn = { $name }'s turn.
+a11y-attack-result = { $launcher } used { $name }.
+palette-default = Default
+palette-deuteranopia = Deuteranopia (red-green)
+palette-protanopia = Protanopia (red-weak)
+palette-high-contrast = High contrast
+
+## server messages (server_messages.rs)
+srv-item-bought = { $character } bought { $item } for { $price } gold
+srv-item-sold = { $character } sold { $item } for { $price } gold
+srv-item-crafted = { $character } crafted { $item }
+srv-trade-completed = { $from } and { $to } traded
+srv-items-given = { $from } gave items to { $to }
+srv-quest-started = Quest started: { $quest }
+srv-quest-completed = Quest completed: { $quest }
+srv-party-potion = { $potion } (party)
+srv-potion-used = { $launcher } uses { $potion } on { $target }
+srv-potion-used-amount = { $launcher } uses { $potion } on { $target } ({ $amount } { $stat })
+srv-consumable-used = { $hero } uses { $consumable }
+srv-credentials-empty = Username or password can't be empty!
+srv-new-password-empty = The new password can't be empty!
+srv-username-not-registered = Username { $username } is not registered!
+srv-username-taken = Username { $username } is already taken!
+srv-user-already-connected = That user is already connected.
+srv-wrong-password = Password is not correct!
+srv-wrong-current-password = The current password is not correct!
+srv-admin-not-deletable = The admin account can't be deleted.
+srv-connection-status-failed = Failed to update the connection status, login aborted.
+srv-scenario-started = 📜 Scenario { $scenario }
+srv-attack-launched = Turn { $turn }.{ $round } · { $launcher } ➜ { $targets }
+
 ## character class labels (shared)
 class-warrior = Warrior
 class-mage = Mage
@@ -447,6 +773,7 @@ gs-new-equipment-for = New equipment for { $name }!
 gs-logs = Logs
 gs-game-stats = Game Stats
 gs-scenarios = Scenarios
+gs-quests = Quests
 gs-settings = Settings
 gs-store-disabled-hint = Store is disabled in Settings
 gs-store = Store
@@ -491,12 +818,40 @@ gs-logs-combat = ⚔ Combat
 gs-logs-healing = 💚 Healing
 gs-logs-events = ℹ Events
 gs-logs-empty = No logs yet.
+gs-logs-no-match = No log line matches these filters.
+gs-logs-search-placeholder = Search the log…
+gs-logs-actor-label = Actor
+gs-logs-any-actor = Any actor
+gs-logs-target-label = Target
+gs-logs-any-target = Any target
+gs-logs-attack-label = Attack
+gs-logs-any-attack = Any attack
+gs-logs-turns-label = Turns
+gs-logs-turn-from = From
+gs-logs-turn-to = To
+gs-logs-scenario-only = Current scenario only
+gs-logs-clear-filters = Clear filters
+gs-logs-export-format = Export format
+gs-logs-export = ⬇ Export
+gs-logs-exported = Log of the current scenario exported.
+gs-logs-export-error = Export failed: { $error }
 gs-scenarios-sheet-title = 📜 Scenarios
 gs-scenarios-sheet-desc = Progress through all available stages.
 gs-scenarios-empty = No scenarios loaded.
 gs-scenario-completed = ✅ Completed
 gs-scenario-in-progress = ⚔️ In Progress
 gs-scenario-not-started = 🔒 Not Started
+gs-quests-title = 🗺️ Quest log
+gs-quests-desc = Quests given by the people you met, and what is left to do.
+gs-quests-empty = No quest yet. Talk to the people of the overworld.
+gs-quest-active = ⏳ Active
+gs-quest-completed = ✅ Completed
+gs-quest-objective-talk = Talk to { $npc }
+gs-quest-objective-defeat = Win { $scenario }
+gs-quest-objective-reach = Reach { $map }
+gs-quest-objective-collect = Own { $amount } { $material }
+gs-quest-rewards = Rewards: { $rewards }
+gs-quest-reward-gold = { $gold } gold
 gs-store-title = 🛒 Store — { $name }
 gs-gold-amount = 💰 { $amount } gold
 gs-store-shop = 🏪 Shop
@@ -510,6 +865,29 @@ gs-store-no-gold = No gold
 gs-store-bag-empty = Your bag is empty.
 gs-store-party-loot = 🎒 Party loot
 gs-store-sell = Sell
+gs-store-crafting = ⚒️ Crafting
+crafting-materials = Party materials
+crafting-no-materials = No materials yet: win scenarios that drop some.
+crafting-no-recipes = No recipes.
+crafting-craft = Craft
+crafting-missing-materials = Missing materials
+trade-title = 🤝 Trade
+trade-no-partner = No other player to trade with.
+trade-partner = With
+trade-give = You give
+trade-ask = You ask
+trade-gold = Gold
+trade-no-items = No items to trade.
+trade-nothing = nothing
+trade-propose = Propose
+trade-incoming = Trades proposed to you
+trade-outgoing = Your trades
+trade-summary = { $from } gives { $give } for { $ask }
+trade-accept = Accept
+trade-decline = Decline
+transfer-title = 🤝 Give to another hero
+transfer-to = To
+transfer-give = Give
 gs-settings-title = ⚙️ Settings
 gs-settings-desc = Personalise your game experience.
 gs-settings-tooltips-label = Attack Tooltips
@@ -526,6 +904,45 @@ gs-settings-shop-label = Shop During Scenario
 gs-settings-shop-hint = Allow opening the Store during an active scenario.
 gs-settings-saving = Saving…
 gs-settings-saved = ✅ Saved
+gs-settings-theme-label = Theme
+gs-settings-theme-hint = Light or dark interface, or follow the device setting.
+gs-settings-universe-skins-label = Universe Skins
+gs-settings-universe-skins-hint = Dress the interface in the look of the universe being played (parchment for LOTR, bright for Pokémon).
+gs-settings-palette-label = Colour Palette
+gs-settings-palette-hint = Colours of the bars, combat logs and damage/heal numbers.
+gs-settings-reduced-motion-label = Reduced Motion
+gs-settings-reduced-motion-hint = Disable attack animations and interface transitions.
+gs-settings-hotkeys-label = Keyboard Shortcuts
+gs-settings-hotkeys-hint = Click a key, then press the new one (Escape aborts). Keys 1-9 pick an attack or potion and the arrow keys change target; they cannot be remapped.
+gs-settings-hotkeys-press = Press a key…
+gs-settings-hotkeys-reserved = ⚠️ { $key } is reserved and cannot be remapped
+gs-settings-hotkeys-reset = Reset shortcuts
+hotkey-toggle-attacks = Attack list
+hotkey-toggle-potions = Potion list
+hotkey-next-target = Next target
+hotkey-previous-target = Previous target
+hotkey-confirm = Confirm attack / potion
+hotkey-cancel = Cancel selection
+hotkey-open-menu = Open Menu
+hotkey-open-talents = Open Talents
+hotkey-open-inventory = Open Inventory
+hotkey-open-logs = Open Logs
+hotkey-open-stats = Open Stats
+hotkey-open-scenarios = Open Scenarios
+hotkey-open-settings = Open Settings
+hotkey-open-store = Open Store
+gs-settings-gamepad-label = Gamepad
+gs-settings-gamepad-connected = 🎮 Gamepad detected. Click an action, then press the new button.
+gs-settings-gamepad-none = No gamepad detected yet — press any button on it.
+gs-settings-gamepad-press = Press a button…
+gs-settings-gamepad-reset = Reset gamepad
+pad-action-up = Up (move / previous attack)
+pad-action-down = Down (move / next attack)
+pad-action-left = Left (move / previous target)
+pad-action-right = Right (move / next target)
+pad-action-confirm = Confirm / interact
+pad-action-cancel = Cancel / close
+pad-action-menu = Open Menu
 
 ## popover_comp.rs (unreferenced demo component)
 popover-demo-trigger = Show Popover
@@ -0,0 +1,9 @@
+# Partial bundle, as an admin would drop in offlines/locales/, for the
+# completeness test of i18n.rs.
+locale-name = 🇩🇪 Deutsch
+home-title = ⚔️ RPG-Abenteuer
+home-welcome = Willkommen, { $user_name }!
+home-create-server = Server erstellen
+home-create-server-desc = Ein neues Abenteuer als Gastgeber beginnen
+home-join-game = Spiel beitreten
+home-join-game-desc = Einem laufenden Abenteuer beitreten
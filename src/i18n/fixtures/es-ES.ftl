@@ -0,0 +1,5 @@
+# Bundle defining a key en-US.ftl lacks, which the completeness test of
+# i18n.rs must reject.
+locale-name = 🇪🇸 Español
+home-title = ⚔️ Aventura RPG
+home-subtitle = Una clave que en-US.ftl no tiene
@@ -1,10 +1,15 @@
 navbar-admin-panel = 🛡️ Panneau
+navbar-leaderboards = 🏆 Classements
+navbar-arena = 🏟️ Arène
 navbar-quit-game = Quitter la partie
 navbar-sign-in = Se connecter
 navbar-sign-out = Se déconnecter
 navbar-menu-title = Options
 navbar-menu-open = Ouvrir les options
 lang-select-label = Langue
+# display name of this bundle in the language selector (see locales.rs)
+locale-name = 🇫🇷 Français
+lang-option-partial = { $name } (traduit à { $percent } %)
 
 common-close = Fermer
 common-cancel = Annuler
@@ -56,6 +61,7 @@ help-section-combat = ⚔️ Combat
 help-step-6 = 6. À votre tour, cliquez sur ⚔️ sur votre carte de personnage pour ouvrir la liste des attaques, puis choisissez une attaque.
 help-step-7 = 7. 🎯 Cliquez sur les boutons de cible pour sélectionner votre/vos cible(s), puis confirmez avec « ⚔️ Lancer l'attaque ».
 help-step-8 = 8. 💊 Cliquez sur 💊 sur votre carte de personnage pour utiliser une potion (compte comme votre action de tour).
+help-combat-keyboard = • ⌨️ Clavier — 1-9 choisissent une attaque (ou une potion quand la liste 💊 est ouverte), Tab / flèches changent de cible, Entrée valide, Échap annule. Modifiez les raccourcis dans ⚙️ Paramètres.
 
 help-section-toolbar = 🛠️ Barre d'outils du jeu
 help-step-9 = 9. 📦 Inventaire — consultez les statistiques et l'équipement de votre héros.
@@ -67,6 +73,8 @@ help-section-overworld = 🗺️ Exploration du monde
 help-step-13 = 13. L'hôte clique sur « 🗺 Monde » pour entrer en mode d'exploration de la carte.
 help-overworld-move = • Touches fléchées / croix directionnelle — déplacez votre héros.
 help-overworld-interact = • Entrée ou Espace — interagissez avec les PNJ adjacents.
+help-gamepad = • 🎮 Manette — croix / stick gauche pour se déplacer (en combat : ▲▼ attaque, ◀▶ cible), Ⓐ valide, Ⓑ annule, ☰ ouvre le menu. Modifiable dans ⚙️ Paramètres.
+help-accessibility = • ♿ Accessibilité — palettes daltoniennes, contraste élevé et animations réduites dans ⚙️ Paramètres. Les événements de combat sont annoncés aux lecteurs d'écran.
 help-overworld-encounter = • Marcher dans l'herbe peut déclencher une rencontre aléatoire (50 % de chance par pas).
 help-overworld-boss = • Interagissez avec un PNJ boss pour démarrer son dialogue d'avant-combat, puis confirmez pour commencer le combat.
 help-overworld-unlock = • Vaincre un PNJ boss déverrouille la porte suivante et retire le PNJ de la carte.
@@ -88,8 +96,12 @@ help-step-18 = 18. ⚙️ Paramètres — activez/désactivez la sauvegarde auto
 help-section-admin = 🛡️ Panneau d'administration
 help-step-19 = 19. Si vous êtes administrateur, accédez au lien 🛡️ Panneau dans la barre de navigation.
 help-admin-users = • Onglet Utilisateurs : gérez les comptes et le statut de connexion.
-help-admin-characters = • Onglet Personnages : parcourez tous les héros et boss par univers.
+help-admin-characters = • Onglet Personnages : parcourez tous les héros et boss par univers, créez un univers ou clonez-en un existant.
 help-admin-scenarios = • Onglet Scénarios : ajoutez, modifiez ou supprimez des scénarios via l'éditeur JSON intégré.
+help-admin-content-packs = • Onglet Packs de contenu : exportez un univers entier en archive et importez des packs d'autres serveurs après une simulation.
+help-admin-history = • Onglet Historique : chaque enregistrement ou suppression de contenu est tracé avec son auteur ; consultez le diff et annulez n'importe quelle modification.
+help-admin-simulator = • Onglet Simulateur : lancez de nombreux combats d'un scénario avec une équipe choisie et consultez taux de victoire, tours, morts et dégâts/soins par héros et par attaque.
+help-admin-translations = • Onglet Traductions : listez les champs d'attaques, personnages, équipements, talents et dialogues de PNJ manquants ou identiques en anglais et en français, éditez-les côte à côte, ou exportez-les en CSV/XLIFF pour les traducteurs et réimportez le résultat.
 
 footer-about = À propos
 footer-lib-rpg-engine = moteur lib-rpg
@@ -117,6 +129,12 @@ admin-tab-users = 👤 Utilisateurs
 admin-tab-scenarios = 📜 Scénarios
 admin-tab-characters = 🧙 Personnages
 admin-tab-equipment = 🔧 Équipement
+admin-tab-recipes = ⚒️ Recettes
+admin-tab-content-packs = 📦 Packs de contenu
+admin-tab-history = 🕘 Historique
+admin-tab-simulator = 🎲 Simulateur
+admin-tab-translations = 🌐 Traductions
+admin-tab-music = 🎵 Musique
 
 ## common (reused across many pages)
 common-loading = Chargement…
@@ -160,7 +178,6 @@ login-create-account-title = Créer un compte
 login-choose-username-placeholder = Choisissez un nom d'utilisateur
 login-choose-password-placeholder = Choisissez un mot de passe
 login-invalid-login = Identifiants invalides
-login-name-taken = Ce nom est déjà pris.
 login-sign-up-button = S'inscrire →
 
 ## create server page
@@ -175,6 +192,8 @@ create-server-empty-slot = Emplacement vide { $index }
 create-server-overwrite-play = ▶ Écraser et jouer
 create-server-load-game = Charger une partie
 create-server-load-game-desc = Continuez une aventure sauvegardée
+create-server-endless = ♾️ Mode sans fin
+create-server-endless-desc = Descendez d'étage en étage jusqu'à la chute de l'équipe. Sans sauvegarde.
 
 ## lobby page
 lobby-title = ⚔️ Salon
@@ -185,7 +204,9 @@ lobby-universe-label = Univers
 lobby-scenarios-label = Scénarios
 lobby-start-game = ▶ Démarrer la partie
 lobby-universe-saved-label = Univers (sauvegardé)
+lobby-universe-daily-label = Univers (défi du jour)
 lobby-universe-locked = 🔒 { $universe }
+lobby-difficulty-label = Difficulté
 lobby-choose-universe-label = Choisissez un univers
 lobby-select-universe-option = — sélectionnez un univers —
 lobby-not-enough-players = Pas assez de joueurs
@@ -231,6 +252,128 @@ charts-attacks-cast = Attaques lancées
 charts-favourite = Favorite
 charts-party-tab = 🌐 Groupe
 
+## profile page (profile_page.rs)
+profile-title = 👤 Profil de { $username }
+profile-view-other = Voir le profil de
+profile-fetch-error = Impossible de charger le profil : { $error }
+profile-no-fights = Aucun scénario terminé pour l'instant.
+profile-fights-won = Combats gagnés
+profile-fights-lost = Combats perdus
+profile-win-rate = Taux de victoire
+profile-time-played = Temps de jeu
+profile-universes-completed = Univers terminés
+profile-favourite-heroes = 🛡️ Héros favoris
+profile-favourite-attacks = ⚔️ Attaques favorites
+profile-suffix-fights = combats
+profile-recent-fights = 📜 Combats récents
+profile-fight-won = Victoire
+profile-fight-lost = Défaite
+profile-fight-details = { $turns } tours · { $damage } dégâts · { $healing } PV
+profile-endless-runs = ♾️ Meilleures descentes sans fin
+profile-tab-stats = 📊 Statistiques
+profile-tab-achievements = 🏅 Succès ({ $unlocked }/{ $total })
+profile-no-achievements = Aucun succès n'est défini sur ce serveur.
+profile-achievement-unlocked-at = Débloqué le { $date }
+achievement-unlocked = Succès débloqué
+
+## leaderboard page (leaderboard_page.rs)
+leaderboard-title = 🏆 Classements
+leaderboard-hint = Seuls les scénarios joués depuis leur premier tour et gagnés sont classés ; les parties chargées ou rejouées ne le sont pas. Un univers compte quand tous ses scénarios ont été classés.
+leaderboard-fetch-error = Impossible de charger le classement : { $error }
+leaderboard-universe = Univers
+leaderboard-scenario = Scénario
+leaderboard-whole-universe = 🌍 Univers complet
+leaderboard-category = Catégorie
+leaderboard-category-fastest = ⏱️ Plus rapide
+leaderboard-category-damage = 🗡️ Plus de dégâts
+leaderboard-category-potions = 🧪 Moins de potions
+leaderboard-mode = Mode de jeu
+leaderboard-solo = Solo
+leaderboard-multiplayer = Multijoueur
+leaderboard-period = Période
+leaderboard-weekly = Cette semaine
+leaderboard-all-time = Depuis toujours
+leaderboard-empty = Aucun résultat classé pour l'instant.
+leaderboard-col-players = Joueurs
+leaderboard-col-heroes = Héros
+leaderboard-col-turns = Tours
+leaderboard-col-damage = Dégâts
+leaderboard-col-potions = Potions
+leaderboard-col-date = Date
+
+## daily challenge (daily_challenge_card.rs)
+daily-title = 📅 Défi du jour
+daily-heroes = Équipe de { $max } au plus, parmi : { $heroes }
+daily-no-modifier = Aucun modificateur aujourd'hui
+daily-modifier-no-potions = 🚫🧪 Sans potions
+daily-modifier-no-talents = 🚫🌳 Sans talents
+daily-modifier-turn-limit = ⏱️ Gagner en { $turns } tours au plus
+daily-luck = Même scénario, mêmes héros et mêmes règles pour tous ; coups critiques et esquives sont tirés à chaque tentative.
+daily-play = ▶ Jouer le défi du jour
+daily-in-progress = ⏳ Votre tentative du jour a commencé. Elle compte à la fin du combat.
+daily-result-won = 🏆 Réussi en { $turns } tours ({ $damage } dégâts) · rang n°{ $rank }
+daily-result-lost = 💀 Échoué aujourd'hui. Revenez demain !
+daily-best-rank = Meilleur classement : n°{ $rank } le { $date }
+
+## difficulty (difficulty_select.rs)
+difficulty-story = 📖 Histoire
+difficulty-normal = ⚔️ Normal
+difficulty-hard = 🔥 Difficile
+difficulty-nightmare = 💀 Cauchemar
+difficulty-story-hint = Des boss plus faibles et des butins réduits, pour profiter de l'histoire.
+difficulty-normal-hint = Les boss et les butins tels que les scénarios les définissent. Le seul niveau classé dans les tableaux des scores.
+difficulty-hard-hint = Des boss plus résistants, plus rapides et qui frappent plus fort. Des butins d'un rang meilleur et plus abondants.
+difficulty-nightmare-hint = Des boss avec deux fois plus de PV. Des butins de deux rangs meilleurs et doublés.
+difficulty-new-game-plus = NG+{ $cycle }
+
+## endless mode (endless_run.rs)
+endless-title = ♾️ Mode sans fin
+endless-lobby-desc = Des étages générés à partir des boss de l'univers, plus forts à chaque étage. Une récompense après chaque étage, sans sauvegarde : la descente s'arrête quand l'équipe tombe.
+endless-floor-cleared = Étage { $floor } terminé
+endless-pick-reward = Choisissez une récompense pour toute l'équipe, puis descendez à l'étage suivant.
+endless-host-picks-reward = L'hôte choisit une récompense pour toute l'équipe.
+endless-reward-talent-points = 🌳 +{ $points } points de talent
+endless-rewards-taken = Récompenses obtenues :
+endless-run-over = La descente s'arrête après { $floors } étages terminés.
+
+## arena (arena_page.rs)
+arena-title = 🏟️ Arène
+arena-hint = Inscrivez le groupe d'une de vos sauvegardes, puis affrontez le groupe d'un joueur de classement proche. Ses héros sont joués par l'IA des boss, et les classements suivent le système Elo.
+arena-fetch-error = Impossible de charger l'arène : { $error }
+arena-rating = Classement { $rating }
+arena-record = { $wins } victoires, { $losses } défaites
+arena-registered-at = Groupe { $universe }, inscrit le { $date }
+arena-no-party = Aucun groupe inscrit pour l'instant.
+arena-no-save = Sauvegardez une partie pour inscrire son groupe.
+arena-save-slot = Sauvegarde à inscrire
+arena-register = Inscrire ce groupe
+arena-register-again = Inscrire ce groupe à la place
+arena-registered = Groupe inscrit.
+arena-register-error = Impossible d'inscrire le groupe : { $error }
+arena-find-opponent = ⚔️ Trouver un adversaire
+arena-no-opponent = Aucun autre groupe inscrit pour l'instant.
+arena-standings = Classement
+arena-standings-empty = Aucun groupe inscrit pour l'instant.
+arena-col-rating = Classement
+arena-col-record = V - D
+arena-history = Vos combats
+arena-history-empty = Aucun combat joué pour l'instant.
+arena-col-opponent = Adversaire
+arena-col-result = Résultat
+arena-col-change = Classement
+arena-role-attacker = en attaque
+arena-role-defender = en défense
+arena-won = Gagné
+arena-lost = Perdu
+arena-replay = ▶ Revoir
+arena-replay-step = Attaque { $step } / { $total }
+arena-replay-end = Jusqu'à la fin
+arena-lobby-title = 🏟️ Combat d'arène contre { $defender }
+arena-lobby-ratings = Votre classement { $attacker }, le sien { $defender }
+arena-result-won = Victoire contre le groupe de { $defender } !
+arena-result-lost = Défaite contre le groupe de { $defender }.
+arena-back = 🏟️ Retour à l'arène
+
 ## startgame / running game page
 startgame-lvl = Niv { $level }
 startgame-defeated = 💀 Vaincu
@@ -238,6 +381,8 @@ startgame-quit = 🚪 Quitter
 startgame-game-over = 💀 Partie terminée
 startgame-remaining-players = Joueurs restants : { $count }
 startgame-replay-game = 🔄 Rejouer la partie
+startgame-new-game-plus = ⭐ Lancer la Nouvelle Partie+
+startgame-new-game-plus-hint = Univers terminé ! La Nouvelle Partie+ le recommence avec les mêmes héros, niveaux, équipements et talents, face à des boss plus forts.
 startgame-scenario-complete = 🏆 Scénario terminé !
 startgame-finishing-blow-dot = ⚔️ Coup fatal (DOT)
 startgame-finishing-blow = ⚔️ Coup fatal
@@ -255,6 +400,7 @@ startgame-run-away = 🗺 Fuir
 
 ## gameboard
 gameboard-spectator-mode = 👁 Mode spectateur — vous n'avez aucun personnage actif dans cette partie
+gameboard-keyboard-hint = Plateau de combat : 1-9 choisissent une attaque, Tab ou les flèches changent de cible, Entrée valide, Échap annule
 gameboard-use = ✅ Utiliser
 gameboard-launch-attack = ⚔️ Lancer l'attaque
 gameboard-attacks = ⚔️ { $launcher } attaque !
@@ -322,12 +468,26 @@ admin-scenarios-name-placeholder = Nom du scénario
 admin-scenarios-description-placeholder = Décrivez le scénario…
 admin-scenarios-level-label = Niveau
 admin-scenarios-bosses-label = Boss (un par ligne — « NomDuBoss » ou « NomDuBoss : 0, 1, 2 »)
+admin-scenarios-music-label = Musique
+admin-scenarios-boss-fight-label = Combat de boss (joue la musique de boss)
 admin-scenarios-loots-label = Butin
 admin-scenarios-loot-level-placeholder = Niv
 admin-scenarios-loot-classes-placeholder = Classes (Standard, Guerrier…)
 admin-scenarios-remove-loot = ✕
 admin-scenarios-add-loot = ＋ Ajouter du butin
 admin-scenarios-file-stem-empty = ❌ Le nom de fichier ne peut pas être vide.
+admin-recipes-title = Recettes d'artisanat
+admin-recipes-hint = Les recettes transforment les butins de type Matériau du groupe et l'or du héros artisan en équipements ou consommables.
+admin-recipes-empty = Aucune recette pour l'instant.
+admin-recipes-add = + Nouvelle recette
+admin-recipes-new-title = Nouvelle recette
+admin-recipes-edit-title = Modifier la recette
+admin-recipes-col-output = Fabrique
+admin-recipes-col-materials = Matériaux
+admin-recipes-col-gold = Or
+admin-recipes-name-placeholder = ex. Infusion d'herbes
+admin-recipes-output-placeholder = Nom unique de l'équipement ou nom du consommable
+admin-recipes-materials-label = Matériaux (un par ligne : « Matériau: quantité »)
 
 ## loot kind / rank labels (shared across admin tabs)
 loot-kind-equipment = Équipement
@@ -350,6 +510,9 @@ admin-atk-form-label = Forme
 admin-atk-photo-label = Photo
 admin-atk-photo-placeholder = ex. Fireball.png
 admin-atk-upload-photo-label = Envoyer une photo
+admin-atk-sfx-label = Effet sonore
+admin-atk-sfx-none = — Sons d'impact génériques —
+admin-atk-upload-sfx-label = Envoyer un effet sonore (.ogg, .opus, .mp3, .wav)
 admin-atk-cost-mana-label = Coût en mana
 admin-atk-cost-rage-label = Coût en rage
 admin-atk-cost-vigor-label = Coût en vigueur
@@ -384,6 +547,8 @@ character-page-no-potions = Aucune potion disponible
 character-page-lvl = Niv. { $level }
 character-page-extra-round-title = Tour supplémentaire grâce à l'avantage de vitesse
 character-page-aggro-title = Aggro
+character-page-fx-dodge = Esquive
+character-page-fx-block = Blocage
 character-page-sort-by-level = ⇅ Niv.
 character-page-sort-by-cost = ⇅ Coût
 character-page-configure-atk-panel = ⚙ Configurer
@@ -399,6 +564,10 @@ admin-chars-all-universes = — tous les univers —
 admin-chars-create-universe-title = 🌍 Créer un univers
 admin-chars-universe-name-placeholder = Nom de l'univers (ex. pokemon)
 admin-chars-universe-created = ✅ Univers créé.
+admin-chars-clone-universe-title = 🧬 Cloner un univers
+admin-chars-clone-universe-hint = Copie personnages, attaques, talents, scénarios et cartes sous un nouveau nom d'univers. Les personnages et scénarios copiés sont suffixés du nouveau nom, les cartes en sont préfixées.
+admin-chars-clone-universe-button = 🧬 Cloner
+admin-chars-universe-cloned = ✅ { $source } cloné en { $target } : { $characters } personnages, { $scenarios } scénarios, { $maps } cartes.
 admin-chars-heroes = 🧙 Héros
 admin-chars-bosses = 👹 Boss
 admin-chars-none-found = Aucun { $kind } trouvé.
@@ -425,6 +594,163 @@ admin-chars-current-col = Actuel
 admin-chars-max-col = Max
 admin-chars-json-title = ✏️ JSON : { $name }
 
+## admin music tab
+admin-music-tracks-title = Pistes
+admin-music-upload-hint = Envoyez un fichier .ogg, .opus, .mp3 ou .wav ; un fichier du même nom est remplacé.
+admin-music-uploaded = ✅ { $track } envoyé.
+admin-music-no-tracks = Aucune piste envoyée pour l'instant.
+admin-music-slots-title = Pistes par moment
+admin-music-scope-default = Tous les univers (par défaut)
+admin-music-slot-default = — repli —
+admin-music-slot-home = Menus
+admin-music-slot-overworld = Monde
+admin-music-slot-combat = Combat
+admin-music-slot-boss = Combat de boss
+admin-music-slot-victory = Victoire
+admin-music-maps-title = Pistes par carte
+admin-music-col-map = Carte
+admin-music-col-track = Piste
+
+## admin content packs tab
+admin-packs-export-title = 📦 Exporter un univers
+admin-packs-export-button = ⬇️ Exporter
+admin-packs-exported = ✅ Pack de contenu de « { $universe } » exporté.
+admin-packs-import-title = 📥 Importer un pack de contenu
+admin-packs-import-hint = Choisissez un pack de contenu .tar. Une simulation est affichée avant toute écriture.
+admin-packs-manifest = Univers « { $universe } » · format v{ $version } · exporté le { $exported_at } (dx-rpg { $app_version })
+admin-packs-summary = { $new } nouveaux · { $conflicts } conflits · { $unchanged } inchangés
+admin-packs-col-status = Statut
+admin-packs-status-new = Nouveau
+admin-packs-status-conflict = Conflit
+admin-packs-status-unchanged = Inchangé
+admin-packs-conflict-label = En cas de conflit :
+admin-packs-conflict-skip = Garder la version du serveur
+admin-packs-conflict-overwrite = Écraser avec le pack
+admin-packs-import-button = 📥 Importer
+admin-packs-imported = ✅ Importé : { $written } écrits, { $skipped } ignorés.
+
+## admin history tab
+admin-history-title = 🕘 Historique du contenu
+admin-history-filter-placeholder = Filtrer par chemin de fichier…
+admin-history-empty = Aucune modification de contenu enregistrée.
+admin-history-col-revisions = Révisions
+admin-history-col-last-edit = Dernière modification
+admin-history-col-user = Utilisateur
+admin-history-col-date = Date
+admin-history-col-action = Action
+admin-history-view = 🕘 Historique
+admin-history-file-title = 🕘 { $path }
+admin-history-action-save = Enregistré
+admin-history-action-delete = Supprimé
+admin-history-action-restore = Restauré
+admin-history-diff = 🔍 Diff
+admin-history-undo = ↩️ Annuler
+admin-history-confirm-undo = ⚠️ Confirmer
+admin-history-restored = ✅ Modification annulée.
+admin-history-diff-title = Modifications
+admin-history-no-change = Aucune différence.
+
+## admin simulator tab
+admin-sim-title = 🎲 Simulateur d'équilibrage
+admin-sim-hint = Joue des combats sans interface d'un scénario avec l'équipe choisie, selon les vraies règles de combat. Les héros gloutons choisissent l'attaque infligeant le plus de dégâts ou de soins ; les héros scriptés suivent votre liste de priorité d'attaques.
+admin-sim-choose-scenario = — choisissez un scénario —
+admin-sim-fights = combats
+admin-sim-choose-hero = — choisissez un héros —
+admin-sim-add-hero = ➕ Ajouter à l'équipe
+admin-sim-policy = Stratégie des héros :
+admin-sim-policy-greedy = Gloutonne
+admin-sim-policy-scripted = Scriptée
+admin-sim-script-placeholder = Noms d'attaques par priorité, séparés par des virgules
+admin-sim-run = ▶️ Lancer la simulation
+admin-sim-running = ⏳ Simulation…
+admin-sim-report-title = 📊 { $scenario } — { $fights } combats
+admin-sim-win-rate = Taux de victoire
+admin-sim-avg-turns = Tours moyens
+admin-sim-deaths = Morts de héros
+admin-sim-timeouts = Délais dépassés
+admin-sim-hero-title = { $name } — { $deaths } morts
+
+## admin translations tab
+admin-translations-title = 🌐 Traductions
+admin-translations-count-missing = { $count } manquantes
+admin-translations-count-identical = { $count } identiques
+admin-translations-count-translated = { $count } traduites
+admin-translations-filter-placeholder = Filtrer par fichier, champ ou texte…
+admin-translations-show-incomplete = Manquantes ou identiques
+admin-translations-show-missing = Manquantes seulement
+admin-translations-show-identical = Identiques seulement
+admin-translations-show-all = Tous les champs
+admin-translations-save-all = 💾 Enregistrer { $count } modifications
+admin-translations-save = 💾 Enregistrer
+admin-translations-empty = Aucun champ ne correspond.
+admin-translations-truncated = { $shown } champs affichés sur { $total }, affinez le filtre pour voir les autres.
+admin-translations-col-field = Fichier / champ
+admin-translations-col-en = Anglais
+admin-translations-col-fr = Français
+admin-translations-status-missing = Manquante
+admin-translations-status-identical = Identique
+admin-translations-status-translated = Traduite
+admin-translations-saved = ✅ { $updated } champs enregistrés, { $unchanged } inchangés.
+admin-translations-transfer-title = 📤 Traduction hors ligne
+admin-translations-transfer-hint = Exportez les champs en CSV ou XLIFF pour les traducteurs, puis réimportez le fichier. Les lignes sont retrouvées par leur id ; les cellules vides laissent le champ intact.
+admin-translations-export-incomplete = Seulement les champs manquants ou identiques
+admin-translations-export-button = ⬇️ Exporter
+admin-translations-exported = ✅ Fichier de traduction exporté.
+admin-translations-import-label = Importer un fichier traduit :
+
+## input prompts (widgets/input_prompts.rs)
+prompt-attack = Attaque
+prompt-target = Cible
+prompt-confirm = Valider
+prompt-cancel = Annuler
+prompt-attacks = Attaques
+prompt-potions = Potions
+prompt-menu = Menu
+prompt-move = Déplacer
+prompt-interact = Interagir
+prompt-dismiss = Fermer
+
+## themes (theme.rs)
+theme-dark = Sombre
+theme-light = Clair
+theme-system = Système
+
+## accessibility (screen reader texts, colour palettes)
+a11y-attack-menu = Menu des attaques
+a11y-potion-menu = Menu des potions
+a11y-target = Cibler { $name }
+a11y-bar-value = { $current } sur { $max } ({ $percent } %)
+a11y-turn = Au tour de { $name }.
+a11y-attack-result = { $launcher } utilise { $name }.
+palette-default = Par défaut
+palette-deuteranopia = Deutéranopie (rouge-vert)
+palette-protanopia = Protanopie (rouge faible)
+palette-high-contrast = Contraste élevé
+
+## server messages (server_messages.rs)
+srv-item-bought = { $character } a acheté { $item } pour { $price } pièces d'or
+srv-item-sold = { $character } a vendu { $item } pour { $price } pièces d'or
+srv-item-crafted = { $character } a fabriqué { $item }
+srv-trade-completed = { $from } et { $to } ont échangé
+srv-items-given = { $from } a donné des objets à { $to }
+srv-quest-started = Quête commencée : { $quest }
+srv-quest-completed = Quête terminée : { $quest }
+srv-party-potion = { $potion } (groupe)
+srv-potion-used = { $launcher } utilise { $potion } sur { $target }
+srv-potion-used-amount = { $launcher } utilise { $potion } sur { $target } ({ $amount } { $stat })
+srv-consumable-used = { $hero } utilise { $consumable }
+srv-credentials-empty = Le nom d'utilisateur et le mot de passe ne peuvent pas être vides !
+srv-new-password-empty = Le nouveau mot de passe ne peut pas être vide !
+srv-username-not-registered = L'utilisateur { $username } n'est pas inscrit !
+srv-username-taken = Le nom { $username } est déjà pris !
+srv-user-already-connected = Cet utilisateur est déjà connecté.
+srv-wrong-password = Mot de passe incorrect !
+srv-wrong-current-password = Le mot de passe actuel est incorrect !
+srv-admin-not-deletable = Le compte administrateur ne peut pas être supprimé.
+srv-connection-status-failed = Échec de la mise à jour du statut de connexion, connexion annulée.
+srv-scenario-started = 📜 Scénario { $scenario }
+srv-attack-launched = Tour { $turn }.{ $round } · { $launcher } ➜ { $targets }
+
 ## character class labels (shared)
 class-warrior = Guerrier
 class-mage = Mage
@@ -447,6 +773,7 @@ gs-new-equipment-for = Nouvel équipement pour { $name } !
 gs-logs = Journal
 gs-game-stats = Statistiques
 gs-scenarios = Scénarios
+gs-quests = Quêtes
 gs-settings = Paramètres
 gs-store-disabled-hint = La boutique est désactivée dans les paramètres
 gs-store = Boutique
@@ -491,12 +818,40 @@ gs-logs-combat = ⚔ Combat
 gs-logs-healing = 💚 Soins
 gs-logs-events = ℹ Événements
 gs-logs-empty = Aucun événement pour l'instant.
+gs-logs-no-match = Aucune ligne ne correspond à ces filtres.
+gs-logs-search-placeholder = Rechercher dans le journal…
+gs-logs-actor-label = Acteur
+gs-logs-any-actor = Tous les acteurs
+gs-logs-target-label = Cible
+gs-logs-any-target = Toutes les cibles
+gs-logs-attack-label = Attaque
+gs-logs-any-attack = Toutes les attaques
+gs-logs-turns-label = Tours
+gs-logs-turn-from = De
+gs-logs-turn-to = À
+gs-logs-scenario-only = Scénario en cours uniquement
+gs-logs-clear-filters = Effacer les filtres
+gs-logs-export-format = Format d'export
+gs-logs-export = ⬇ Exporter
+gs-logs-exported = Journal du scénario en cours exporté.
+gs-logs-export-error = Échec de l'export : { $error }
 gs-scenarios-sheet-title = 📜 Scénarios
 gs-scenarios-sheet-desc = Progression à travers toutes les étapes disponibles.
 gs-scenarios-empty = Aucun scénario chargé.
 gs-scenario-completed = ✅ Terminé
 gs-scenario-in-progress = ⚔️ En cours
 gs-scenario-not-started = 🔒 Non commencé
+gs-quests-title = 🗺️ Journal de quêtes
+gs-quests-desc = Les quêtes confiées par les personnes rencontrées, et ce qu'il reste à faire.
+gs-quests-empty = Aucune quête pour l'instant. Parlez aux habitants du monde.
+gs-quest-active = ⏳ En cours
+gs-quest-completed = ✅ Terminée
+gs-quest-objective-talk = Parler à { $npc }
+gs-quest-objective-defeat = Gagner { $scenario }
+gs-quest-objective-reach = Atteindre { $map }
+gs-quest-objective-collect = Posséder { $amount } { $material }
+gs-quest-rewards = Récompenses : { $rewards }
+gs-quest-reward-gold = { $gold } or
 gs-store-title = 🛒 Boutique — { $name }
 gs-gold-amount = 💰 { $amount } or
 gs-store-shop = 🏪 Boutique
@@ -510,6 +865,29 @@ gs-store-no-gold = Pas d'or
 gs-store-bag-empty = Votre sac est vide.
 gs-store-party-loot = 🎒 Butin du groupe
 gs-store-sell = Vendre
+gs-store-crafting = ⚒️ Artisanat
+crafting-materials = Matériaux du groupe
+crafting-no-materials = Aucun matériau : remportez des scénarios qui en donnent.
+crafting-no-recipes = Aucune recette.
+crafting-craft = Fabriquer
+crafting-missing-materials = Matériaux manquants
+trade-title = 🤝 Échange
+trade-no-partner = Aucun autre joueur avec qui échanger.
+trade-partner = Avec
+trade-give = Vous donnez
+trade-ask = Vous demandez
+trade-gold = Or
+trade-no-items = Aucun objet à échanger.
+trade-nothing = rien
+trade-propose = Proposer
+trade-incoming = Échanges proposés
+trade-outgoing = Vos échanges
+trade-summary = { $from } donne { $give } contre { $ask }
+trade-accept = Accepter
+trade-decline = Refuser
+transfer-title = 🤝 Donner à un autre héros
+transfer-to = À
+transfer-give = Donner
 gs-settings-title = ⚙️ Paramètres
 gs-settings-desc = Personnalisez votre expérience de jeu.
 gs-settings-tooltips-label = Infobulles d'attaque
@@ -526,6 +904,45 @@ gs-settings-shop-label = Boutique pendant le scénario
 gs-settings-shop-hint = Autoriser l'ouverture de la boutique pendant un scénario en cours.
 gs-settings-saving = Enregistrement…
 gs-settings-saved = ✅ Enregistré
+gs-settings-theme-label = Thème
+gs-settings-theme-hint = Interface claire ou sombre, ou selon le réglage de l'appareil.
+gs-settings-universe-skins-label = Habillage des univers
+gs-settings-universe-skins-hint = Habiller l'interface aux couleurs de l'univers en cours (parchemin pour LOTR, couleurs vives pour Pokémon).
+gs-settings-palette-label = Palette de couleurs
+gs-settings-palette-hint = Couleurs des barres, des journaux de combat et des nombres de dégâts/soins.
+gs-settings-reduced-motion-label = Animations réduites
+gs-settings-reduced-motion-hint = Désactiver les animations d'attaque et les transitions de l'interface.
+gs-settings-hotkeys-label = Raccourcis clavier
+gs-settings-hotkeys-hint = Cliquez sur une touche puis appuyez sur la nouvelle (Échap annule). Les touches 1-9 choisissent une attaque ou une potion et les flèches changent de cible ; elles ne sont pas modifiables.
+gs-settings-hotkeys-press = Appuyez sur une touche…
+gs-settings-hotkeys-reserved = ⚠️ { $key } est réservée et ne peut pas être réassignée
+gs-settings-hotkeys-reset = Réinitialiser les raccourcis
+hotkey-toggle-attacks = Liste des attaques
+hotkey-toggle-potions = Liste des potions
+hotkey-next-target = Cible suivante
+hotkey-previous-target = Cible précédente
+hotkey-confirm = Valider l'attaque / la potion
+hotkey-cancel = Annuler la sélection
+hotkey-open-menu = Ouvrir le menu
+hotkey-open-talents = Ouvrir les talents
+hotkey-open-inventory = Ouvrir l'inventaire
+hotkey-open-logs = Ouvrir le journal
+hotkey-open-stats = Ouvrir les statistiques
+hotkey-open-scenarios = Ouvrir les scénarios
+hotkey-open-settings = Ouvrir les paramètres
+hotkey-open-store = Ouvrir la boutique
+gs-settings-gamepad-label = Manette
+gs-settings-gamepad-connected = 🎮 Manette détectée. Cliquez sur une action puis appuyez sur le nouveau bouton.
+gs-settings-gamepad-none = Aucune manette détectée — appuyez sur l'un de ses boutons.
+gs-settings-gamepad-press = Appuyez sur un bouton…
+gs-settings-gamepad-reset = Réinitialiser la manette
+pad-action-up = Haut (déplacement / attaque précédente)
+pad-action-down = Bas (déplacement / attaque suivante)
+pad-action-left = Gauche (déplacement / cible précédente)
+pad-action-right = Droite (déplacement / cible suivante)
+pad-action-confirm = Valider / interagir
+pad-action-cancel = Annuler / fermer
+pad-action-menu = Ouvrir le menu
 
 ## popover_comp.rs (unreferenced demo component)
 popover-demo-trigger = Afficher la popover
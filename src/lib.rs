@@ -1,9 +1,26 @@
+pub mod accessibility;
+pub mod achievements;
+pub mod arena;
 pub mod audio;
 pub mod auth_manager;
 pub mod board_game_components;
+pub mod combat_animation;
+pub mod combat_log;
 pub mod common;
 pub mod components;
+pub mod crafting;
+pub mod daily_challenge;
+pub mod difficulty;
+pub mod endless;
+pub mod gamepad;
+pub mod hotkeys;
 pub mod i18n;
+pub mod locales;
+pub mod music;
+pub mod quests;
+pub mod server_messages;
+pub mod theme;
+pub mod trading;
 pub mod utils;
 pub mod websocket_handler;
 pub mod widgets;
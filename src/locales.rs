@@ -0,0 +1,267 @@
+//! Locale registry: the `.ftl` bundles compiled into every client (English and
+//! French) plus the ones the server serves from `offlines/locales/`, so a new UI
+//! language only needs a `<lang-id>.ftl` file dropped on the server. English is
+//! the fallback for every key a bundle is missing.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Mutex,
+};
+
+use dioxus_i18n::prelude::I18nConfig;
+use once_cell::sync::Lazy;
+use unic_langid::LanguageIdentifier;
+
+/// Locale of the fallback bundle, also the reference for completeness.
+pub const FALLBACK_LANG_ID: &str = "en-US";
+
+/// Directory under `OFFLINE_PATH` holding the server-served `<lang-id>.ftl` files.
+pub const LOCALES_DIR: &str = "locales";
+
+/// Message every bundle defines with its own display name, e.g. `🇫🇷 Français`.
+const LOCALE_NAME_KEY: &str = "locale-name";
+
+/// A locale compiled into the binary.
+pub struct BundledLocale {
+    /// Value saved in `synced_app_lang`
+    pub code: &'static str,
+    pub lang_id: &'static str,
+    pub ftl: &'static str,
+}
+
+pub const BUNDLED_LOCALES: [BundledLocale; 2] = [
+    BundledLocale {
+        code: "en",
+        lang_id: FALLBACK_LANG_ID,
+        ftl: include_str!("./i18n/en-US.ftl"),
+    },
+    BundledLocale {
+        code: "fr",
+        lang_id: "fr-FR",
+        ftl: include_str!("./i18n/fr-FR.ftl"),
+    },
+];
+
+/// A locale served by the server (see `list_locale_bundles`).
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LocaleBundle {
+    /// Language identifier from the file name, e.g. `de-DE`; also the value saved
+    /// in `synced_app_lang`
+    pub lang_id: String,
+    pub ftl: String,
+}
+
+/// Entry of the language `<select>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocaleInfo {
+    /// Value saved in `synced_app_lang`: "en"/"fr" for the bundled locales, the
+    /// language identifier for the server-served ones
+    pub code: String,
+    pub name: String,
+    /// Share of the English keys the bundle translates, 0-100
+    pub completeness: u8,
+}
+
+/// Message ids defined by an `.ftl` file. Indented lines continue the previous
+/// message, so only the lines starting at column 0 are read.
+pub fn ftl_keys(ftl: &str) -> HashSet<String> {
+    ftl.lines()
+        .filter(|line| !line.starts_with([' ', '\t', '#']))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, _)| key.trim().to_owned())
+        .filter(|key| !key.is_empty())
+        .collect()
+}
+
+/// Single-line value of `key` in an `.ftl` file.
+fn ftl_message<'a>(ftl: &'a str, key: &str) -> Option<&'a str> {
+    ftl.lines()
+        .filter(|line| !line.starts_with([' ', '\t', '#']))
+        .find_map(|line| {
+            let (k, v) = line.split_once('=')?;
+            (k.trim() == key)
+                .then(|| v.trim())
+                .filter(|v| !v.is_empty())
+        })
+}
+
+/// Percentage of the `reference` keys defined by `ftl`.
+pub fn completeness(reference: &HashSet<String>, ftl: &str) -> u8 {
+    if reference.is_empty() {
+        return 100;
+    }
+    let keys = ftl_keys(ftl);
+    let translated = reference.iter().filter(|k| keys.contains(*k)).count();
+    (translated * 100 / reference.len()) as u8
+}
+
+/// Bundled locales followed by the server-served ones, for the language `<select>`.
+pub fn available_locales(served: &[LocaleBundle]) -> Vec<LocaleInfo> {
+    let reference = ftl_keys(BUNDLED_LOCALES[0].ftl);
+    let bundled = BUNDLED_LOCALES.iter().map(|b| (b.code, b.lang_id, b.ftl));
+    let served = served
+        .iter()
+        .map(|b| (b.lang_id.as_str(), b.lang_id.as_str(), b.ftl.as_str()));
+    bundled
+        .chain(served)
+        .map(|(code, lang_id, ftl)| LocaleInfo {
+            code: code.to_owned(),
+            name: ftl_message(ftl, LOCALE_NAME_KEY)
+                .unwrap_or(lang_id)
+                .to_owned(),
+            completeness: completeness(&reference, ftl),
+        })
+        .collect()
+}
+
+/// Language identifier to activate for a `synced_app_lang` value; unknown codes
+/// (e.g. a server locale that is gone) get the fallback.
+pub fn lang_id_for_code(code: &str, served: &[LocaleBundle]) -> LanguageIdentifier {
+    BUNDLED_LOCALES
+        .iter()
+        .find(|b| b.code == code)
+        .map(|b| b.lang_id)
+        .or_else(|| {
+            served
+                .iter()
+                .find(|b| b.lang_id == code)
+                .map(|b| b.lang_id.as_str())
+        })
+        .and_then(|id| id.parse().ok())
+        .unwrap_or_else(fallback_lang_id)
+}
+
+fn fallback_lang_id() -> LanguageIdentifier {
+    FALLBACK_LANG_ID
+        .parse()
+        .expect("FALLBACK_LANG_ID is a valid language identifier")
+}
+
+/// Served bundles handed to dioxus-i18n so far.
+static STATIC_FTLS: Lazy<Mutex<HashSet<&'static str>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// `ftl` as a static text, as dioxus-i18n only takes those. Each distinct bundle
+/// is leaked once, however often the config is rebuilt.
+fn static_ftl(ftl: &str) -> &'static str {
+    let mut ftls = STATIC_FTLS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(leaked) = ftls.get(ftl) {
+        return leaked;
+    }
+    let leaked: &'static str = Box::leak(ftl.to_owned().into_boxed_str());
+    ftls.insert(leaked);
+    leaked
+}
+
+/// dioxus-i18n config holding every bundled and served locale, with English as
+/// fallback.
+pub fn i18n_config(served: &[LocaleBundle]) -> I18nConfig {
+    let mut config = I18nConfig::new(fallback_lang_id()).with_fallback(fallback_lang_id());
+    for bundled in &BUNDLED_LOCALES {
+        if let Ok(id) = bundled.lang_id.parse::<LanguageIdentifier>() {
+            config = config.with_locale((id, bundled.ftl));
+        }
+    }
+    for bundle in served {
+        if let Ok(id) = bundle.lang_id.parse::<LanguageIdentifier>() {
+            config = config.with_locale((id, static_ftl(&bundle.ftl)));
+        }
+    }
+    config
+}
+
+/// Text of a game-data field in any number of languages, keyed by language code
+/// ("en", "fr", "de-DE"…), e.g. `{"en": "Oak's parcel", "fr": "Le colis de Chen"}`
+/// in a quest book. lib-rpg's data keeps its paired `_en`/`_fr` fields, read
+/// through `from_en_fr`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LocalizedText {
+    /// Single-language text of content written before the translated fields
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub legacy: String,
+    #[serde(flatten)]
+    pub texts: BTreeMap<String, String>,
+}
+
+impl LocalizedText {
+    /// From lib-rpg's legacy/English/French triple; empty texts are left out.
+    pub fn from_en_fr(legacy: &str, en: &str, fr: &str) -> Self {
+        let texts = [("en", en), ("fr", fr)]
+            .into_iter()
+            .filter(|(_, text)| !text.is_empty())
+            .map(|(code, text)| (code.to_owned(), text.to_owned()))
+            .collect();
+        Self {
+            legacy: legacy.to_owned(),
+            texts,
+        }
+    }
+
+    /// Text for an app language code: the exact code, then its primary language
+    /// ("de" for "de-DE"), then English, then the legacy text.
+    pub fn get(&self, code: &str) -> &str {
+        let primary = code.split('-').next().unwrap_or(code);
+        [code, primary, "en"]
+            .into_iter()
+            .find_map(|c| self.texts.get(c).filter(|t| !t.is_empty()))
+            .map_or(self.legacy.as_str(), String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ftl_keys_skip_comments_and_continuation_lines() {
+        let ftl = "# title = no\nhello = Hi\nmulti =\n    line = still multi\n-term = T\n";
+        let keys = ftl_keys(ftl);
+        assert_eq!(keys.len(), 3);
+        assert!(keys.contains("hello") && keys.contains("multi") && keys.contains("-term"));
+        assert_eq!(ftl_message(ftl, "hello"), Some("Hi"));
+        assert_eq!(ftl_message(ftl, "multi"), None);
+    }
+
+    #[test]
+    fn served_locales_report_completeness_and_fall_back() {
+        let served = vec![LocaleBundle {
+            lang_id: "de-DE".to_owned(),
+            ftl: "locale-name = Deutsch\n".to_owned(),
+        }];
+        let locales = available_locales(&served);
+        let summary: Vec<_> = locales
+            .iter()
+            .map(|l| (l.code.as_str(), l.completeness))
+            .collect();
+        assert_eq!(summary[..2], [("en", 100), ("fr", 100)]);
+        assert_eq!(locales[2].code, "de-DE");
+        assert_eq!(locales[2].name, "Deutsch");
+        assert!(locales[2].completeness < 5);
+        assert_eq!(lang_id_for_code("de-DE", &served).to_string(), "de-DE");
+        assert_eq!(lang_id_for_code("fr", &served).to_string(), "fr-FR");
+        assert_eq!(lang_id_for_code("xx-YY", &served).to_string(), "en-US");
+        // Rebuilding the config doesn't leak the served bundles again
+        assert!(std::ptr::eq(
+            static_ftl(&served[0].ftl),
+            static_ftl(&served[0].ftl.clone())
+        ));
+    }
+
+    #[test]
+    fn localized_text_falls_back_to_english_then_legacy() {
+        let mut text = LocalizedText::from_en_fr("Ancien", "Old", "");
+        assert_eq!(text.get("fr"), "Old");
+        text.texts.insert("de".to_owned(), "Alt".to_owned());
+        assert_eq!(text.get("de-AT"), "Alt");
+        assert_eq!(
+            LocalizedText::from_en_fr("Ancien", "", "").get("en"),
+            "Ancien"
+        );
+        let parsed: LocalizedText =
+            serde_json::from_str(r#"{"en": "Old", "de-DE": "Alt"}"#).unwrap();
+        assert_eq!(parsed.get("de-DE"), "Alt");
+        assert_eq!(parsed.get("fr"), "Old");
+        assert_eq!(serde_json::to_value(&parsed).unwrap()["de-DE"], "Alt");
+    }
+}
@@ -10,17 +10,29 @@ use dioxus_sdk_storage::{StorageBacking, set_dir};
 #[cfg(not(target_arch = "wasm32"))]
 use dotenv::dotenv;
 use dx_rpg::{
+    accessibility::ColorPalette,
+    auth_manager::server_fn::list_locale_bundles,
     common::{
-        CtxAppLang, CtxAtkPanelOrders, CtxAudioSettings, CtxAutoSaveScenario, CtxDeviceToken,
-        CtxShopEnabled, CtxShowAtkTooltips, CtxShowBossEnergy, CtxShowBossHp, CtxShowHeroAggro,
-        CtxSyncedInsecureCerts, CtxSyncedServerUrl, CtxToggleAtkAnimation, DISCONNECTED_USER,
-        DX_COMP_CSS, Route, SERVER_NAME, SYNCED_AUDIO_MUTED_KEY, SYNCED_DEVICE_TOKEN_KEY,
-        SYNCED_MUSIC_VOLUME_KEY, SYNCED_SFX_VOLUME_KEY,
+        CtxA11y, CtxAchievementToasts, CtxAppLang, CtxAtkPanelOrders, CtxAudioSettings,
+        CtxAutoSaveScenario, CtxCombatAnimation, CtxDeviceToken, CtxGameSettings, CtxGamepad,
+        CtxHotkeys, CtxLocales, CtxLogMessages, CtxMaterials, CtxQuests, CtxShopEnabled,
+        CtxShowAtkTooltips, CtxShowBossEnergy, CtxShowBossHp, CtxShowHeroAggro,
+        CtxSyncedInsecureCerts, CtxSyncedServerUrl, CtxTheme, CtxToggleAtkAnimation, CtxTrades,
+        DISCONNECTED_USER, DX_COMP_CSS, Route, SERVER_NAME, SYNCED_AUDIO_MUTED_KEY,
+        SYNCED_DEVICE_TOKEN_KEY, SYNCED_MUSIC_VOLUME_KEY, SYNCED_SFX_VOLUME_KEY,
     },
     components::{
         alert_dialog, button, drag_and_drop_list, input, label, popover, select, separator, sheet,
         sidebar, tabs, tooltip,
     },
+    crafting::MaterialStash,
+    difficulty::GameSettings,
+    gamepad::{GamepadBindings, InputMode, PadPress},
+    hotkeys::HotkeyBindings,
+    locales,
+    quests::QuestsUpdate,
+    server_messages::LogMessages,
+    theme::{ThemeMode, UniverseSkin},
     websocket_handler::{
         NO_CLIENT_ID,
         event::{ClientEvent, ServerEvent, on_rcv_client_event},
@@ -31,7 +43,6 @@ use dx_rpg::{
 #[cfg(all(not(feature = "server"), not(target_arch = "wasm32")))]
 use dx_rpg::common::{SYNCED_INSECURE_CERTS_KEY, SYNCED_SERVER_URL_KEY};
 use lib_rpg::server::server_manager::{GamePhase, ServerData};
-use unic_langid::langid;
 // StorageBacking is needed on wasm32 to call LocalStorage::get / LocalStorage::set
 // (trait methods) inside the login-restore effect.  On native it is already
 // imported above via the #[cfg(all(not(feature="server"),not(target_arch="wasm32")))]
@@ -178,6 +189,11 @@ fn main() {
         // Create an axum router that dioxus will attach the app to
         Ok(dioxus::server::router(App)
             .route("/img-srv/{filename}", axum::routing::get(serve_img_handler))
+            .route(
+                "/music-srv/{filename}",
+                axum::routing::get(serve_music_handler),
+            )
+            .route("/sfx-srv/{filename}", axum::routing::get(serve_sfx_handler))
             .layer(axum::middleware::from_fn(auth_rate_limit))
             .layer(AuthLayer::new(Some(pool.clone())).with_config(
                 AuthConfig::<i64>::default().with_anonymous_user_id(Some(STARTING_CLIENT_ID)),
@@ -280,6 +296,63 @@ pub async fn serve_img_handler(
     }
 }
 
+/// Serves an uploaded music track (see `music.rs`) from the MUSIC_PATH directory.
+#[cfg(feature = "server")]
+pub async fn serve_music_handler(
+    axum::extract::Path(filename): axum::extract::Path<String>,
+) -> axum::response::Response {
+    use dx_rpg::auth_manager::server_fn::music_dir;
+    serve_audio_file(&music_dir(), &filename)
+}
+
+/// Serves an uploaded attack sound effect from the SFX_PATH directory.
+#[cfg(feature = "server")]
+pub async fn serve_sfx_handler(
+    axum::extract::Path(filename): axum::extract::Path<String>,
+) -> axum::response::Response {
+    use dx_rpg::auth_manager::server_fn::sfx_dir;
+    serve_audio_file(&sfx_dir(), &filename)
+}
+
+#[cfg(feature = "server")]
+fn serve_audio_file(dir: &std::path::Path, filename: &str) -> axum::response::Response {
+    use axum::http::{StatusCode, header};
+    use axum::response::IntoResponse;
+    use dx_rpg::audio::is_audio_file_name;
+    // Security: reject any path traversal attempt
+    if !is_audio_file_name(filename) {
+        return (
+            StatusCode::BAD_REQUEST,
+            [(header::CONTENT_TYPE, "text/plain")],
+            vec![],
+        )
+            .into_response();
+    }
+    let path = dir.join(filename);
+    match std::fs::read(&path) {
+        Ok(bytes) => {
+            let mime = match path
+                .extension()
+                .and_then(|e| e.to_str())
+                .map(|e| e.to_lowercase())
+                .as_deref()
+            {
+                Some("ogg") | Some("opus") => "audio/ogg",
+                Some("mp3") => "audio/mpeg",
+                Some("wav") => "audio/wav",
+                _ => "application/octet-stream",
+            };
+            (StatusCode::OK, [(header::CONTENT_TYPE, mime)], bytes).into_response()
+        }
+        Err(_) => (
+            StatusCode::NOT_FOUND,
+            [(header::CONTENT_TYPE, "text/plain")],
+            vec![],
+        )
+            .into_response(),
+    }
+}
+
 #[cfg(feature = "server")]
 pub async fn init_data_manager() {
     use dx_rpg::common::{DATA_MANAGER, OFFLINE_PATH};
@@ -346,13 +419,6 @@ mod tests {
 
 #[component]
 fn App() -> Element {
-    // i18n — English default, French available; toggle lives in the Navbar.
-    use_init_i18n(|| {
-        I18nConfig::new(langid!("en-US"))
-            .with_locale((langid!("en-US"), include_str!("./i18n/en-US.ftl")))
-            .with_locale((langid!("fr-FR"), include_str!("./i18n/fr-FR.ftl")))
-    });
-
     // Local UI state
     let mut message = use_signal(String::new);
     let mut player_client_id = use_signal(|| 0);
@@ -363,6 +429,19 @@ fn App() -> Element {
     let mut toggle_atk_animation = use_signal(|| false);
     // Set to Some(map_id) by the lightweight OverworldEntered event.
     let mut overworld_map_id: Signal<Option<String>> = use_signal(|| None);
+    // Filled by AchievementsUnlocked, emptied by the toasts (see CtxAchievementToasts).
+    let mut achievement_toasts = use_signal(Vec::new);
+    // Filled by UpdateGameSettings (see CtxGameSettings).
+    let mut game_settings = use_signal(GameSettings::default);
+    let mut new_game_plus_available = use_signal(|| false);
+    // Filled by UpdateMaterials (see CtxMaterials).
+    let mut materials = use_signal(MaterialStash::new);
+    // Filled by UpdateTrades (see CtxTrades).
+    let mut trades = use_signal(Vec::new);
+    // Filled by UpdateQuests (see CtxQuests).
+    let mut quests = use_signal(QuestsUpdate::default);
+    // Filled by UpdateLogMessages (see CtxLogMessages).
+    let mut log_messages = use_signal(LogMessages::default);
 
     // Log which server URL this client is about to talk to (server-fn calls + websocket) —
     // same-origin implicit on web/server, explicit remote target on native — to make
@@ -431,23 +510,35 @@ fn App() -> Element {
     #[cfg(any(target_arch = "wasm32", feature = "server"))]
     let synced_insecure_certs = use_signal(|| false);
 
-    // Keep dioxus-i18n's active locale synced to the persisted "en"/"fr" value —
-    // covers both the initial load from localStorage and every toggle click.
+    // Locales served on top of the bundled ones (see locales.rs). Fetched client-side
+    // like get_use_password in navbar.rs; until they arrive only the bundled ones exist.
+    let mut served_locales = use_signal(Vec::new);
     use_effect(move || {
-        let mut i18n = i18n();
-        let target = if app_lang_local_sync() == "fr" {
-            langid!("fr-FR")
-        } else {
-            langid!("en-US")
-        };
-        i18n.set_language(target);
+        spawn(async move {
+            match list_locale_bundles().await {
+                Ok(bundles) => served_locales.set(bundles),
+                Err(e) => tracing::warn!("list_locale_bundles: {e}"),
+            }
+        });
     });
 
-    // Set the theme to dark on app load.
-    // `document::eval` (not raw web_sys) so this also works on desktop/mobile clients,
-    // which don't compile web_sys (it's a wasm-bindgen crate, native targets don't have it).
-    use_effect(|| {
-        document::eval("document.documentElement.setAttribute('data-theme', 'dark');");
+    // Theme — dark until GameSheets loads the saved settings; the open game's universe
+    // picks the skin. `document::eval` (not raw web_sys) so this also works on
+    // desktop/mobile clients, which don't compile web_sys (it's a wasm-bindgen crate,
+    // native targets don't have it).
+    let theme = CtxTheme {
+        mode: use_signal(ThemeMode::default),
+        universe_skins: use_signal(|| true),
+    };
+    use_context_provider(|| theme);
+    let universe = use_memo(move || server_data.read().core_game_data.universe.clone());
+    use_effect(move || {
+        let skin = if (theme.universe_skins)() {
+            UniverseSkin::for_universe(&universe())
+        } else {
+            None
+        };
+        dx_rpg::theme::apply_to_document((theme.mode)(), skin);
     });
 
     // Sets up the background-music/sfx `<audio>` elements once. Same document::eval
@@ -455,6 +546,10 @@ fn App() -> Element {
     use_effect(|| {
         dx_rpg::audio::init_audio_bridge();
     });
+    // Same for the strike effects of the combat animations.
+    use_effect(|| {
+        dx_rpg::combat_animation::init_combat_fx();
+    });
 
     // Android's WebView (used by the native mobile client) never enables "wide viewport"
     // mode, so it ignores <meta name="viewport"> and evaluates every `@media (max-width:
@@ -634,6 +729,17 @@ fn App() -> Element {
                                 .core_game_data
                                 .apply_combat_update(*combat_update);
                         }
+                        ServerEvent::AchievementsUnlocked(unlocked) => {
+                            achievement_toasts.write().extend(unlocked);
+                        }
+                        ServerEvent::UpdateGameSettings(settings, can_start_new_game_plus) => {
+                            game_settings.set(settings);
+                            new_game_plus_available.set(can_start_new_game_plus);
+                        }
+                        ServerEvent::UpdateMaterials(stash) => materials.set(stash),
+                        ServerEvent::UpdateTrades(list) => trades.set(list),
+                        ServerEvent::UpdateQuests(update) => quests.set(*update),
+                        ServerEvent::UpdateLogMessages(messages) => log_messages.set(*messages),
                     }
                 }
                 tracing::warn!(
@@ -678,12 +784,23 @@ fn App() -> Element {
     });
     use_context_provider(|| server_data);
     use_context_provider(|| overworld_map_id);
+    use_context_provider(|| CtxAchievementToasts(achievement_toasts));
+    use_context_provider(|| CtxGameSettings {
+        settings: game_settings,
+        new_game_plus_available,
+    });
+    use_context_provider(|| CtxMaterials(materials));
+    use_context_provider(|| CtxTrades(trades));
+    use_context_provider(|| CtxQuests(quests));
+    use_context_provider(|| CtxLogMessages(log_messages));
     use_context_provider(|| ongoing_games);
     use_context_provider(|| saved_game_list);
     use_context_provider(|| all_characters_names);
     // Wrap each bool signal in a distinct newtype so Dioxus context lookup
     // (keyed by TypeId) stores them independently instead of all colliding on Signal<bool>.
     use_context_provider(|| CtxToggleAtkAnimation(toggle_atk_animation));
+    let combat_animation = use_signal(|| None);
+    use_context_provider(|| CtxCombatAnimation(combat_animation));
     // Show attack tooltips — default true, overridden from DB once settings load
     let show_atk_tooltips: Signal<bool> = use_signal(|| true);
     use_context_provider(|| CtxShowAtkTooltips(show_atk_tooltips));
@@ -706,8 +823,40 @@ fn App() -> Element {
     let atk_panel_orders: Signal<std::collections::HashMap<String, Vec<String>>> =
         use_signal(std::collections::HashMap::new);
     use_context_provider(|| CtxAtkPanelOrders(atk_panel_orders));
-    // UI language ("en"/"fr") — localStorage-backed so it works pre-login
+    // Keyboard bindings — defaults until GameSheets loads the saved table
+    let hotkeys: Signal<HotkeyBindings> = use_signal(HotkeyBindings::default);
+    use_context_provider(|| CtxHotkeys(hotkeys));
+    // Gamepad — fed by the bridge spawned below, bindings loaded by GameSheets
+    let gamepad = CtxGamepad {
+        pressed: use_signal(PadPress::default),
+        input_mode: use_signal(InputMode::default),
+        connected: use_signal(|| false),
+        bindings: use_signal(GamepadBindings::default),
+        capturing: use_signal(|| false),
+    };
+    use_context_provider(|| gamepad);
+    // Accessibility — defaults until GameSheets loads the saved settings
+    let a11y = CtxA11y {
+        palette: use_signal(ColorPalette::default),
+        reduced_motion: use_signal(|| false),
+    };
+    use_context_provider(|| a11y);
+    use_effect(move || {
+        dx_rpg::accessibility::apply_to_document((a11y.palette)(), (a11y.reduced_motion)());
+    });
+    use_effect(move || {
+        spawn(dx_rpg::gamepad::run_gamepad_bridge(gamepad));
+    });
+    // UI language ("en"/"fr"/served language id) — localStorage-backed so it works pre-login
     use_context_provider(|| CtxAppLang(app_lang_local_sync));
+    use_context_provider(|| CtxLocales(served_locales));
+    // Remounts the router (and dioxus-i18n with it) when served locales arrive.
+    let locales_key = served_locales
+        .read()
+        .iter()
+        .map(|b| b.lang_id.as_str())
+        .collect::<Vec<_>>()
+        .join(",");
     // Native clients only: server address / TLS-validation override, editable from the
     // Server settings dialog in Navbar (see the doc comment on CtxSyncedServerUrl above).
     use_context_provider(|| CtxSyncedServerUrl(synced_server_url));
@@ -734,6 +883,29 @@ fn App() -> Element {
         document::Link { rel: "stylesheet", href: tabs::STYLE_CSS }
         document::Link { rel: "stylesheet", href: tooltip::STYLE_CSS }
 
+        LocalizedRouter { key: "{locales_key}" }
+    }
+}
+
+/// Router under the dioxus-i18n root. dioxus-i18n reads its locales once, so `App()`
+/// keys this component by the served locales to rebuild the config when they change.
+#[component]
+fn LocalizedRouter() -> Element {
+    let app_lang = use_context::<CtxAppLang>().0;
+    let served_locales = use_context::<CtxLocales>().0;
+    use_init_i18n(move || locales::i18n_config(&served_locales.peek()));
+
+    // Keep dioxus-i18n's active locale synced to the persisted language — covers both
+    // the initial load from localStorage and every selector change.
+    use_effect(move || {
+        let mut i18n = i18n();
+        i18n.set_language(locales::lang_id_for_code(
+            &app_lang(),
+            &served_locales.peek(),
+        ));
+    });
+
+    rsx! {
         Router::<Route> {}
     }
 }
@@ -0,0 +1,213 @@
+//! Data-driven background music. A track can be named by a map JSON (`"music"`),
+//! by a scenario JSON (`"music"`, plus `"boss_fight": true` for boss tracks), by
+//! a universe's settings (`offlines/music/<universe>.json`) or by the server-wide
+//! defaults (`offlines/music/default.json`); the most specific one wins. Track
+//! files are uploaded by admins and served from `/music-srv/`. Without any of
+//! them, the menus and the overworld keep the bundled tracks and fights stay
+//! silent.
+
+use std::collections::BTreeMap;
+
+use crate::audio::MusicTrack;
+
+/// Directory under `OFFLINE_PATH` holding the `<universe>.json` music settings.
+pub const MUSIC_CONFIG_DIR: &str = "music";
+
+/// Stem of the settings file applying to every universe.
+pub const DEFAULT_MUSIC_CONFIG: &str = "default";
+
+/// Moments of the game that have their own track.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MusicSlot {
+    Home,
+    Overworld,
+    Combat,
+    Boss,
+    Victory,
+}
+
+impl MusicSlot {
+    pub const ALL: [MusicSlot; 5] = [
+        MusicSlot::Home,
+        MusicSlot::Overworld,
+        MusicSlot::Combat,
+        MusicSlot::Boss,
+        MusicSlot::Victory,
+    ];
+
+    /// Bundled track used when no served one is set.
+    fn bundled(self) -> Option<MusicTrack> {
+        match self {
+            MusicSlot::Home => Some(MusicTrack::Home),
+            MusicSlot::Overworld => Some(MusicTrack::Overworld),
+            MusicSlot::Combat | MusicSlot::Boss | MusicSlot::Victory => None,
+        }
+    }
+}
+
+/// Track file of each slot for a universe (or the defaults); empty = not set.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct SlotTracks {
+    pub home: String,
+    pub overworld: String,
+    pub combat: String,
+    pub boss: String,
+    pub victory: String,
+}
+
+impl SlotTracks {
+    pub fn get(&self, slot: MusicSlot) -> &str {
+        match slot {
+            MusicSlot::Home => &self.home,
+            MusicSlot::Overworld => &self.overworld,
+            MusicSlot::Combat => &self.combat,
+            MusicSlot::Boss => &self.boss,
+            MusicSlot::Victory => &self.victory,
+        }
+    }
+
+    pub fn set(&mut self, slot: MusicSlot, track: String) {
+        match slot {
+            MusicSlot::Home => self.home = track,
+            MusicSlot::Overworld => self.overworld = track,
+            MusicSlot::Combat => self.combat = track,
+            MusicSlot::Boss => self.boss = track,
+            MusicSlot::Victory => self.victory = track,
+        }
+    }
+}
+
+/// Music fields of a scenario JSON.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ScenarioMusic {
+    /// Track of the fight; empty = the universe's combat or boss track
+    pub track: String,
+    pub boss_fight: bool,
+}
+
+/// Every music setting of the server, fetched once by the client.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MusicCatalog {
+    pub defaults: SlotTracks,
+    pub universes: BTreeMap<String, SlotTracks>,
+    /// Map id → track named by the map JSON
+    pub maps: BTreeMap<String, String>,
+    /// `scenario_key` → music fields of the scenario JSON
+    pub scenarios: BTreeMap<String, ScenarioMusic>,
+}
+
+/// Key of a scenario in `MusicCatalog::scenarios`; scenario names are only unique
+/// within a universe.
+pub fn scenario_key(universe: &str, name: &str) -> String {
+    format!("{universe}/{name}")
+}
+
+/// What the player is looking at, as far as music goes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MusicScene<'a> {
+    Menu,
+    Overworld { map_id: &'a str },
+    Combat { scenario: &'a str },
+    Victory,
+}
+
+/// Track to play: bundled with the app or served from `/music-srv/`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MusicSource {
+    Bundled(MusicTrack),
+    Served(String),
+}
+
+impl MusicCatalog {
+    /// Track for `scene` in `universe`: the map's or scenario's own track, then the
+    /// universe's track for the slot, then the defaults, then the bundled one.
+    /// `None` means silence.
+    pub fn resolve(&self, universe: &str, scene: MusicScene) -> Option<MusicSource> {
+        let (slot, own_track) = match scene {
+            MusicScene::Menu => (MusicSlot::Home, None),
+            MusicScene::Overworld { map_id } => (MusicSlot::Overworld, self.maps.get(map_id)),
+            MusicScene::Combat { scenario } => {
+                let music = self.scenarios.get(&scenario_key(universe, scenario));
+                let slot = if music.is_some_and(|m| m.boss_fight) {
+                    MusicSlot::Boss
+                } else {
+                    MusicSlot::Combat
+                };
+                (slot, music.map(|m| &m.track))
+            }
+            MusicScene::Victory => (MusicSlot::Victory, None),
+        };
+        own_track
+            .map(String::as_str)
+            .into_iter()
+            .chain(self.universes.get(universe).map(|u| u.get(slot)))
+            .chain([self.defaults.get(slot)])
+            .find(|track| !track.is_empty())
+            .map(|track| MusicSource::Served(track.to_owned()))
+            .or_else(|| slot.bundled().map(MusicSource::Bundled))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn catalog() -> MusicCatalog {
+        let mut catalog = MusicCatalog::default();
+        catalog.defaults.combat = "battle.ogg".to_owned();
+        catalog.universes.insert(
+            "lotr".to_owned(),
+            SlotTracks {
+                overworld: "shire.ogg".to_owned(),
+                boss: "sauron.ogg".to_owned(),
+                ..Default::default()
+            },
+        );
+        catalog
+            .maps
+            .insert("lotr_moria".to_owned(), "moria.ogg".to_owned());
+        catalog.scenarios.insert(
+            scenario_key("lotr", "L'Oeil de Sauron"),
+            ScenarioMusic {
+                track: String::new(),
+                boss_fight: true,
+            },
+        );
+        catalog
+    }
+
+    #[test]
+    fn most_specific_track_wins() {
+        let catalog = catalog();
+        let served = |t: &str| Some(MusicSource::Served(t.to_owned()));
+        let moria = MusicScene::Overworld {
+            map_id: "lotr_moria",
+        };
+        let shire = MusicScene::Overworld {
+            map_id: "lotr_shire",
+        };
+        assert_eq!(catalog.resolve("lotr", moria), served("moria.ogg"));
+        assert_eq!(catalog.resolve("lotr", shire), served("shire.ogg"));
+        let sauron = MusicScene::Combat {
+            scenario: "L'Oeil de Sauron",
+        };
+        let orcs = MusicScene::Combat { scenario: "Orcs" };
+        assert_eq!(catalog.resolve("lotr", sauron), served("sauron.ogg"));
+        assert_eq!(catalog.resolve("lotr", orcs), served("battle.ogg"));
+    }
+
+    #[test]
+    fn unset_slots_fall_back_to_bundled_tracks_or_silence() {
+        let catalog = catalog();
+        assert_eq!(
+            catalog.resolve("pokemon", MusicScene::Overworld { map_id: "route_1" }),
+            Some(MusicSource::Bundled(MusicTrack::Overworld))
+        );
+        assert_eq!(
+            catalog.resolve("pokemon", MusicScene::Menu),
+            Some(MusicSource::Bundled(MusicTrack::Home))
+        );
+        assert_eq!(catalog.resolve("pokemon", MusicScene::Victory), None);
+    }
+}
@@ -0,0 +1,424 @@
+//! Quests and dialogue trees of the overworld NPCs. Quest books, one JSON file
+//! each in `offlines/quests/`, hold the quests, the dialogues of the NPCs of
+//! a map and the doors that open once their conditions hold. The progress of
+//! the quests is kept with the game save (`SAVED_QUESTS`); the server side is
+//! in `websocket_handler/event_quests.rs`. NPCs without a dialogue here keep
+//! the linear `dialog` of their map.
+
+use crate::{
+    crafting::{Ingredient, MaterialStash},
+    locales::LocalizedText,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Directory of the quest books, under `OFFLINE_PATH`.
+pub const QUESTS_DIR: &str = "quests";
+/// File of the quest log, in the directory of a saved game.
+pub const SAVED_QUESTS: &str = "quests.json";
+
+/// What must hold for a dialogue, a choice or a door to be available.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Condition {
+    QuestNotStarted(String),
+    QuestActive(String),
+    QuestCompleted(String),
+    /// Set by a dialogue action
+    Flag(String),
+}
+
+/// What the party must do for a quest, in any order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Objective {
+    TalkTo {
+        map: String,
+        npc: String,
+    },
+    Defeat {
+        scenario: String,
+    },
+    ReachMap {
+        map: String,
+    },
+    /// Owned in the party stash; they aren't taken by the quest
+    CollectMaterials {
+        material: String,
+        amount: u64,
+    },
+}
+
+/// Given when the quest is completed: the gold and consumables to every hero
+/// of the party, the materials to its stash.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestReward {
+    pub gold: u64,
+    pub consumables: Vec<String>,
+    pub materials: Vec<Ingredient>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Quest {
+    pub id: String,
+    pub title: LocalizedText,
+    pub description: LocalizedText,
+    pub objectives: Vec<Objective>,
+    pub rewards: QuestReward,
+}
+
+/// Done when a dialogue node is shown or a choice is picked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DialogueAction {
+    StartQuest(String),
+    SetFlag(String),
+    /// Ends the dialogue and starts the fight of this scenario
+    Fight(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DialogueChoice {
+    pub text: LocalizedText,
+    /// All must hold for the choice to be offered
+    pub conditions: Vec<Condition>,
+    /// Node shown next, the dialogue ends without one
+    pub next: Option<String>,
+    pub actions: Vec<DialogueAction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DialogueNode {
+    pub id: String,
+    pub lines: Vec<LocalizedText>,
+    /// Without any, the dialogue ends when the node is dismissed
+    pub choices: Vec<DialogueChoice>,
+    pub actions: Vec<DialogueAction>,
+}
+
+impl DialogueNode {
+    /// Choices whose conditions hold for `log`, as offered to the player.
+    pub fn offered<'a>(&'a self, log: &QuestLog) -> Vec<&'a DialogueChoice> {
+        self.choices
+            .iter()
+            .filter(|choice| log.holds_all(&choice.conditions))
+            .collect()
+    }
+}
+
+/// Dialogue tree of the NPC `npc` on the map `map`. An NPC may have several,
+/// the first whose conditions all hold is played.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Dialogue {
+    pub map: String,
+    pub npc: String,
+    pub conditions: Vec<Condition>,
+    /// Id of the first node
+    pub start: String,
+    pub nodes: Vec<DialogueNode>,
+}
+
+impl Dialogue {
+    pub fn node(&self, id: &str) -> Option<&DialogueNode> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+}
+
+/// The locked door at "x_y" of `map` opens once `conditions` all hold.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DoorRule {
+    pub map: String,
+    pub door: String,
+    pub conditions: Vec<Condition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestBook {
+    pub quests: Vec<Quest>,
+    pub dialogues: Vec<Dialogue>,
+    pub doors: Vec<DoorRule>,
+}
+
+impl QuestBook {
+    pub fn quest(&self, id: &str) -> Option<&Quest> {
+        self.quests.iter().find(|quest| quest.id == id)
+    }
+
+    /// Dialogue the NPC `npc` of `map` plays for `log`, if it has one.
+    pub fn dialogue_of(&self, map: &str, npc: &str, log: &QuestLog) -> Option<&Dialogue> {
+        self.dialogues
+            .iter()
+            .find(|d| d.map == map && d.npc == npc && log.holds_all(&d.conditions))
+    }
+
+    /// Doors of `map` that `log` opens.
+    pub fn open_doors<'a>(
+        &'a self,
+        map: &'a str,
+        log: &'a QuestLog,
+    ) -> impl Iterator<Item = &'a str> {
+        self.doors
+            .iter()
+            .filter(move |rule| rule.map == map && log.holds_all(&rule.conditions))
+            .map(|rule| rule.door.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum QuestStatus {
+    #[default]
+    Active,
+    Completed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestProgress {
+    pub status: QuestStatus,
+    /// Whether each objective of the quest is done
+    pub done: Vec<bool>,
+}
+
+/// Quests of a game as the clients show them, with the choices of the
+/// dialogue going on in the overworld.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestsUpdate {
+    pub quests: Vec<(Quest, QuestProgress)>,
+    pub choices: Vec<LocalizedText>,
+}
+
+/// Something the party did, which may fulfill quest objectives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuestEvent<'a> {
+    TalkedTo {
+        map: &'a str,
+        npc: &'a str,
+    },
+    Won(&'a str),
+    Entered(&'a str),
+    /// The stash changed
+    Materials,
+}
+
+impl Objective {
+    fn is_met(&self, event: QuestEvent, stash: &MaterialStash) -> bool {
+        match (self, event) {
+            (Objective::TalkTo { map, npc }, QuestEvent::TalkedTo { map: m, npc: n }) => {
+                map == m && npc == n
+            }
+            (Objective::Defeat { scenario }, QuestEvent::Won(s)) => scenario == s,
+            (Objective::ReachMap { map }, QuestEvent::Entered(m)) => map == m,
+            // Checked whatever happened
+            (Objective::CollectMaterials { material, amount }, _) => {
+                stash.get(material).copied().unwrap_or(0) >= *amount
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Quests started by a party and the flags set by its dialogues.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestLog {
+    pub quests: BTreeMap<String, QuestProgress>,
+    pub flags: BTreeSet<String>,
+}
+
+impl QuestLog {
+    pub fn status(&self, quest_id: &str) -> Option<QuestStatus> {
+        self.quests.get(quest_id).map(|progress| progress.status)
+    }
+
+    pub fn holds(&self, condition: &Condition) -> bool {
+        match condition {
+            Condition::QuestNotStarted(id) => self.status(id).is_none(),
+            Condition::QuestActive(id) => self.status(id) == Some(QuestStatus::Active),
+            Condition::QuestCompleted(id) => self.status(id) == Some(QuestStatus::Completed),
+            Condition::Flag(flag) => self.flags.contains(flag),
+        }
+    }
+
+    pub fn holds_all(&self, conditions: &[Condition]) -> bool {
+        conditions.iter().all(|condition| self.holds(condition))
+    }
+
+    /// Starts `quest`. Returns `false` when it was already started.
+    pub fn start(&mut self, quest: &Quest) -> bool {
+        if self.quests.contains_key(&quest.id) {
+            return false;
+        }
+        self.quests.insert(
+            quest.id.clone(),
+            QuestProgress {
+                status: QuestStatus::Active,
+                done: vec![false; quest.objectives.len()],
+            },
+        );
+        true
+    }
+
+    /// Marks the objectives of the active quests of `book` met by `event`.
+    /// Returns the ids of the quests it completes.
+    pub fn record(
+        &mut self,
+        book: &QuestBook,
+        event: QuestEvent,
+        stash: &MaterialStash,
+    ) -> Vec<String> {
+        let mut completed = Vec::new();
+        for quest in &book.quests {
+            let Some(progress) = self.quests.get_mut(&quest.id) else {
+                continue;
+            };
+            if progress.status == QuestStatus::Completed {
+                continue;
+            }
+            // Quests edited after the save was made
+            progress.done.resize(quest.objectives.len(), false);
+            for (done, objective) in progress.done.iter_mut().zip(&quest.objectives) {
+                *done = *done || objective.is_met(event, stash);
+            }
+            if progress.done.iter().all(|done| *done) {
+                progress.status = QuestStatus::Completed;
+                completed.push(quest.id.clone());
+            }
+        }
+        completed
+    }
+}
+
+/// Quest books of `dir`, read in file name order, as one book. Files that
+/// aren't quest books are skipped.
+#[cfg(feature = "server")]
+pub fn read_quest_books(dir: &std::path::Path) -> QuestBook {
+    let mut paths: Vec<std::path::PathBuf> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|x| x == "json"))
+        .collect();
+    paths.sort();
+    let mut all = QuestBook::default();
+    for book in paths.iter().filter_map(|path| {
+        serde_json::from_str::<QuestBook>(&std::fs::read_to_string(path).ok()?).ok()
+    }) {
+        all.quests.extend(book.quests);
+        all.dialogues.extend(book.dialogues);
+        all.doors.extend(book.doors);
+    }
+    all
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book() -> QuestBook {
+        let json = r#"{
+            "quests": [{
+                "id": "parcel",
+                "title": {"en": "Oak's parcel", "fr": "Le colis de Chen"},
+                "objectives": [
+                    {"ReachMap": {"map": "route_1"}},
+                    {"Defeat": {"scenario": "Patrouille Rattata"}},
+                    {"CollectMaterials": {"material": "Iron ore", "amount": 2}}
+                ],
+                "rewards": {"gold": 100}
+            }],
+            "dialogues": [{
+                "map": "pallet_town", "npc": "professor_oak",
+                "conditions": [{"QuestCompleted": "parcel"}], "start": "thanks",
+                "nodes": [{"id": "thanks", "lines": [{"en": "Thank you!"}]}]
+            }, {
+                "map": "pallet_town", "npc": "professor_oak", "start": "ask",
+                "nodes": [{"id": "ask", "choices": [
+                    {"text": {"en": "Yes"}, "actions": [{"StartQuest": "parcel"}]},
+                    {"text": {"en": "Again?"}, "conditions": [{"QuestActive": "parcel"}]}
+                ]}]
+            }],
+            "doors": [{"map": "pallet_town", "door": "3_0", "conditions": [{"QuestCompleted": "parcel"}]}]
+        }"#;
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn quests_complete_once_every_objective_is_met() {
+        let book = book();
+        let quest = book.quest("parcel").unwrap();
+        assert_eq!(quest.title.get("fr"), "Le colis de Chen");
+        assert_eq!(quest.description.get("fr"), "");
+
+        let mut log = QuestLog::default();
+        let mut stash = MaterialStash::new();
+        assert!(
+            log.record(&book, QuestEvent::Entered("route_1"), &stash)
+                .is_empty()
+        );
+        assert_eq!(log.status("parcel"), None);
+
+        assert!(log.start(quest));
+        assert!(!log.start(quest));
+        assert!(
+            log.record(&book, QuestEvent::Entered("route_1"), &stash)
+                .is_empty()
+        );
+        assert!(
+            log.record(&book, QuestEvent::Won("Double Attaque"), &stash)
+                .is_empty()
+        );
+        assert!(
+            log.record(&book, QuestEvent::Won("Patrouille Rattata"), &stash)
+                .is_empty()
+        );
+        assert_eq!(log.quests["parcel"].done, vec![true, true, false]);
+
+        stash.insert("Iron ore".to_owned(), 2);
+        assert_eq!(
+            log.record(&book, QuestEvent::Materials, &stash),
+            vec!["parcel".to_owned()]
+        );
+        assert_eq!(log.status("parcel"), Some(QuestStatus::Completed));
+        // Completed only once
+        assert!(log.record(&book, QuestEvent::Materials, &stash).is_empty());
+    }
+
+    #[test]
+    fn conditions_pick_the_dialogue_and_open_the_doors() {
+        let book = book();
+        let mut log = QuestLog::default();
+        let dialogue = book
+            .dialogue_of("pallet_town", "professor_oak", &log)
+            .unwrap();
+        assert_eq!(dialogue.start, "ask");
+        let offered = dialogue.node("ask").unwrap().offered(&log);
+        assert_eq!(offered.len(), 1);
+        assert_eq!(offered[0].text.get("fr"), "Yes");
+        assert!(book.dialogue_of("route_1", "professor_oak", &log).is_none());
+        assert_eq!(book.open_doors("pallet_town", &log).count(), 0);
+
+        log.quests.insert(
+            "parcel".to_owned(),
+            QuestProgress {
+                status: QuestStatus::Completed,
+                done: vec![true; 3],
+            },
+        );
+        let dialogue = book
+            .dialogue_of("pallet_town", "professor_oak", &log)
+            .unwrap();
+        assert_eq!(dialogue.start, "thanks");
+        assert_eq!(
+            book.open_doors("pallet_town", &log).collect::<Vec<_>>(),
+            vec!["3_0"]
+        );
+        assert!(log.holds(&Condition::QuestCompleted("parcel".to_owned())));
+        assert!(!log.holds(&Condition::Flag("met_oak".to_owned())));
+    }
+}
@@ -0,0 +1,496 @@
+//! Texts the server generates (shop/potion log lines, action banners, account
+//! errors) travel as a message id plus arguments instead of a finished sentence,
+//! and each client renders them with its own fluent bundle, so two players of the
+//! same game read the log in their own language.
+//!
+//! lib-rpg's `LogData` only holds a text, so a log line or banner gets the English
+//! text of its message and the message itself is filed next to the game, by line,
+//! in `LogMessages` (see `event_logs`). Errors of server functions are texts too:
+//! they embed `ServerMessage::token()`. Lines built by lib-rpg stay plain text.
+
+use std::collections::BTreeMap;
+
+use dioxus::prelude::*;
+use dioxus_i18n::{fluent::FluentArgs, prelude::i18n};
+#[cfg(feature = "server")]
+use lib_rpg::common::log_data::LogData;
+
+/// File of the log messages, in the directory of a game save.
+pub const SAVED_LOG_MESSAGES: &str = "log_messages.json";
+
+/// Delimiters of a token inside an error, e.g. `… ⟦{"id":"wrong-password"}⟧`.
+const TOKEN_OPEN: char = '⟦';
+const TOKEN_CLOSE: char = '⟧';
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "id", rename_all = "kebab-case")]
+pub enum ServerMessage {
+    ItemBought {
+        character: String,
+        item: String,
+        price: i64,
+    },
+    ItemSold {
+        character: String,
+        item: String,
+        price: i64,
+    },
+    ItemCrafted {
+        character: String,
+        item: String,
+    },
+    /// Trade accepted between the heroes of two players
+    TradeCompleted {
+        from: String,
+        to: String,
+    },
+    /// Items and gold moved between two heroes of the same player
+    ItemsGiven {
+        from: String,
+        to: String,
+    },
+    QuestStarted {
+        quest: String,
+    },
+    QuestCompleted {
+        quest: String,
+    },
+    /// Potion used in combat; `amount` is the stat change, 0 when nothing changed
+    PotionUsed {
+        launcher: String,
+        potion: String,
+        target: String,
+        party: bool,
+        amount: i64,
+        stat: String,
+    },
+    /// Consumable used outside combat
+    ConsumableUsed {
+        hero: String,
+        consumable: String,
+    },
+    CredentialsEmpty,
+    NewPasswordEmpty,
+    UsernameNotRegistered {
+        username: String,
+    },
+    UsernameTaken {
+        username: String,
+    },
+    UserAlreadyConnected,
+    WrongPassword,
+    WrongCurrentPassword,
+    AdminNotDeletable,
+    ConnectionStatusFailed,
+    /// Opens the log lines of a scenario (see `combat_log`)
+    ScenarioStarted {
+        scenario: String,
+    },
+    /// Heads the log lines of one attack (see `combat_log`)
+    AttackLaunched {
+        turn: usize,
+        round: usize,
+        launcher: String,
+        attack: String,
+        targets: Vec<String>,
+    },
+}
+
+impl ServerMessage {
+    /// Text to embed in a server function error.
+    pub fn token(&self) -> String {
+        let json = serde_json::to_string(self).unwrap_or_default();
+        format!("{TOKEN_OPEN}{json}{TOKEN_CLOSE}")
+    }
+}
+
+impl From<ServerMessage> for ServerFnError {
+    fn from(message: ServerMessage) -> Self {
+        ServerFnError::new(message.token())
+    }
+}
+
+enum Part<'a> {
+    Text(&'a str),
+    Message(ServerMessage),
+}
+
+/// Splits `text` into plain parts and decoded tokens. A delimiter that doesn't open
+/// a valid token is kept as text (e.g. a player name containing `⟦`).
+fn split_tokens(text: &str) -> Vec<Part<'_>> {
+    let mut parts = Vec::new();
+    let mut rest = text;
+    let mut search_from = 0;
+    while let Some(pos) = rest[search_from..]
+        .find(TOKEN_OPEN)
+        .map(|p| p + search_from)
+    {
+        let json = &rest[pos + TOKEN_OPEN.len_utf8()..];
+        let mut stream = serde_json::Deserializer::from_str(json).into_iter::<ServerMessage>();
+        match stream.next() {
+            Some(Ok(message)) if json[stream.byte_offset()..].starts_with(TOKEN_CLOSE) => {
+                if pos > 0 {
+                    parts.push(Part::Text(&rest[..pos]));
+                }
+                parts.push(Part::Message(message));
+                rest = &json[stream.byte_offset() + TOKEN_CLOSE.len_utf8()..];
+                search_from = 0;
+            }
+            _ => search_from = pos + TOKEN_OPEN.len_utf8(),
+        }
+    }
+    if !rest.is_empty() {
+        parts.push(Part::Text(rest));
+    }
+    parts
+}
+
+/// Text of an error: its tokens alone, rendered by `render`, when it has some
+/// (without the transport's "error running server function" wrapper), the raw
+/// text otherwise.
+fn error_text_with(text: &str, render: impl Fn(&ServerMessage) -> String) -> String {
+    let rendered: Vec<String> = split_tokens(text)
+        .into_iter()
+        .filter_map(|part| match part {
+            Part::Message(message) => Some(render(&message)),
+            Part::Text(_) => None,
+        })
+        .collect();
+    if rendered.is_empty() {
+        text.to_owned()
+    } else {
+        rendered.join(" ")
+    }
+}
+
+/// Text of a server function error in the current UI language.
+pub fn localize_error(error: &ServerFnError) -> String {
+    error_text_with(&error.to_string(), render)
+}
+
+/// Message of a line the server wrote in a game log, or of a banner.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LogMessage {
+    /// Text of `message` inside the line, in English
+    pub english: String,
+    pub message: ServerMessage,
+}
+
+impl LogMessage {
+    /// `line` with the text of the message rendered by `render`.
+    pub fn localize_with(&self, line: &str, render: impl Fn(&ServerMessage) -> String) -> String {
+        line.replacen(&self.english, &render(&self.message), 1)
+    }
+}
+
+/// Messages of the lines the server wrote in the log of a game, by line. Sent to
+/// the clients next to the game (`ServerEvent::UpdateLogMessages`) and saved with it.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LogMessages(pub BTreeMap<String, LogMessage>);
+
+impl LogMessages {
+    pub fn get(&self, line: &str) -> Option<&LogMessage> {
+        self.0.get(line)
+    }
+
+    /// `line` in the current UI language; lines of lib-rpg are returned as they are.
+    pub fn localize(&self, line: &str) -> String {
+        self.get(line)
+            .map_or_else(|| line.to_owned(), |m| m.localize_with(line, render))
+    }
+
+    /// Messages of `lines` only, e.g. to keep the log of one scenario.
+    #[cfg(feature = "server")]
+    pub fn of_lines(&self, lines: &[LogData]) -> LogMessages {
+        LogMessages(
+            lines
+                .iter()
+                .filter_map(|l| Some((l.message.clone(), self.get(&l.message)?.clone())))
+                .collect(),
+        )
+    }
+
+    /// Banner text of `message` after `prefix`, e.g. `💊 Elara uses Potion on Orc`.
+    #[cfg(feature = "server")]
+    pub fn banner(&mut self, prefix: &str, message: ServerMessage) -> String {
+        self.file(prefix, message, |text| text)
+    }
+
+    /// Timestamped log line of `message` after `prefix`, in `color`.
+    #[cfg(feature = "server")]
+    pub fn line(&mut self, prefix: &str, message: ServerMessage, color: &str) -> LogData {
+        let line = self.file(prefix, message, |text| {
+            lib_rpg::utils::format_string_with_timestamp(&text)
+        });
+        LogData {
+            message: line,
+            color: color.to_owned(),
+        }
+    }
+
+    /// Files `message` under the text `finish` makes of its English text after
+    /// `prefix`, and returns that text.
+    #[cfg(feature = "server")]
+    fn file(
+        &mut self,
+        prefix: &str,
+        message: ServerMessage,
+        finish: impl FnOnce(String) -> String,
+    ) -> String {
+        let english = english(&message);
+        let text = finish(if prefix.is_empty() {
+            english.clone()
+        } else {
+            format!("{prefix} {english}")
+        });
+        self.0.insert(text.clone(), LogMessage { english, message });
+        text
+    }
+}
+
+/// Fluent id and arguments of `message`, turned into text by `translate`: the one
+/// mapping both the clients (in their language) and the server (in English, see
+/// `english`) go through.
+fn render_with(
+    message: &ServerMessage,
+    translate: &dyn Fn(&str, &[(&str, String)]) -> String,
+) -> String {
+    match message {
+        ServerMessage::ItemBought {
+            character,
+            item,
+            price,
+        } => translate(
+            "srv-item-bought",
+            &[
+                ("character", character.clone()),
+                ("item", item.clone()),
+                ("price", price.to_string()),
+            ],
+        ),
+        ServerMessage::ItemSold {
+            character,
+            item,
+            price,
+        } => translate(
+            "srv-item-sold",
+            &[
+                ("character", character.clone()),
+                ("item", item.clone()),
+                ("price", price.to_string()),
+            ],
+        ),
+        ServerMessage::ItemCrafted { character, item } => translate(
+            "srv-item-crafted",
+            &[("character", character.clone()), ("item", item.clone())],
+        ),
+        ServerMessage::TradeCompleted { from, to } => translate(
+            "srv-trade-completed",
+            &[("from", from.clone()), ("to", to.clone())],
+        ),
+        ServerMessage::ItemsGiven { from, to } => translate(
+            "srv-items-given",
+            &[("from", from.clone()), ("to", to.clone())],
+        ),
+        ServerMessage::QuestStarted { quest } => {
+            translate("srv-quest-started", &[("quest", quest.clone())])
+        }
+        ServerMessage::QuestCompleted { quest } => {
+            translate("srv-quest-completed", &[("quest", quest.clone())])
+        }
+        ServerMessage::PotionUsed {
+            launcher,
+            potion,
+            target,
+            party,
+            amount,
+            stat,
+        } => {
+            let potion = if *party {
+                translate("srv-party-potion", &[("potion", potion.clone())])
+            } else {
+                potion.clone()
+            };
+            let mut args = vec![
+                ("launcher", launcher.clone()),
+                ("potion", potion),
+                ("target", target.clone()),
+            ];
+            if *amount == 0 {
+                translate("srv-potion-used", &args)
+            } else {
+                args.push(("amount", format!("{amount:+}")));
+                args.push(("stat", stat.clone()));
+                translate("srv-potion-used-amount", &args)
+            }
+        }
+        ServerMessage::ConsumableUsed { hero, consumable } => translate(
+            "srv-consumable-used",
+            &[("hero", hero.clone()), ("consumable", consumable.clone())],
+        ),
+        ServerMessage::CredentialsEmpty => translate("srv-credentials-empty", &[]),
+        ServerMessage::NewPasswordEmpty => translate("srv-new-password-empty", &[]),
+        ServerMessage::UsernameNotRegistered { username } => translate(
+            "srv-username-not-registered",
+            &[("username", username.clone())],
+        ),
+        ServerMessage::UsernameTaken { username } => {
+            translate("srv-username-taken", &[("username", username.clone())])
+        }
+        ServerMessage::UserAlreadyConnected => translate("srv-user-already-connected", &[]),
+        ServerMessage::WrongPassword => translate("srv-wrong-password", &[]),
+        ServerMessage::WrongCurrentPassword => translate("srv-wrong-current-password", &[]),
+        ServerMessage::AdminNotDeletable => translate("srv-admin-not-deletable", &[]),
+        ServerMessage::ConnectionStatusFailed => translate("srv-connection-status-failed", &[]),
+        ServerMessage::ScenarioStarted { scenario } => {
+            translate("srv-scenario-started", &[("scenario", scenario.clone())])
+        }
+        ServerMessage::AttackLaunched {
+            turn,
+            round,
+            launcher,
+            targets,
+            ..
+        } => translate(
+            "srv-attack-launched",
+            &[
+                ("turn", turn.to_string()),
+                ("round", round.to_string()),
+                ("launcher", launcher.clone()),
+                ("targets", targets.join(", ")),
+            ],
+        ),
+    }
+}
+
+fn fluent_args<'a>(args: &'a [(&'a str, String)]) -> FluentArgs<'a> {
+    let mut fluent_args = FluentArgs::new();
+    for (name, value) in args {
+        fluent_args.set(*name, value.as_str());
+    }
+    fluent_args
+}
+
+/// `message` in the current UI language.
+pub fn render(message: &ServerMessage) -> String {
+    render_with(message, &|id, args| {
+        i18n().translate_with_args(id, Some(&fluent_args(args)))
+    })
+}
+
+/// `message` in English, from the bundled fallback locale: for the texts the
+/// server writes itself, such as the log exports.
+#[cfg(feature = "server")]
+pub fn english(message: &ServerMessage) -> String {
+    use crate::locales::{BUNDLED_LOCALES, FALLBACK_LANG_ID};
+    use dioxus_i18n::fluent::{FluentBundle, FluentResource};
+
+    thread_local! {
+        static ENGLISH: FluentBundle<FluentResource> = {
+            let ftl = BUNDLED_LOCALES
+                .iter()
+                .find(|b| b.lang_id == FALLBACK_LANG_ID)
+                .map_or("", |b| b.ftl);
+            let resource = FluentResource::try_new(ftl.to_owned())
+                .unwrap_or_else(|(resource, _)| resource);
+            let mut bundle = FluentBundle::new(vec![
+                FALLBACK_LANG_ID
+                    .parse()
+                    .expect("FALLBACK_LANG_ID is a valid language identifier"),
+            ]);
+            // No bidi isolation marks around the arguments of a plain-text export
+            bundle.set_use_isolating(false);
+            let _ = bundle.add_resource(resource);
+            bundle
+        };
+    }
+    ENGLISH.with(|bundle| {
+        render_with(message, &|id, args| {
+            let Some(pattern) = bundle.get_message(id).and_then(|m| m.value()) else {
+                return id.to_owned();
+            };
+            let mut errors = Vec::new();
+            bundle
+                .format_pattern(pattern, Some(&fluent_args(args)), &mut errors)
+                .into_owned()
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bought() -> ServerMessage {
+        ServerMessage::ItemBought {
+            character: "Elara".to_owned(),
+            item: "Potion".to_owned(),
+            price: 12,
+        }
+    }
+
+    #[test]
+    fn error_tokens_replace_the_transport_text() {
+        let error = format!("error running server function: {} !", bought().token());
+        let rendered = error_text_with(&error, |m| match m {
+            ServerMessage::ItemBought { item, price, .. } => format!("{item} ({price})"),
+            _ => unreachable!(),
+        });
+        assert_eq!(rendered, "Potion (12)");
+    }
+
+    #[test]
+    fn text_without_valid_token_is_unchanged() {
+        for text in [
+            "plain error",
+            "⟦not json⟧ then ⟦",
+            "⟦{\"id\":\"item-bought\"}⟧",
+        ] {
+            assert_eq!(error_text_with(text, |_| "X".to_owned()), text);
+        }
+        let two = format!(
+            "⟦{}{}",
+            bought().token(),
+            ServerMessage::WrongPassword.token()
+        );
+        assert_eq!(error_text_with(&two, |_| "X".to_owned()), "X X");
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn english_renders_the_bundled_fallback() {
+        assert_eq!(english(&bought()), "Elara bought Potion for 12 gold");
+        let potion = ServerMessage::PotionUsed {
+            launcher: "Elara".to_owned(),
+            potion: "Elixir".to_owned(),
+            target: "Thalia".to_owned(),
+            party: true,
+            amount: 30,
+            stat: "HP".to_owned(),
+        };
+        assert_eq!(
+            english(&potion),
+            "Elara uses Elixir (party) on Thalia (+30 HP)"
+        );
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn filed_lines_render_their_message() {
+        let mut messages = LogMessages::default();
+        let line = messages.line("🛒", bought(), "");
+        assert!(line.message.ends_with("🛒 Elara bought Potion for 12 gold"));
+        let in_french = |m: &ServerMessage| match m {
+            ServerMessage::ItemBought { item, .. } => format!("achat de {item}"),
+            _ => unreachable!(),
+        };
+        let filed = messages.get(&line.message).unwrap();
+        assert!(
+            filed
+                .localize_with(&line.message, in_french)
+                .ends_with("🛒 achat de Potion")
+        );
+        assert!(messages.get("Elara hits Orc").is_none());
+        assert_eq!(messages.of_lines(&[line]), messages);
+    }
+}
@@ -0,0 +1,111 @@
+use dioxus::prelude::*;
+
+/// `user_settings` keys of the theme options (Settings sheet).
+pub const SETTING_THEME: &str = "theme";
+pub const SETTING_UNIVERSE_SKINS: &str = "universe_skins";
+
+/// Light/dark mode of the interface. `System` follows the device's
+/// `prefers-color-scheme` (see the media queries of dx-components-theme.css).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemeMode {
+    #[default]
+    Dark,
+    Light,
+    System,
+}
+
+impl ThemeMode {
+    pub const ALL: [ThemeMode; 3] = [ThemeMode::Dark, ThemeMode::Light, ThemeMode::System];
+
+    /// Value saved in `user_settings`; also `<html data-theme>` except for `System`,
+    /// which leaves the attribute unset.
+    pub fn key(self) -> &'static str {
+        match self {
+            ThemeMode::Dark => "dark",
+            ThemeMode::Light => "light",
+            ThemeMode::System => "system",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|m| m.key() == key)
+            .unwrap_or_default()
+    }
+}
+
+/// Look of a universe while one of its games is open: `<html data-skin>` replaces
+/// the light/dark design tokens (dx-components-theme.css).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UniverseSkin {
+    /// Aged paper and ink (LOTR)
+    Parchment,
+    /// Saturated, rounded UI (Pokémon)
+    Bright,
+}
+
+impl UniverseSkin {
+    pub fn key(self) -> &'static str {
+        match self {
+            UniverseSkin::Parchment => "parchment",
+            UniverseSkin::Bright => "bright",
+        }
+    }
+
+    /// Skin of `universe` (its `offlines/scenarios/<universe>` directory name), if it
+    /// has one.
+    pub fn for_universe(universe: &str) -> Option<Self> {
+        match universe {
+            "lotr" => Some(UniverseSkin::Parchment),
+            "pokemon" => Some(UniverseSkin::Bright),
+            _ => None,
+        }
+    }
+}
+
+/// Sets `<html data-theme>` and `<html data-skin>` so the stylesheets pick the
+/// matching tokens. Same approach as `accessibility::apply_to_document`.
+pub fn apply_to_document(mode: ThemeMode, skin: Option<UniverseSkin>) {
+    let theme = match mode {
+        ThemeMode::System => "document.documentElement.removeAttribute('data-theme');".to_owned(),
+        mode => format!(
+            "document.documentElement.setAttribute('data-theme', '{}');",
+            mode.key()
+        ),
+    };
+    let skin = match skin {
+        Some(skin) => format!(
+            "document.documentElement.setAttribute('data-skin', '{}');",
+            skin.key()
+        ),
+        None => "document.documentElement.removeAttribute('data-skin');".to_owned(),
+    };
+    document::eval(&format!("{theme} {skin}"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn theme_keys_round_trip() {
+        for mode in ThemeMode::ALL {
+            assert_eq!(ThemeMode::from_key(mode.key()), mode);
+        }
+        assert_eq!(ThemeMode::from_key("sepia"), ThemeMode::Dark);
+    }
+
+    #[test]
+    fn only_known_universes_have_a_skin() {
+        assert_eq!(
+            UniverseSkin::for_universe("lotr"),
+            Some(UniverseSkin::Parchment)
+        );
+        assert_eq!(
+            UniverseSkin::for_universe("pokemon"),
+            Some(UniverseSkin::Bright)
+        );
+        assert_eq!(UniverseSkin::for_universe(""), None);
+    }
+}
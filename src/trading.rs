@@ -0,0 +1,147 @@
+//! Trading between heroes. In multiplayer a player proposes a trade to another
+//! player of the party, what their hero gives and what it asks in return; the
+//! other player accepts or declines it. In single-player, a player moves items
+//! and gold from one of their heroes to another. Only unequipped equipment and
+//! personal consumables can change hands. The server side is in
+//! `websocket_handler/event_trading.rs`.
+
+use lib_rpg::character_mod::character::Character;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum TradeItemKind {
+    Equipment,
+    Consumable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct TradeItem {
+    pub kind: TradeItemKind,
+    /// Unique name of the equipment, or name of the consumable
+    pub name: String,
+}
+
+/// What one hero hands over in a trade.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TradeOffer {
+    pub items: Vec<TradeItem>,
+    pub gold: u64,
+}
+
+impl TradeOffer {
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty() && self.gold == 0
+    }
+
+    /// Checks that a hero owning `owned` (see `tradable_items`) and `gold`
+    /// can hand the offer over.
+    pub fn check(&self, owned: &[TradeItem], gold: u64) -> Result<(), String> {
+        if gold < self.gold {
+            return Err(format!("{} gold short", self.gold - gold));
+        }
+        let mut counts: BTreeMap<&TradeItem, usize> = BTreeMap::new();
+        for item in owned {
+            *counts.entry(item).or_default() += 1;
+        }
+        for item in &self.items {
+            match counts.get_mut(item) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => return Err(format!("'{}' is not in the bag", item.name)),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A trade proposed by a player, waiting for the answer of the other one.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TradeProposal {
+    /// Set by the server
+    pub id: u64,
+    pub from_player: String,
+    pub from_hero: String,
+    pub to_player: String,
+    pub to_hero: String,
+    /// Handed over by `from_hero`
+    pub give: TradeOffer,
+    /// Handed over by `to_hero`
+    pub ask: TradeOffer,
+}
+
+impl TradeProposal {
+    /// A trade moves something, between two different heroes.
+    pub fn is_valid(&self) -> bool {
+        self.from_hero != self.to_hero && !(self.give.is_empty() && self.ask.is_empty())
+    }
+}
+
+/// Items of `character` that can be traded: its unequipped equipment and its
+/// personal consumables, sorted.
+pub fn tradable_items(character: &Character) -> Vec<TradeItem> {
+    let mut items: Vec<TradeItem> = character
+        .inventory
+        .equipments
+        .values()
+        .flatten()
+        .filter(|e| !e.is_equipped)
+        .map(|e| TradeItem {
+            kind: TradeItemKind::Equipment,
+            name: e.unique_name.clone(),
+        })
+        .chain(character.inventory.consumables.iter().map(|c| TradeItem {
+            kind: TradeItemKind::Consumable,
+            name: c.name.clone(),
+        }))
+        .collect();
+    items.sort();
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(kind: TradeItemKind, name: &str) -> TradeItem {
+        TradeItem {
+            kind,
+            name: name.to_owned(),
+        }
+    }
+
+    #[test]
+    fn an_offer_needs_every_item_and_the_gold() {
+        let owned = vec![
+            item(TradeItemKind::Consumable, "Common potion"),
+            item(TradeItemKind::Equipment, "medium belt"),
+        ];
+        let mut offer = TradeOffer {
+            items: vec![item(TradeItemKind::Consumable, "Common potion")],
+            gold: 30,
+        };
+        assert_eq!(offer.check(&owned, 30), Ok(()));
+        assert!(offer.check(&owned, 29).is_err());
+
+        // Each copy is counted, and an equipment isn't a consumable
+        offer
+            .items
+            .push(item(TradeItemKind::Consumable, "Common potion"));
+        assert!(offer.check(&owned, 30).is_err());
+        offer.items = vec![item(TradeItemKind::Consumable, "medium belt")];
+        assert!(offer.check(&owned, 30).is_err());
+    }
+
+    #[test]
+    fn a_trade_moves_something_between_two_heroes() {
+        let mut proposal = TradeProposal {
+            from_hero: "Elara".to_owned(),
+            to_hero: "Thalia".to_owned(),
+            ..Default::default()
+        };
+        assert!(!proposal.is_valid());
+        proposal.ask.gold = 10;
+        assert!(proposal.is_valid());
+        proposal.to_hero = "Elara".to_owned();
+        assert!(!proposal.is_valid());
+    }
+}
@@ -0,0 +1,335 @@
+//! Headless fight simulator used to check scenario balance.
+//!
+//! Fights are played through the same `GameManager::launch_attack` path as real
+//! games, with the heroes driven by a `SimulationPolicy` instead of players.
+//! Exposed to the admin page through `admin_simulate_scenario` and usable from
+//! tests through `simulate_fights`.
+
+#[cfg(feature = "server")]
+use anyhow::{Result, bail};
+use lib_rpg::character_mod::attack_type::AtksInfo;
+#[cfg(feature = "server")]
+use lib_rpg::server::{data_manager::DataManager, game_manager::GameManager};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Upper bound of fights per simulation request.
+pub const MAX_SIMULATED_FIGHTS: usize = 200;
+/// Actions after which a fight is stopped and counted as a timeout.
+pub const MAX_ACTIONS_PER_FIGHT: usize = 500;
+
+/// How the simulated heroes pick their attack.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SimulationPolicy {
+    /// Tries every launchable attack on a copy of the fight and keeps the one
+    /// dealing the most damage to bosses plus healing to heroes.
+    Greedy,
+    /// Hero name → attack names by priority. The first launchable one is used,
+    /// falling back to the first launchable attack of the hero.
+    Scripted(BTreeMap<String, Vec<String>>),
+}
+
+/// One simulation run: `nb_fights` fights of `scenario_name` with `heroes`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulationRequest {
+    pub scenario_name: String,
+    /// Hero names (`db_full_name`); a name may appear several times
+    pub heroes: Vec<String>,
+    pub nb_fights: usize,
+    pub policy: SimulationPolicy,
+}
+
+/// Results of one hero over all the simulated fights.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulatedHeroReport {
+    pub id_name: String,
+    pub name: String,
+    /// Number of times the hero fell, over all fights
+    pub deaths: usize,
+    /// Attack uses and damage/heal totals summed over all fights
+    pub atks_info: Vec<AtksInfo>,
+}
+
+/// Aggregated result of a simulation run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulationReport {
+    pub scenario_name: String,
+    pub nb_fights: usize,
+    pub wins: usize,
+    /// Fights stopped after `MAX_ACTIONS_PER_FIGHT` actions, counted as lost
+    pub timeouts: usize,
+    /// Turns summed over all fights
+    pub total_turns: usize,
+    pub heroes: Vec<SimulatedHeroReport>,
+}
+
+impl SimulationReport {
+    pub fn win_rate(&self) -> f64 {
+        if self.nb_fights == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.nb_fights as f64
+        }
+    }
+
+    pub fn avg_turns(&self) -> f64 {
+        if self.nb_fights == 0 {
+            0.0
+        } else {
+            self.total_turns as f64 / self.nb_fights as f64
+        }
+    }
+}
+
+/// Outcome of one simulated fight.
+#[cfg(feature = "server")]
+struct FightOutcome {
+    won: bool,
+    timed_out: bool,
+    turns: usize,
+    /// id_name → (deaths, attack stats)
+    heroes: BTreeMap<String, (usize, Vec<AtksInfo>)>,
+}
+
+/// Damage dealt to bosses plus healing given to heroes by the last attack.
+#[cfg(feature = "server")]
+fn last_atk_score(gm: &GameManager) -> i64 {
+    use lib_rpg::common::constants::stats_const::HP;
+    gm.game_state
+        .last_result_atk
+        .new_game_atk_effects
+        .iter()
+        .filter(|gae| {
+            gae.processed_effect_param
+                .input_effect_param
+                .buffer
+                .stats_name
+                == HP
+        })
+        .map(|gae| {
+            let outcome = &gae.effect_outcome;
+            let amount = i64::from(outcome.real_amount_tx);
+            let on_boss = gm
+                .pm
+                .active_bosses
+                .iter()
+                .any(|b| b.id_name == outcome.target_id_name);
+            match (on_boss, amount < 0) {
+                (true, true) => -amount,
+                (false, false) => amount,
+                _ => 0,
+            }
+        })
+        .sum()
+}
+
+/// Attack the current hero launches, `None` when it has no launchable attack.
+#[cfg(feature = "server")]
+fn choose_attack(gm: &GameManager, policy: &SimulationPolicy) -> Option<String> {
+    let hero = &gm.pm.current_player;
+    let launchable: Vec<&str> = hero
+        .character_rounds_info
+        .launchable_atks
+        .iter()
+        .map(|atk| atk.name.as_str())
+        .collect();
+    match policy {
+        SimulationPolicy::Scripted(scripts) => scripts
+            .get(&hero.db_full_name)
+            .and_then(|script| script.iter().find(|atk| launchable.contains(&atk.as_str())))
+            .cloned()
+            .or_else(|| launchable.first().map(|atk| atk.to_string())),
+        SimulationPolicy::Greedy => launchable
+            .iter()
+            .map(|atk| {
+                let mut trial = gm.clone();
+                trial.pm.set_targeted_characters(&hero.id_name, atk);
+                let _ = trial.launch_attack(Some(atk));
+                (last_atk_score(&trial), *atk)
+            })
+            // max_by_key keeps the last maximum; reverse so ties go to the first attack
+            .rev()
+            .max_by_key(|(score, _)| *score)
+            .map(|(_, atk)| atk.to_owned()),
+    }
+}
+
+/// Plays one fight until the scenario ends or `MAX_ACTIONS_PER_FIGHT` is reached.
+#[cfg(feature = "server")]
+fn simulate_fight(dm: &DataManager, request: &SimulationRequest) -> Result<FightOutcome> {
+    use lib_rpg::server::{core_game_data::CoreGameData, game_state::GameStatus};
+
+    let Some(scenario) = dm
+        .all_scenarios
+        .iter()
+        .find(|s| s.name == request.scenario_name)
+    else {
+        bail!("Unknown scenario '{}'", request.scenario_name);
+    };
+    let mut core_game_data =
+        CoreGameData::new_with_scenarios(dm, "balance-simulation", vec![scenario.clone()])?;
+    let gm = &mut core_game_data.game_manager;
+    for name in &request.heroes {
+        let Some(hero) = dm.all_heroes.iter().find(|h| h.db_full_name == *name) else {
+            bail!("Unknown hero '{name}'");
+        };
+        let mut hero = hero.clone();
+        // same id_name scheme as add_character_on_server_data
+        hero.id_name = format!(
+            "{}_#{}",
+            name,
+            1 + gm.pm.get_nb_of_active_heroes_by_name(name)
+        );
+        gm.pm.active_heroes.push(hero);
+    }
+    gm.start_game();
+
+    let is_dead = |gm: &GameManager, id_name: &str| {
+        gm.pm
+            .active_heroes
+            .iter()
+            .find(|h| h.id_name == id_name)
+            .map(|h| h.stats.is_dead().unwrap_or(false))
+            .unwrap_or(false)
+    };
+    let mut heroes: BTreeMap<String, (usize, Vec<AtksInfo>)> = gm
+        .pm
+        .active_heroes
+        .iter()
+        .map(|h| (h.id_name.clone(), (0, Vec::new())))
+        .collect();
+    let mut dead: Vec<String> = Vec::new();
+    let mut nb_actions = 0;
+    while !matches!(
+        gm.game_state.status,
+        GameStatus::EndOfScenario | GameStatus::EndOfGame
+    ) && nb_actions < MAX_ACTIONS_PER_FIGHT
+    {
+        if gm.is_round_auto() {
+            let _ = gm.launch_attack(None);
+        } else {
+            let atk = choose_attack(gm, &request.policy);
+            if let Some(atk) = &atk {
+                let launcher = gm.pm.current_player.id_name.clone();
+                gm.pm.set_targeted_characters(&launcher, atk);
+            }
+            let _ = gm.launch_attack(atk.as_deref());
+        }
+        nb_actions += 1;
+        // count each fall once, a revived hero can fall again
+        for (id_name, (deaths, _)) in heroes.iter_mut() {
+            let now_dead = is_dead(gm, id_name);
+            let was_dead = dead.contains(id_name);
+            if now_dead && !was_dead {
+                *deaths += 1;
+                dead.push(id_name.clone());
+            } else if !now_dead && was_dead {
+                dead.retain(|d| d != id_name);
+            }
+        }
+    }
+
+    for (id_name, (_, atks_info)) in heroes.iter_mut() {
+        if let Some(stats) = gm.game_state.stats_in_game.get(id_name) {
+            *atks_info = stats.all_atk_info.clone();
+        }
+    }
+    let timed_out = nb_actions >= MAX_ACTIONS_PER_FIGHT;
+    let party_wiped = gm
+        .pm
+        .active_heroes
+        .iter()
+        .all(|h| h.stats.is_dead().unwrap_or(false));
+    Ok(FightOutcome {
+        won: !timed_out && !party_wiped,
+        timed_out,
+        turns: gm.game_state.current_turn_nb,
+        heroes,
+    })
+}
+
+/// Plays `request.nb_fights` fights (capped at `MAX_SIMULATED_FIGHTS`) and aggregates them.
+/// Meant for the admin simulator and for balance regression tests.
+#[cfg(feature = "server")]
+pub fn simulate_fights(dm: &DataManager, request: &SimulationRequest) -> Result<SimulationReport> {
+    if request.heroes.is_empty() {
+        bail!("The party has no hero");
+    }
+    let mut report = SimulationReport {
+        scenario_name: request.scenario_name.clone(),
+        nb_fights: request.nb_fights.min(MAX_SIMULATED_FIGHTS),
+        wins: 0,
+        timeouts: 0,
+        total_turns: 0,
+        heroes: Vec::new(),
+    };
+    for _ in 0..report.nb_fights {
+        let outcome = simulate_fight(dm, request)?;
+        report.wins += usize::from(outcome.won);
+        report.timeouts += usize::from(outcome.timed_out);
+        report.total_turns += outcome.turns;
+        for (id_name, (deaths, atks_info)) in outcome.heroes {
+            let hero = match report.heroes.iter_mut().find(|h| h.id_name == id_name) {
+                Some(hero) => hero,
+                None => {
+                    report.heroes.push(SimulatedHeroReport {
+                        name: id_name.split("_#").next().unwrap_or(&id_name).to_owned(),
+                        id_name,
+                        deaths: 0,
+                        atks_info: Vec::new(),
+                    });
+                    report.heroes.last_mut().expect("just pushed")
+                }
+            };
+            hero.deaths += deaths;
+            crate::widgets::charts::merge_atks_info(&mut hero.atks_info, atks_info);
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+    use crate::common::OFFLINE_PATH;
+
+    fn pokemon_request(policy: SimulationPolicy) -> SimulationRequest {
+        SimulationRequest {
+            scenario_name: "Patrouille Rattata".to_owned(),
+            heroes: vec![
+                "Bulbasaur".to_owned(),
+                "Charmander".to_owned(),
+                "Squirtle".to_owned(),
+            ],
+            nb_fights: 5,
+            policy,
+        }
+    }
+
+    #[test]
+    fn first_pokemon_stage_is_won_by_a_full_party() {
+        let dm = DataManager::try_new(OFFLINE_PATH).unwrap();
+        let report = simulate_fights(&dm, &pokemon_request(SimulationPolicy::Greedy)).unwrap();
+        assert_eq!(report.nb_fights, 5);
+        assert_eq!(report.timeouts, 0);
+        assert!(report.win_rate() >= 0.8, "win rate {}", report.win_rate());
+        assert_eq!(report.heroes.len(), 3);
+        assert!(
+            report
+                .heroes
+                .iter()
+                .any(|h| h.atks_info.iter().any(|a| a.nb_use > 0))
+        );
+    }
+
+    #[test]
+    fn simulation_rejects_unknown_scenario_and_empty_party() {
+        let dm = DataManager::try_new(OFFLINE_PATH).unwrap();
+        let mut request = pokemon_request(SimulationPolicy::Scripted(BTreeMap::new()));
+        request.scenario_name = "No such scenario".to_owned();
+        assert!(simulate_fights(&dm, &request).is_err());
+        let mut request = pokemon_request(SimulationPolicy::Greedy);
+        request.heroes.clear();
+        assert!(simulate_fights(&dm, &request).is_err());
+    }
+}
@@ -1,2 +1,3 @@
+pub mod balance_simulator;
 mod pokemon_data_tests;
 pub mod server_file_utils;
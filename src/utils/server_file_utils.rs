@@ -9,6 +9,8 @@ use dioxus::prelude::*;
 use lib_rpg::utils::list_dirs_in_dir;
 use serde::{Deserialize, Serialize};
 
+use crate::difficulty::GameSettings;
+
 /// Lightweight metadata for a saved game slot, used by the slot-picker UI.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SaveSlotInfo {
@@ -28,6 +30,8 @@ pub struct SaveSlotInfo {
     pub is_single_player: bool,
     /// Number of players required
     pub players_nb: i64,
+    /// Difficulty and New Game Plus cycle
+    pub settings: GameSettings,
 }
 
 #[post("/api/save")]
@@ -87,7 +91,7 @@ pub async fn get_max_saves() -> Result<usize, ServerFnError> {
 /// At most `MAX_SAVES` slots are returned.
 #[post("/api/get_save_slots")]
 pub async fn get_save_slots(player_name: String) -> Result<Vec<SaveSlotInfo>, ServerFnError> {
-    use crate::common::SAVED_DATA;
+    use crate::{common::SAVED_DATA, difficulty::SAVED_GAME_SETTINGS};
     use lib_rpg::common::constants::{
         core_game_data_const::SAVED_CORE_GAME_DATA, paths_const::GAMES_DIR,
     };
@@ -151,6 +155,11 @@ pub async fn get_save_slots(player_name: String) -> Result<Vec<SaveSlotInfo>, Se
                         (scenario, level, universe, is_sp, nb)
                     })
                     .unwrap_or_default();
+            // Saves made before difficulty levels existed have no settings file
+            let settings = fs::read_to_string(game_path.join(SAVED_GAME_SETTINGS))
+                .ok()
+                .and_then(|s| serde_json::from_str::<GameSettings>(&s).ok())
+                .unwrap_or_default();
 
             SaveSlotInfo {
                 path: game_path,
@@ -161,6 +170,7 @@ pub async fn get_save_slots(player_name: String) -> Result<Vec<SaveSlotInfo>, Se
                 universe,
                 is_single_player,
                 players_nb,
+                settings,
             }
         })
         .collect();
@@ -176,6 +186,7 @@ pub async fn get_save_slots(player_name: String) -> Result<Vec<SaveSlotInfo>, Se
             universe: String::new(),
             is_single_player: false,
             players_nb: 0,
+            settings: GameSettings::default(),
         });
     }
 
@@ -1,4 +1,4 @@
-use lib_rpg::server::server_manager::ServerManager;
+use lib_rpg::server::server_manager::{ServerData, ServerManager};
 use once_cell::sync::Lazy;
 use std::sync::{Arc, Mutex, MutexGuard};
 
@@ -17,3 +17,42 @@ pub fn lock_server_manager() -> MutexGuard<'static, ServerManager> {
         .lock()
         .unwrap_or_else(|poisoned| poisoned.into_inner())
 }
+
+/// Account behind a `players_info` key: single-player games add their extra
+/// heroes under synthetic `"{player}__sp{N}"` keys.
+pub fn player_account(player_key: &str) -> &str {
+    player_key.split("__sp").next().unwrap_or(player_key)
+}
+
+/// Accounts playing the game, each once.
+pub fn player_accounts(server_data: &ServerData) -> Vec<String> {
+    server_data
+        .players_data
+        .players_info
+        .keys()
+        .map(|key| player_account(key).to_owned())
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Player playing the hero `id_name`, if any.
+pub fn player_of(server_data: &ServerData, id_name: &str) -> Option<String> {
+    server_data
+        .players_data
+        .players_info
+        .iter()
+        .find(|(_, info)| info.character_id_names.iter().any(|id| id == id_name))
+        .map(|(key, _)| player_account(key).to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extra_single_player_heroes_belong_to_their_player() {
+        assert_eq!(player_account("alice"), "alice");
+        assert_eq!(player_account("alice__sp2"), "alice");
+    }
+}
@@ -1,9 +1,45 @@
+use crate::achievements::AchievementDef;
+#[cfg(feature = "server")]
+use crate::combat_animation::ENEMY_ATK_INTERVAL_MS;
+#[cfg(feature = "server")]
+use crate::combat_log;
 #[cfg(feature = "server")]
 use crate::common::DATA_MANAGER;
+use crate::crafting::MaterialStash;
+#[cfg(feature = "server")]
+use crate::daily_challenge::ChallengeModifier;
+use crate::difficulty::{Difficulty, GameSettings};
+#[cfg(feature = "server")]
+use crate::quests::QuestLog;
+use crate::quests::QuestsUpdate;
+use crate::server_messages::LogMessages;
+#[cfg(feature = "server")]
+use crate::server_messages::ServerMessage;
+use crate::trading::{TradeOffer, TradeProposal};
 #[cfg(feature = "server")]
 use crate::utils::server_file_utils;
 #[cfg(feature = "server")]
-use crate::websocket_handler::common_event::lock_server_manager;
+use crate::websocket_handler::common_event::{self, lock_server_manager};
+#[cfg(feature = "server")]
+use crate::websocket_handler::event_achievements;
+#[cfg(feature = "server")]
+use crate::websocket_handler::event_arena;
+#[cfg(feature = "server")]
+use crate::websocket_handler::event_crafting;
+#[cfg(feature = "server")]
+use crate::websocket_handler::event_daily_challenge;
+#[cfg(feature = "server")]
+use crate::websocket_handler::event_difficulty;
+#[cfg(feature = "server")]
+use crate::websocket_handler::event_endless;
+#[cfg(feature = "server")]
+use crate::websocket_handler::event_logs;
+#[cfg(feature = "server")]
+use crate::websocket_handler::event_profile;
+#[cfg(feature = "server")]
+use crate::websocket_handler::event_quests;
+#[cfg(feature = "server")]
+use crate::websocket_handler::event_trading;
 #[cfg(feature = "server")]
 use anyhow::Result;
 #[cfg(feature = "server")]
@@ -70,11 +106,25 @@ type SharedClients = Arc<Mutex<ClientsMap>>;
 #[cfg(feature = "server")]
 static CLIENTS: Lazy<SharedClients> = Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
 
+/// What a new game is made of, picked when it is created.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum GameMode {
+    /// The stages of the universe chosen in the lobby
+    #[default]
+    Story,
+    /// Today's challenge, see `daily_challenge.rs`
+    DailyChallenge,
+    /// Procedural floors until the party falls, see `endless.rs`
+    Endless,
+    /// A match against another player's party, see `arena.rs`
+    Arena,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum ClientEvent {
     LoginAllSessions(String, i64, String), // username, sql-id, device_token
     RequestLogOut(String),                 // `String`: username
-    InitializeGame(String, String, String, bool), // server_name, player_name, universe, is_single_player
+    InitializeGame(String, String, String, bool, GameMode), // server_name, player_name, universe, is_single_player, game_mode
     AddCharacterOnServerData(String, String, String), // `String`: server_name, `String`: player_name, `String`: character_name
     RemoveCharacterOnServerData(String, String),      // `String`: server_name, `String`: player_key
     StartGame(String),                                // `String`: server_name
@@ -102,14 +152,22 @@ pub enum ClientEvent {
     UseOverworldConsumable(String, String, String, bool),
     BuyItem(String, String, String, String), // server_name, character_id_name, item_name, item_kind ("Equipment"|"Consumable")
     SellItem(String, String, String, String), // server_name, character_id_name, item_name_or_unique_name, item_kind
+    CraftItem(String, String, String),        // server_name, character_id_name, recipe file stem
+    ProposeTrade(String, TradeProposal), // server_name, proposal (id and players set by the server)
+    AnswerTrade(String, u64, bool),      // server_name, trade id, accept
+    TransferItems(String, String, String, TradeOffer), // server_name, from hero, to hero, offer (single-player)
     MovePlayer(String, String, Direction, String), // server_name, player_name, direction, lang ("en"|"fr")
     Interact(String, String, String),              // server_name, player_name, lang ("en"|"fr")
     DismissDialog(String, String),                 // server_name, player_name
+    ChooseDialogue(String, String, usize, String), // server_name, player_name, choice index among those offered, lang
     EnterOverworld(String, String),                // server_name, map_id
     ExitOverworld(String),                         // server_name
     RequestUnlockTalent(String, String, String),   // server_name, character_id_name, talent_id
     RequestRespecTalents(String, String),          // server_name, character_id_name
     RequestMarkTalentSeen(String, String),         // server_name, character_id_name
+    PickEndlessReward(String, String, usize),      // server_name, player_name, reward index
+    SetDifficulty(String, String, Difficulty),     // server_name, player_name, difficulty
+    StartNewGamePlus(String, String),              // server_name, player_name
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -126,6 +184,12 @@ pub enum ServerEvent {
     OverworldEntered(String), // map_id — lightweight trigger; no complex types
     UpdateOverworld(Box<OverworldState>), // Lightweight update for plain movement steps that don't touch combat state
     UpdateCombat(Box<CombatUpdate>), // Lightweight combat-only update sent after an ordinary attack
+    AchievementsUnlocked(Vec<AchievementDef>), // sent to the player who unlocked them only
+    UpdateGameSettings(GameSettings, bool), // difficulty of the game, whether New Game Plus can start
+    UpdateMaterials(MaterialStash),         // materials owned by the party, for crafting
+    UpdateTrades(Vec<TradeProposal>),       // trades waiting for an answer
+    UpdateQuests(Box<QuestsUpdate>),        // quest log and dialogue choices of the party
+    UpdateLogMessages(Box<LogMessages>),    // messages of the lines the server wrote in the log
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -222,15 +286,19 @@ pub async fn on_rcv_client_event(
                             }
                             Ok(ClientEvent::StartGame(server_name)) => {
                                 tracing::info!("{} is starting a new game", server_name);
-                                start_new_game_by_player(&server_name, false).await;
+                                if event_daily_challenge::claim_start(&server_name).await
+                                    && event_endless::prepare_first_floors(&server_name)
+                                {
+                                    start_new_game_by_player(&server_name, false).await;
+                                }
                             }
                             Ok(ClientEvent::SetUniverse(server_name, universe)) => {
                                 tracing::info!("Setting universe '{}' for server {}", universe, server_name);
                                 set_universe_on_server_data(&server_name, &universe);
                             }
-                            Ok(ClientEvent::InitializeGame(server_name, player_name, universe, is_single_player)) => {
-                                tracing::info!("{} is initializing a new game (universe: {}, single: {})", server_name, universe, is_single_player);
-                                let Ok(_) = init_new_game_by_player(&server_name, client_id, &player_name, &universe, is_single_player).await else {
+                            Ok(ClientEvent::InitializeGame(server_name, player_name, universe, is_single_player, game_mode)) => {
+                                tracing::info!("{} is initializing a new game (universe: {}, single: {}, mode: {:?})", server_name, universe, is_single_player, game_mode);
+                                let Ok(_) = init_new_game_by_player(&server_name, client_id, &player_name, &universe, is_single_player, game_mode).await else {
                                     tracing::error!("Failed to initialize game for server {}, player {}", server_name, player_name);
                                     return;
                                 };
@@ -270,8 +338,14 @@ pub async fn on_rcv_client_event(
                                 update_ongoing_games_list_display(client_id).await;
                             }
                             Ok(ClientEvent::ReplayGame(server_name)) => {
-                                tracing::info!("Client {} requested replay game", client_id);
-                                process_replay_game(&server_name, client_id).await;
+                                if event_endless::is_endless(&server_name) {
+                                    tracing::warn!("An endless run can't be replayed (server {})", server_name);
+                                } else if event_arena::is_arena(&server_name) {
+                                    tracing::warn!("An arena match can't be replayed, only watched again (server {})", server_name);
+                                } else {
+                                    tracing::info!("Client {} requested replay game", client_id);
+                                    process_replay_game(&server_name, client_id).await;
+                                }
                             }
                             Ok(ClientEvent::DisconnectFromServerData(server_name, player_name)) => {
                                 tracing::info!("Client {} requested disconnection from server-data {}", client_id, server_name);
@@ -294,7 +368,11 @@ pub async fn on_rcv_client_event(
                                 }
                             }
                             Ok(ClientEvent::SaveGame(server_name, player_name)) => {
-                                if client_can_act(&server_name, client_id) {
+                                if event_endless::is_endless(&server_name) {
+                                    tracing::warn!("An endless run can't be saved (server {})", server_name);
+                                } else if event_arena::is_arena(&server_name) {
+                                    tracing::warn!("An arena match can't be saved (server {})", server_name);
+                                } else if client_can_act(&server_name, client_id) {
                                     tracing::info!("Client {} requested save game by {}", client_id, player_name);
                                     process_save_game(&server_name, &player_name).await;
                                 } else {
@@ -327,7 +405,11 @@ pub async fn on_rcv_client_event(
                                 }
                             }
                             Ok(ClientEvent::LoadNextScenario(server_name, auto_save)) => {
-                                if client_can_act(&server_name, client_id) {
+                                if event_endless::is_endless(&server_name) {
+                                    tracing::warn!("An endless run goes down by picking a reward (server {})", server_name);
+                                } else if event_arena::is_arena(&server_name) {
+                                    tracing::warn!("An arena match has a single scenario (server {})", server_name);
+                                } else if client_can_act(&server_name, client_id) {
                                     tracing::info!("Client {} requested to load next scenario for server {} (auto_save={auto_save})", client_id, server_name);
                                     let _ = process_load_next_scenario(&server_name, auto_save).await;
                                 } else {
@@ -344,7 +426,9 @@ pub async fn on_rcv_client_event(
                                 }
                             }
                             Ok(ClientEvent::UsePotion(server_name, player_name, potion_name, target_id_name)) => {
-                                if client_can_act(&server_name, client_id) {
+                                if event_daily_challenge::forbids(&server_name, ChallengeModifier::NoPotions) {
+                                    tracing::warn!("Potions are forbidden by the daily challenge of server {}", server_name);
+                                } else if client_can_act(&server_name, client_id) {
                                     tracing::info!("Player {} using potion {} on target {} on server {}", player_name, potion_name, target_id_name, server_name);
                                     use_potion_handler(&server_name, &player_name, &potion_name, &target_id_name);
                                     // Using a potion counts as the turn action — advance the turn
@@ -355,7 +439,9 @@ pub async fn on_rcv_client_event(
                                 }
                             }
                             Ok(ClientEvent::UsePartyPotion(server_name, player_name, potion_name, target_id_name)) => {
-                                if client_can_act(&server_name, client_id) {
+                                if event_daily_challenge::forbids(&server_name, ChallengeModifier::NoPotions) {
+                                    tracing::warn!("Potions are forbidden by the daily challenge of server {}", server_name);
+                                } else if client_can_act(&server_name, client_id) {
                                     tracing::info!("Player {} using party potion {} on target {} on server {}", player_name, potion_name, target_id_name, server_name);
                                     use_party_potion_handler(&server_name, &player_name, &potion_name, &target_id_name);
                                     update_core_game_data_after_atk(&server_name, None, tx_server.clone()).await;
@@ -365,7 +451,9 @@ pub async fn on_rcv_client_event(
                                 }
                             }
                             Ok(ClientEvent::UseOverworldConsumable(server_name, hero_id_name, consumable_name, is_party)) => {
-                                if client_can_act(&server_name, client_id) {
+                                if event_daily_challenge::forbids(&server_name, ChallengeModifier::NoPotions) {
+                                    tracing::warn!("Potions are forbidden by the daily challenge of server {}", server_name);
+                                } else if client_can_act(&server_name, client_id) {
                                     tracing::info!("Hero {} using overworld consumable {} (party={}) on server {}", hero_id_name, consumable_name, is_party, server_name);
                                     use_overworld_consumable_handler(&server_name, &hero_id_name, &consumable_name, is_party);
                                     update_clients_server_data(&server_name);
@@ -391,6 +479,42 @@ pub async fn on_rcv_client_event(
                                     tracing::warn!("Client {} is not authorized to act on server {} (view-only)", client_id, server_name);
                                 }
                             }
+                            Ok(ClientEvent::CraftItem(server_name, character_id_name, recipe_stem)) => {
+                                if client_can_act(&server_name, client_id) {
+                                    tracing::info!("Character {} crafting '{}' on server {}", character_id_name, recipe_stem, server_name);
+                                    event_crafting::craft_item_handler(&server_name, &character_id_name, &recipe_stem);
+                                    update_clients_server_data(&server_name);
+                                } else {
+                                    tracing::warn!("Client {} is not authorized to act on server {} (view-only)", client_id, server_name);
+                                }
+                            }
+                            Ok(ClientEvent::ProposeTrade(server_name, proposal)) => {
+                                if let Some(player_name) = client_acting_player(&server_name, client_id) {
+                                    if event_trading::propose_trade(&server_name, &player_name, proposal) {
+                                        update_clients_server_data(&server_name);
+                                    }
+                                } else {
+                                    tracing::warn!("Client {} is not authorized to act on server {} (view-only)", client_id, server_name);
+                                }
+                            }
+                            Ok(ClientEvent::AnswerTrade(server_name, trade_id, accept)) => {
+                                if let Some(player_name) = client_acting_player(&server_name, client_id) {
+                                    if event_trading::answer_trade(&server_name, &player_name, trade_id, accept) {
+                                        update_clients_server_data(&server_name);
+                                    }
+                                } else {
+                                    tracing::warn!("Client {} is not authorized to act on server {} (view-only)", client_id, server_name);
+                                }
+                            }
+                            Ok(ClientEvent::TransferItems(server_name, from_hero, to_hero, offer)) => {
+                                if let Some(player_name) = client_acting_player(&server_name, client_id) {
+                                    if event_trading::transfer_items(&server_name, &player_name, &from_hero, &to_hero, &offer) {
+                                        update_clients_server_data(&server_name);
+                                    }
+                                } else {
+                                    tracing::warn!("Client {} is not authorized to act on server {} (view-only)", client_id, server_name);
+                                }
+                            }
                             Ok(ClientEvent::MovePlayer(server_name, player_name, dir, lang)) => {
                                 tracing::debug!("Player {} moving {:?} on server {}", player_name, dir, server_name);
                                 overworld_move_handler(&server_name, &player_name, dir, &lang);
@@ -403,8 +527,16 @@ pub async fn on_rcv_client_event(
                                 tracing::debug!("Player {} dismissing dialog on server {}", player_name, server_name);
                                 overworld_dismiss_dialog_handler(&server_name, &player_name);
                             }
+                            Ok(ClientEvent::ChooseDialogue(server_name, player_name, index, lang)) => {
+                                tracing::debug!("Player {} choosing dialogue choice {} on server {}", player_name, index, server_name);
+                                if event_quests::choose(&server_name, &player_name, index, &lang) {
+                                    update_clients_server_data(&server_name);
+                                }
+                            }
                             Ok(ClientEvent::EnterOverworld(server_name, map_id)) => {
-                                if client_can_act(&server_name, client_id) {
+                                if event_endless::is_endless(&server_name) || event_arena::is_arena(&server_name) {
+                                    tracing::warn!("An endless run or an arena match has no overworld (server {})", server_name);
+                                } else if client_can_act(&server_name, client_id) {
                                     tracing::info!("Entering overworld map '{}' on server {}", map_id, server_name);
                                     // Auto-save on returning to (or entering) the overworld so a
                                     // reload resumes here instead of at the last manual save.
@@ -424,7 +556,9 @@ pub async fn on_rcv_client_event(
                                 }
                             }
                             Ok(ClientEvent::RequestUnlockTalent(server_name, character_id_name, talent_id)) => {
-                                if client_can_act(&server_name, client_id) {
+                                if event_daily_challenge::forbids(&server_name, ChallengeModifier::NoTalents) {
+                                    tracing::warn!("Talents are forbidden by the daily challenge of server {}", server_name);
+                                } else if client_can_act(&server_name, client_id) {
                                     tracing::info!("Character {} unlocking talent '{}' on server {}", character_id_name, talent_id, server_name);
                                     request_unlock_talent(&server_name, &character_id_name, &talent_id);
                                 } else {
@@ -447,6 +581,32 @@ pub async fn on_rcv_client_event(
                                     tracing::warn!("Client {} is not authorized to act on server {} (view-only)", client_id, server_name);
                                 }
                             }
+                            Ok(ClientEvent::PickEndlessReward(server_name, player_name, index)) => {
+                                if client_can_act(&server_name, client_id) {
+                                    tracing::info!("Player {} picking endless reward {} on server {}", player_name, index, server_name);
+                                    event_endless::pick_reward(&server_name, &player_name, index).await;
+                                } else {
+                                    tracing::warn!("Client {} is not authorized to act on server {} (view-only)", client_id, server_name);
+                                }
+                            }
+                            Ok(ClientEvent::SetDifficulty(server_name, player_name, difficulty)) => {
+                                if client_can_act(&server_name, client_id) {
+                                    tracing::info!("Player {} setting difficulty {:?} on server {}", player_name, difficulty, server_name);
+                                    if event_difficulty::set_difficulty(&server_name, &player_name, difficulty) {
+                                        update_clients_server_data(&server_name);
+                                    }
+                                } else {
+                                    tracing::warn!("Client {} is not authorized to act on server {} (view-only)", client_id, server_name);
+                                }
+                            }
+                            Ok(ClientEvent::StartNewGamePlus(server_name, player_name)) => {
+                                if client_can_act(&server_name, client_id) {
+                                    tracing::info!("Player {} starting New Game Plus on server {}", player_name, server_name);
+                                    event_difficulty::start_new_game_plus(&server_name, &player_name).await;
+                                } else {
+                                    tracing::warn!("Client {} is not authorized to act on server {} (view-only)", client_id, server_name);
+                                }
+                            }
                             Err(_) => {
                                 // ClientEvent::ConnectionClosed
                                 tracing::info!("Client {} disconnected", client_id);
@@ -496,6 +656,24 @@ fn client_can_act(server_name: &str, client_id: u32) -> bool {
         })
 }
 
+/// The player `client_id` is connected as on `server_name`, if `client_can_act` lets it
+/// act there. Events that act for a player take it from here rather than from their
+/// payload, so that one party member cannot speak for another.
+#[cfg(feature = "server")]
+fn client_acting_player(server_name: &str, client_id: u32) -> Option<String> {
+    if !client_can_act(server_name, client_id) {
+        return None;
+    }
+    let sm = lock_server_manager();
+    let server_data = sm.servers_data.get(server_name)?;
+    server_data
+        .players_data
+        .players_info
+        .iter()
+        .find(|(_, info)| info.player_ids.contains(&client_id))
+        .map(|(player_key, _)| common_event::player_account(player_key).to_owned())
+}
+
 #[cfg(feature = "server")]
 pub fn is_username_connected(name: &str) -> bool {
     lock_server_manager()
@@ -838,6 +1016,7 @@ pub async fn send_disconnection_to_server_manager(client_id: u32) {
                     }
                 }
             }
+            forget_server(&affected_server_name);
             lock_server_manager()
                 .servers_data
                 .remove(&affected_server_name);
@@ -854,6 +1033,22 @@ pub async fn send_disconnection_to_server_manager(client_id: u32) {
     });
 }
 
+/// Drops what is kept on the side of the server data of `server_name`, which is
+/// about to be removed, so that a new game on a server of that name starts
+/// afresh. Not to be called under the server manager lock.
+#[cfg(feature = "server")]
+fn forget_server(server_name: &str) {
+    event_arena::abandon_match(server_name);
+    event_profile::stop_profile_tracking(server_name);
+    event_endless::set_run(server_name, None);
+    event_daily_challenge::set_challenge(server_name, None);
+    event_difficulty::clear_settings(server_name);
+    event_crafting::clear_materials(server_name);
+    event_trading::clear_trades(server_name);
+    event_quests::clear_quests(server_name);
+    event_logs::clear_log_messages(server_name);
+}
+
 #[cfg(feature = "server")]
 pub async fn send_disconnection_to_server_data(
     client_id: u32,
@@ -886,6 +1081,9 @@ pub async fn send_disconnection_to_server_data(
     // send end of game to clients before deleting the ids from the server data, so that the clients can know which game is ending based on the server data they have
     send_end_of_serverdata(server_name, client_id, is_owner_disconnecting);
 
+    if is_owner_disconnecting {
+        forget_server(server_name);
+    }
     // remove from servers data
     let mut sm = lock_server_manager();
     if let Some(server_data) = sm.servers_data.get_mut(server_name) {
@@ -942,7 +1140,9 @@ pub async fn start_new_game_by_player(server_name: &str, is_replay: bool) {
         // start_game only for initialized game the first time
         // not for replay
         // not for loaded
-        if !is_replay && server_data.core_game_data.game_phase == GamePhase::InitGame {
+        let is_new_game =
+            !is_replay && server_data.core_game_data.game_phase == GamePhase::InitGame;
+        if is_new_game {
             server_data.core_game_data.game_manager.start_game();
             // states_scenarios was reset to all-NotStarted when the universe was
             // picked in the lobby (set_universe_on_server_data clears the map).
@@ -961,12 +1161,16 @@ pub async fn start_new_game_by_player(server_name: &str, is_replay: bool) {
             {
                 *state = lib_rpg::server::scenario::ScenarioState::InProgress;
             }
+            log_scenario_start(&mut server_data.core_game_data);
+            event_endless::scale_floor_bosses(server_name, &mut server_data.core_game_data);
+            event_difficulty::scale_new_scenario(server_name, &mut server_data.core_game_data);
         }
 
         // Don't downgrade Overworld → Running for games loaded from a save in overworld mode.
         if server_data.core_game_data.game_phase != GamePhase::Overworld {
             server_data.core_game_data.game_phase = GamePhase::Running;
         }
+        event_profile::start_profile_tracking(server_name, server_data, is_new_game);
         tracing::info!("Game started for server: {}", server_name);
 
         (
@@ -976,8 +1180,12 @@ pub async fn start_new_game_by_player(server_name: &str, is_replay: bool) {
     }; // sm is guaranteed dropped here
 
     // async work happens after the lock is gone
-    save_core_game_data(&core_game_data, SAVED_CORE_GAME_DATA, &server_owner).await;
-    save_core_game_data(&core_game_data, SAVED_CORE_GAME_DATA_REPLAY, &server_owner).await;
+    // An endless run is never saved: permadeath leaves nothing to reload. Nor is
+    // an arena match, fought with a snapshot of the party
+    if !event_endless::is_endless(server_name) && !event_arena::is_arena(server_name) {
+        save_core_game_data(&core_game_data, SAVED_CORE_GAME_DATA, &server_owner).await;
+        save_core_game_data(&core_game_data, SAVED_CORE_GAME_DATA_REPLAY, &server_owner).await;
+    }
 
     update_clients_server_data(server_name);
 }
@@ -989,17 +1197,66 @@ pub async fn init_new_game_by_player(
     player_name: &str,
     universe: &str,
     is_single_player: bool,
+    game_mode: GameMode,
 ) -> Result<()> {
+    use crate::auth_manager::server_fn::{has_started_daily_challenge, todays_challenge};
+    use crate::endless::EndlessRun;
+
+    // A daily challenge is a solo game of today's scenario only, played once a day
+    let challenge = if game_mode == GameMode::DailyChallenge {
+        let Some(challenge) = todays_challenge() else {
+            anyhow::bail!("No scenario to draw a daily challenge from");
+        };
+        if has_started_daily_challenge(player_name, &challenge.date).await? {
+            anyhow::bail!(
+                "{} already played the challenge of {}",
+                player_name,
+                challenge.date
+            );
+        }
+        Some(challenge)
+    } else {
+        None
+    };
+    // An arena match is a solo game of the player's registered party
+    let arena = if game_mode == GameMode::Arena {
+        Some(event_arena::find_match(player_name).await?)
+    } else {
+        None
+    };
+    // The new game replaces the one of the server
+    event_arena::abandon_match(server_name);
+    let universe = match (&challenge, &arena) {
+        (Some(challenge), _) => challenge.universe.as_str(),
+        (None, Some(arena)) => arena.universe.as_str(),
+        (None, None) => universe,
+    };
+    let is_single_player = is_single_player || challenge.is_some() || arena.is_some();
+
     let dm = DATA_MANAGER.lock().unwrap();
     // Filter scenarios by chosen universe (empty = all)
-    let scenarios = if universe.is_empty() {
-        dm.all_scenarios.clone()
-    } else {
-        dm.all_scenarios
+    let scenarios = match &challenge {
+        // Its first scenario is the template of the match, see `set_up_match`
+        None if arena.is_some() => dm
+            .all_scenarios
+            .iter()
+            .filter(|s| universe.is_empty() || s.universe == universe)
+            .take(1)
+            .cloned()
+            .collect(),
+        Some(challenge) => dm
+            .all_scenarios
+            .iter()
+            .filter(|s| s.universe == challenge.universe && s.name == challenge.scenario)
+            .cloned()
+            .collect(),
+        None if universe.is_empty() => dm.all_scenarios.clone(),
+        None => dm
+            .all_scenarios
             .iter()
             .filter(|s| s.universe == universe)
             .cloned()
-            .collect()
+            .collect(),
     };
     // init a new game
     let mut core_game_data = CoreGameData::new_with_scenarios(&dm, server_name, scenarios)?;
@@ -1027,6 +1284,24 @@ pub async fn init_new_game_by_player(
     // add first player
     core_game_data.players_nb = 0;
     add_server_data_with_player(&core_game_data, server_name, id, player_name);
+    event_daily_challenge::set_challenge(server_name, challenge);
+    // Its floors are built when the game starts, in the universe picked in the lobby
+    event_endless::set_run(
+        server_name,
+        (game_mode == GameMode::Endless).then(|| EndlessRun::new(rand::random())),
+    );
+    // Picked in the lobby
+    event_difficulty::set_settings(server_name, GameSettings::default(), None);
+    event_crafting::set_materials(server_name, MaterialStash::new());
+    event_quests::set_log(server_name, QuestLog::default());
+    event_trading::clear_trades(server_name);
+    event_logs::clear_log_messages(server_name);
+    match arena {
+        Some(setup) if !event_arena::set_up_match(server_name, player_name, setup) => {
+            anyhow::bail!("Cannot set up the arena match of {}", player_name);
+        }
+        _ => {}
+    }
     // update for the clients connected to that server
     update_clients_server_data(server_name);
     update_clients_ongoing_games();
@@ -1120,21 +1395,15 @@ pub fn use_potion_handler(
                     target_id_name
                 );
                 let stat_delta: i64 = effects.iter().map(|e| e.real_amount_tx).sum();
-                let msg = if stat_delta != 0 {
-                    format!(
-                        "💊 {} uses {} on {} ({:+} {})",
-                        launcher_id, potion_name, target_id_name, stat_delta, stat_name
-                    )
-                } else {
-                    format!(
-                        "💊 {} uses {} on {}",
-                        launcher_id, potion_name, target_id_name
-                    )
+                let msg = ServerMessage::PotionUsed {
+                    launcher: launcher_id.clone(),
+                    potion: potion_name.to_owned(),
+                    target: target_id_name.to_owned(),
+                    party: false,
+                    amount: stat_delta,
+                    stat: stat_name,
                 };
-                potion_log = Some(LogData {
-                    message: utils::format_string_with_timestamp(&msg),
-                    color: String::new(),
-                });
+                potion_log = Some(event_logs::message_line(server_name, "💊", msg));
                 server_data
                     .core_game_data
                     .game_manager
@@ -1154,9 +1423,17 @@ pub fn use_potion_handler(
         pm.modify_active_character(&launcher_id);
         if let Some(entry) = potion_log {
             // header uses the clean msg (no timestamp); log entry keeps the full timestamped text
-            let header = format!(
-                "💊 {} uses {} on {}",
-                launcher_id, potion_name, target_id_name
+            let header = event_logs::message_banner(
+                server_name,
+                "💊",
+                ServerMessage::PotionUsed {
+                    launcher: launcher_id.clone(),
+                    potion: potion_name.to_owned(),
+                    target: target_id_name.to_owned(),
+                    party: false,
+                    amount: 0,
+                    stat: String::new(),
+                },
             );
             tracing::info!(
                 "use_potion_handler: setting last_action_header={:?}",
@@ -1164,6 +1441,7 @@ pub fn use_potion_handler(
             );
             server_data.core_game_data.game_manager.logs.push(entry);
             server_data.core_game_data.last_action_header = header;
+            event_profile::count_potion_use(server_name);
         } else {
             tracing::warn!("use_potion_handler: potion_log is None, last_action_header NOT set");
         }
@@ -1203,21 +1481,15 @@ pub fn use_party_potion_handler(
                     target_id_name
                 );
                 let stat_delta: i64 = effects.iter().map(|e| e.real_amount_tx).sum();
-                let msg = if stat_delta != 0 {
-                    format!(
-                        "💊 {} uses {} (party) on {} ({:+} {})",
-                        launcher_id, potion_name, target_id_name, stat_delta, stat_name
-                    )
-                } else {
-                    format!(
-                        "💊 {} uses {} (party) on {}",
-                        launcher_id, potion_name, target_id_name
-                    )
+                let msg = ServerMessage::PotionUsed {
+                    launcher: launcher_id.clone(),
+                    potion: potion_name.to_owned(),
+                    target: target_id_name.to_owned(),
+                    party: true,
+                    amount: stat_delta,
+                    stat: stat_name,
                 };
-                potion_log = Some(LogData {
-                    message: utils::format_string_with_timestamp(&msg),
-                    color: String::new(),
-                });
+                potion_log = Some(event_logs::message_line(server_name, "💊", msg));
                 server_data
                     .core_game_data
                     .game_manager
@@ -1234,9 +1506,17 @@ pub fn use_party_potion_handler(
         // Sync current_player back to active_heroes
         pm.modify_active_character(&launcher_id);
         if let Some(entry) = potion_log {
-            let header = format!(
-                "💊 {} uses {} (party) on {}",
-                launcher_id, potion_name, target_id_name
+            let header = event_logs::message_banner(
+                server_name,
+                "💊",
+                ServerMessage::PotionUsed {
+                    launcher: launcher_id.clone(),
+                    potion: potion_name.to_owned(),
+                    target: target_id_name.to_owned(),
+                    party: true,
+                    amount: 0,
+                    stat: String::new(),
+                },
             );
             tracing::info!(
                 "use_party_potion_handler: setting last_action_header={:?}",
@@ -1244,6 +1524,7 @@ pub fn use_party_potion_handler(
             );
             server_data.core_game_data.game_manager.logs.push(entry);
             server_data.core_game_data.last_action_header = header;
+            event_profile::count_potion_use(server_name);
         }
     }
 }
@@ -1306,11 +1587,14 @@ fn use_overworld_consumable_handler(
                 if is_party { "party" } else { "personal" },
                 consumable_name
             );
-            let msg = format!("💊 {} uses {}", hero_id_name, consumable_name);
-            server_data.core_game_data.game_manager.logs.push(LogData {
-                message: utils::format_string_with_timestamp(&msg),
-                color: String::new(),
-            });
+            event_logs::push_message(
+                &mut server_data.core_game_data,
+                "💊",
+                ServerMessage::ConsumableUsed {
+                    hero: hero_id_name.to_owned(),
+                    consumable: consumable_name.to_owned(),
+                },
+            );
             server_data
                 .core_game_data
                 .game_manager
@@ -1374,6 +1658,7 @@ fn overworld_move_handler(server_name: &str, player_name: &str, dir: Direction,
         };
         ow_state.active_dialog.clear();
         ow_state.pending_fight = None;
+        event_quests::end_dialogue(server_name);
         let mut manager = OverworldManager::from_state(ow_state.clone());
         let result = manager.move_player(&hero_id, dir, lang);
         match result {
@@ -1391,6 +1676,7 @@ fn overworld_move_handler(server_name: &str, player_name: &str, dir: Direction,
                 server_data
                     .core_game_data
                     .exit_overworld_to_fight(&scenario_id);
+                event_difficulty::scale_new_scenario(server_name, &mut server_data.core_game_data);
                 PostAction::BroadcastFull
             }
             MoveResult::MapTransition(target_map, spawn) => PostAction::EnterMap(target_map, spawn),
@@ -1412,6 +1698,11 @@ fn overworld_move_handler(server_name: &str, player_name: &str, dir: Direction,
 fn overworld_interact_handler(server_name: &str, player_name: &str, lang: &str) {
     use lib_rpg::server::overworld_manager::{InteractResult, OverworldManager};
 
+    // NPCs with a dialogue tree are handled by the quests
+    if event_quests::interact(server_name, player_name, lang) {
+        update_clients_server_data(server_name);
+        return;
+    }
     let lang = crate::common::lang_from_app_lang(lang);
 
     let fight_scenario = {
@@ -1454,6 +1745,7 @@ fn overworld_interact_handler(server_name: &str, player_name: &str, lang: &str)
             server_data
                 .core_game_data
                 .exit_overworld_to_fight(&scenario_id);
+            event_difficulty::scale_new_scenario(server_name, &mut server_data.core_game_data);
             tracing::info!(
                 "overworld_interact: fight triggered '{}' on server {}",
                 scenario_id,
@@ -1478,6 +1770,7 @@ fn overworld_dismiss_dialog_handler(server_name: &str, player_name: &str) {
         ow_state.active_dialog.clear();
         ow_state.pending_fight = None;
     }
+    event_quests::end_dialogue(server_name);
     drop(sm);
     update_clients_server_data(server_name);
 }
@@ -1534,6 +1827,7 @@ fn overworld_enter_handler(
                 ow.player_positions.insert(hero_id.clone(), pos);
             }
         }
+        event_quests::on_map_entered(server_name, &mut server_data.core_game_data);
         tracing::info!(
             "Entered overworld map '{}' on server {} (owner: {})",
             map_id,
@@ -1576,6 +1870,29 @@ pub fn update_clients_server_data(server_name: &str) {
         server_name,
         &ServerEvent::UpdateServerData(Box::new(server_data.clone())),
     );
+    send_server_event_to_clients(
+        server_name,
+        &ServerEvent::UpdateGameSettings(
+            event_difficulty::settings_of(server_name),
+            event_difficulty::can_start_new_game_plus(server_name, &server_data),
+        ),
+    );
+    send_server_event_to_clients(
+        server_name,
+        &ServerEvent::UpdateMaterials(event_crafting::materials_of(server_name)),
+    );
+    send_server_event_to_clients(
+        server_name,
+        &ServerEvent::UpdateTrades(event_trading::trades_of(server_name)),
+    );
+    send_server_event_to_clients(
+        server_name,
+        &ServerEvent::UpdateQuests(Box::new(event_quests::quests_update_of(server_name))),
+    );
+    send_server_event_to_clients(
+        server_name,
+        &ServerEvent::UpdateLogMessages(Box::new(event_logs::log_messages_of(server_name))),
+    );
 }
 
 #[cfg(feature = "server")]
@@ -1588,6 +1905,11 @@ pub fn update_clients_combat(server_name: &str) {
         return;
     };
     let update = server_data.core_game_data.to_combat_update();
+    // Before the combat update, so that its new lines find their messages
+    send_server_event_to_clients(
+        server_name,
+        &ServerEvent::UpdateLogMessages(Box::new(event_logs::log_messages_of(server_name))),
+    );
     send_server_event_to_clients(server_name, &ServerEvent::UpdateCombat(Box::new(update)));
 }
 
@@ -1652,6 +1974,31 @@ fn send_server_event_to_clients(server_name: &str, server_event: &ServerEvent) {
     );
 }
 
+/// Sends `server_event` to the clients of `username` playing on `server_name` only.
+#[cfg(feature = "server")]
+pub fn send_server_event_to_player(server_name: &str, username: &str, server_event: &ServerEvent) {
+    let Some(player_ids) = get_server_data_by_server_name(server_name)
+        .and_then(|d| d.players_data.players_info.get(username).cloned())
+        .map(|info| info.player_ids)
+    else {
+        tracing::warn!(
+            "send_server_event_to_player: {} is not playing on {}",
+            username,
+            server_name
+        );
+        return;
+    };
+    let clients = CLIENTS.lock().unwrap();
+    for (&other_id, sender) in clients.iter() {
+        if player_ids.contains(&(other_id as u32)) && sender.send(server_event.clone()).is_err() {
+            tracing::error!(
+                "[server] mpsc send to client {} FAILED (channel closed?)",
+                other_id
+            );
+        }
+    }
+}
+
 #[cfg(feature = "server")]
 fn update_clients_ongoing_games() {
     tracing::info!("Updating clients with ongoing games");
@@ -1703,6 +2050,8 @@ pub async fn update_core_game_data_after_atk(
     let mut sm: std::sync::MutexGuard<'_, ServerManager> = lock_server_manager();
     let logs: Vec<LogData>;
     let status_after_atk: GameStatus;
+    let mut scenario_record = None;
+    let mut achievement_credits;
     if let Some(server_data) = sm.servers_data.get_mut(server_name) {
         // launch attack
         // case several ennemy-auto-atk in a row and one atk ended the game, the next atk should not reach.
@@ -1714,10 +2063,28 @@ pub async fn update_core_game_data_after_atk(
             );
             return;
         }
+        let first_atk_log = server_data.core_game_data.game_manager.logs.len();
+        let status_before_atk = server_data
+            .core_game_data
+            .game_manager
+            .game_state
+            .status
+            .clone();
         let _ = server_data
             .core_game_data
             .game_manager
             .launch_attack(selected_atk_name);
+        // Head the attack's lines with its context, for the log filters and export
+        let gm = &mut server_data.core_game_data.game_manager;
+        if gm.logs.len() > first_atk_log {
+            let ra = &mut gm.game_state.last_result_atk;
+            let context = combat_log::AttackContext::from_result(ra);
+            let marker = event_logs::with_log_messages(server_name, |messages| {
+                combat_log::attack_marker(messages, context)
+            });
+            ra.logs_atk.insert(0, marker.clone());
+            gm.logs.insert(first_atk_log, marker);
+        }
         // Clear the consumable header when a real attack was launched so the
         // gameboard banner switches back to the attack banner.
         if selected_atk_name.is_some() {
@@ -1744,6 +2111,43 @@ pub async fn update_core_game_data_after_atk(
             .game_state
             .status
             .clone();
+        achievement_credits = event_achievements::attack_credits(server_data);
+        let is_end =
+            |s: &GameStatus| matches!(s, GameStatus::EndOfScenario | GameStatus::EndOfGame);
+        if is_end(&status_after_atk) && !is_end(&status_before_atk) {
+            let (shares, mut record) =
+                event_profile::take_scenario_record(server_name, server_data);
+            // The one scenario of a daily challenge doesn't complete its universe,
+            // and its result goes to the daily leaderboard only; endless floors
+            // aren't ranked either, nor arena matches, which move the arena ratings
+            let challenge = event_daily_challenge::challenge_of(server_name);
+            let is_endless = event_endless::is_endless(server_name);
+            let arena = event_arena::finish_match(server_name).map(|arena_match| {
+                let logs = &server_data.core_game_data.game_manager.logs;
+                (arena_match, event_arena::match_log(server_name, logs))
+            });
+            if challenge.is_some() || is_endless || arena.is_some() {
+                record.universe_completed = false;
+                record.ranked = false;
+                record.universe_run = None;
+            }
+            // Only the scenarios as they are defined are ranked
+            if !event_difficulty::settings_of(server_name).is_standard() {
+                record.ranked = false;
+                record.universe_run = None;
+            }
+            event_difficulty::scenario_ended(server_name);
+            if record.won && arena.is_none() {
+                event_crafting::collect_materials(server_name, &server_data.core_game_data);
+                event_quests::on_scenario_won(server_name, &mut server_data.core_game_data);
+            }
+            achievement_credits.extend(event_achievements::scenario_end_credits(
+                server_data,
+                record.won,
+                record.universe_completed,
+            ));
+            scenario_record = Some((shares, record, challenge, is_endless, arena));
+        }
         tracing::info!(
             "update_core_game_data_after_atk server={} atk={:?} logs={} last_atk_name={:?} header={:?}",
             server_name,
@@ -1762,6 +2166,40 @@ pub async fn update_core_game_data_after_atk(
 
     drop(sm);
 
+    if let Some((shares, record, challenge, is_endless, arena)) = scenario_record {
+        let players: Vec<String> = shares.iter().map(|share| share.player.clone()).collect();
+        let ended_run = if is_endless {
+            event_endless::on_floor_end(server_name, record.won)
+        } else {
+            None
+        };
+        tokio::spawn(async move {
+            use crate::auth_manager::server_fn::{
+                save_arena_result, save_daily_challenge_result, save_endless_run,
+                save_leaderboard_entries, save_scenario_record,
+            };
+            save_scenario_record(&shares, &record).await;
+            match challenge {
+                Some(challenge) => save_daily_challenge_result(&players, &challenge, &record).await,
+                None if is_endless || arena.is_some() => {}
+                None => save_leaderboard_entries(&players, &record).await,
+            }
+            if let Some((arena_match, logs)) = arena {
+                save_arena_result(&arena_match, record.won, record.turns, &logs).await;
+            }
+            if let Some(run) = ended_run {
+                let heroes = record
+                    .heroes
+                    .iter()
+                    .map(|(hero, _, _)| hero.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                save_endless_run(&players, &run.universe, run.floors_cleared, &heroes).await;
+            }
+        });
+    }
+    event_achievements::spawn_achievement_credits(server_name, achievement_credits);
+
     // update clients
     update_clients_end_of_atk_animation(server_name, true);
     // An attack that ends the scenario/game also changes end_of_scenario/game_phase
@@ -1792,7 +2230,7 @@ pub async fn process_ennemy_atk(server_name: &str, tx: mpsc::UnboundedSender<Ser
         tokio::spawn(async move {
             let mut i = 0;
             while i < nb_in_a_row {
-                sleep(std::time::Duration::from_millis(3000)).await;
+                sleep(std::time::Duration::from_millis(ENEMY_ATK_INTERVAL_MS)).await;
                 let _ = tx.send(ServerOwnEvent::AutoAtkIsDone(server_name.clone()));
                 tracing::info!("process_ennemy_atk in a row : {}", nb_in_a_row);
                 i += 1;
@@ -1857,9 +2295,31 @@ fn update_lobby_page_after_joining_game(server_name: &str, player_name: &str, cl
 // Used when GamePhase::InitGame
 #[cfg(feature = "server")]
 fn add_character_on_server_data(server_name: &str, player_name: &str, character_name: &str) {
+    // An arena match is fought by the registered party
+    if event_arena::is_arena(server_name) {
+        tracing::warn!(
+            "Heroes of the arena match of server {} are set",
+            server_name
+        );
+        return;
+    }
     let dm = DATA_MANAGER.lock().unwrap();
     let mut sm = lock_server_manager();
     if let Some(server_data) = sm.servers_data.get_mut(server_name) {
+        if !event_daily_challenge::allows_hero(
+            server_name,
+            server_data,
+            player_name,
+            character_name,
+        ) {
+            tracing::warn!(
+                "The daily challenge of server {} doesn't allow {} to pick {}",
+                server_name,
+                player_name,
+                character_name
+            );
+            return;
+        }
         // remove characters from one player in server data
         server_data
             .players_data
@@ -1970,6 +2430,13 @@ fn add_character_on_server_data(server_name: &str, player_name: &str, character_
 /// In single-player, pass `"{player}__sp{N}"` to remove a specific extra hero.
 #[cfg(feature = "server")]
 fn remove_character_on_server_data(server_name: &str, player_key: &str) {
+    if event_arena::is_arena(server_name) {
+        tracing::warn!(
+            "Heroes of the arena match of server {} are set",
+            server_name
+        );
+        return;
+    }
     let dm = DATA_MANAGER.lock().unwrap();
     let mut sm = lock_server_manager();
     if let Some(server_data) = sm.servers_data.get_mut(server_name) {
@@ -2030,6 +2497,17 @@ fn remove_character_on_server_data(server_name: &str, player_key: &str) {
 fn set_universe_on_server_data(server_name: &str, universe: &str) {
     use lib_rpg::server::scenario::ScenarioState;
 
+    // The universe of a daily challenge is drawn with it, the one of an arena
+    // match is the attacker's
+    if event_daily_challenge::challenge_of(server_name).is_some()
+        || event_arena::is_arena(server_name)
+    {
+        tracing::warn!(
+            "Cannot change the universe of the daily challenge or arena match of server {}",
+            server_name
+        );
+        return;
+    }
     let dm = DATA_MANAGER.lock().unwrap();
     let all_scenarios_full = dm.all_scenarios.clone();
     drop(dm);
@@ -2128,7 +2606,16 @@ async fn load_game_by_player(
     // Mark this game as loaded from a save so the lobby locks the universe selector
     if !is_replay {
         app.loaded_from_save = true;
+        // A saved daily challenge goes on as a regular game
+        event_daily_challenge::set_challenge(&server_name, None);
+        event_endless::set_run(&server_name, None);
+        event_arena::abandon_match(&server_name);
     }
+    event_difficulty::load_settings(&app.server_name, &load_path, &app);
+    event_crafting::load_materials(&app.server_name, &load_path);
+    event_quests::load_quests(&app.server_name, &load_path);
+    event_trading::clear_trades(&app.server_name);
+    event_logs::load_log_messages(&app.server_name, &load_path);
 
     // persist state (no locks involved)
     save_core_game_data(&app, SAVED_CORE_GAME_DATA, &player_name).await;
@@ -2325,6 +2812,10 @@ pub async fn save_core_game_data(
         Ok(()) => tracing::info!("Core game data saved successfully {}", save_game_name),
         Err(e) => tracing::error!("Failed to save Core game data: {}", e),
     }
+    event_difficulty::save_settings(&core_game_data.server_name, &saved_dir).await;
+    event_crafting::save_materials(&core_game_data.server_name, &saved_dir).await;
+    event_quests::save_quests(&core_game_data.server_name, &saved_dir).await;
+    event_logs::save_log_messages(&core_game_data.server_name, &saved_dir).await;
 }
 
 #[cfg(feature = "server")]
@@ -2347,6 +2838,16 @@ pub async fn get_core_game_data_by_dir(
     }
 }
 
+/// Opens the log lines of the current scenario (see `combat_log::current_scenario`).
+#[cfg(feature = "server")]
+fn log_scenario_start(core_game_data: &mut CoreGameData) {
+    let gm = &mut core_game_data.game_manager;
+    let marker = event_logs::with_log_messages(&core_game_data.server_name, |messages| {
+        combat_log::scenario_marker(messages, &gm.current_scenario.name)
+    });
+    gm.logs.push(marker);
+}
+
 #[cfg(feature = "server")]
 pub async fn process_load_next_scenario(server_name: &str, auto_save: bool) -> Result<()> {
     let owner_player_name = {
@@ -2359,6 +2860,10 @@ pub async fn process_load_next_scenario(server_name: &str, auto_save: bool) -> R
             return Ok(());
         };
         server_data.core_game_data.load_next_scenario()?;
+        log_scenario_start(&mut server_data.core_game_data);
+        event_endless::scale_floor_bosses(server_name, &mut server_data.core_game_data);
+        event_difficulty::scale_new_scenario(server_name, &mut server_data.core_game_data);
+        event_profile::mark_scenario_start(server_name);
         server_data.players_data.owner_player_name.clone()
     };
     // Debug-only: helps diagnose reports of the Scenarios tab showing stale progress
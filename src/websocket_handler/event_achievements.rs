@@ -0,0 +1,163 @@
+#[cfg(feature = "server")]
+use crate::achievements::AchievementKind;
+#[cfg(feature = "server")]
+use crate::websocket_handler::common_event::{player_accounts, player_of};
+#[cfg(feature = "server")]
+use lib_rpg::server::server_manager::ServerData;
+
+/// Achievement events earned on a server: (player, kind, amount).
+#[cfg(feature = "server")]
+pub type AchievementCredits = Vec<(String, AchievementKind, i64)>;
+
+/// Critical hits of the last attack, when launched by a hero.
+#[cfg(feature = "server")]
+pub fn attack_credits(server_data: &ServerData) -> AchievementCredits {
+    use lib_rpg::common::sound_cue::{SoundCue, classify_result_atk};
+
+    let ra = &server_data
+        .core_game_data
+        .game_manager
+        .game_state
+        .last_result_atk;
+    let crits = classify_result_atk(ra)
+        .into_iter()
+        .filter(|cue| matches!(cue, SoundCue::CriticalHit))
+        .count();
+    match player_of(server_data, &ra.launcher_id_name) {
+        Some(player) if crits > 0 => {
+            vec![(player, AchievementKind::CriticalHit, crits as i64)]
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Gold held by each hero, credited to its player: the counter keeps the most.
+#[cfg(feature = "server")]
+pub fn gold_credits(server_data: &ServerData) -> AchievementCredits {
+    let heroes = &server_data.core_game_data.game_manager.pm.active_heroes;
+    heroes
+        .iter()
+        .filter_map(|h| {
+            player_of(server_data, &h.id_name)
+                .map(|player| (player, AchievementKind::GoldHeld, h.inventory.money as i64))
+        })
+        .collect()
+}
+
+/// What the end of the scenario earns every player of the game: the scenario
+/// and the universe won, a flawless fight, an overworld boss beaten, the gold
+/// looted.
+#[cfg(feature = "server")]
+pub fn scenario_end_credits(
+    server_data: &ServerData,
+    won: bool,
+    universe_completed: bool,
+) -> AchievementCredits {
+    let gm = &server_data.core_game_data.game_manager;
+    let mut kinds = Vec::new();
+    if won {
+        kinds.push(AchievementKind::ScenarioCompleted);
+        if universe_completed {
+            kinds.push(AchievementKind::GameWon);
+        }
+        if !gm
+            .pm
+            .active_heroes
+            .iter()
+            .any(|h| h.stats.is_dead().unwrap_or(false))
+        {
+            kinds.push(AchievementKind::FlawlessFight);
+        }
+        // Boss NPCs start their fight under the scenario name they point to
+        let scenario = &gm.current_scenario.name;
+        if server_data
+            .core_game_data
+            .overworld
+            .as_ref()
+            .is_some_and(|ow| {
+                ow.npcs
+                    .iter()
+                    .any(|npc| npc.fight_scenario_id.as_ref() == Some(scenario))
+            })
+        {
+            kinds.push(AchievementKind::BossDefeated);
+        }
+    }
+    let mut credits: AchievementCredits = player_accounts(server_data)
+        .into_iter()
+        .flat_map(|player| kinds.iter().map(move |kind| (player.clone(), *kind, 1)))
+        .collect();
+    credits.extend(gold_credits(server_data));
+    credits
+}
+
+/// Number of talent paths of `tree` whose every talent is in `unlocked`.
+#[cfg(feature = "server")]
+pub fn completed_talent_paths(
+    tree: &lib_rpg::character_mod::talent::TalentTree,
+    unlocked: &[String],
+) -> i64 {
+    tree.paths
+        .iter()
+        .filter(|path| {
+            !path.talents.is_empty() && path.talents.iter().all(|t| unlocked.contains(&t.id))
+        })
+        .count() as i64
+}
+
+/// A talent path completed by the hero `id_name`.
+#[cfg(feature = "server")]
+pub fn talent_path_credits(
+    server_data: &ServerData,
+    id_name: &str,
+    newly_completed: i64,
+) -> AchievementCredits {
+    match player_of(server_data, id_name) {
+        Some(player) if newly_completed > 0 => vec![(
+            player,
+            AchievementKind::TalentPathCompleted,
+            newly_completed,
+        )],
+        _ => Vec::new(),
+    }
+}
+
+/// Records `credits` in the background, then notifies each player of the
+/// achievements it unlocked. A failure is logged: the game goes on either way.
+#[cfg(feature = "server")]
+pub fn spawn_achievement_credits(server_name: &str, credits: AchievementCredits) {
+    use crate::{
+        auth_manager::server_fn::record_achievement_events,
+        websocket_handler::event::{ServerEvent, send_server_event_to_player},
+    };
+    use dioxus::logger::tracing;
+    use std::collections::BTreeMap;
+
+    if credits.is_empty() {
+        return;
+    }
+    let mut by_player: BTreeMap<String, Vec<(AchievementKind, i64)>> = BTreeMap::new();
+    for (player, kind, amount) in credits {
+        by_player.entry(player).or_default().push((kind, amount));
+    }
+    let server_name = server_name.to_owned();
+    tokio::spawn(async move {
+        for (player, events) in by_player {
+            match record_achievement_events(&player, &events).await {
+                Ok(unlocked) if !unlocked.is_empty() => {
+                    tracing::info!(
+                        "{player} unlocked {:?}",
+                        unlocked.iter().map(|d| &d.id).collect::<Vec<_>>()
+                    );
+                    send_server_event_to_player(
+                        &server_name,
+                        &player,
+                        &ServerEvent::AchievementsUnlocked(unlocked),
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => tracing::error!("Cannot record the achievements of {player}: {e}"),
+            }
+        }
+    });
+}
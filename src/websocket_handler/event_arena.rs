@@ -0,0 +1,302 @@
+#[cfg(feature = "server")]
+use crate::{arena::ArenaMatch, auth_manager::server_fn::ArenaLog};
+#[cfg(feature = "server")]
+use lib_rpg::{
+    character_mod::character::Character, common::log_data::LogData, server::scenario::Scenario,
+};
+#[cfg(feature = "server")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "server")]
+use std::{collections::HashMap, sync::Mutex};
+
+/// Arena match played by each server, for the games that are one, with whether
+/// its result is recorded.
+#[cfg(feature = "server")]
+static ARENA_MATCHES: Lazy<Mutex<HashMap<String, (ArenaMatch, bool)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[cfg(feature = "server")]
+fn lock_matches() -> std::sync::MutexGuard<'static, HashMap<String, (ArenaMatch, bool)>> {
+    ARENA_MATCHES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(feature = "server")]
+pub fn match_of(server_name: &str) -> Option<ArenaMatch> {
+    lock_matches()
+        .get(server_name)
+        .map(|(arena_match, _)| arena_match.clone())
+}
+
+/// The match of `server_name`, which just ended: its result is the caller's to
+/// record. `None` for another game, or when the result is already recorded.
+#[cfg(feature = "server")]
+pub fn finish_match(server_name: &str) -> Option<ArenaMatch> {
+    let mut matches = lock_matches();
+    let (arena_match, finished) = matches.get_mut(server_name)?;
+    if *finished {
+        return None;
+    }
+    *finished = true;
+    Some(arena_match.clone())
+}
+
+#[cfg(feature = "server")]
+pub fn is_arena(server_name: &str) -> bool {
+    lock_matches().contains_key(server_name)
+}
+
+/// Parties of a match found by `find_match`, ready to be seated on a server.
+#[cfg(feature = "server")]
+pub struct ArenaSetup {
+    pub arena_match: ArenaMatch,
+    /// Universe of the attacker's party
+    pub universe: String,
+    pub attackers: Vec<Character>,
+    /// Already turned into bosses
+    pub defenders: Vec<Character>,
+}
+
+/// Starts the fight at full HP and energy, whatever the save was left with.
+#[cfg(feature = "server")]
+fn rest(character: &mut Character) {
+    use lib_rpg::common::constants::stats_const::{HP, MANA, VIGOR};
+
+    for stat in [HP, MANA, VIGOR] {
+        if let Some(attribute) = character.stats.all_stats.get_mut(stat) {
+            attribute.current = attribute.max;
+        }
+    }
+}
+
+/// A match for `player_name`: their registered party against one drawn among
+/// the parties rated closest to it.
+#[cfg(feature = "server")]
+pub async fn find_match(player_name: &str) -> anyhow::Result<ArenaSetup> {
+    use crate::{
+        arena::{ARENA_START_RATING, defender_name, pick_opponent},
+        auth_manager::server_fn::{arena_opponents, arena_party_of},
+    };
+    use lib_rpg::character_mod::character::CharacterKind;
+
+    let Some((attacker_rating, mut attackers)) = arena_party_of(player_name).await? else {
+        anyhow::bail!("{} has no arena party", player_name);
+    };
+    let candidates = arena_opponents(player_name).await?;
+    let Some(defender) = pick_opponent(attacker_rating, &candidates, rand::random()) else {
+        anyhow::bail!("No arena opponent for {}", player_name);
+    };
+    let defender_rating = candidates
+        .iter()
+        .find(|(name, _)| *name == defender)
+        .map_or(ARENA_START_RATING, |(_, rating)| *rating);
+    let Some((_, mut defenders)) = arena_party_of(&defender).await? else {
+        anyhow::bail!("{} has no arena party", defender);
+    };
+
+    attackers.iter_mut().for_each(rest);
+    let mut defender_heroes: Vec<String> = Vec::new();
+    for hero in defenders.iter_mut() {
+        // Twice the same hero in a party gets a number
+        let mut name = defender_name(&defender, &hero.db_full_name);
+        let mut n = 1;
+        while defender_heroes.contains(&name) {
+            n += 1;
+            name = format!("{} {n}", defender_name(&defender, &hero.db_full_name));
+        }
+        hero.kind = CharacterKind::Boss;
+        hero.db_full_name = name.clone();
+        hero.id_name = name.clone();
+        rest(hero);
+        defender_heroes.push(name);
+    }
+    let universe = attackers
+        .first()
+        .map(|h| h.universe.clone())
+        .unwrap_or_default();
+    Ok(ArenaSetup {
+        arena_match: ArenaMatch {
+            attacker: player_name.to_owned(),
+            attacker_rating,
+            defender,
+            defender_rating,
+            defender_heroes,
+        },
+        universe,
+        attackers,
+        defenders,
+    })
+}
+
+/// Scenario of the match, made from `template` like an endless floor: the
+/// defender's heroes all come in at the first turn and it has no loots.
+#[cfg(feature = "server")]
+fn arena_scenario(template: &Scenario, arena_match: &ArenaMatch) -> Option<Scenario> {
+    use crate::arena::ARENA_SCENARIO;
+
+    let mut v = serde_json::to_value(template).ok()?;
+    v["name"] = serde_json::json!(ARENA_SCENARIO);
+    v["description"] = serde_json::json!(arena_match.defender);
+    v["loots"] = serde_json::json!([]);
+    v["boss_patterns"] = arena_match
+        .defender_heroes
+        .iter()
+        .map(|boss| (boss.clone(), serde_json::json!([0])))
+        .collect::<serde_json::Map<_, _>>()
+        .into();
+    let mut scenario: Scenario = serde_json::from_value(v).ok()?;
+    scenario.universe = template.universe.clone();
+    Some(scenario)
+}
+
+/// Drops the match of `server_name`, for a regular or loaded game. Returns it
+/// when it was left before its end.
+#[cfg(feature = "server")]
+fn clear_match(server_name: &str) -> Option<ArenaMatch> {
+    lock_matches()
+        .remove(server_name)
+        .and_then(|(arena_match, finished)| (!finished).then_some(arena_match))
+}
+
+/// Log of the match played on `server_name`, whose game logs are `logs`: the
+/// lines of its scenario with their messages.
+#[cfg(feature = "server")]
+pub fn match_log(server_name: &str, logs: &[LogData]) -> ArenaLog {
+    use crate::{combat_log, websocket_handler::event_logs};
+
+    let messages = event_logs::log_messages_of(server_name);
+    let lines = combat_log::current_scenario(logs, &messages).to_vec();
+    ArenaLog {
+        messages: messages.of_lines(&lines),
+        lines,
+    }
+}
+
+/// Drops the match of `server_name`, as its game is replaced or closed. A
+/// match left before its end is lost by the attacker, so that quitting a
+/// losing fight doesn't spare them the rating loss. Not to be called under
+/// the server manager lock.
+#[cfg(feature = "server")]
+pub fn abandon_match(server_name: &str) {
+    use crate::{
+        auth_manager::server_fn::save_arena_result,
+        websocket_handler::common_event::lock_server_manager,
+    };
+    use dioxus::logger::tracing;
+
+    let Some(arena_match) = clear_match(server_name) else {
+        return;
+    };
+    let (turns, log) = lock_server_manager()
+        .servers_data
+        .get(server_name)
+        .map(|server_data| {
+            let gm = &server_data.core_game_data.game_manager;
+            (
+                i64::try_from(gm.game_state.current_turn_nb).unwrap_or(i64::MAX),
+                match_log(server_name, &gm.logs),
+            )
+        })
+        .unwrap_or_default();
+    tracing::info!(
+        "Arena match of {} against {} abandoned on server {}",
+        arena_match.attacker,
+        arena_match.defender,
+        server_name
+    );
+    tokio::spawn(async move {
+        save_arena_result(&arena_match, false, turns, &log).await;
+    });
+}
+
+/// The game of `server_name` now plays `setup`. The attacker's heroes take the
+/// seats of `player_name`, as in a single-player game, and the one scenario of
+/// the game becomes the match. Returns `false` when the match can't be built.
+#[cfg(feature = "server")]
+pub fn set_up_match(server_name: &str, player_name: &str, setup: ArenaSetup) -> bool {
+    use crate::websocket_handler::common_event::lock_server_manager;
+    use dioxus::logger::tracing;
+    use lib_rpg::server::scenario::ScenarioState;
+
+    let mut sm = lock_server_manager();
+    let Some(server_data) = sm.servers_data.get_mut(server_name) else {
+        return false;
+    };
+    let core_game_data = &mut server_data.core_game_data;
+    let Some(scenario) = core_game_data
+        .game_manager
+        .all_scenarios
+        .first()
+        .and_then(|template| arena_scenario(template, &setup.arena_match))
+    else {
+        tracing::error!("Cannot build the arena scenario of server {}", server_name);
+        return false;
+    };
+    let gm = &mut core_game_data.game_manager;
+    gm.states_scenarios = [(scenario.name.clone(), ScenarioState::NotStarted)].into();
+    gm.all_scenarios = vec![scenario];
+    gm.pm.all_bosses.extend(setup.defenders);
+    gm.pm.active_heroes.clear();
+    core_game_data.heroes_chosen.clear();
+    core_game_data.universe = setup.universe;
+    let players_info = &mut server_data.players_data.players_info;
+    players_info.retain(|key, _| key == player_name);
+    for (i, hero) in setup.attackers.into_iter().enumerate() {
+        // Extra heroes of a single-player game go under synthetic keys
+        let key = if i == 0 {
+            player_name.to_owned()
+        } else {
+            format!("{player_name}__sp{i}")
+        };
+        players_info
+            .entry(key.clone())
+            .or_default()
+            .character_id_names = vec![hero.id_name.clone()];
+        core_game_data
+            .heroes_chosen
+            .insert(key, hero.id_name.clone());
+        core_game_data.game_manager.pm.active_heroes.push(hero);
+    }
+    tracing::info!(
+        "Arena match on server {}: {} ({}) against {} ({})",
+        server_name,
+        setup.arena_match.attacker,
+        setup.arena_match.attacker_rating,
+        setup.arena_match.defender,
+        setup.arena_match.defender_rating
+    );
+    drop(sm);
+    lock_matches().insert(server_name.to_owned(), (setup.arena_match, false));
+    true
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+
+    fn arena_match() -> ArenaMatch {
+        ArenaMatch {
+            attacker: "alice".to_owned(),
+            attacker_rating: 1000,
+            defender: "bob".to_owned(),
+            defender_rating: 1000,
+            defender_heroes: vec!["Elara (bob)".to_owned()],
+        }
+    }
+
+    #[test]
+    fn a_match_left_before_its_end_is_abandoned() {
+        lock_matches().insert("arena-left".to_owned(), (arena_match(), false));
+        assert_eq!(clear_match("arena-left"), Some(arena_match()));
+        assert!(!is_arena("arena-left"));
+
+        // Its result recorded, a match is only dropped
+        lock_matches().insert("arena-done".to_owned(), (arena_match(), false));
+        assert_eq!(finish_match("arena-done"), Some(arena_match()));
+        assert_eq!(finish_match("arena-done"), None);
+        assert!(is_arena("arena-done"));
+        assert_eq!(clear_match("arena-done"), None);
+        assert_eq!(clear_match("arena-none"), None);
+    }
+}
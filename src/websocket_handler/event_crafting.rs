@@ -0,0 +1,201 @@
+#[cfg(feature = "server")]
+use crate::crafting::{MaterialStash, SAVED_MATERIALS};
+#[cfg(feature = "server")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "server")]
+use std::{collections::HashMap, path::Path, sync::Mutex};
+
+/// Materials owned by the party of each server.
+#[cfg(feature = "server")]
+static MATERIALS: Lazy<Mutex<HashMap<String, MaterialStash>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[cfg(feature = "server")]
+fn lock_materials() -> std::sync::MutexGuard<'static, HashMap<String, MaterialStash>> {
+    MATERIALS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(feature = "server")]
+pub fn set_materials(server_name: &str, stash: MaterialStash) {
+    lock_materials().insert(server_name.to_owned(), stash);
+}
+
+/// Drops the materials of `server_name`, as its game is closed.
+#[cfg(feature = "server")]
+pub fn clear_materials(server_name: &str) {
+    lock_materials().remove(server_name);
+}
+
+#[cfg(feature = "server")]
+pub fn materials_of(server_name: &str) -> MaterialStash {
+    lock_materials()
+        .get(server_name)
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Reads the materials saved in `game_dir` for the game loaded on
+/// `server_name`. Saves made before crafting existed have none.
+#[cfg(feature = "server")]
+pub fn load_materials(server_name: &str, game_dir: &Path) {
+    let stash = std::fs::read_to_string(game_dir.join(SAVED_MATERIALS))
+        .ok()
+        .and_then(|s| serde_json::from_str::<MaterialStash>(&s).ok())
+        .unwrap_or_default();
+    set_materials(server_name, stash);
+}
+
+/// Writes the materials of the party on `server_name` in `game_dir`, next to its save.
+#[cfg(feature = "server")]
+pub async fn save_materials(server_name: &str, game_dir: &Path) {
+    use crate::utils::server_file_utils;
+    use dioxus::logger::tracing;
+
+    let stash = materials_of(server_name);
+    let Ok(value) = serde_json::to_string_pretty(&stash) else {
+        return;
+    };
+    if let Err(e) = server_file_utils::save(game_dir.join(SAVED_MATERIALS), value).await {
+        tracing::error!(
+            "Failed to save the materials of server {}: {}",
+            server_name,
+            e
+        );
+    }
+}
+
+/// The party of `server_name` won its scenario: its Material loots go to the
+/// stash, the amount of each being the loot's level.
+#[cfg(feature = "server")]
+pub fn collect_materials(
+    server_name: &str,
+    core_game_data: &lib_rpg::server::core_game_data::CoreGameData,
+) {
+    use crate::crafting::add_material;
+    use lib_rpg::character_mod::loot::LootType;
+
+    let mut stashes = lock_materials();
+    let stash = stashes.entry(server_name.to_owned()).or_default();
+    for loot in core_game_data
+        .game_manager
+        .current_scenario
+        .loots
+        .iter()
+        .filter(|loot| loot.kind == LootType::Material)
+    {
+        add_material(stash, &loot.name, loot.level.max(0) as _);
+    }
+}
+
+/// Crafts the recipe `recipe_stem` for a character: the character pays its
+/// gold, the party stash its materials, and the item goes to the bag.
+#[cfg(feature = "server")]
+pub fn craft_item_handler(server_name: &str, character_id_name: &str, recipe_stem: &str) {
+    use crate::{
+        common::{DATA_MANAGER, OFFLINE_PATH},
+        crafting::{CraftedKind, RECIPES_DIR, read_recipes, take_materials},
+        server_messages::ServerMessage,
+        websocket_handler::{common_event::lock_server_manager, event_logs},
+    };
+    use dioxus::logger::tracing;
+    use lib_rpg::{character_mod::character::Character, shop::build_consumable_by_name};
+
+    let Some(recipe) = read_recipes(&Path::new(OFFLINE_PATH).join(RECIPES_DIR))
+        .into_iter()
+        .find(|(stem, _)| stem == recipe_stem)
+        .map(|(_, recipe)| recipe)
+    else {
+        tracing::warn!("craft_item_handler: unknown recipe '{}'", recipe_stem);
+        return;
+    };
+    let equipment = match recipe.kind {
+        CraftedKind::Equipment => {
+            let dm = DATA_MANAGER.lock().unwrap();
+            let equipment = dm
+                .equipment_table
+                .values()
+                .flatten()
+                .find(|e| e.unique_name == recipe.output)
+                .cloned();
+            if equipment.is_none() {
+                tracing::error!(
+                    "craft_item_handler: equipment '{}' not found",
+                    recipe.output
+                );
+                return;
+            }
+            equipment
+        }
+        CraftedKind::Consumable => None,
+    };
+    // Pays the gold and puts the item in the bag of `character`
+    let make = |character: &mut Character| match &equipment {
+        Some(e) => character
+            .inventory
+            .buy_equipment(e, recipe.gold as _)
+            .map_err(|e| e.to_string()),
+        None => match build_consumable_by_name(&recipe.output) {
+            Some(c) => character
+                .inventory
+                .buy_consumable(c, recipe.gold as _)
+                .map_err(|e| e.to_string()),
+            None => Err(format!("unknown consumable '{}'", recipe.output)),
+        },
+    };
+
+    let mut sm = lock_server_manager();
+    let Some(server_data) = sm.servers_data.get_mut(server_name) else {
+        tracing::error!("craft_item_handler: no server data for '{}'", server_name);
+        return;
+    };
+    let pm = &mut server_data.core_game_data.game_manager.pm;
+    let Some(hero) = pm
+        .active_heroes
+        .iter_mut()
+        .find(|h| h.id_name == character_id_name)
+    else {
+        tracing::error!(
+            "craft_item_handler: character '{}' not found",
+            character_id_name
+        );
+        return;
+    };
+
+    let mut stashes = lock_materials();
+    let stash = stashes.entry(server_name.to_owned()).or_default();
+    if !recipe.can_craft(stash, hero.inventory.money as _) {
+        tracing::warn!(
+            "{} lacks gold or materials to craft '{}'",
+            character_id_name,
+            recipe.name
+        );
+        return;
+    }
+    if let Err(e) = make(hero) {
+        tracing::warn!("craft_item_handler: {}", e);
+        return;
+    }
+    take_materials(stash, &recipe);
+    drop(stashes);
+    // `current_player` is a shadow copy of the character playing now, written
+    // back over it at the end of its turn
+    if pm.current_player.id_name == character_id_name {
+        let _ = make(&mut pm.current_player);
+    }
+    tracing::info!(
+        "{} crafted '{}' for {} gold",
+        character_id_name,
+        recipe.output,
+        recipe.gold
+    );
+    event_logs::push_message(
+        &mut server_data.core_game_data,
+        "⚒️",
+        ServerMessage::ItemCrafted {
+            character: character_id_name.to_owned(),
+            item: recipe.output.clone(),
+        },
+    );
+}
@@ -0,0 +1,112 @@
+#[cfg(feature = "server")]
+use crate::daily_challenge::{ChallengeModifier, DailyChallenge};
+#[cfg(feature = "server")]
+use lib_rpg::server::server_manager::ServerData;
+#[cfg(feature = "server")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "server")]
+use std::{collections::HashMap, sync::Mutex};
+
+/// Daily challenge played by each server, for the games that are one.
+#[cfg(feature = "server")]
+static DAILY_CHALLENGE_GAMES: Lazy<Mutex<HashMap<String, DailyChallenge>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[cfg(feature = "server")]
+fn lock_games() -> std::sync::MutexGuard<'static, HashMap<String, DailyChallenge>> {
+    DAILY_CHALLENGE_GAMES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The game of `server_name` now plays `challenge`, or is a regular game.
+#[cfg(feature = "server")]
+pub fn set_challenge(server_name: &str, challenge: Option<DailyChallenge>) {
+    match challenge {
+        Some(challenge) => lock_games().insert(server_name.to_owned(), challenge),
+        None => lock_games().remove(server_name),
+    };
+}
+
+#[cfg(feature = "server")]
+pub fn challenge_of(server_name: &str) -> Option<DailyChallenge> {
+    lock_games().get(server_name).cloned()
+}
+
+/// Whether the game of `server_name` is a daily challenge with `modifier`.
+#[cfg(feature = "server")]
+pub fn forbids(server_name: &str, modifier: ChallengeModifier) -> bool {
+    challenge_of(server_name).is_some_and(|c| c.has(modifier))
+}
+
+/// Whether `player_key` may pick `character_name` for the game of `server_name`:
+/// a challenge limits the heroes and the size of the party.
+#[cfg(feature = "server")]
+pub fn allows_hero(
+    server_name: &str,
+    server_data: &ServerData,
+    player_key: &str,
+    character_name: &str,
+) -> bool {
+    let Some(challenge) = challenge_of(server_name) else {
+        return true;
+    };
+    let party_size = server_data
+        .players_data
+        .players_info
+        .iter()
+        .filter(|(key, info)| key.as_str() != player_key && !info.character_id_names.is_empty())
+        .count();
+    challenge.allows_hero(character_name, party_size)
+}
+
+/// Uses up the attempt of the day of every player, right before the challenge
+/// on `server_name` starts. Returns `false` when a player already played it:
+/// the game must not start, and no attempt is used up. Regular games always start.
+#[cfg(feature = "server")]
+pub async fn claim_start(server_name: &str) -> bool {
+    use crate::{
+        auth_manager::server_fn::claim_daily_challenge,
+        websocket_handler::common_event::{lock_server_manager, player_accounts},
+    };
+    use dioxus::logger::tracing;
+    use lib_rpg::server::server_manager::GamePhase;
+
+    let Some(challenge) = challenge_of(server_name) else {
+        return true;
+    };
+    let (players, heroes) = {
+        let sm = lock_server_manager();
+        let Some(server_data) = sm.servers_data.get(server_name) else {
+            return false;
+        };
+        if server_data.core_game_data.game_phase != GamePhase::InitGame {
+            return true;
+        }
+        let heroes = server_data
+            .core_game_data
+            .game_manager
+            .pm
+            .active_heroes
+            .iter()
+            .map(|h| h.db_full_name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        (player_accounts(server_data), heroes)
+    };
+    match claim_daily_challenge(&players, &challenge, &heroes).await {
+        Ok(already_played) if already_played.is_empty() => true,
+        Ok(already_played) => {
+            tracing::warn!(
+                "{} already played the challenge of {}",
+                already_played.join(", "),
+                challenge.date
+            );
+            false
+        }
+        Err(e) => {
+            tracing::error!("Cannot record the daily challenge of {server_name}: {e}");
+            false
+        }
+    }
+}
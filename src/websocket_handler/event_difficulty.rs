@@ -0,0 +1,318 @@
+#[cfg(feature = "server")]
+use crate::difficulty::{Difficulty, GameSettings, SAVED_GAME_SETTINGS};
+#[cfg(feature = "server")]
+use lib_rpg::server::core_game_data::CoreGameData;
+#[cfg(feature = "server")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "server")]
+use std::{collections::HashMap, path::Path, sync::Mutex};
+
+#[cfg(feature = "server")]
+#[derive(Default)]
+struct DifficultyState {
+    settings: GameSettings,
+    /// Scenario whose bosses and loots were scaled last, so that a fight
+    /// resumed from the overworld isn't scaled twice
+    scaled_scenario: Option<String>,
+}
+
+/// Difficulty of the game played by each server.
+#[cfg(feature = "server")]
+static GAME_SETTINGS: Lazy<Mutex<HashMap<String, DifficultyState>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[cfg(feature = "server")]
+fn lock_settings() -> std::sync::MutexGuard<'static, HashMap<String, DifficultyState>> {
+    GAME_SETTINGS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The game of `server_name` is now played with `settings`; `scaled_scenario`
+/// is the scenario already scaled in it, if any.
+#[cfg(feature = "server")]
+pub fn set_settings(server_name: &str, settings: GameSettings, scaled_scenario: Option<String>) {
+    lock_settings().insert(
+        server_name.to_owned(),
+        DifficultyState {
+            settings,
+            scaled_scenario,
+        },
+    );
+}
+
+/// Drops the settings of `server_name`, as its game is closed.
+#[cfg(feature = "server")]
+pub fn clear_settings(server_name: &str) {
+    lock_settings().remove(server_name);
+}
+
+#[cfg(feature = "server")]
+pub fn settings_of(server_name: &str) -> GameSettings {
+    lock_settings()
+        .get(server_name)
+        .map(|state| state.settings)
+        .unwrap_or_default()
+}
+
+/// Reads the settings saved in `game_dir` for the game loaded on
+/// `server_name`. Saves made before difficulty levels existed are Normal.
+#[cfg(feature = "server")]
+pub fn load_settings(server_name: &str, game_dir: &Path, core_game_data: &CoreGameData) {
+    use lib_rpg::server::scenario::ScenarioState;
+
+    let settings = std::fs::read_to_string(game_dir.join(SAVED_GAME_SETTINGS))
+        .ok()
+        .and_then(|s| serde_json::from_str::<GameSettings>(&s).ok())
+        .unwrap_or_default();
+    // A scenario saved while fought already has its scaled bosses
+    let gm = &core_game_data.game_manager;
+    let scaled_scenario = (gm.states_scenarios.get(&gm.current_scenario.name)
+        == Some(&ScenarioState::InProgress))
+    .then(|| gm.current_scenario.name.clone());
+    set_settings(server_name, settings, scaled_scenario);
+}
+
+/// Writes the settings of the game on `server_name` in `game_dir`, next to its save.
+#[cfg(feature = "server")]
+pub async fn save_settings(server_name: &str, game_dir: &Path) {
+    use crate::utils::server_file_utils;
+    use dioxus::logger::tracing;
+
+    let settings = settings_of(server_name);
+    let Ok(value) = serde_json::to_string_pretty(&settings) else {
+        return;
+    };
+    if let Err(e) = server_file_utils::save(game_dir.join(SAVED_GAME_SETTINGS), value).await {
+        tracing::error!(
+            "Failed to save the settings of server {}: {}",
+            server_name,
+            e
+        );
+    }
+}
+
+/// The owner of `server_name` picks the difficulty of its new game, in the
+/// lobby. A daily challenge keeps the difficulty its rules were drawn for, and
+/// an arena match is always fought at the standard one.
+#[cfg(feature = "server")]
+pub fn set_difficulty(server_name: &str, player_name: &str, difficulty: Difficulty) -> bool {
+    use crate::websocket_handler::{
+        common_event::lock_server_manager, event_arena::is_arena,
+        event_daily_challenge::challenge_of,
+    };
+    use dioxus::logger::tracing;
+    use lib_rpg::server::server_manager::GamePhase;
+
+    let can_set = lock_server_manager()
+        .servers_data
+        .get(server_name)
+        .is_some_and(|sd| {
+            sd.players_data.owner_player_name == player_name
+                && sd.core_game_data.game_phase == GamePhase::InitGame
+        });
+    if !can_set || challenge_of(server_name).is_some() || is_arena(server_name) {
+        tracing::warn!(
+            "{} cannot change the difficulty of server {}",
+            player_name,
+            server_name
+        );
+        return false;
+    }
+    lock_settings()
+        .entry(server_name.to_owned())
+        .or_default()
+        .settings
+        .difficulty = difficulty;
+    true
+}
+
+/// Multiplies `stats` of `character` by `multiplier`, max and current values.
+#[cfg(feature = "server")]
+pub(crate) fn scale_stats(
+    character: &mut lib_rpg::character_mod::character::Character,
+    stats: &[&str],
+    multiplier: f64,
+) {
+    for stat in stats {
+        if let Some(attribute) = character.stats.all_stats.get_mut(*stat) {
+            attribute.max = (attribute.max as f64 * multiplier).round() as _;
+            attribute.current = (attribute.current as f64 * multiplier).round() as _;
+        }
+    }
+}
+
+/// Multiplies `stats` of the bosses of the scenario being played by `multiplier`.
+#[cfg(feature = "server")]
+pub(crate) fn scale_bosses(core_game_data: &mut CoreGameData, stats: &[&str], multiplier: f64) {
+    let pm = &mut core_game_data.game_manager.pm;
+    for boss in pm.active_bosses.iter_mut() {
+        scale_stats(boss, stats, multiplier);
+    }
+    // `current_player` is a shadow copy of the character playing now, written
+    // back over it at the end of its turn
+    if pm
+        .active_bosses
+        .iter()
+        .any(|b| b.id_name == pm.current_player.id_name)
+    {
+        scale_stats(&mut pm.current_player, stats, multiplier);
+    }
+}
+
+#[cfg(feature = "server")]
+fn raise_rank(
+    rank: &lib_rpg::character_mod::rank::Rank,
+    steps: u8,
+) -> lib_rpg::character_mod::rank::Rank {
+    use lib_rpg::character_mod::rank::Rank;
+
+    let index = match rank {
+        Rank::Common => 0,
+        Rank::Intermediate => 1,
+        Rank::Advanced => 2,
+    };
+    match index + u32::from(steps) {
+        0 => Rank::Common,
+        1 => Rank::Intermediate,
+        _ => Rank::Advanced,
+    }
+}
+
+/// Scales the bosses and loots of the scenario that just started on
+/// `server_name` to the difficulty of its game. Call it right after a scenario
+/// is loaded; a fight resumed from the overworld is left as it is.
+#[cfg(feature = "server")]
+pub fn scale_new_scenario(server_name: &str, core_game_data: &mut CoreGameData) {
+    use lib_rpg::character_mod::loot::LootType;
+    use lib_rpg::common::constants::stats_const::{
+        CRITICAL_STRIKE, HP, MAGICAL_POWER, PHYSICAL_POWER, SPEED,
+    };
+
+    let scenario = core_game_data.game_manager.current_scenario.name.clone();
+    let settings = {
+        let mut states = lock_settings();
+        let state = states.entry(server_name.to_owned()).or_default();
+        if state.scaled_scenario.as_ref() == Some(&scenario) {
+            return;
+        }
+        state.scaled_scenario = Some(scenario);
+        state.settings
+    };
+    if settings.is_standard() {
+        return;
+    }
+    scale_bosses(core_game_data, &[HP], settings.boss_hp_multiplier());
+    scale_bosses(
+        core_game_data,
+        &[PHYSICAL_POWER, MAGICAL_POWER],
+        settings.boss_damage_multiplier(),
+    );
+    scale_bosses(
+        core_game_data,
+        &[SPEED, CRITICAL_STRIKE],
+        settings.boss_aggressiveness_multiplier(),
+    );
+    for loot in core_game_data
+        .game_manager
+        .current_scenario
+        .loots
+        .iter_mut()
+    {
+        // Gold has no rank to go up: only its amount does, like the others'
+        if loot.kind != LootType::Currency {
+            loot.rank = raise_rank(&loot.rank, settings.loot_rank_bonus());
+        }
+        loot.level = settings.loot_amount(loot.level.max(0) as u64) as _;
+    }
+}
+
+/// The scenario of `server_name` ended: the next one to start gets scaled,
+/// even when it is the same scenario played again.
+#[cfg(feature = "server")]
+pub fn scenario_ended(server_name: &str) {
+    if let Some(state) = lock_settings().get_mut(server_name) {
+        state.scaled_scenario = None;
+    }
+}
+
+/// The party of `server_name` won the last stage of its universe, some hero
+/// standing, in a game that is neither a daily challenge, an endless run nor an
+/// arena match: it can go on in New Game Plus.
+#[cfg(feature = "server")]
+pub fn can_start_new_game_plus(
+    server_name: &str,
+    server_data: &lib_rpg::server::server_manager::ServerData,
+) -> bool {
+    use crate::websocket_handler::{event_arena, event_daily_challenge, event_endless};
+    use lib_rpg::server::{game_state::GameStatus, scenario::ScenarioState};
+
+    let gm = &server_data.core_game_data.game_manager;
+    gm.game_state.status == GameStatus::EndOfGame
+        && !gm.all_scenarios.is_empty()
+        && !gm
+            .states_scenarios
+            .values()
+            .any(|state| *state == ScenarioState::NotStarted)
+        && !gm
+            .pm
+            .active_heroes
+            .iter()
+            .all(|h| h.stats.is_dead().unwrap_or(false))
+        && event_daily_challenge::challenge_of(server_name).is_none()
+        && !event_endless::is_endless(server_name)
+        && !event_arena::is_arena(server_name)
+}
+
+/// The owner of `server_name` starts the universe again from its first stage,
+/// with the same heroes, their levels, equipment and talents, against bosses
+/// scaled up by one more cycle.
+#[cfg(feature = "server")]
+pub async fn start_new_game_plus(server_name: &str, player_name: &str) {
+    use crate::websocket_handler::{
+        common_event::lock_server_manager, event::process_load_next_scenario,
+    };
+    use dioxus::logger::tracing;
+    use lib_rpg::server::scenario::ScenarioState;
+
+    {
+        let mut sm = lock_server_manager();
+        let Some(server_data) = sm.servers_data.get_mut(server_name) else {
+            return;
+        };
+        if server_data.players_data.owner_player_name != player_name
+            || !can_start_new_game_plus(server_name, server_data)
+        {
+            tracing::warn!(
+                "{} cannot start New Game Plus on server {}",
+                player_name,
+                server_name
+            );
+            return;
+        }
+        for state in server_data
+            .core_game_data
+            .game_manager
+            .states_scenarios
+            .values_mut()
+        {
+            *state = ScenarioState::NotStarted;
+        }
+    }
+    let cycle = {
+        let mut states = lock_settings();
+        let state = states.entry(server_name.to_owned()).or_default();
+        state.settings.new_game_plus += 1;
+        state.scaled_scenario = None;
+        state.settings.new_game_plus
+    };
+    tracing::info!("Server {} starts New Game Plus {}", server_name, cycle);
+    if let Err(e) = process_load_next_scenario(server_name, true).await {
+        tracing::error!(
+            "Cannot start New Game Plus {} on server {}: {}",
+            cycle,
+            server_name,
+            e
+        );
+    }
+}
@@ -0,0 +1,315 @@
+#[cfg(feature = "server")]
+use crate::endless::{EndlessFloor, EndlessReward, EndlessRun};
+#[cfg(feature = "server")]
+use lib_rpg::server::{
+    core_game_data::CoreGameData, data_manager::DataManager, scenario::Scenario,
+};
+#[cfg(feature = "server")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "server")]
+use std::{collections::HashMap, sync::Mutex};
+
+/// Endless run played by each server, for the games that are one.
+#[cfg(feature = "server")]
+static ENDLESS_RUNS: Lazy<Mutex<HashMap<String, EndlessRun>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[cfg(feature = "server")]
+fn lock_runs() -> std::sync::MutexGuard<'static, HashMap<String, EndlessRun>> {
+    ENDLESS_RUNS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The game of `server_name` now plays `run`, or is a regular game.
+#[cfg(feature = "server")]
+pub fn set_run(server_name: &str, run: Option<EndlessRun>) {
+    match run {
+        Some(run) => lock_runs().insert(server_name.to_owned(), run),
+        None => lock_runs().remove(server_name),
+    };
+}
+
+#[cfg(feature = "server")]
+pub fn run_of(server_name: &str) -> Option<EndlessRun> {
+    lock_runs().get(server_name).cloned()
+}
+
+#[cfg(feature = "server")]
+pub fn is_endless(server_name: &str) -> bool {
+    lock_runs().contains_key(server_name)
+}
+
+/// Scenario of `floor`, made from `template`, a stage of the same universe:
+/// the floor's bosses all come in at the first turn and it has no loots.
+#[cfg(feature = "server")]
+fn floor_scenario(template: &Scenario, floor: &EndlessFloor) -> Option<Scenario> {
+    let mut v = serde_json::to_value(template).ok()?;
+    v["name"] = serde_json::json!(floor.scenario_name());
+    v["description"] = serde_json::json!(floor.bosses.join(", "));
+    v["level"] = serde_json::json!(floor.depth);
+    v["loots"] = serde_json::json!([]);
+    v["boss_patterns"] = floor
+        .bosses
+        .iter()
+        .map(|boss| (boss.clone(), serde_json::json!([0])))
+        .collect::<serde_json::Map<_, _>>()
+        .into();
+    let mut scenario: Scenario = serde_json::from_value(v).ok()?;
+    scenario.universe = template.universe.clone();
+    Some(scenario)
+}
+
+/// Scenario of the floor `depth` of `run`, drawn from the bosses of its universe.
+#[cfg(feature = "server")]
+fn build_floor(dm: &DataManager, run: &EndlessRun, depth: u32) -> Option<Scenario> {
+    use crate::endless::{EndlessBoss, generate_floor};
+
+    let template = dm
+        .all_scenarios
+        .iter()
+        .find(|s| s.universe == run.universe)?;
+    let pool: Vec<EndlessBoss> = dm
+        .all_bosses
+        .iter()
+        .filter(|b| b.universe == run.universe)
+        .map(|b| EndlessBoss {
+            name: b.db_full_name.clone(),
+            level: b.level,
+        })
+        .collect();
+    floor_scenario(template, &generate_floor(run.seed, depth, &pool)?)
+}
+
+/// Replaces the stages of the game on `server_name` with the first floor of its
+/// run, and the next one so that winning a floor never ends the game. Returns
+/// `false` when the floors can't be built and the game must not start. Regular
+/// games always start.
+#[cfg(feature = "server")]
+pub fn prepare_first_floors(server_name: &str) -> bool {
+    use crate::{common::DATA_MANAGER, websocket_handler::common_event::lock_server_manager};
+    use dioxus::logger::tracing;
+    use lib_rpg::server::{scenario::ScenarioState, server_manager::GamePhase};
+
+    let Some(mut run) = run_of(server_name) else {
+        return true;
+    };
+    let universe = {
+        let sm = lock_server_manager();
+        let Some(server_data) = sm.servers_data.get(server_name) else {
+            return false;
+        };
+        if server_data.core_game_data.game_phase != GamePhase::InitGame {
+            return true;
+        }
+        server_data.core_game_data.universe.clone()
+    };
+    run.universe = universe;
+    let floors = {
+        let dm = DATA_MANAGER.lock().unwrap();
+        [1, 2]
+            .into_iter()
+            .map(|depth| build_floor(&dm, &run, depth))
+            .collect::<Option<Vec<_>>>()
+    };
+    let Some(floors) = floors else {
+        tracing::warn!(
+            "No endless floor can be built in universe '{}' for server {}",
+            run.universe,
+            server_name
+        );
+        return false;
+    };
+
+    let mut sm = lock_server_manager();
+    let Some(server_data) = sm.servers_data.get_mut(server_name) else {
+        return false;
+    };
+    let gm = &mut server_data.core_game_data.game_manager;
+    gm.states_scenarios = floors
+        .iter()
+        .map(|floor| (floor.name.clone(), ScenarioState::NotStarted))
+        .collect();
+    gm.all_scenarios = floors;
+    drop(sm);
+    set_run(server_name, Some(run));
+    true
+}
+
+/// Makes the bosses of the floor that just started on `server_name` as strong
+/// as its depth. Call it once per floor, right after it is loaded; regular
+/// games are left as they are.
+#[cfg(feature = "server")]
+pub fn scale_floor_bosses(server_name: &str, core_game_data: &mut CoreGameData) {
+    use crate::{endless::depth_multiplier, websocket_handler::event_difficulty::scale_bosses};
+    use lib_rpg::common::constants::stats_const::{HP, MAGICAL_POWER, PHYSICAL_POWER};
+
+    let Some(run) = run_of(server_name) else {
+        return;
+    };
+    scale_bosses(
+        core_game_data,
+        &[HP, PHYSICAL_POWER, MAGICAL_POWER],
+        depth_multiplier(run.depth),
+    );
+}
+
+/// Ends the floor of the run on `server_name`: a won floor offers its rewards,
+/// a lost one ends the run, which is returned to be recorded.
+#[cfg(feature = "server")]
+pub fn on_floor_end(server_name: &str, won: bool) -> Option<EndlessRun> {
+    use crate::{common::DATA_MANAGER, endless::offer_rewards};
+    use lib_rpg::character_mod::loot::LootType;
+
+    // Shop items from the cheapest to the most expensive
+    let (equipments, consumables) = {
+        let dm = DATA_MANAGER.lock().unwrap();
+        let mut catalog: Vec<_> = dm.shop_catalog.iter().collect();
+        catalog.sort_by_key(|item| item.price);
+        let names = |kind: LootType| -> Vec<String> {
+            catalog
+                .iter()
+                .filter(|item| item.kind == kind)
+                .map(|item| item.name.clone())
+                .collect()
+        };
+        (names(LootType::Equipment), names(LootType::Consumable))
+    };
+
+    let mut runs = lock_runs();
+    let run = runs.get_mut(server_name)?;
+    if won {
+        let rewards = offer_rewards(run.seed, run.depth, &equipments, &consumables);
+        run.clear_floor(rewards);
+        None
+    } else {
+        run.is_over = true;
+        Some(run.clone())
+    }
+}
+
+/// Gives `reward` to `character`, for free.
+#[cfg(feature = "server")]
+fn give_reward(
+    character: &mut lib_rpg::character_mod::character::Character,
+    reward: &EndlessReward,
+    equipment: Option<&lib_rpg::character_mod::equipment::Equipment>,
+) {
+    use dioxus::logger::tracing;
+    use lib_rpg::shop::build_consumable_by_name;
+
+    match reward {
+        EndlessReward::Equipment(_) => match equipment {
+            Some(e) => {
+                if let Err(err) = character.inventory.buy_equipment(e, 0) {
+                    tracing::warn!(
+                        "Cannot give {} to {}: {}",
+                        e.unique_name,
+                        character.id_name,
+                        err
+                    );
+                }
+            }
+            None => tracing::warn!("Unknown endless reward {:?}", reward),
+        },
+        EndlessReward::Consumable(name) => match build_consumable_by_name(name) {
+            Some(c) => {
+                if let Err(err) = character.inventory.buy_consumable(c, 0) {
+                    tracing::warn!("Cannot give {} to {}: {}", name, character.id_name, err);
+                }
+            }
+            None => tracing::warn!("Unknown endless reward {:?}", reward),
+        },
+        // On top of the points of the hero's level
+        EndlessReward::TalentPoints(points) => character.talents.grant_points(*points),
+    }
+}
+
+/// The owner of `server_name` picks the reward `index` of the floor just
+/// cleared: every hero of the party gets it, and the next floor starts.
+#[cfg(feature = "server")]
+pub async fn pick_reward(server_name: &str, player_name: &str, index: usize) {
+    use crate::{
+        common::DATA_MANAGER,
+        websocket_handler::{common_event::lock_server_manager, event::process_load_next_scenario},
+    };
+    use dioxus::logger::tracing;
+    use lib_rpg::server::scenario::ScenarioState;
+
+    let is_owner = lock_server_manager()
+        .servers_data
+        .get(server_name)
+        .is_some_and(|sd| sd.players_data.owner_player_name == player_name);
+    if !is_owner {
+        tracing::warn!(
+            "{} cannot pick the endless reward of server {}",
+            player_name,
+            server_name
+        );
+        return;
+    }
+    let Some((run, reward)) = lock_runs().get_mut(server_name).and_then(|run| {
+        let reward = run.pick_reward(index)?;
+        Some((run.clone(), reward))
+    }) else {
+        tracing::warn!(
+            "No endless reward {} to pick on server {}",
+            index,
+            server_name
+        );
+        return;
+    };
+
+    // The floor after the one starting now, so that winning it doesn't end the game
+    let (next_floor, equipment) = {
+        let dm = DATA_MANAGER.lock().unwrap();
+        let equipment = match &reward {
+            EndlessReward::Equipment(name) => dm
+                .equipment_table
+                .values()
+                .flatten()
+                .find(|e| e.unique_name == *name)
+                .cloned(),
+            _ => None,
+        };
+        (build_floor(&dm, &run, run.depth + 1), equipment)
+    };
+    {
+        let mut sm = lock_server_manager();
+        let Some(server_data) = sm.servers_data.get_mut(server_name) else {
+            return;
+        };
+        let pm = &mut server_data.core_game_data.game_manager.pm;
+        for hero in pm.active_heroes.iter_mut() {
+            give_reward(hero, &reward, equipment.as_ref());
+        }
+        // Keep the shadow copy of the hero playing now in sync
+        if pm
+            .active_heroes
+            .iter()
+            .any(|h| h.id_name == pm.current_player.id_name)
+        {
+            give_reward(&mut pm.current_player, &reward, equipment.as_ref());
+        }
+        let gm = &mut server_data.core_game_data.game_manager;
+        if let Some(floor) = next_floor {
+            gm.states_scenarios
+                .insert(floor.name.clone(), ScenarioState::NotStarted);
+            gm.all_scenarios.push(floor);
+        }
+    }
+    tracing::info!(
+        "Server {} picked {:?} and goes down to floor {}",
+        server_name,
+        reward,
+        run.depth
+    );
+    if let Err(e) = process_load_next_scenario(server_name, false).await {
+        tracing::error!(
+            "Cannot load floor {} of the endless run of server {}: {}",
+            run.depth,
+            server_name,
+            e
+        );
+    }
+}
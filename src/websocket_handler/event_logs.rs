@@ -0,0 +1,94 @@
+#[cfg(feature = "server")]
+use crate::server_messages::{LogMessages, SAVED_LOG_MESSAGES, ServerMessage};
+#[cfg(feature = "server")]
+use lib_rpg::{common::log_data::LogData, server::core_game_data::CoreGameData};
+#[cfg(feature = "server")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "server")]
+use std::{collections::HashMap, path::Path, sync::Mutex};
+
+/// Messages of the lines the server wrote in the log of each server's game.
+#[cfg(feature = "server")]
+static LOG_MESSAGES: Lazy<Mutex<HashMap<String, LogMessages>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[cfg(feature = "server")]
+fn lock_log_messages() -> std::sync::MutexGuard<'static, HashMap<String, LogMessages>> {
+    LOG_MESSAGES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(feature = "server")]
+pub fn log_messages_of(server_name: &str) -> LogMessages {
+    lock_log_messages()
+        .get(server_name)
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Runs `f` on the log messages of `server_name`, e.g. to file a marker line.
+#[cfg(feature = "server")]
+pub fn with_log_messages<T>(server_name: &str, f: impl FnOnce(&mut LogMessages) -> T) -> T {
+    f(lock_log_messages()
+        .entry(server_name.to_owned())
+        .or_default())
+}
+
+/// Drops the log messages of `server_name`, as its game is replaced or closed.
+#[cfg(feature = "server")]
+pub fn clear_log_messages(server_name: &str) {
+    lock_log_messages().remove(server_name);
+}
+
+/// Log line of `message` after `prefix` for the game of `server_name`, its
+/// message filed; the caller pushes it.
+#[cfg(feature = "server")]
+pub fn message_line(server_name: &str, prefix: &str, message: ServerMessage) -> LogData {
+    with_log_messages(server_name, |messages| messages.line(prefix, message, ""))
+}
+
+/// Pushes the line of `message` after `prefix` in the logs of `core_game_data`.
+#[cfg(feature = "server")]
+pub fn push_message(core_game_data: &mut CoreGameData, prefix: &str, message: ServerMessage) {
+    let line = message_line(&core_game_data.server_name, prefix, message);
+    core_game_data.game_manager.logs.push(line);
+}
+
+/// Banner text of `message` after `prefix` for the game of `server_name`, its
+/// message filed.
+#[cfg(feature = "server")]
+pub fn message_banner(server_name: &str, prefix: &str, message: ServerMessage) -> String {
+    with_log_messages(server_name, |messages| messages.banner(prefix, message))
+}
+
+/// Reads the log messages saved in `game_dir` for the game loaded on
+/// `server_name`. Saves made before they existed have none.
+#[cfg(feature = "server")]
+pub fn load_log_messages(server_name: &str, game_dir: &Path) {
+    let messages = std::fs::read_to_string(game_dir.join(SAVED_LOG_MESSAGES))
+        .ok()
+        .and_then(|s| serde_json::from_str::<LogMessages>(&s).ok())
+        .unwrap_or_default();
+    lock_log_messages().insert(server_name.to_owned(), messages);
+}
+
+/// Writes the log messages of the game on `server_name` in `game_dir`, next to
+/// its save.
+#[cfg(feature = "server")]
+pub async fn save_log_messages(server_name: &str, game_dir: &Path) {
+    use crate::utils::server_file_utils;
+    use dioxus::logger::tracing;
+
+    let messages = log_messages_of(server_name);
+    let Ok(value) = serde_json::to_string_pretty(&messages) else {
+        return;
+    };
+    if let Err(e) = server_file_utils::save(game_dir.join(SAVED_LOG_MESSAGES), value).await {
+        tracing::error!(
+            "Failed to save the log messages of server {}: {}",
+            server_name,
+            e
+        );
+    }
+}
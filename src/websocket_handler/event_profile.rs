@@ -0,0 +1,301 @@
+#[cfg(feature = "server")]
+use crate::auth_manager::server_fn::{PlayerShare, RunTotals, ScenarioRecord};
+#[cfg(feature = "server")]
+use crate::websocket_handler::common_event;
+#[cfg(feature = "server")]
+use lib_rpg::character_mod::stats_in_game::StatsInGame;
+#[cfg(feature = "server")]
+use lib_rpg::server::server_manager::ServerData;
+#[cfg(feature = "server")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "server")]
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Mutex,
+    time::Instant,
+};
+
+/// (uses, damage, healing) per (hero id_name, attack name).
+#[cfg(feature = "server")]
+type AtkTotals = BTreeMap<(String, String), (i64, i64, i64)>;
+
+/// What was already recorded for the game of a server: `stats_in_game` may keep
+/// adding up across scenarios, so each scenario end only records the difference.
+#[cfg(feature = "server")]
+struct ProfileBaseline {
+    totals: AtkTotals,
+    since: Instant,
+    /// Potions used since the scenario started
+    potions: i64,
+    /// Tracked since the first turn of the scenario: the record is complete and
+    /// can enter the leaderboards
+    ranked: bool,
+    /// Totals of the universe run, while it is played from its first scenario
+    /// with ranked scenarios only
+    run: Option<RunTotals>,
+}
+
+#[cfg(feature = "server")]
+fn lock_baselines() -> std::sync::MutexGuard<'static, HashMap<String, ProfileBaseline>> {
+    PROFILE_BASELINES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(feature = "server")]
+static PROFILE_BASELINES: Lazy<Mutex<HashMap<String, ProfileBaseline>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[cfg(feature = "server")]
+fn atk_totals<'a>(
+    heroes: &[String],
+    stats_in_game: impl IntoIterator<Item = (&'a String, &'a StatsInGame)>,
+) -> AtkTotals {
+    let mut totals = AtkTotals::new();
+    for (id_name, stats) in stats_in_game {
+        if !heroes.contains(id_name) {
+            continue;
+        }
+        for atk in &stats.all_atk_info {
+            let entry = totals
+                .entry((id_name.clone(), atk.atk_name.clone()))
+                .or_default();
+            entry.0 += atk.nb_use;
+            for t in atk.totals_by_target.values() {
+                entry.1 += t.total_real_dmg.abs();
+                entry.2 += t.total_real_heal;
+            }
+        }
+    }
+    totals
+}
+
+/// `current - baseline`, per attack. An attack used fewer times than in the
+/// baseline means the stats were reset in between: it counts from zero.
+#[cfg(feature = "server")]
+fn totals_delta(current: &AtkTotals, baseline: &AtkTotals) -> AtkTotals {
+    current
+        .iter()
+        .map(|(key, &(uses, dmg, heal))| {
+            let delta = match baseline.get(key) {
+                Some(&(b_uses, b_dmg, b_heal)) if b_uses <= uses => {
+                    (uses - b_uses, dmg - b_dmg, heal - b_heal)
+                }
+                _ => (uses, dmg, heal),
+            };
+            (key.clone(), delta)
+        })
+        .filter(|(_, (uses, _, _))| *uses > 0)
+        .collect()
+}
+
+#[cfg(feature = "server")]
+fn hero_ids(server_data: &ServerData) -> Vec<String> {
+    server_data
+        .core_game_data
+        .game_manager
+        .pm
+        .active_heroes
+        .iter()
+        .map(|h| h.id_name.clone())
+        .collect()
+}
+
+/// Starts counting the profile statistics of the game on `server_name` from its
+/// current state (new, replayed or loaded game). Only a `new_game` starts at the
+/// first turn of its first scenario, so only its fights are ranked.
+#[cfg(feature = "server")]
+pub fn start_profile_tracking(server_name: &str, server_data: &ServerData, new_game: bool) {
+    let totals = atk_totals(
+        &hero_ids(server_data),
+        &server_data
+            .core_game_data
+            .game_manager
+            .game_state
+            .stats_in_game,
+    );
+    lock_baselines().insert(
+        server_name.to_owned(),
+        ProfileBaseline {
+            totals,
+            since: Instant::now(),
+            potions: 0,
+            ranked: new_game,
+            run: new_game.then(RunTotals::default),
+        },
+    );
+}
+
+/// Stops counting the profile statistics of `server_name`, as its game is closed.
+#[cfg(feature = "server")]
+pub fn stop_profile_tracking(server_name: &str) {
+    lock_baselines().remove(server_name);
+}
+
+/// The next scenario of the game on `server_name` starts at its first turn.
+#[cfg(feature = "server")]
+pub fn mark_scenario_start(server_name: &str) {
+    if let Some(baseline) = lock_baselines().get_mut(server_name) {
+        baseline.since = Instant::now();
+        baseline.potions = 0;
+        baseline.ranked = true;
+    }
+}
+
+/// A potion was drunk during the fight on `server_name`.
+#[cfg(feature = "server")]
+pub fn count_potion_use(server_name: &str) {
+    if let Some(baseline) = lock_baselines().get_mut(server_name) {
+        baseline.potions += 1;
+    }
+}
+
+/// Merges the (attack, uses, damage, healing) of `from` into `into`.
+#[cfg(feature = "server")]
+fn add_attacks<'a>(
+    into: &mut BTreeMap<&'a str, (i64, i64, i64)>,
+    from: &BTreeMap<&'a str, (i64, i64, i64)>,
+) {
+    for (name, (uses, dmg, heal)) in from {
+        let entry = into.entry(*name).or_default();
+        entry.0 += uses;
+        entry.1 += dmg;
+        entry.2 += heal;
+    }
+}
+
+#[cfg(feature = "server")]
+fn attack_rows(attacks: BTreeMap<&str, (i64, i64, i64)>) -> Vec<(String, i64, i64, i64)> {
+    attacks
+        .into_iter()
+        .map(|(name, (uses, dmg, heal))| (name.to_owned(), uses, dmg, heal))
+        .collect()
+}
+
+/// What the scenario that just ended on `server_name` adds to the profiles of
+/// its players: the record of the party, and the share of each player in it.
+#[cfg(feature = "server")]
+pub fn take_scenario_record(
+    server_name: &str,
+    server_data: &ServerData,
+) -> (Vec<PlayerShare>, ScenarioRecord) {
+    use lib_rpg::server::game_state::GameStatus;
+
+    let gm = &server_data.core_game_data.game_manager;
+    let heroes = &gm.pm.active_heroes;
+    let current = atk_totals(&hero_ids(server_data), &gm.game_state.stats_in_game);
+    let previous = lock_baselines().remove(server_name);
+    let delta = totals_delta(
+        &current,
+        previous.as_ref().map_or(&AtkTotals::new(), |b| &b.totals),
+    );
+
+    let won = !heroes.iter().all(|h| h.stats.is_dead().unwrap_or(false));
+    let mut record = ScenarioRecord {
+        universe: server_data.core_game_data.universe.clone(),
+        scenario: gm.current_scenario.name.clone(),
+        single_player: server_data.core_game_data.is_single_player,
+        won,
+        universe_completed: won && gm.game_state.status == GameStatus::EndOfGame,
+        turns: i64::try_from(gm.game_state.current_turn_nb).unwrap_or(i64::MAX),
+        duration_secs: previous.as_ref().map_or(0, |b| {
+            i64::try_from(b.since.elapsed().as_secs()).unwrap_or(i64::MAX)
+        }),
+        potions_used: previous.as_ref().map_or(0, |b| b.potions),
+        ranked: won && previous.as_ref().is_some_and(|b| b.ranked),
+        ..Default::default()
+    };
+    let mut shares: BTreeMap<String, (PlayerShare, BTreeMap<&str, (i64, i64, i64)>)> =
+        common_event::player_accounts(server_data)
+            .into_iter()
+            .map(|player| {
+                let share = PlayerShare {
+                    player: player.clone(),
+                    ..Default::default()
+                };
+                (player, (share, BTreeMap::new()))
+            })
+            .collect();
+    let mut attacks: BTreeMap<&str, (i64, i64, i64)> = BTreeMap::new();
+    for hero in heroes {
+        let (mut damage, mut healing) = (0, 0);
+        let mut hero_attacks: BTreeMap<&str, (i64, i64, i64)> = BTreeMap::new();
+        for ((_, atk_name), &(uses, dmg, heal)) in
+            delta.iter().filter(|((id, _), _)| *id == hero.id_name)
+        {
+            damage += dmg;
+            healing += heal;
+            hero_attacks.insert(atk_name, (uses, dmg, heal));
+        }
+        add_attacks(&mut attacks, &hero_attacks);
+        let row = (hero.db_full_name.clone(), damage, healing);
+        // In multiplayer, each player is credited with their own heroes only
+        if let Some((share, share_attacks)) = common_event::player_of(server_data, &hero.id_name)
+            .and_then(|player| shares.get_mut(&player))
+        {
+            share.heroes.push(row.clone());
+            add_attacks(share_attacks, &hero_attacks);
+        }
+        record.heroes.push(row);
+    }
+    record.attacks = attack_rows(attacks);
+    let shares = shares
+        .into_values()
+        .map(|(mut share, share_attacks)| {
+            share.attacks = attack_rows(share_attacks);
+            share
+        })
+        .collect();
+
+    // A lost or unranked scenario ends the ranked run
+    let mut run = previous
+        .and_then(|b| b.run)
+        .filter(|_| record.ranked)
+        .map(|mut run| {
+            run.turns += record.turns;
+            run.damage += record.heroes.iter().map(|(_, d, _)| d).sum::<i64>();
+            run.potions += record.potions_used;
+            run
+        });
+    if record.universe_completed {
+        record.universe_run = run.take();
+    }
+    lock_baselines().insert(
+        server_name.to_owned(),
+        ProfileBaseline {
+            totals: current,
+            since: Instant::now(),
+            potions: 0,
+            ranked: false,
+            run,
+        },
+    );
+
+    (shares, record)
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_new_uses_are_recorded() {
+        let key = |atk: &str| ("Elara_#1".to_owned(), atk.to_owned());
+        let baseline = AtkTotals::from([(key("Feu"), (2, 40, 0)), (key("Soin"), (5, 0, 50))]);
+        let current = AtkTotals::from([
+            (key("Feu"), (3, 70, 0)),
+            (key("Soin"), (1, 0, 10)),
+            (key("Glace"), (1, 15, 0)),
+        ]);
+        assert_eq!(
+            totals_delta(&current, &baseline),
+            AtkTotals::from([
+                (key("Feu"), (1, 30, 0)),
+                // fewer uses than recorded: the stats were reset
+                (key("Soin"), (1, 0, 10)),
+                (key("Glace"), (1, 15, 0)),
+            ])
+        );
+        assert!(totals_delta(&baseline, &baseline).is_empty());
+    }
+}
@@ -0,0 +1,484 @@
+#[cfg(feature = "server")]
+use crate::quests::{
+    Dialogue, DialogueAction, QuestBook, QuestEvent, QuestLog, QuestsUpdate, SAVED_QUESTS,
+};
+#[cfg(feature = "server")]
+use lib_rpg::server::{core_game_data::CoreGameData, server_manager::ServerData};
+#[cfg(feature = "server")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "server")]
+use std::{collections::HashMap, path::Path, sync::Mutex};
+
+/// Quest books of `offlines/quests/`, read once.
+#[cfg(feature = "server")]
+static QUEST_BOOK: Lazy<QuestBook> = Lazy::new(|| {
+    use crate::{common::OFFLINE_PATH, quests::QUESTS_DIR};
+
+    crate::quests::read_quest_books(&Path::new(OFFLINE_PATH).join(QUESTS_DIR))
+});
+
+/// Quest log of the party of each server.
+#[cfg(feature = "server")]
+static QUEST_LOGS: Lazy<Mutex<HashMap<String, QuestLog>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Dialogue going on in the overworld of a server, and the node it shows.
+#[cfg(feature = "server")]
+struct Session {
+    dialogue: Dialogue,
+    node: String,
+}
+
+#[cfg(feature = "server")]
+static SESSIONS: Lazy<Mutex<HashMap<String, Session>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[cfg(feature = "server")]
+fn lock_logs() -> std::sync::MutexGuard<'static, HashMap<String, QuestLog>> {
+    QUEST_LOGS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(feature = "server")]
+fn lock_sessions() -> std::sync::MutexGuard<'static, HashMap<String, Session>> {
+    SESSIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(feature = "server")]
+pub fn set_log(server_name: &str, log: QuestLog) {
+    lock_logs().insert(server_name.to_owned(), log);
+    end_dialogue(server_name);
+}
+
+/// Drops the quest log and the dialogue of `server_name`, as its game is closed.
+#[cfg(feature = "server")]
+pub fn clear_quests(server_name: &str) {
+    lock_logs().remove(server_name);
+    end_dialogue(server_name);
+}
+
+#[cfg(feature = "server")]
+pub fn log_of(server_name: &str) -> QuestLog {
+    lock_logs().get(server_name).cloned().unwrap_or_default()
+}
+
+/// Reads the quest log saved in `game_dir` for the game loaded on
+/// `server_name`. Saves made before quests existed have none.
+#[cfg(feature = "server")]
+pub fn load_quests(server_name: &str, game_dir: &Path) {
+    let log = std::fs::read_to_string(game_dir.join(SAVED_QUESTS))
+        .ok()
+        .and_then(|s| serde_json::from_str::<QuestLog>(&s).ok())
+        .unwrap_or_default();
+    set_log(server_name, log);
+}
+
+/// Writes the quest log of the party on `server_name` in `game_dir`, next to its save.
+#[cfg(feature = "server")]
+pub async fn save_quests(server_name: &str, game_dir: &Path) {
+    use crate::utils::server_file_utils;
+    use dioxus::logger::tracing;
+
+    let log = log_of(server_name);
+    let Ok(value) = serde_json::to_string_pretty(&log) else {
+        return;
+    };
+    if let Err(e) = server_file_utils::save(game_dir.join(SAVED_QUESTS), value).await {
+        tracing::error!(
+            "Failed to save the quest log of server {}: {}",
+            server_name,
+            e
+        );
+    }
+}
+
+/// Ends the dialogue going on in the overworld of `server_name`, if any; the
+/// caller clears the lines shown.
+#[cfg(feature = "server")]
+pub fn end_dialogue(server_name: &str) {
+    lock_sessions().remove(server_name);
+}
+
+/// Quests of `server_name` for its clients, with the choices of its dialogue.
+#[cfg(feature = "server")]
+pub fn quests_update_of(server_name: &str) -> QuestsUpdate {
+    let log = log_of(server_name);
+    let quests: Vec<_> = QUEST_BOOK
+        .quests
+        .iter()
+        .filter_map(|quest| Some((quest.clone(), log.quests.get(&quest.id)?.clone())))
+        .collect();
+    let choices = lock_sessions()
+        .get(server_name)
+        .and_then(|session| session.dialogue.node(&session.node))
+        .map(|node| {
+            node.offered(&log)
+                .into_iter()
+                .map(|choice| choice.text.clone())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    QuestsUpdate { quests, choices }
+}
+
+/// Pushes a quest line in the logs of the game.
+#[cfg(feature = "server")]
+fn push_log(core_game_data: &mut CoreGameData, message: crate::server_messages::ServerMessage) {
+    use crate::websocket_handler::event_logs;
+
+    event_logs::push_message(core_game_data, "📜", message);
+}
+
+/// Gives the rewards of the quest `quest_id`: gold and consumables to every
+/// hero of the party, materials to its stash.
+#[cfg(feature = "server")]
+fn reward(server_name: &str, core_game_data: &mut CoreGameData, quest_id: &str) {
+    use crate::{
+        crafting::add_material,
+        server_messages::ServerMessage,
+        websocket_handler::event_crafting::{materials_of, set_materials},
+    };
+    use dioxus::logger::tracing;
+    use lib_rpg::{character_mod::character::Character, shop::build_consumable_by_name};
+
+    let Some(quest) = QUEST_BOOK.quest(quest_id) else {
+        return;
+    };
+    let give = |character: &mut Character| {
+        character.inventory.money += quest.rewards.gold as _;
+        for name in &quest.rewards.consumables {
+            match build_consumable_by_name(name) {
+                Some(c) => {
+                    if let Err(err) = character.inventory.buy_consumable(c, 0) {
+                        tracing::warn!("Cannot give {} to {}: {}", name, character.id_name, err);
+                    }
+                }
+                None => tracing::warn!("Unknown consumable '{}' in quest {}", name, quest.id),
+            }
+        }
+    };
+    let pm = &mut core_game_data.game_manager.pm;
+    for hero in pm.active_heroes.iter_mut() {
+        give(hero);
+    }
+    // `current_player` is a shadow copy of the character playing now, written
+    // back over it at the end of its turn
+    if pm
+        .active_heroes
+        .iter()
+        .any(|h| h.id_name == pm.current_player.id_name)
+    {
+        give(&mut pm.current_player);
+    }
+    if !quest.rewards.materials.is_empty() {
+        let mut stash = materials_of(server_name);
+        for ingredient in &quest.rewards.materials {
+            add_material(&mut stash, &ingredient.material, ingredient.amount);
+        }
+        set_materials(server_name, stash);
+    }
+    tracing::info!("Quest '{}' completed on server {}", quest.id, server_name);
+    push_log(
+        core_game_data,
+        ServerMessage::QuestCompleted {
+            quest: quest.title.text.clone(),
+        },
+    );
+}
+
+/// Opens the locked doors of the current map whose conditions hold.
+#[cfg(feature = "server")]
+fn open_doors(core_game_data: &mut CoreGameData, log: &QuestLog) {
+    let Some(ow) = core_game_data.overworld.as_mut() else {
+        return;
+    };
+    let open: Vec<&str> = QUEST_BOOK.open_doors(&ow.map_id, log).collect();
+    ow.locked_doors
+        .retain(|door| !open.contains(&door.as_str()));
+}
+
+/// Records `event` in `log`, rewards the quests it completes and opens the
+/// doors they unlock.
+#[cfg(feature = "server")]
+fn progress(
+    server_name: &str,
+    core_game_data: &mut CoreGameData,
+    log: &mut QuestLog,
+    event: QuestEvent,
+) {
+    use crate::websocket_handler::event_crafting::materials_of;
+
+    let stash = materials_of(server_name);
+    for quest_id in log.record(&QUEST_BOOK, event, &stash) {
+        reward(server_name, core_game_data, &quest_id);
+    }
+    open_doors(core_game_data, log);
+}
+
+/// The party of `server_name` won the current scenario, its loots collected.
+#[cfg(feature = "server")]
+pub fn on_scenario_won(server_name: &str, core_game_data: &mut CoreGameData) {
+    let scenario = core_game_data.game_manager.current_scenario.name.clone();
+    let mut log = log_of(server_name);
+    progress(
+        server_name,
+        core_game_data,
+        &mut log,
+        QuestEvent::Won(&scenario),
+    );
+    lock_logs().insert(server_name.to_owned(), log);
+}
+
+/// The party of `server_name` entered the map of its overworld, leaving the
+/// dialogue going on.
+#[cfg(feature = "server")]
+pub fn on_map_entered(server_name: &str, core_game_data: &mut CoreGameData) {
+    end_dialogue(server_name);
+    let Some(map_id) = core_game_data
+        .overworld
+        .as_ref()
+        .map(|ow| ow.map_id.clone())
+    else {
+        return;
+    };
+    let mut log = log_of(server_name);
+    progress(
+        server_name,
+        core_game_data,
+        &mut log,
+        QuestEvent::Entered(&map_id),
+    );
+    lock_logs().insert(server_name.to_owned(), log);
+}
+
+/// Does `actions` of a dialogue. Returns the scenario of the fight one of
+/// them starts.
+#[cfg(feature = "server")]
+fn act(
+    server_name: &str,
+    core_game_data: &mut CoreGameData,
+    log: &mut QuestLog,
+    actions: &[DialogueAction],
+) -> Option<String> {
+    use crate::server_messages::ServerMessage;
+
+    let mut fight = None;
+    for action in actions {
+        match action {
+            DialogueAction::StartQuest(id) => {
+                let Some(quest) = QUEST_BOOK.quest(id) else {
+                    dioxus::logger::tracing::warn!("Dialogue starts unknown quest '{}'", id);
+                    continue;
+                };
+                if log.start(quest) {
+                    push_log(
+                        core_game_data,
+                        ServerMessage::QuestStarted {
+                            quest: quest.title.text.clone(),
+                        },
+                    );
+                    // The materials may be owned already
+                    progress(server_name, core_game_data, log, QuestEvent::Materials);
+                }
+            }
+            DialogueAction::SetFlag(flag) => {
+                log.flags.insert(flag.clone());
+                open_doors(core_game_data, log);
+            }
+            DialogueAction::Fight(scenario) => fight = Some(scenario.clone()),
+        }
+    }
+    fight
+}
+
+/// Shows the node `node` of `dialogue` and does its actions, or ends the
+/// dialogue when there is no such node or it starts a fight.
+#[cfg(feature = "server")]
+fn show_node(
+    server_name: &str,
+    core_game_data: &mut CoreGameData,
+    log: &mut QuestLog,
+    dialogue: Dialogue,
+    node: Option<&str>,
+    lang: &str,
+) {
+    let Some(shown) = node.and_then(|id| dialogue.node(id)).cloned() else {
+        close(server_name, core_game_data);
+        return;
+    };
+    if let Some(scenario) = act(server_name, core_game_data, log, &shown.actions) {
+        start_fight(server_name, core_game_data, &scenario);
+        return;
+    }
+    if let Some(ow) = core_game_data.overworld.as_mut() {
+        ow.active_dialog = shown
+            .lines
+            .iter()
+            .map(|line| line.get(lang).to_owned())
+            .collect();
+        ow.pending_fight = None;
+    }
+    lock_sessions().insert(
+        server_name.to_owned(),
+        Session {
+            dialogue,
+            node: shown.id,
+        },
+    );
+}
+
+#[cfg(feature = "server")]
+fn close(server_name: &str, core_game_data: &mut CoreGameData) {
+    end_dialogue(server_name);
+    if let Some(ow) = core_game_data.overworld.as_mut() {
+        ow.active_dialog.clear();
+        ow.pending_fight = None;
+    }
+}
+
+#[cfg(feature = "server")]
+fn start_fight(server_name: &str, core_game_data: &mut CoreGameData, scenario: &str) {
+    use crate::websocket_handler::event_difficulty;
+
+    close(server_name, core_game_data);
+    core_game_data.exit_overworld_to_fight(scenario);
+    event_difficulty::scale_new_scenario(server_name, core_game_data);
+    dioxus::logger::tracing::info!(
+        "Dialogue started the fight '{}' on server {}",
+        scenario,
+        server_name
+    );
+}
+
+/// NPC standing next to the party of `server_data`, or on its tile.
+#[cfg(feature = "server")]
+fn npc_next_to_party(server_data: &ServerData) -> Option<String> {
+    let owner_name = &server_data.players_data.owner_player_name;
+    let hero_id = server_data
+        .players_data
+        .players_info
+        .get(owner_name)?
+        .character_id_names
+        .first()?;
+    let ow = server_data.core_game_data.overworld.as_ref()?;
+    let pos = ow.player_positions.get(hero_id)?;
+    ow.npcs
+        .iter()
+        .filter(|npc| !npc.defeated)
+        .find(|npc| npc.pos.x.abs_diff(pos.x) + npc.pos.y.abs_diff(pos.y) <= 1)
+        .map(|npc| npc.id.clone())
+}
+
+/// The owner of `server_name` talks to the NPC next to the party. Talking to
+/// an NPC counts for the quests; those with a dialogue tree play it, the
+/// others keep their linear dialog. While a node offers choices, one of them
+/// must be picked; otherwise talking again ends the dialogue. Returns `false`
+/// when the linear dialog of the map is to be played.
+#[cfg(feature = "server")]
+pub fn interact(server_name: &str, player_name: &str, lang: &str) -> bool {
+    use crate::websocket_handler::common_event::lock_server_manager;
+
+    let mut sm = lock_server_manager();
+    let Some(server_data) = sm.servers_data.get_mut(server_name) else {
+        return false;
+    };
+    if player_name != server_data.players_data.owner_player_name {
+        return false;
+    }
+    let mut log = log_of(server_name);
+    let session = lock_sessions()
+        .get(server_name)
+        .map(|session| (session.dialogue.clone(), session.node.clone()));
+    if let Some((dialogue, node)) = session {
+        let has_choices = dialogue
+            .node(&node)
+            .is_some_and(|node| !node.offered(&log).is_empty());
+        if !has_choices {
+            close(server_name, &mut server_data.core_game_data);
+        }
+        return true;
+    }
+    let Some(ow) = server_data.core_game_data.overworld.as_ref() else {
+        return false;
+    };
+    // The linear dialog of the map is paging
+    if !ow.active_dialog.is_empty() {
+        return false;
+    }
+    let map_id = ow.map_id.clone();
+    let Some(npc) = npc_next_to_party(server_data) else {
+        return false;
+    };
+    let core_game_data = &mut server_data.core_game_data;
+    progress(
+        server_name,
+        core_game_data,
+        &mut log,
+        QuestEvent::TalkedTo {
+            map: &map_id,
+            npc: &npc,
+        },
+    );
+    let handled = match QUEST_BOOK.dialogue_of(&map_id, &npc, &log).cloned() {
+        Some(dialogue) => {
+            let start = dialogue.start.clone();
+            show_node(
+                server_name,
+                core_game_data,
+                &mut log,
+                dialogue,
+                Some(start.as_str()),
+                lang,
+            );
+            true
+        }
+        None => false,
+    };
+    lock_logs().insert(server_name.to_owned(), log);
+    handled
+}
+
+/// The owner of `server_name` picks the choice `index` of those offered by
+/// the dialogue going on. Returns `false` when nothing changed.
+#[cfg(feature = "server")]
+pub fn choose(server_name: &str, player_name: &str, index: usize, lang: &str) -> bool {
+    use crate::websocket_handler::common_event::lock_server_manager;
+
+    let mut sm = lock_server_manager();
+    let Some(server_data) = sm.servers_data.get_mut(server_name) else {
+        return false;
+    };
+    if player_name != server_data.players_data.owner_player_name {
+        return false;
+    }
+    let Some((dialogue, node)) = lock_sessions()
+        .get(server_name)
+        .map(|session| (session.dialogue.clone(), session.node.clone()))
+    else {
+        return false;
+    };
+    let mut log = log_of(server_name);
+    let Some(choice) = dialogue.node(&node).and_then(|node| {
+        node.offered(&log)
+            .get(index)
+            .map(|choice| (*choice).clone())
+    }) else {
+        return false;
+    };
+    let core_game_data = &mut server_data.core_game_data;
+    match act(server_name, core_game_data, &mut log, &choice.actions) {
+        Some(scenario) => start_fight(server_name, core_game_data, &scenario),
+        None => show_node(
+            server_name,
+            core_game_data,
+            &mut log,
+            dialogue,
+            choice.next.as_deref(),
+            lang,
+        ),
+    }
+    lock_logs().insert(server_name.to_owned(), log);
+    true
+}
@@ -1,15 +1,17 @@
 #[cfg(feature = "server")]
 use crate::common::DATA_MANAGER;
 #[cfg(feature = "server")]
+use crate::server_messages::ServerMessage;
+#[cfg(feature = "server")]
 use crate::websocket_handler::common_event::lock_server_manager;
 #[cfg(feature = "server")]
+use crate::websocket_handler::{event_achievements, event_logs};
+#[cfg(feature = "server")]
 use dioxus::logger::tracing;
 #[cfg(feature = "server")]
 use lib_rpg::common::log_data::LogData;
 #[cfg(feature = "server")]
 use lib_rpg::shop::{build_consumable_by_name, sell_price};
-#[cfg(feature = "server")]
-use lib_rpg::utils;
 
 /// Buy an item (equipment or consumable) for a character.
 /// Deducts the catalog price from the character's money and adds the item to the bag.
@@ -70,13 +72,15 @@ pub fn buy_item_handler(
                     item_name,
                     price
                 );
-                purchase_log = Some(LogData {
-                    message: utils::format_string_with_timestamp(&format!(
-                        "🛒 {} bought {} for {} gold",
-                        character_id_name, item_name, price
-                    )),
-                    color: String::new(),
-                });
+                purchase_log = Some(event_logs::message_line(
+                    server_name,
+                    "🛒",
+                    ServerMessage::ItemBought {
+                        character: character_id_name.to_owned(),
+                        item: item_name.to_owned(),
+                        price: price as i64,
+                    },
+                ));
             }
             Err(e) => tracing::warn!("buy_item_handler consumable: {}", e),
         }
@@ -102,13 +106,15 @@ pub fn buy_item_handler(
                     item_name,
                     price
                 );
-                purchase_log = Some(LogData {
-                    message: utils::format_string_with_timestamp(&format!(
-                        "🛒 {} bought {} for {} gold",
-                        character_id_name, item_name, price
-                    )),
-                    color: String::new(),
-                });
+                purchase_log = Some(event_logs::message_line(
+                    server_name,
+                    "🛒",
+                    ServerMessage::ItemBought {
+                        character: character_id_name.to_owned(),
+                        item: item_name.to_owned(),
+                        price: price as i64,
+                    },
+                ));
             }
             Err(e) => tracing::warn!("buy_item_handler equipment: {}", e),
         }
@@ -193,13 +199,15 @@ pub fn sell_item_handler(
                     item_name,
                     refund
                 );
-                sale_log = Some(LogData {
-                    message: utils::format_string_with_timestamp(&format!(
-                        "💰 {} sold {} for {} gold",
-                        character_id_name, item_name, refund
-                    )),
-                    color: String::new(),
-                });
+                sale_log = Some(event_logs::message_line(
+                    server_name,
+                    "💰",
+                    ServerMessage::ItemSold {
+                        character: character_id_name.to_owned(),
+                        item: item_name.to_owned(),
+                        price: refund as i64,
+                    },
+                ));
             }
             Err(_) => {
                 if let Some(idx) = pm
@@ -215,13 +223,15 @@ pub fn sell_item_handler(
                         item_name,
                         refund
                     );
-                    sale_log = Some(LogData {
-                        message: utils::format_string_with_timestamp(&format!(
-                            "💰 {} sold {} for {} gold",
-                            character_id_name, item_name, refund
-                        )),
-                        color: String::new(),
-                    });
+                    sale_log = Some(event_logs::message_line(
+                        server_name,
+                        "💰",
+                        ServerMessage::ItemSold {
+                            character: character_id_name.to_owned(),
+                            item: item_name.to_owned(),
+                            price: refund as i64,
+                        },
+                    ));
                 } else {
                     tracing::warn!(
                         "sell_item_handler: '{}' not found in personal or party inventory",
@@ -240,13 +250,15 @@ pub fn sell_item_handler(
                     item_name,
                     refund
                 );
-                sale_log = Some(LogData {
-                    message: utils::format_string_with_timestamp(&format!(
-                        "💰 {} sold {} for {} gold",
-                        character_id_name, item_name, refund
-                    )),
-                    color: String::new(),
-                });
+                sale_log = Some(event_logs::message_line(
+                    server_name,
+                    "💰",
+                    ServerMessage::ItemSold {
+                        character: character_id_name.to_owned(),
+                        item: item_name.to_owned(),
+                        price: refund as i64,
+                    },
+                ));
             }
             Err(e) => tracing::warn!("sell_item_handler equipment: {}", e),
         }
@@ -255,4 +267,7 @@ pub fn sell_item_handler(
     if let Some(entry) = sale_log {
         server_data.core_game_data.game_manager.logs.push(entry);
     }
+    let credits = event_achievements::gold_credits(server_data);
+    drop(sm);
+    event_achievements::spawn_achievement_credits(server_name, credits);
 }
@@ -12,9 +12,13 @@ use lib_rpg::server::server_manager::ServerManager;
 /// failures are logged and simply don't change server state.
 #[cfg(feature = "server")]
 pub fn request_unlock_talent(server_name: &str, character_id_name: &str, talent_id: &str) {
+    use crate::websocket_handler::event_achievements::{
+        completed_talent_paths, spawn_achievement_credits, talent_path_credits,
+    };
     use lib_rpg::character_mod::character::Character;
 
     let mut sm: std::sync::MutexGuard<'_, ServerManager> = lock_server_manager();
+    let mut credits = Vec::new();
 
     let apply = |character: &mut Character, tree: &lib_rpg::character_mod::talent::TalentTree| {
         if let Err(e) = character.unlock_talent(talent_id, tree) {
@@ -45,8 +49,11 @@ pub fn request_unlock_talent(server_name: &str, character_id_name: &str, talent_
             return;
         };
 
+        let completed_before = completed_talent_paths(&tree, &character.talents.unlocked);
+        let mut completed_after = completed_before;
         if let Some(character) = pm.get_mut_active_hero_character(character_id_name) {
             apply(character, &tree);
+            completed_after = completed_talent_paths(&tree, &character.talents.unlocked);
         }
         // `current_player` is a shadow working copy of the hero whose turn it is;
         // an attack writes it back over `active_heroes` (modify_active_character).
@@ -54,8 +61,14 @@ pub fn request_unlock_talent(server_name: &str, character_id_name: &str, talent_
         if pm.current_player.id_name == character_id_name {
             apply(&mut pm.current_player, &tree);
         }
+        credits = talent_path_credits(
+            server_data,
+            character_id_name,
+            completed_after - completed_before,
+        );
     }
     drop(sm);
+    spawn_achievement_credits(server_name, credits);
     update_clients_server_data(server_name);
 }
 
@@ -0,0 +1,358 @@
+#[cfg(feature = "server")]
+use crate::trading::{TradeItemKind, TradeOffer, TradeProposal, tradable_items};
+#[cfg(feature = "server")]
+use lib_rpg::{character_mod::character::Character, server::server_manager::ServerData};
+#[cfg(feature = "server")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "server")]
+use std::{
+    collections::HashMap,
+    sync::{
+        Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+/// Trades waiting for an answer on each server.
+#[cfg(feature = "server")]
+static TRADES: Lazy<Mutex<HashMap<String, Vec<TradeProposal>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[cfg(feature = "server")]
+static NEXT_TRADE_ID: AtomicU64 = AtomicU64::new(1);
+
+#[cfg(feature = "server")]
+fn lock_trades() -> std::sync::MutexGuard<'static, HashMap<String, Vec<TradeProposal>>> {
+    TRADES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(feature = "server")]
+pub fn trades_of(server_name: &str) -> Vec<TradeProposal> {
+    lock_trades().get(server_name).cloned().unwrap_or_default()
+}
+
+/// Drops the trades of `server_name`, for a new or loaded game.
+#[cfg(feature = "server")]
+pub fn clear_trades(server_name: &str) {
+    lock_trades().remove(server_name);
+}
+
+/// Item or consumable taken out of a bag, to be put in another one.
+#[cfg(feature = "server")]
+enum Moved {
+    Equipment(Box<lib_rpg::character_mod::equipment::Equipment>),
+    Consumable(String),
+}
+
+/// Takes `offer` out of the inventory of `character`.
+#[cfg(feature = "server")]
+fn take(character: &mut Character, offer: &TradeOffer) -> Result<Vec<Moved>, String> {
+    let mut moved = Vec::new();
+    for item in &offer.items {
+        match item.kind {
+            TradeItemKind::Equipment => {
+                let equipment = character
+                    .inventory
+                    .equipments
+                    .values()
+                    .flatten()
+                    .find(|e| e.unique_name == item.name && !e.is_equipped)
+                    .cloned()
+                    .ok_or_else(|| format!("'{}' is not in the bag", item.name))?;
+                character
+                    .inventory
+                    .sell_equipment(&item.name, 0)
+                    .map_err(|e| e.to_string())?;
+                moved.push(Moved::Equipment(Box::new(equipment)));
+            }
+            TradeItemKind::Consumable => {
+                character
+                    .inventory
+                    .sell_consumable(&item.name, 0)
+                    .map_err(|e| e.to_string())?;
+                moved.push(Moved::Consumable(item.name.clone()));
+            }
+        }
+    }
+    character.inventory.money -= offer.gold as _;
+    Ok(moved)
+}
+
+/// Puts `moved` and `gold` in the inventory of `character`.
+#[cfg(feature = "server")]
+fn put(character: &mut Character, moved: &[Moved], gold: u64) -> Result<(), String> {
+    use lib_rpg::shop::build_consumable_by_name;
+
+    for item in moved {
+        match item {
+            Moved::Equipment(e) => character.inventory.buy_equipment(e, 0),
+            Moved::Consumable(name) => {
+                let consumable = build_consumable_by_name(name)
+                    .ok_or_else(|| format!("unknown consumable '{name}'"))?;
+                character.inventory.buy_consumable(consumable, 0)
+            }
+        }
+        .map_err(|e| format!("{}: {}", character.id_name, e))?;
+    }
+    character.inventory.money += gold as _;
+    Ok(())
+}
+
+/// Hero `a` hands `give_a` to hero `b`, which hands `give_b` back. The moves
+/// are made on copies of the heroes, written back only once every one of them
+/// succeeded, so the trade happens whole or not at all.
+#[cfg(feature = "server")]
+fn exchange(
+    server_data: &mut ServerData,
+    a: &str,
+    give_a: &TradeOffer,
+    b: &str,
+    give_b: &TradeOffer,
+) -> Result<(), String> {
+    let pm = &mut server_data.core_game_data.game_manager.pm;
+    let index_of = |id: &str| {
+        pm.active_heroes
+            .iter()
+            .position(|h| h.id_name == id)
+            .ok_or_else(|| format!("no hero '{id}'"))
+    };
+    let (ia, ib) = (index_of(a)?, index_of(b)?);
+    for (i, offer) in [(ia, give_a), (ib, give_b)] {
+        let hero = &pm.active_heroes[i];
+        offer
+            .check(&tradable_items(hero), hero.inventory.money as _)
+            .map_err(|e| format!("{}: {}", hero.id_name, e))?;
+    }
+
+    let mut hero_a = pm.active_heroes[ia].clone();
+    let mut hero_b = pm.active_heroes[ib].clone();
+    let moved_a = take(&mut hero_a, give_a)?;
+    let moved_b = take(&mut hero_b, give_b)?;
+    put(&mut hero_b, &moved_a, give_a.gold)?;
+    put(&mut hero_a, &moved_b, give_b.gold)?;
+    // `current_player` is a shadow copy of the character playing now, written
+    // back over it at the end of its turn: it must follow the trade too
+    let mut shadow = pm.current_player.clone();
+    let shadow_moves = if shadow.id_name == a {
+        Some((give_a, &moved_b, give_b.gold))
+    } else if shadow.id_name == b {
+        Some((give_b, &moved_a, give_a.gold))
+    } else {
+        None
+    };
+    if let Some((given, received, gold)) = shadow_moves {
+        take(&mut shadow, given)
+            .and_then(|_| put(&mut shadow, received, gold))
+            .map_err(|e| format!("current player out of sync: {e}"))?;
+        pm.current_player = shadow;
+    }
+    pm.active_heroes[ia] = hero_a;
+    pm.active_heroes[ib] = hero_b;
+    Ok(())
+}
+
+#[cfg(feature = "server")]
+fn push_log(server_data: &mut ServerData, message: crate::server_messages::ServerMessage) {
+    use crate::websocket_handler::event_logs;
+
+    event_logs::push_message(&mut server_data.core_game_data, "🤝", message);
+}
+
+/// `player_name` proposes `proposal` to the player of its `to_hero`, in a
+/// multiplayer game. Returns whether it now waits for an answer.
+#[cfg(feature = "server")]
+pub fn propose_trade(server_name: &str, player_name: &str, mut proposal: TradeProposal) -> bool {
+    use crate::websocket_handler::common_event::{self, lock_server_manager};
+    use dioxus::logger::tracing;
+
+    let to_player = {
+        let sm = lock_server_manager();
+        let Some(server_data) = sm.servers_data.get(server_name) else {
+            return false;
+        };
+        let from_player = common_event::player_of(server_data, &proposal.from_hero);
+        let to_player = common_event::player_of(server_data, &proposal.to_hero);
+        let check = || -> Result<String, String> {
+            if server_data.core_game_data.is_single_player {
+                return Err("trades are for multiplayer games".to_owned());
+            }
+            if from_player.as_deref() != Some(player_name) {
+                return Err(format!("{} is not their hero", proposal.from_hero));
+            }
+            let to_player = to_player
+                .clone()
+                .filter(|p| p != player_name)
+                .ok_or_else(|| format!("{} plays no other hero", proposal.to_hero))?;
+            if !proposal.is_valid() {
+                return Err("the trade moves nothing".to_owned());
+            }
+            let hero = server_data
+                .core_game_data
+                .game_manager
+                .pm
+                .get_active_hero_character(&proposal.from_hero)
+                .ok_or_else(|| format!("no hero '{}'", proposal.from_hero))?;
+            proposal
+                .give
+                .check(&tradable_items(hero), hero.inventory.money as _)?;
+            Ok(to_player)
+        };
+        match check() {
+            Ok(to_player) => to_player,
+            Err(e) => {
+                tracing::warn!(
+                    "{} cannot propose a trade on server {}: {}",
+                    player_name,
+                    server_name,
+                    e
+                );
+                return false;
+            }
+        }
+    };
+    proposal.id = NEXT_TRADE_ID.fetch_add(1, Ordering::SeqCst);
+    proposal.from_player = player_name.to_owned();
+    proposal.to_player = to_player;
+    tracing::info!(
+        "Trade {} proposed by {} to {} on server {}",
+        proposal.id,
+        proposal.from_player,
+        proposal.to_player,
+        server_name
+    );
+    lock_trades()
+        .entry(server_name.to_owned())
+        .or_default()
+        .push(proposal);
+    true
+}
+
+/// `player_name` accepts or declines the trade `trade_id` proposed to them;
+/// its proposer can only withdraw it. An accepted trade is checked again, as
+/// the bags may have changed since it was proposed.
+#[cfg(feature = "server")]
+pub fn answer_trade(server_name: &str, player_name: &str, trade_id: u64, accept: bool) -> bool {
+    use crate::{
+        server_messages::ServerMessage,
+        websocket_handler::common_event::{self, lock_server_manager},
+    };
+    use dioxus::logger::tracing;
+
+    let proposal = lock_trades().get_mut(server_name).and_then(|trades| {
+        let index = trades.iter().position(|t| {
+            t.id == trade_id
+                && (t.to_player == player_name || (!accept && t.from_player == player_name))
+        })?;
+        Some(trades.remove(index))
+    });
+    let Some(proposal) = proposal else {
+        tracing::warn!(
+            "{} cannot answer trade {} on server {}",
+            player_name,
+            trade_id,
+            server_name
+        );
+        return false;
+    };
+    if !accept {
+        tracing::info!(
+            "Trade {} dropped by {} on server {}",
+            trade_id,
+            player_name,
+            server_name
+        );
+        return true;
+    }
+
+    let mut sm = lock_server_manager();
+    let Some(server_data) = sm.servers_data.get_mut(server_name) else {
+        return true;
+    };
+    // The heroes may have changed hands since
+    if common_event::player_of(server_data, &proposal.from_hero).as_deref()
+        != Some(proposal.from_player.as_str())
+        || common_event::player_of(server_data, &proposal.to_hero).as_deref() != Some(player_name)
+    {
+        tracing::warn!("Trade {} no longer matches its heroes", trade_id);
+        return true;
+    }
+    match exchange(
+        server_data,
+        &proposal.from_hero,
+        &proposal.give,
+        &proposal.to_hero,
+        &proposal.ask,
+    ) {
+        Ok(()) => {
+            tracing::info!("Trade {} done on server {}", trade_id, server_name);
+            push_log(
+                server_data,
+                ServerMessage::TradeCompleted {
+                    from: proposal.from_hero.clone(),
+                    to: proposal.to_hero.clone(),
+                },
+            );
+        }
+        Err(e) => tracing::warn!("Trade {} failed: {}", trade_id, e),
+    }
+    true
+}
+
+/// In a single-player game, `player_name` moves `offer` from one of their
+/// heroes to another.
+#[cfg(feature = "server")]
+pub fn transfer_items(
+    server_name: &str,
+    player_name: &str,
+    from_hero: &str,
+    to_hero: &str,
+    offer: &TradeOffer,
+) -> bool {
+    use crate::{
+        server_messages::ServerMessage,
+        websocket_handler::common_event::{self, lock_server_manager},
+    };
+    use dioxus::logger::tracing;
+
+    let mut sm = lock_server_manager();
+    let Some(server_data) = sm.servers_data.get_mut(server_name) else {
+        return false;
+    };
+    let owns =
+        |hero: &str| common_event::player_of(server_data, hero).as_deref() == Some(player_name);
+    if !server_data.core_game_data.is_single_player
+        || !owns(from_hero)
+        || !owns(to_hero)
+        || from_hero == to_hero
+        || offer.is_empty()
+    {
+        tracing::warn!(
+            "{} cannot transfer items from {} to {} on server {}",
+            player_name,
+            from_hero,
+            to_hero,
+            server_name
+        );
+        return false;
+    }
+    if let Err(e) = exchange(
+        server_data,
+        from_hero,
+        offer,
+        to_hero,
+        &TradeOffer::default(),
+    ) {
+        tracing::warn!("Transfer from {} to {} failed: {}", from_hero, to_hero, e);
+        return false;
+    }
+    push_log(
+        server_data,
+        ServerMessage::ItemsGiven {
+            from: from_hero.to_owned(),
+            to: to_hero.to_owned(),
+        },
+    );
+    true
+}
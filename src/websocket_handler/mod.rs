@@ -1,7 +1,17 @@
 pub mod event;
+pub mod event_achievements;
+pub mod event_arena;
+pub mod event_crafting;
+pub mod event_daily_challenge;
+pub mod event_difficulty;
+pub mod event_endless;
 pub mod event_inventory;
+pub mod event_logs;
+pub mod event_profile;
+pub mod event_quests;
 pub mod event_store;
 pub mod event_talents;
+pub mod event_trading;
 pub mod msg_from_client;
 // Not public outside of this module
 #[cfg(feature = "server")]
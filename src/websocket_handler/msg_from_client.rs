@@ -5,13 +5,14 @@ use dioxus::{
 
 use crate::{
     common::SERVER_NAME,
-    websocket_handler::event::{ClientEvent, ServerEvent},
+    websocket_handler::event::{ClientEvent, GameMode, ServerEvent},
 };
 
 pub async fn send_initialize_game(
     user_name: &str,
     universe: &str,
     is_single_player: bool,
+    game_mode: GameMode,
     socket: UseWebsocket<ClientEvent, ServerEvent, CborEncoding>,
 ) {
     if user_name.is_empty() {
@@ -26,6 +27,7 @@ pub async fn send_initialize_game(
             user_name.to_string(),
             universe.to_string(),
             is_single_player,
+            game_mode,
         ))
         .await;
 }
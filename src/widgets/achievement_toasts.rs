@@ -0,0 +1,58 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::{
+    achievements::AchievementDef,
+    common::{CtxAchievementToasts, CtxAppLang},
+};
+
+/// How long an unlock toast stays on screen.
+const TOAST_DURATION_MS: u64 = 6000;
+
+/// Unlock notifications pushed by `App()` on `ServerEvent::AchievementsUnlocked`,
+/// stacked in a corner and dismissed after `TOAST_DURATION_MS` or on click.
+#[component]
+pub fn AchievementToasts() -> Element {
+    let CtxAchievementToasts(mut toasts) = use_context::<CtxAchievementToasts>();
+    let CtxAppLang(app_lang) = use_context::<CtxAppLang>();
+    let lang = app_lang();
+
+    // Each toast schedules its own removal when it arrives
+    let mut scheduled = use_signal(Vec::<String>::new);
+    use_effect(move || {
+        for def in toasts() {
+            if scheduled.peek().contains(&def.id) {
+                continue;
+            }
+            scheduled.write().push(def.id.clone());
+            spawn(async move {
+                dioxus_sdk_time::sleep(std::time::Duration::from_millis(TOAST_DURATION_MS)).await;
+                toasts.write().retain(|d| d.id != def.id);
+                scheduled.write().retain(|id| *id != def.id);
+            });
+        }
+    });
+
+    let dismiss = move |def: &AchievementDef| {
+        let id = def.id.clone();
+        move |_| toasts.write().retain(|d| d.id != id)
+    };
+
+    rsx! {
+        div { class: "achievement-toasts", role: "status", aria_live: "polite",
+            for def in toasts() {
+                div {
+                    key: "{def.id}",
+                    class: "achievement-toast",
+                    onclick: dismiss(&def),
+                    span { class: "achievement-toast-icon", "{def.icon}" }
+                    div { class: "achievement-toast-body",
+                        span { class: "achievement-toast-title", {t!("achievement-unlocked")} }
+                        span { class: "achievement-toast-name", "{def.name.get(&lang)}" }
+                        span { class: "achievement-toast-desc", "{def.description.get(&lang)}" }
+                    }
+                }
+            }
+        }
+    }
+}
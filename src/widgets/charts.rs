@@ -27,10 +27,28 @@ fn total_uses(atks: &[AtksInfo]) -> i64 {
     atks.iter().map(|a| a.nb_use).sum()
 }
 
+/// Adds `atks` into `merged`, summing uses and per-target totals of same-named attacks.
+pub fn merge_atks_info(merged: &mut Vec<AtksInfo>, atks: Vec<AtksInfo>) {
+    for atk_info in atks {
+        if let Some(existing) = merged.iter_mut().find(|a| a.atk_name == atk_info.atk_name) {
+            existing.nb_use += atk_info.nb_use;
+            for (target, totals) in &atk_info.totals_by_target {
+                let entry = existing.totals_by_target.entry(target.clone()).or_default();
+                entry.total_full_heal += totals.total_full_heal;
+                entry.total_real_heal += totals.total_real_heal;
+                entry.total_full_dmg += totals.total_full_dmg;
+                entry.total_real_dmg += totals.total_real_dmg;
+            }
+        } else {
+            merged.push(atk_info);
+        }
+    }
+}
+
 // ─── Summary cards ──────────────────────────────────────────────────────────
 
 #[component]
-fn StatCard(
+pub fn StatCard(
     label: String,
     value: String,
     color: String,
@@ -56,7 +74,7 @@ fn StatCard(
 // ─── Horizontal bar ─────────────────────────────────────────────────────────
 
 #[component]
-fn ProgressBar(label: String, value: i64, max: i64, color: String, suffix: String) -> Element {
+pub fn ProgressBar(label: String, value: i64, max: i64, color: String, suffix: String) -> Element {
     let pct = if max == 0 {
         0.0_f64
     } else {
@@ -188,7 +206,7 @@ fn AtkAmountTable(atks_info: Vec<AtksInfo>) -> Element {
 // ─── Full hero/party stats panel ────────────────────────────────────────────
 
 #[component]
-fn HeroStatsPanel(atks_info: Vec<AtksInfo>, rounds: usize) -> Element {
+pub fn HeroStatsPanel(atks_info: Vec<AtksInfo>, rounds: usize) -> Element {
     let dmg = total_real_dmg(&atks_info);
     let heal = total_real_heal(&atks_info);
     let uses = total_uses(&atks_info);
@@ -279,21 +297,7 @@ pub fn TabStats() -> Element {
                 .get(&hero.id_name)
                 .cloned()
                 .unwrap_or_default();
-            for atk_info in hero_info.all_atk_info {
-                if let Some(existing) = merged.iter_mut().find(|a| a.atk_name == atk_info.atk_name)
-                {
-                    existing.nb_use += atk_info.nb_use;
-                    for (target, totals) in &atk_info.totals_by_target {
-                        let entry = existing.totals_by_target.entry(target.clone()).or_default();
-                        entry.total_full_heal += totals.total_full_heal;
-                        entry.total_real_heal += totals.total_real_heal;
-                        entry.total_full_dmg += totals.total_full_dmg;
-                        entry.total_real_dmg += totals.total_real_dmg;
-                    }
-                } else {
-                    merged.push(atk_info);
-                }
-            }
+            merge_atks_info(&mut merged, hero_info.all_atk_info);
         }
         merged
     };
@@ -0,0 +1,101 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::{
+    common::{CtxGamepad, CtxHotkeys},
+    gamepad::{InputMode, PadAction},
+    hotkeys::{HotkeyAction, key_label},
+};
+
+/// Which controls the prompt bar describes.
+#[derive(Clone, Copy, PartialEq)]
+pub enum PromptContext {
+    Combat,
+    Overworld,
+}
+
+/// Bar of button prompts ("Ⓐ Confirm", "Enter Confirm"…). Shows the current key or
+/// pad bindings, following the device the player used last (`CtxGamepad::input_mode`).
+#[component]
+pub fn InputPrompts(context: PromptContext) -> Element {
+    let gamepad = use_context::<CtxGamepad>();
+    let hotkeys = use_context::<CtxHotkeys>().0;
+
+    let is_pad = (gamepad.input_mode)() == InputMode::Gamepad;
+    let prompts: Vec<(String, String)> = if is_pad {
+        let pads = (gamepad.bindings)();
+        let glyph = |actions: &[PadAction]| {
+            actions
+                .iter()
+                .map(|a| pads.button_of(*a).glyph())
+                .collect::<Vec<_>>()
+                .join("")
+        };
+        match context {
+            PromptContext::Combat => vec![
+                (
+                    glyph(&[PadAction::Up, PadAction::Down]),
+                    t!("prompt-attack"),
+                ),
+                (
+                    glyph(&[PadAction::Left, PadAction::Right]),
+                    t!("prompt-target"),
+                ),
+                (glyph(&[PadAction::Confirm]), t!("prompt-confirm")),
+                (glyph(&[PadAction::Cancel]), t!("prompt-cancel")),
+                (glyph(&[PadAction::ToggleAttacks]), t!("prompt-attacks")),
+                (glyph(&[PadAction::TogglePotions]), t!("prompt-potions")),
+                (glyph(&[PadAction::OpenMenu]), t!("prompt-menu")),
+            ],
+            PromptContext::Overworld => vec![
+                (
+                    glyph(&[
+                        PadAction::Up,
+                        PadAction::Down,
+                        PadAction::Left,
+                        PadAction::Right,
+                    ]),
+                    t!("prompt-move"),
+                ),
+                (glyph(&[PadAction::Confirm]), t!("prompt-interact")),
+                (glyph(&[PadAction::Cancel]), t!("prompt-dismiss")),
+                (glyph(&[PadAction::OpenMenu]), t!("prompt-menu")),
+            ],
+        }
+    } else {
+        let keys = hotkeys();
+        let key = |action: HotkeyAction| key_label(keys.key_of(action));
+        match context {
+            PromptContext::Combat => vec![
+                ("1-9".to_owned(), t!("prompt-attack")),
+                (
+                    format!("{} / ←→", key(HotkeyAction::NextTarget)),
+                    t!("prompt-target"),
+                ),
+                (key(HotkeyAction::Confirm), t!("prompt-confirm")),
+                (key(HotkeyAction::Cancel), t!("prompt-cancel")),
+                (key(HotkeyAction::ToggleAttacks), t!("prompt-attacks")),
+                (key(HotkeyAction::TogglePotions), t!("prompt-potions")),
+                (key(HotkeyAction::OpenMenu), t!("prompt-menu")),
+            ],
+            PromptContext::Overworld => vec![
+                ("WASD / ←↑→↓".to_owned(), t!("prompt-move")),
+                ("Enter / Space".to_owned(), t!("prompt-interact")),
+                (key(HotkeyAction::OpenMenu), t!("prompt-menu")),
+            ],
+        }
+    };
+
+    rsx! {
+        div {
+            class: if is_pad { "input-prompts input-prompts-pad" } else { "input-prompts" },
+            aria_hidden: "true",
+            for (glyph , label) in prompts {
+                span { class: "input-prompt",
+                    kbd { "{glyph}" }
+                    " {label}"
+                }
+            }
+        }
+    }
+}
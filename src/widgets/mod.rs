@@ -1,4 +1,6 @@
+pub mod achievement_toasts;
 pub mod alert_dialog;
 pub mod charts;
+pub mod input_prompts;
 pub mod tab_equipment;
 pub mod tab_talents;